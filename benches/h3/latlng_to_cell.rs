@@ -35,6 +35,45 @@ pub fn bench(c: &mut Criterion) {
     group.finish();
 }
 
+pub fn bench_batch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("latLngToCellBatch");
+
+    // Points spread over the globe, to cross many faces.
+    let points = (0..10_000_u32)
+        .map(|i| {
+            let i = f64::from(i);
+            ((i * 0.017).sin() * 80., (i * 0.031).sin() * 179.)
+        })
+        .collect::<Vec<_>>();
+    let resolution = Resolution::Nine;
+
+    group.bench_function("h3o/Batch", |b| {
+        let mut cells = Vec::with_capacity(points.len());
+        b.iter(|| {
+            cells.clear();
+            LatLng::to_cells_batch(
+                black_box(&points),
+                black_box(resolution),
+                &mut cells,
+            )
+            .expect("valid points");
+        });
+    });
+    group.bench_function("h3o/Loop", |b| {
+        let mut cells = Vec::with_capacity(points.len());
+        b.iter(|| {
+            cells.clear();
+            cells.extend(black_box(&points).iter().map(|&(lat, lng)| {
+                LatLng::new(lat, lng)
+                    .expect("valid point")
+                    .to_cell(black_box(resolution))
+            }));
+        });
+    });
+
+    group.finish();
+}
+
 // -----------------------------------------------------------------------------
 
 fn bench_h3o(b: &mut Bencher<'_>, ll: LatLng, resolution: u8) {
@@ -0,0 +1,367 @@
+use crate::{error::ResolutionMismatch, CellIndex, LatLng, Resolution};
+use std::{
+    collections::{hash_map::Entry, BTreeMap, HashMap},
+    hash::BuildHasher,
+};
+
+/// Aggregates values by cell, in a single pass.
+///
+/// Each point is indexed at the given resolution, and its value is folded
+/// into the accumulator of the corresponding cell (initialized with `init` on
+/// the first value of each cell).
+///
+/// The hasher of the resulting map is up to the caller, which makes it easy
+/// to use a faster one than the default.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{aggregate_by_cell, LatLng, Resolution};
+/// use std::collections::HashMap;
+///
+/// let points = [
+///     (LatLng::new(48.864716, 2.349014)?, 2.),
+///     (LatLng::new(48.864717, 2.349015)?, 3.),
+///     (LatLng::new(31.224361, 121.469170)?, 5.),
+/// ];
+/// let sums: HashMap<_, _> =
+///     aggregate_by_cell(points, Resolution::Nine, || 0., |sum, value| {
+///         *sum += value;
+///     });
+/// assert_eq!(sums.len(), 2);
+/// # Ok::<(), h3o::error::InvalidLatLng>(())
+/// ```
+pub fn aggregate_by_cell<T, Acc, S>(
+    points: impl IntoIterator<Item = (LatLng, T)>,
+    resolution: Resolution,
+    mut init: impl FnMut() -> Acc,
+    mut fold: impl FnMut(&mut Acc, T),
+) -> HashMap<CellIndex, Acc, S>
+where
+    S: BuildHasher + Default,
+{
+    let mut aggregates = HashMap::with_hasher(S::default());
+
+    for (ll, value) in points {
+        let acc = aggregates
+            .entry(ll.to_cell(resolution))
+            .or_insert_with(&mut init);
+        fold(acc, value);
+    }
+
+    aggregates
+}
+
+/// Merges per-cell aggregates into another one.
+///
+/// Accumulators of cells present in both maps are combined with `merge`,
+/// the others are moved as-is. This is the reduce step that goes along with
+/// [`aggregate_by_cell`], e.g. to combine partial aggregates computed in
+/// parallel.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{aggregate_by_cell, merge_by_cell, LatLng, Resolution};
+/// use std::collections::HashMap;
+///
+/// let ll = LatLng::new(48.864716, 2.349014)?;
+/// let mut counts: HashMap<_, _> =
+///     aggregate_by_cell([(ll, ())], Resolution::Nine, || 0, |n, ()| *n += 1);
+/// let other: HashMap<_, _> =
+///     aggregate_by_cell([(ll, ())], Resolution::Nine, || 0, |n, ()| *n += 1);
+///
+/// merge_by_cell(&mut counts, other, |n, other| *n += other);
+/// assert_eq!(counts.get(&ll.to_cell(Resolution::Nine)), Some(&2));
+/// # Ok::<(), h3o::error::InvalidLatLng>(())
+/// ```
+pub fn merge_by_cell<Acc, S1, S2>(
+    aggregates: &mut HashMap<CellIndex, Acc, S1>,
+    other: HashMap<CellIndex, Acc, S2>,
+    mut merge: impl FnMut(&mut Acc, Acc),
+) where
+    S1: BuildHasher,
+{
+    for (cell, value) in other {
+        match aggregates.entry(cell) {
+            Entry::Occupied(mut entry) => merge(entry.get_mut(), value),
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+            }
+        }
+    }
+}
+
+/// Aggregation function used to roll values up to coarser resolutions.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Aggregation {
+    /// Sum of the values.
+    Sum,
+    /// Mean of the values.
+    ///
+    /// The mean is computed over the original values (not as a mean of
+    /// means), so cells with fewer descendants (e.g. pentagons, or sparse
+    /// data) don't skew the result.
+    Mean,
+    /// Minimum of the values.
+    Min,
+    /// Maximum of the values.
+    Max,
+}
+
+/// Aggregates per-cell values at every coarser resolution, down to
+/// `coarsest`.
+///
+/// Every level is computed from the original values, using the given
+/// aggregation function. The resolution of the input values is not part of
+/// the pyramid.
+///
+/// # Errors
+///
+/// [`ResolutionMismatch`] if the cells don't share the same resolution, or if
+/// `coarsest` is finer than the resolution of the cells.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{build_pyramid, Aggregation, CellIndex, Resolution};
+/// use std::collections::HashMap;
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let values = index
+///     .children(Resolution::Eleven)
+///     .map(|cell| (cell, 1.))
+///     .collect::<HashMap<_, _>>();
+///
+/// let pyramid = build_pyramid(&values, Resolution::Nine, Aggregation::Sum)?;
+/// assert_eq!(pyramid[&Resolution::Ten][&index], 7.);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn build_pyramid<S>(
+    values: &HashMap<CellIndex, f64, S>,
+    coarsest: Resolution,
+    aggregation: Aggregation,
+) -> Result<BTreeMap<Resolution, HashMap<CellIndex, f64, S>>, ResolutionMismatch>
+where
+    S: BuildHasher + Default,
+{
+    let mut pyramid = BTreeMap::new();
+    let Some(resolution) = values.keys().next().map(|cell| cell.resolution())
+    else {
+        return Ok(pyramid);
+    };
+    if coarsest > resolution
+        || values.keys().any(|cell| cell.resolution() != resolution)
+    {
+        return Err(ResolutionMismatch);
+    }
+
+    let mut level = values
+        .iter()
+        .map(|(&cell, &value)| (cell, Summary::new(value)))
+        .collect::<HashMap<_, _, S>>();
+    for resolution in Resolution::range(coarsest, resolution).rev().skip(1) {
+        let mut parents = HashMap::with_hasher(S::default());
+        for (cell, summary) in level {
+            let parent = cell.parent(resolution).expect("coarser resolution");
+            parents
+                .entry(parent)
+                .and_modify(|acc: &mut Summary| acc.merge(summary))
+                .or_insert(summary);
+        }
+
+        let values = parents
+            .iter()
+            .map(|(&cell, summary)| (cell, summary.get(aggregation)))
+            .collect();
+        pyramid.insert(resolution, values);
+        level = parents;
+    }
+
+    Ok(pyramid)
+}
+
+/// Summary of a set of values, enough to compute every aggregation.
+#[derive(Clone, Copy, Debug)]
+struct Summary {
+    count: u64,
+    sum: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Summary {
+    const fn new(value: f64) -> Self {
+        Self {
+            count: 1,
+            sum: value,
+            min: value,
+            max: value,
+        }
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.count += other.count;
+        self.sum += other.sum;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    #[expect(clippy::cast_precision_loss, reason = "count is a small integer")]
+    fn get(&self, aggregation: Aggregation) -> f64 {
+        match aggregation {
+            Aggregation::Sum => self.sum,
+            Aggregation::Mean => self.sum / self.count as f64,
+            Aggregation::Min => self.min,
+            Aggregation::Max => self.max,
+        }
+    }
+}
+
+/// Resampling method used to convert per-cell values to another resolution.
+///
+/// Every method is defined in both directions: the first three are meant
+/// for coarsening and the last two for refining, but each of them falls back
+/// on its natural counterpart when used the other way around.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Resampling {
+    /// Mean of the values of the descendants (only those with a value).
+    ///
+    /// When refining, every child gets the value of its parent.
+    Mean,
+    /// Sum of the values of the descendants.
+    ///
+    /// When refining, behaves like [`Resampling::Split`].
+    Sum,
+    /// Most frequent value among the descendants (the smallest one on ties).
+    ///
+    /// When refining, every child gets the value of its parent.
+    Majority,
+    /// When refining, every child gets the value of its parent.
+    ///
+    /// When coarsening, the value of the descendant closest to the center of
+    /// the ancestor is used.
+    Nearest,
+    /// When refining, the value is evenly split between the children (which
+    /// accounts for pentagons having fewer children).
+    ///
+    /// When coarsening, behaves like [`Resampling::Sum`].
+    Split,
+}
+
+/// Converts per-cell values to another resolution.
+///
+/// This is useful to harmonize datasets indexed at different resolutions.
+///
+/// # Errors
+///
+/// [`ResolutionMismatch`] if the cells don't share the same resolution.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{resample, CellIndex, Resampling, Resolution};
+/// use std::collections::HashMap;
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let values = HashMap::from([(index, 7.)]);
+///
+/// let children: HashMap<_, _> =
+///     resample(&values, Resolution::Eleven, Resampling::Split)?;
+/// assert_eq!(children.len(), 7);
+/// assert!(children.values().all(|&value| value == 1.));
+///
+/// let parents: HashMap<_, _> =
+///     resample(&children, Resolution::Ten, Resampling::Sum)?;
+/// assert_eq!(parents, values);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn resample<S1, S2>(
+    values: &HashMap<CellIndex, f64, S1>,
+    resolution: Resolution,
+    method: Resampling,
+) -> Result<HashMap<CellIndex, f64, S2>, ResolutionMismatch>
+where
+    S2: BuildHasher + Default,
+{
+    let mut result = HashMap::with_hasher(S2::default());
+    let Some(source) = values.keys().next().map(|cell| cell.resolution())
+    else {
+        return Ok(result);
+    };
+    if values.keys().any(|cell| cell.resolution() != source) {
+        return Err(ResolutionMismatch);
+    }
+
+    if resolution >= source {
+        for (&cell, &value) in values {
+            let value =
+                if method == Resampling::Sum || method == Resampling::Split {
+                    #[expect(
+                        clippy::cast_precision_loss,
+                        reason = "children count is exact up to 2^53"
+                    )]
+                    let count = cell.children_count(resolution) as f64;
+                    value / count
+                } else {
+                    value
+                };
+            result
+                .extend(cell.children(resolution).map(|child| (child, value)));
+        }
+        return Ok(result);
+    }
+
+    let mut groups = HashMap::<_, Vec<_>>::new();
+    for (&cell, &value) in values {
+        let parent = cell.parent(resolution).expect("coarser resolution");
+        groups.entry(parent).or_default().push((cell, value));
+    }
+    result.extend(groups.into_iter().map(|(parent, mut group)| {
+        let value = match method {
+            Resampling::Sum | Resampling::Split => {
+                group.iter().map(|(_, value)| value).sum()
+            }
+            Resampling::Mean => {
+                #[expect(
+                    clippy::cast_precision_loss,
+                    reason = "group size is a small integer"
+                )]
+                let count = group.len() as f64;
+                group.iter().map(|(_, value)| value).sum::<f64>() / count
+            }
+            Resampling::Majority => majority(&mut group),
+            Resampling::Nearest => {
+                let center = LatLng::from(parent);
+                group
+                    .iter()
+                    .map(|&(cell, value)| {
+                        (LatLng::from(cell).distance_rads(center), value)
+                    })
+                    .min_by(|a, b| a.0.total_cmp(&b.0))
+                    .expect("non-empty group")
+                    .1
+            }
+        };
+        (parent, value)
+    }));
+
+    Ok(result)
+}
+
+/// Returns the most frequent value of a non-empty group (the smallest one on
+/// ties).
+fn majority(group: &mut [(CellIndex, f64)]) -> f64 {
+    group.sort_unstable_by(|a, b| a.1.total_cmp(&b.1));
+
+    let (mut best, mut best_count) = (group[0].1, 0);
+    for run in group.chunk_by(|a, b| a.1.total_cmp(&b.1).is_eq()) {
+        if run.len() > best_count {
+            (best, best_count) = (run[0].1, run.len());
+        }
+    }
+
+    best
+}
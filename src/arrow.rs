@@ -0,0 +1,141 @@
+//! Compute kernels over Arrow arrays of H3 cell indexes.
+//!
+//! Those kernels operate on whole `UInt64Array` at once, which allows query
+//! engines to push H3 operations down without paying for a scalar function
+//! call per row.
+//!
+//! Null inputs produce null outputs, and so do invalid cell indexes (or
+//! operations that fail for a given row): a kernel never fails because of the
+//! content of a row.
+
+use crate::{CellIndex, Resolution};
+use arrow_array::{BooleanArray, Int32Array, UInt64Array};
+use arrow_schema::ArrowError;
+
+/// Computes the parent, at the given resolution, of each cell index.
+///
+/// The output is null for null or invalid cell indexes, and for cells with a
+/// resolution coarser than `resolution`.
+///
+/// # Example
+///
+/// ```
+/// use arrow_array::UInt64Array;
+/// use h3o::{arrow::parent_array, Resolution};
+///
+/// let cells = UInt64Array::from(vec![Some(0x8a1fb46622dffff), None, Some(0)]);
+/// let parents = parent_array(&cells, Resolution::Five);
+/// assert_eq!(
+///     parents,
+///     UInt64Array::from(vec![Some(0x851fb467fffffff), None, None])
+/// );
+/// ```
+#[must_use]
+pub fn parent_array(
+    cells: &UInt64Array,
+    resolution: Resolution,
+) -> UInt64Array {
+    cells.unary_opt(|value| {
+        CellIndex::try_from(value)
+            .ok()
+            .and_then(|cell| cell.parent(resolution))
+            .map(u64::from)
+    })
+}
+
+/// Computes the number of children, at the given resolution, of each cell
+/// index.
+///
+/// The output is null for null or invalid cell indexes.
+///
+/// # Example
+///
+/// ```
+/// use arrow_array::UInt64Array;
+/// use h3o::{arrow::children_count_array, Resolution};
+///
+/// let cells = UInt64Array::from(vec![Some(0x8a1fb46622dffff), None]);
+/// let counts = children_count_array(&cells, Resolution::Twelve);
+/// assert_eq!(counts, UInt64Array::from(vec![Some(49), None]));
+/// ```
+#[must_use]
+pub fn children_count_array(
+    cells: &UInt64Array,
+    resolution: Resolution,
+) -> UInt64Array {
+    cells.unary_opt(|value| {
+        CellIndex::try_from(value)
+            .ok()
+            .map(|cell| cell.children_count(resolution))
+    })
+}
+
+/// Computes the grid distance between each pair of cell indexes.
+///
+/// The output is null if either cell index is null or invalid, or if the
+/// distance cannot be computed (cf. [`CellIndex::grid_distance`]).
+///
+/// # Errors
+///
+/// [`ArrowError::InvalidArgumentError`] if the arrays don't have the same
+/// length.
+///
+/// # Example
+///
+/// ```
+/// use arrow_array::{Int32Array, UInt64Array};
+/// use h3o::arrow::grid_distance_arrays;
+///
+/// let src = UInt64Array::from(vec![Some(0x8a1fb46622dffff), None]);
+/// let dst = UInt64Array::from(vec![Some(0x8a1fb46622d7fff), Some(0)]);
+/// let distances = grid_distance_arrays(&src, &dst)?;
+/// assert_eq!(distances, Int32Array::from(vec![Some(1), None]));
+/// # Ok::<(), arrow_schema::ArrowError>(())
+/// ```
+pub fn grid_distance_arrays(
+    src: &UInt64Array,
+    dst: &UInt64Array,
+) -> Result<Int32Array, ArrowError> {
+    if src.len() != dst.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "arrays must have the same length ({} vs {})",
+            src.len(),
+            dst.len()
+        )));
+    }
+
+    Ok(src
+        .iter()
+        .zip(dst.iter())
+        .map(|(src, dst)| {
+            let src = CellIndex::try_from(src?).ok()?;
+            let dst = CellIndex::try_from(dst?).ok()?;
+            src.grid_distance(dst).ok()
+        })
+        .collect())
+}
+
+/// Checks the validity of each cell index.
+///
+/// The output is null for null inputs.
+///
+/// # Example
+///
+/// ```
+/// use arrow_array::{BooleanArray, UInt64Array};
+/// use h3o::arrow::is_valid_array;
+///
+/// let cells = UInt64Array::from(vec![Some(0x8a1fb46622dffff), None, Some(0)]);
+/// let validity = is_valid_array(&cells);
+/// assert_eq!(
+///     validity,
+///     BooleanArray::from(vec![Some(true), None, Some(false)])
+/// );
+/// ```
+#[must_use]
+pub fn is_valid_array(cells: &UInt64Array) -> BooleanArray {
+    cells
+        .iter()
+        .map(|value| value.map(|value| CellIndex::try_from(value).is_ok()))
+        .collect()
+}
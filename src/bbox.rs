@@ -0,0 +1,402 @@
+use crate::{math::cos, CellIndex, LatLng, Resolution, Set};
+#[cfg(feature = "std")]
+use ahash::HashSetExt;
+use alloc::{collections::VecDeque, vec::Vec};
+use core::f64::consts::FRAC_PI_2;
+
+/// The North pole.
+const NORTH_POLE: LatLng = LatLng::new_unchecked(FRAC_PI_2, 0.);
+/// The South pole.
+const SOUTH_POLE: LatLng = LatLng::new_unchecked(-FRAC_PI_2, 0.);
+
+/// Returns the cells, at the given resolution, whose center lies within the
+/// bounding box defined by its south-west (`min`) and north-east (`max`)
+/// corners.
+///
+/// Boxes crossing the antimeridian are supported: they are detected when the
+/// longitude of `min` is greater than the one of `max`.
+///
+/// Note that boxes smaller than a cell may contain no cell center at all.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{cells_in_bbox, LatLng, Resolution};
+///
+/// let min = LatLng::new(48.81, 2.22)?;
+/// let max = LatLng::new(48.90, 2.47)?;
+/// let cells = cells_in_bbox(min, max, Resolution::Seven);
+/// # Ok::<(), h3o::error::InvalidLatLng>(())
+/// ```
+#[must_use]
+pub fn cells_in_bbox(
+    min: LatLng,
+    max: LatLng,
+    resolution: Resolution,
+) -> Vec<CellIndex> {
+    let mut cells = Vec::new();
+    if min.lat() > max.lat() {
+        return cells;
+    }
+    let bbox = BBox::new(min, max);
+
+    // Explore a slightly larger box, to make sure that every cell whose
+    // center is in the box is reachable from the origin.
+    let margin = (2. * resolution.edge_length_rads()).to_degrees();
+    let mut seen = Set::new();
+    let mut candidates = VecDeque::new();
+    let origin = bbox.center().to_cell(resolution);
+    seen.insert(origin);
+    candidates.push_back(origin);
+
+    while let Some(cell) = candidates.pop_front() {
+        let center = LatLng::from(cell);
+        if !bbox.contains(center, margin) {
+            continue;
+        }
+        if bbox.contains(center, 0.) {
+            cells.push(cell);
+        }
+        for neighbor in cell.grid_disk_safe(1) {
+            if seen.insert(neighbor) {
+                candidates.push_back(neighbor);
+            }
+        }
+    }
+
+    cells
+}
+
+/// Returns the cells, at the given resolution, whose center lies within the
+/// latitude band defined by `min_lat` and `max_lat` (in degrees, inclusive).
+///
+/// Cells are streamed by walking down the hierarchy from the base cells and
+/// skipping the branches that cannot reach the band, so memory usage stays
+/// constant whatever the size of the band, poles included.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{cells_in_latitude_band, LatLng, Resolution};
+///
+/// let count = cells_in_latitude_band(-10., 10., Resolution::Two)
+///     .inspect(|&cell| {
+///         let lat = LatLng::from(cell).lat();
+///         assert!((-10. ..=10.).contains(&lat));
+///     })
+///     .count();
+/// ```
+pub fn cells_in_latitude_band(
+    min_lat: f64,
+    max_lat: f64,
+    resolution: Resolution,
+) -> impl Iterator<Item = CellIndex> {
+    let mut candidates = if min_lat <= max_lat {
+        CellIndex::base_cells().collect()
+    } else {
+        Vec::new()
+    };
+
+    core::iter::from_fn(move || {
+        while let Some(cell) = candidates.pop() {
+            let cell_resolution = cell.resolution();
+            if cell_resolution == resolution {
+                let lat = LatLng::from(cell).lat();
+                if lat >= min_lat && lat <= max_lat {
+                    return Some(cell);
+                }
+                continue;
+            }
+
+            let (min, max) = descendants_lat_range(cell);
+            if max >= min_lat && min <= max_lat {
+                let child_resolution =
+                    cell_resolution.succ().expect("finer resolution");
+                candidates.extend(cell.children(child_resolution));
+            }
+        }
+        None
+    })
+}
+
+/// Returns a latitude range, in degrees, containing the center of every
+/// descendant of the cell.
+fn descendants_lat_range(cell: CellIndex) -> (f64, f64) {
+    let center = LatLng::from(cell);
+    let boundary = cell.boundary();
+    let (mut min, mut max) = (center.lat(), center.lat());
+    let mut radius = 0_f64;
+    for vertex in boundary.iter() {
+        min = min.min(vertex.lat());
+        max = max.max(vertex.lat());
+        radius = radius.max(center.distance_rads(*vertex));
+    }
+
+    // Children slightly overflow their parent: grow the range by the radius
+    // of the cell, which bounds the overflow of all the descendants.
+    let resolution = cell.resolution();
+    let margin = radius.to_degrees();
+    if NORTH_POLE.to_cell(resolution) == cell {
+        max = 90.;
+    }
+    if SOUTH_POLE.to_cell(resolution) == cell {
+        min = -90.;
+    }
+    (min - margin, max + margin)
+}
+
+/// Returns the bounding box of a set of cells, as its south-west and
+/// north-east corners (same convention as [`cells_in_bbox`]).
+///
+/// The box is the smallest one (in longitude) containing every cell, thus it
+/// may cross the antimeridian, in which case the longitude of the south-west
+/// corner is greater than the one of the north-east corner.
+///
+/// Cells containing a pole extend the box to that pole, across every
+/// longitude.
+///
+/// Note that the box is computed from the vertices of the cells, edges are
+/// not taken into account.
+///
+/// Returns `None` if there is no cell.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{coverage_bbox, CellIndex};
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let (min, max) = coverage_bbox(index.grid_disk::<Vec<_>>(2))
+///     .expect("non-empty coverage");
+/// assert!(min.lat() < max.lat() && min.lng() < max.lng());
+/// # Ok::<(), h3o::error::InvalidCellIndex>(())
+/// ```
+#[must_use]
+pub fn coverage_bbox(
+    cells: impl IntoIterator<Item = CellIndex>,
+) -> Option<(LatLng, LatLng)> {
+    let (mut min_lat, mut max_lat) = (f64::INFINITY, f64::NEG_INFINITY);
+    let mut intervals = Vec::new();
+    let mut is_polar = false;
+
+    for cell in cells {
+        let boundary = cell.boundary();
+        for vertex in boundary.iter() {
+            min_lat = min_lat.min(vertex.lat());
+            max_lat = max_lat.max(vertex.lat());
+        }
+
+        let resolution = cell.resolution();
+        if NORTH_POLE.to_cell(resolution) == cell {
+            max_lat = 90.;
+            is_polar = true;
+        } else if SOUTH_POLE.to_cell(resolution) == cell {
+            min_lat = -90.;
+            is_polar = true;
+        } else {
+            intervals.push(lng_interval(cell.into(), boundary.iter()));
+        }
+    }
+    if min_lat > max_lat {
+        return None;
+    }
+
+    let (min_lng, max_lng) = if is_polar {
+        (-180., 180.)
+    } else {
+        lng_bounds(intervals)
+    };
+    Some((
+        LatLng::new(min_lat, min_lng).expect("valid south-west corner"),
+        LatLng::new(max_lat, max_lng).expect("valid north-east corner"),
+    ))
+}
+
+/// Returns the smallest box containing both boxes, given as their south-west
+/// and north-east corners (same convention as [`coverage_bbox`]).
+#[cfg(feature = "geoparquet")]
+pub fn merge_bbox(
+    a: (LatLng, LatLng),
+    b: (LatLng, LatLng),
+) -> (LatLng, LatLng) {
+    let interval = |(min, max): (LatLng, LatLng)| {
+        let (west, east) = (min.lng(), max.lng());
+        (west, if east < west { east + 360. } else { east })
+    };
+    let (min_lng, max_lng) = lng_bounds(vec![interval(a), interval(b)]);
+    let min_lat = a.0.lat().min(b.0.lat());
+    let max_lat = a.1.lat().max(b.1.lat());
+
+    (
+        LatLng::new(min_lat, min_lng).expect("valid south-west corner"),
+        LatLng::new(max_lat, max_lng).expect("valid north-east corner"),
+    )
+}
+
+/// Returns the smallest cell containing every given cell (i.e. their
+/// deepest common ancestor, or the cell itself if there is only one).
+///
+/// Returns `None` if there is no cell or if they don't share a base cell.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{coverage_bounding_cell, CellIndex, Resolution};
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let parent = index.parent(Resolution::Eight).expect("parent");
+/// let cells = parent.children(Resolution::Ten);
+/// assert_eq!(coverage_bounding_cell(cells), Some(parent));
+/// # Ok::<(), h3o::error::InvalidCellIndex>(())
+/// ```
+#[must_use]
+pub fn coverage_bounding_cell(
+    cells: impl IntoIterator<Item = CellIndex>,
+) -> Option<CellIndex> {
+    let mut cells = cells.into_iter();
+    let mut ancestor = cells.next()?;
+
+    for cell in cells {
+        let mut resolution = ancestor.resolution().min(cell.resolution());
+        loop {
+            let parent = cell.parent(resolution).expect("coarser resolution");
+            if ancestor.parent(resolution) == Some(parent) {
+                ancestor = parent;
+                break;
+            }
+            resolution = resolution.pred()?;
+        }
+    }
+
+    Some(ancestor)
+}
+
+/// Returns the longitude range of a cell, as `(start, end)` degrees where
+/// `start` is in [-180, 180) and `end` may exceed 180.
+fn lng_interval<'a>(
+    center: LatLng,
+    vertices: impl Iterator<Item = &'a LatLng>,
+) -> (f64, f64) {
+    // Offsets relative to the center, to handle the antimeridian.
+    let offset = |vertex: &LatLng| wrap_lng(vertex.lng() - center.lng());
+    let (mut west, mut east) = (center.lng(), center.lng());
+    let (mut min, mut max) = (0., 0.);
+    for vertex in vertices {
+        let offset = offset(vertex);
+        if offset < min {
+            (min, west) = (offset, vertex.lng());
+        }
+        if offset > max {
+            (max, east) = (offset, vertex.lng());
+        }
+    }
+    if west >= 180. {
+        west -= 360.;
+    }
+    if east < west {
+        east += 360.;
+    }
+    (west, east)
+}
+
+/// Returns the smallest longitude range covering every interval, as
+/// `(west, east)` bounds in degrees (`west > east` when crossing the
+/// antimeridian).
+fn lng_bounds(mut intervals: Vec<(f64, f64)>) -> (f64, f64) {
+    intervals.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let Some(&(first, _)) = intervals.first() else {
+        return (-180., 180.);
+    };
+    let end = intervals
+        .iter()
+        .map(|&(_, end)| end)
+        .fold(f64::NEG_INFINITY, f64::max);
+    // Intervals going past 180° wrap around and cover the beginning.
+    let wrapped = end - 360.;
+
+    // The box is the complement of the largest uncovered gap, starting with
+    // the one going around the antimeridian.
+    let mut gap = (first + 360. - end, end, first);
+    let mut covered = f64::NEG_INFINITY;
+    for &(start, stop) in &intervals {
+        let gap_start = covered.max(wrapped);
+        if start - gap_start > gap.0 {
+            gap = (start - gap_start, gap_start, start);
+        }
+        covered = covered.max(stop);
+    }
+    if gap.0 <= 0. {
+        return (-180., 180.);
+    }
+
+    let (west, east) = (gap.2, gap.1);
+    (wrap_lng(west), wrap_lng(east))
+}
+
+/// Wraps a longitude, in degrees, into [-180, 180].
+fn wrap_lng(lng: f64) -> f64 {
+    if lng > 180. {
+        lng - 360.
+    } else if lng < -180. {
+        lng + 360.
+    } else {
+        lng
+    }
+}
+
+/// A bounding box, in degrees.
+struct BBox {
+    min_lat: f64,
+    max_lat: f64,
+    min_lng: f64,
+    max_lng: f64,
+}
+
+impl BBox {
+    fn new(min: LatLng, max: LatLng) -> Self {
+        Self {
+            min_lat: min.lat(),
+            max_lat: max.lat(),
+            min_lng: min.lng(),
+            max_lng: max.lng(),
+        }
+    }
+
+    /// Returns true if the box crosses the antimeridian.
+    fn is_transmeridian(&self) -> bool {
+        self.min_lng > self.max_lng
+    }
+
+    /// Returns the center of the box.
+    fn center(&self) -> LatLng {
+        let lat = f64::midpoint(self.min_lat, self.max_lat);
+        let mut lng = f64::midpoint(self.min_lng, self.max_lng);
+        if self.is_transmeridian() {
+            lng += if lng > 0. { -180. } else { 180. };
+        }
+        LatLng::new(lat, lng).expect("valid center")
+    }
+
+    /// Returns true if the point is within the box grown by `margin` degrees.
+    fn contains(&self, ll: LatLng, margin: f64) -> bool {
+        let (lat, lng) = (ll.lat(), ll.lng());
+        if lat < self.min_lat - margin || lat > self.max_lat + margin {
+            return false;
+        }
+
+        // Longitude degrees shrink as latitude increases.
+        let lng_margin = margin / cos(ll.lat_radians()).max(f64::EPSILON);
+        if lng_margin >= 180. {
+            return true;
+        }
+        let (min_lng, max_lng) =
+            (self.min_lng - lng_margin, self.max_lng + lng_margin);
+        if self.is_transmeridian() {
+            lng >= min_lng || lng <= max_lng
+        } else {
+            (lng >= min_lng && lng <= max_lng)
+                || lng + 360. <= max_lng
+                || lng - 360. >= min_lng
+        }
+    }
+}
@@ -0,0 +1,273 @@
+//! Checked bit manipulation of raw H3 indexes.
+//!
+//! These functions work on the raw `u64` representation of the indexes, which
+//! is useful to build custom encodings, while making sure that the result is
+//! always a valid index (cell, directed edge or vertex, depending on its
+//! mode).
+//!
+//! Undirected edges are not supported.
+
+use crate::{
+    error::InvalidIndex,
+    index::{bits, IndexMode},
+    CellIndex, DirectedEdgeIndex, Direction, Edge, Resolution, Vertex,
+    VertexIndex,
+};
+
+/// Returns the mode of the index.
+///
+/// # Errors
+///
+/// [`InvalidIndex`] if the mode is not a valid one.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{bits, IndexMode};
+///
+/// assert_eq!(bits::get_mode(0x8a1fb46622dffff)?, IndexMode::Cell);
+/// assert!(bits::get_mode(0).is_err());
+/// # Ok::<(), h3o::error::InvalidIndex>(())
+/// ```
+pub const fn get_mode(bits: u64) -> Result<IndexMode, InvalidIndex> {
+    match bits::get_mode(bits) {
+        1 => Ok(IndexMode::Cell),
+        2 => Ok(IndexMode::DirectedEdge),
+        3 => Ok(IndexMode::UndirectedEdge),
+        4 => Ok(IndexMode::Vertex),
+        _ => Err(InvalidIndex::new(bits, "invalid index mode")),
+    }
+}
+
+/// Sets the mode of the index.
+///
+/// Note that the other bits are left untouched, so changing the mode of a
+/// valid index usually requires to update the mode-specific bits (edge or
+/// vertex) as well: to turn a cell into a directed edge or a vertex, use
+/// [`set_edge`] or [`set_vertex`] instead.
+///
+/// # Errors
+///
+/// [`InvalidIndex`] if the resulting index isn't valid.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{bits, IndexMode};
+///
+/// // From a directed edge to its origin cell.
+/// let edge = 0x13a194e699ab7fff;
+/// assert!(bits::set_mode(edge, IndexMode::Cell).is_err(), "edge bits set");
+/// let cell = bits::set_mode(edge & !(0b111 << 56), IndexMode::Cell)?;
+/// assert_eq!(cell, 0x8a194e699ab7fff);
+/// # Ok::<(), h3o::error::InvalidIndex>(())
+/// ```
+pub fn set_mode(bits: u64, mode: IndexMode) -> Result<u64, InvalidIndex> {
+    validate(bits::set_mode(bits, mode))
+}
+
+/// Turns a cell index into the directed edge index of one of its edges.
+///
+/// # Errors
+///
+/// [`InvalidIndex`] if the input isn't a valid cell index, or if the edge
+/// doesn't exist (the deleted edge of pentagons).
+///
+/// # Example
+///
+/// ```
+/// use h3o::{bits, CellIndex, DirectedEdgeIndex, Edge};
+///
+/// let cell = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let bits = bits::set_edge(cell.into(), Edge::try_from(1)?)?;
+/// assert_eq!(bits, 0x11a1fb46622dffff);
+/// assert_eq!(DirectedEdgeIndex::try_from(bits)?.origin(), cell);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn set_edge(bits: u64, edge: Edge) -> Result<u64, InvalidIndex> {
+    check_cell(bits)?;
+    validate(bits::set_edge(
+        bits::set_mode(bits, IndexMode::DirectedEdge),
+        edge,
+    ))
+}
+
+/// Turns a cell index into the vertex index of one of its vertexes.
+///
+/// A vertex is shared by up to three cells, but only one of them (its owner)
+/// gives a valid vertex index: [`CellIndex::vertex`] finds it for you.
+///
+/// # Errors
+///
+/// [`InvalidIndex`] if the input isn't a valid cell index, if the vertex
+/// doesn't exist (pentagons only have 5 vertexes), or if the cell isn't the
+/// owner of the vertex.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{bits, Vertex};
+///
+/// let bits = bits::set_vertex(0x8a1fb46622c7fff, Vertex::try_from(4)?)?;
+/// assert_eq!(bits, 0x24a1fb46622c7fff);
+///
+/// // Not the owner of its first vertex.
+/// assert!(bits::set_vertex(0x8a1fb46622dffff, Vertex::try_from(0)?).is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn set_vertex(bits: u64, vertex: Vertex) -> Result<u64, InvalidIndex> {
+    check_cell(bits)?;
+    validate(bits::set_vertex(
+        bits::set_mode(bits, IndexMode::Vertex),
+        vertex,
+    ))
+}
+
+/// Returns the resolution of the index.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{bits, Resolution};
+///
+/// assert_eq!(bits::get_resolution(0x8a1fb46622dffff), Resolution::Ten);
+/// ```
+#[must_use]
+pub const fn get_resolution(bits: u64) -> Resolution {
+    bits::get_resolution(bits)
+}
+
+/// Sets the resolution of the index.
+///
+/// Directions finer than the new resolution are cleared and, when the
+/// resolution increases, the new directions are set to [`Direction::Center`]
+/// (i.e. the result is either an ancestor or a center child of the original
+/// cell).
+///
+/// # Errors
+///
+/// [`InvalidIndex`] if the input or the resulting index isn't valid.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{bits, Resolution};
+///
+/// let bits = bits::set_resolution(0x8a1fb46622dffff, Resolution::Five)?;
+/// assert_eq!(bits, 0x851fb467fffffff);
+/// # Ok::<(), h3o::error::InvalidIndex>(())
+/// ```
+pub fn set_resolution(
+    bits: u64,
+    resolution: Resolution,
+) -> Result<u64, InvalidIndex> {
+    let current = get_resolution(validate(bits)?);
+
+    let mut bits = bits::set_unused(
+        bits::set_resolution(bits, resolution),
+        resolution.min(current),
+    );
+    for resolution in Resolution::range(current, resolution).skip(1) {
+        bits = bits::set_direction(bits, Direction::Center.into(), resolution);
+    }
+
+    validate(bits)
+}
+
+/// Returns the direction of the index at the given resolution.
+///
+/// # Errors
+///
+/// [`InvalidIndex`] if there is no direction at this resolution (i.e.
+/// resolution 0, or finer than the resolution of the index).
+///
+/// # Example
+///
+/// ```
+/// use h3o::{bits, Direction, Resolution};
+///
+/// let bits = 0x8a1fb46622dffff;
+/// assert_eq!(bits::get_direction(bits, Resolution::Five)?, Direction::K);
+/// assert!(bits::get_direction(bits, Resolution::Eleven).is_err());
+/// # Ok::<(), h3o::error::InvalidIndex>(())
+/// ```
+pub fn get_direction(
+    bits: u64,
+    resolution: Resolution,
+) -> Result<Direction, InvalidIndex> {
+    check_direction_resolution(bits, resolution)?;
+    Direction::try_from(bits::get_direction(bits, resolution))
+        .map_err(|_| InvalidIndex::new(bits, "invalid direction"))
+}
+
+/// Sets the direction of the index at the given resolution.
+///
+/// # Errors
+///
+/// [`InvalidIndex`] if there is no direction at this resolution (i.e.
+/// resolution 0, or finer than the resolution of the index), or if the
+/// resulting index isn't valid (e.g. the deleted `K` subsequence of
+/// pentagons).
+///
+/// # Example
+///
+/// ```
+/// use h3o::{bits, Direction, Resolution};
+///
+/// let bits = bits::set_direction(
+///     0x8a1fb46622dffff,
+///     Resolution::Ten,
+///     Direction::IJ,
+/// )?;
+/// assert_eq!(bits::get_direction(bits, Resolution::Ten)?, Direction::IJ);
+/// # Ok::<(), h3o::error::InvalidIndex>(())
+/// ```
+pub fn set_direction(
+    bits: u64,
+    resolution: Resolution,
+    direction: Direction,
+) -> Result<u64, InvalidIndex> {
+    check_direction_resolution(bits, resolution)?;
+    validate(bits::set_direction(bits, direction.into(), resolution))
+}
+
+// -----------------------------------------------------------------------------
+
+/// Checks that the index has a direction at the given resolution.
+fn check_direction_resolution(
+    bits: u64,
+    resolution: Resolution,
+) -> Result<(), InvalidIndex> {
+    if resolution == Resolution::Zero {
+        return Err(InvalidIndex::new(bits, "no direction at resolution 0"));
+    }
+    if resolution > get_resolution(bits) {
+        return Err(InvalidIndex::new(bits, "resolution too fine"));
+    }
+    Ok(())
+}
+
+/// Checks that the bits represent a valid cell index.
+fn check_cell(bits: u64) -> Result<(), InvalidIndex> {
+    CellIndex::try_from(bits)
+        .map(|_| ())
+        .map_err(|err| InvalidIndex::new(bits, err.reason))
+}
+
+/// Checks that the bits represent a valid index, according to its mode.
+fn validate(bits: u64) -> Result<u64, InvalidIndex> {
+    match get_mode(bits)? {
+        IndexMode::Cell => CellIndex::try_from(bits)
+            .map(|_| bits)
+            .map_err(|err| InvalidIndex::new(bits, err.reason)),
+        IndexMode::DirectedEdge => DirectedEdgeIndex::try_from(bits)
+            .map(|_| bits)
+            .map_err(|err| InvalidIndex::new(bits, err.reason)),
+        IndexMode::Vertex => VertexIndex::try_from(bits)
+            .map(|_| bits)
+            .map_err(|err| InvalidIndex::new(bits, err.reason)),
+        IndexMode::UndirectedEdge => {
+            Err(InvalidIndex::new(bits, "unsupported index mode"))
+        }
+    }
+}
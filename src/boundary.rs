@@ -6,6 +6,22 @@ use core::{fmt, ops::Deref};
 /// Worst case is pentagon: 5 original verts + 5 edge crossings.
 const MAX_BNDRY_VERTS: usize = 10;
 
+/// Orientation of the vertices of a boundary.
+///
+/// Orientations are defined on the sphere, as seen from outside the globe
+/// (which matches the planar orientation in longitude/latitude, as long as the
+/// boundary doesn't cross the antimeridian nor contain a pole).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+#[expect(clippy::exhaustive_enums, reason = "only two ways to go around")]
+pub enum Winding {
+    /// Counter-clockwise, as expected by `GeoJSON` (RFC 7946) for exterior
+    /// rings.
+    #[default]
+    CounterClockwise,
+    /// Clockwise.
+    Clockwise,
+}
+
 /// Boundary in latitude/longitude.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Default)]
 pub struct Boundary {
@@ -29,6 +45,11 @@ impl Boundary {
         self.points[usize::from(self.count)] = ll;
         self.count += 1;
     }
+
+    /// Reverses the order of the vertices.
+    pub(crate) fn reverse(&mut self) {
+        self.points[..self.count.into()].reverse();
+    }
 }
 
 impl Deref for Boundary {
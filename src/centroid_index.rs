@@ -0,0 +1,280 @@
+use crate::{coord::Vec3d, math::sin, CellIndex, LatLng, EARTH_RADIUS_KM};
+use alloc::{collections::BinaryHeap, vec::Vec};
+use core::{cmp::Ordering, f64::consts::PI};
+
+/// A static spatial index over the centroids of a set of cells.
+///
+/// The centroids are stored in a flat, implicit, k-d tree (on the 3D
+/// coordinates of the unit sphere) which allows metric nearest-neighbor and
+/// radius queries, useful on sparse coverages where grid-distance queries
+/// are not applicable.
+///
+/// Distances are great circle distances between the query point and the cell
+/// centroids.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{CellCentroidIndex, CellIndex, LatLng, Resolution};
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let centroids = index
+///     .grid_disk::<Vec<_>>(2)
+///     .into_iter()
+///     .collect::<CellCentroidIndex>();
+///
+/// let nearest = centroids.nearest(LatLng::from(index), 1);
+/// assert_eq!(nearest, vec![index]);
+/// # Ok::<(), h3o::error::InvalidCellIndex>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CellCentroidIndex {
+    /// Centroids, laid out as a k-d tree (median of each slice is the node).
+    nodes: Vec<(Vec3d, CellIndex)>,
+}
+
+impl CellCentroidIndex {
+    /// Returns the number of indexed cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellCentroidIndex, CellIndex, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let centroids = index
+    ///     .children(Resolution::Eleven)
+    ///     .collect::<CellCentroidIndex>();
+    /// assert_eq!(centroids.len(), 7);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns true if there is no indexed cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let centroids = h3o::CellCentroidIndex::default();
+    /// assert!(centroids.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the `k` cells whose centroids are the closest to `ll`, sorted
+    /// by increasing distance.
+    ///
+    /// Fewer than `k` cells are returned if the index contains less than `k`
+    /// cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellCentroidIndex, CellIndex, LatLng, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let centroids = index
+    ///     .children(Resolution::Twelve)
+    ///     .collect::<CellCentroidIndex>();
+    ///
+    /// let nearest = centroids.nearest(LatLng::from(index), 7);
+    /// assert_eq!(
+    ///     nearest.first().copied(),
+    ///     index.center_child(Resolution::Twelve)
+    /// );
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn nearest(&self, ll: LatLng, k: usize) -> Vec<CellIndex> {
+        if k == 0 {
+            return Vec::new();
+        }
+
+        let mut candidates = BinaryHeap::with_capacity(k + 1);
+        search_nearest(&self.nodes, 0, &Vec3d::from(ll), k, &mut candidates);
+
+        candidates
+            .into_sorted_vec()
+            .into_iter()
+            .map(|candidate| candidate.cell)
+            .collect()
+    }
+
+    /// Returns the cells whose centroids are within `radius_km` kilometers of
+    /// `ll`, in no particular order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellCentroidIndex, CellIndex, LatLng, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let centroids = index
+    ///     .grid_disk::<Vec<_>>(10)
+    ///     .into_iter()
+    ///     .collect::<CellCentroidIndex>();
+    ///
+    /// let cells = centroids.within_radius(LatLng::from(index), 0.5);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn within_radius(&self, ll: LatLng, radius_km: f64) -> Vec<CellIndex> {
+        let mut cells = Vec::new();
+        if radius_km.is_nan() || radius_km < 0. {
+            return cells;
+        }
+
+        // Convert the great circle distance into the (squared) length of the
+        // chord, the metric used by the tree.
+        let angle = (radius_km / EARTH_RADIUS_KM).min(PI);
+        let chord = 2. * sin(angle / 2.);
+
+        search_radius(
+            &self.nodes,
+            0,
+            &Vec3d::from(ll),
+            chord * chord,
+            &mut cells,
+        );
+
+        cells
+    }
+}
+
+impl FromIterator<CellIndex> for CellCentroidIndex {
+    fn from_iter<T: IntoIterator<Item = CellIndex>>(iter: T) -> Self {
+        let mut nodes = iter
+            .into_iter()
+            .map(|cell| (Vec3d::from(LatLng::from(cell)), cell))
+            .collect::<Vec<_>>();
+
+        build(&mut nodes, 0);
+
+        Self { nodes }
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// A candidate for the nearest neighbor search, ordered by distance.
+#[derive(Debug, Clone, Copy)]
+struct Candidate {
+    distance: f64,
+    cell: CellIndex,
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then_with(|| self.cell.cmp(&other.cell))
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+/// Returns the component of the vector used to split the tree at this depth.
+const fn component(point: &Vec3d, depth: usize) -> f64 {
+    match depth % 3 {
+        0 => point.x,
+        1 => point.y,
+        _ => point.z,
+    }
+}
+
+/// Recursively reorders the nodes into a k-d tree.
+fn build(nodes: &mut [(Vec3d, CellIndex)], depth: usize) {
+    if nodes.len() <= 1 {
+        return;
+    }
+
+    let mid = nodes.len() / 2;
+    nodes.select_nth_unstable_by(mid, |a, b| {
+        component(&a.0, depth).total_cmp(&component(&b.0, depth))
+    });
+
+    let (left, right) = nodes.split_at_mut(mid);
+    build(left, depth + 1);
+    build(&mut right[1..], depth + 1);
+}
+
+/// Keeps the `k` closest nodes in `candidates`.
+fn search_nearest(
+    nodes: &[(Vec3d, CellIndex)],
+    depth: usize,
+    target: &Vec3d,
+    k: usize,
+    candidates: &mut BinaryHeap<Candidate>,
+) {
+    if nodes.is_empty() {
+        return;
+    }
+
+    let mid = nodes.len() / 2;
+    let (point, cell) = nodes[mid];
+    candidates.push(Candidate {
+        distance: point.distance(target),
+        cell,
+    });
+    if candidates.len() > k {
+        candidates.pop();
+    }
+
+    let delta = component(target, depth) - component(&point, depth);
+    let (near, far) = if delta < 0. {
+        (&nodes[..mid], &nodes[mid + 1..])
+    } else {
+        (&nodes[mid + 1..], &nodes[..mid])
+    };
+
+    search_nearest(near, depth + 1, target, k, candidates);
+    // Only visit the other side if it may contain closer nodes.
+    let worst = candidates.peek().map_or(f64::INFINITY, |c| c.distance);
+    if candidates.len() < k || delta * delta < worst {
+        search_nearest(far, depth + 1, target, k, candidates);
+    }
+}
+
+/// Collects the nodes within the given squared chord distance.
+fn search_radius(
+    nodes: &[(Vec3d, CellIndex)],
+    depth: usize,
+    target: &Vec3d,
+    threshold: f64,
+    cells: &mut Vec<CellIndex>,
+) {
+    if nodes.is_empty() {
+        return;
+    }
+
+    let mid = nodes.len() / 2;
+    let (point, cell) = nodes[mid];
+    if point.distance(target) <= threshold {
+        cells.push(cell);
+    }
+
+    let delta = component(target, depth) - component(&point, depth);
+    if delta <= 0. || delta * delta <= threshold {
+        search_radius(&nodes[..mid], depth + 1, target, threshold, cells);
+    }
+    if delta >= 0. || delta * delta <= threshold {
+        search_radius(&nodes[mid + 1..], depth + 1, target, threshold, cells);
+    }
+}
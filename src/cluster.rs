@@ -0,0 +1,74 @@
+use crate::{weights_matrix, CellIndex, WeightScheme};
+use alloc::{vec, vec::Vec};
+
+/// Groups the given cells into clusters, using a grid-native DBSCAN.
+///
+/// The neighborhood of a cell is its `k`-ring: a cell with at least
+/// `min_neighbors` other cells of the list within `k` rings is a core cell,
+/// and core cells reachable from each other (through core cells) form a
+/// cluster, along with the non-core cells in their neighborhood.
+///
+/// Returns the label of each cell, in the same order as the input: clusters
+/// are numbered from 0 in order of discovery, and cells that don't belong to
+/// any cluster (i.e. noise) are labeled `None`. A non-core cell within reach
+/// of several clusters is assigned to the first one that reaches it.
+///
+/// Cells are expected to be unique, and cells at different resolutions are
+/// never neighbors.
+///
+/// This is typically used for hotspot detection on binned data (e.g. the
+/// cells whose count is above some threshold).
+///
+/// # Example
+///
+/// ```
+/// use h3o::{cluster_cells, CellIndex};
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let mut cells = index.grid_disk::<Vec<_>>(1);
+/// // An isolated cell.
+/// cells.push(index.grid_disk::<Vec<_>>(10).pop().expect("cell"));
+///
+/// let labels = cluster_cells(&cells, 3, 1);
+/// assert!(labels[..7].iter().all(|&label| label == Some(0)));
+/// assert_eq!(labels[7], None);
+/// # Ok::<(), h3o::error::InvalidCellIndex>(())
+/// ```
+#[must_use]
+pub fn cluster_cells(
+    cells: &[CellIndex],
+    min_neighbors: usize,
+    k: u32,
+) -> Vec<Option<usize>> {
+    let neighbors = weights_matrix(cells, WeightScheme::InverseDistance { k });
+    let is_core = |i: usize| neighbors.row(i).count() >= min_neighbors;
+
+    let mut labels = vec![None; cells.len()];
+    let mut cluster_count = 0;
+    let mut queue = Vec::new();
+    for i in 0..cells.len() {
+        if labels[i].is_some() || !is_core(i) {
+            continue;
+        }
+
+        // Expand a new cluster from this core cell.
+        let label = Some(cluster_count);
+        cluster_count += 1;
+        labels[i] = label;
+        queue.push(i);
+        while let Some(j) = queue.pop() {
+            // Non-core cells are part of the cluster, but don't extend it.
+            if !is_core(j) {
+                continue;
+            }
+            for (neighbor, _) in neighbors.row(j) {
+                if labels[neighbor].is_none() {
+                    labels[neighbor] = label;
+                    queue.push(neighbor);
+                }
+            }
+        }
+    }
+
+    labels
+}
@@ -0,0 +1,125 @@
+use super::LatLng;
+use crate::math::{acos, atan, atan2, cos, mul_add, sin, sqrt, tan};
+
+/// WGS84 semi-major axis, in meters.
+const WGS84_A: f64 = 6_378_137.;
+/// WGS84 flattening.
+const WGS84_F: f64 = 1. / 298.257_223_563;
+/// WGS84 semi-minor axis, in meters.
+const WGS84_B: f64 = WGS84_A * (1. - WGS84_F);
+
+/// Maximum number of iterations of Vincenty's formula.
+const VINCENTY_MAX_ITERATIONS: usize = 200;
+/// Convergence threshold of Vincenty's formula (~0.006mm).
+const VINCENTY_EPSILON: f64 = 1e-12;
+
+/// Formula used to compute the distance between two coordinates.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DistanceMethod {
+    /// Haversine formula, on the authalic sphere.
+    ///
+    /// This is the method used by [`LatLng::distance_km`] and
+    /// [`LatLng::distance_m`].
+    #[default]
+    Haversine,
+    /// Spherical law of cosines, on the authalic sphere.
+    ///
+    /// Slightly faster than the haversine formula, but suffers from rounding
+    /// errors for small distances (below a meter or so).
+    SphericalLawOfCosines,
+    /// Vincenty's inverse formula, on the WGS84 ellipsoid.
+    ///
+    /// Slower but accurate to the millimeter. For nearly antipodal points,
+    /// where the formula fails to converge, the haversine distance is used.
+    Vincenty,
+}
+
+/// Computes the great circle distance, in radians, using the spherical law of
+/// cosines.
+pub fn law_of_cosines_rads(a: LatLng, b: LatLng) -> f64 {
+    let (lat1, lat2) = (a.lat_radians(), b.lat_radians());
+    let cos_angle = mul_add(
+        sin(lat1),
+        sin(lat2),
+        cos(lat1) * cos(lat2) * cos(b.lng_radians() - a.lng_radians()),
+    );
+
+    acos(cos_angle.clamp(-1., 1.))
+}
+
+/// Computes the distance, in meters, on the WGS84 ellipsoid using Vincenty's
+/// inverse formula.
+///
+/// Returns `None` if the formula doesn't converge (nearly antipodal points).
+#[expect(
+    clippy::suboptimal_flops,
+    clippy::many_single_char_names,
+    reason = "follow the notation of the reference formula"
+)]
+pub fn vincenty_m(a: LatLng, b: LatLng) -> Option<f64> {
+    let l = b.lng_radians() - a.lng_radians();
+    let u1 = atan((1. - WGS84_F) * tan(a.lat_radians()));
+    let u2 = atan((1. - WGS84_F) * tan(b.lat_radians()));
+    let (sin_u1, cos_u1) = (sin(u1), cos(u1));
+    let (sin_u2, cos_u2) = (sin(u2), cos(u2));
+
+    let mut lambda = l;
+    for _ in 0..VINCENTY_MAX_ITERATIONS {
+        let (sin_lambda, cos_lambda) = (sin(lambda), cos(lambda));
+        let x = cos_u2 * sin_lambda;
+        let y = cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda;
+        let sin_sigma = sqrt(x * x + y * y);
+        if sin_sigma == 0. {
+            return Some(0.); // Coincident points.
+        }
+        let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+        let sigma = atan2(sin_sigma, cos_sigma);
+        let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+        let cos_sq_alpha = 1. - sin_alpha * sin_alpha;
+        // Equatorial line: `cos_sq_alpha` is 0.
+        let cos_2sigma_m = if cos_sq_alpha == 0. {
+            0.
+        } else {
+            cos_sigma - 2. * sin_u1 * sin_u2 / cos_sq_alpha
+        };
+        let c = WGS84_F / 16.
+            * cos_sq_alpha
+            * (4. + WGS84_F * (4. - 3. * cos_sq_alpha));
+
+        let previous = lambda;
+        lambda = l
+            + (1. - c)
+                * WGS84_F
+                * sin_alpha
+                * (sigma
+                    + c * sin_sigma
+                        * (cos_2sigma_m
+                            + c * cos_sigma
+                                * (-1. + 2. * cos_2sigma_m * cos_2sigma_m)));
+
+        if (lambda - previous).abs() < VINCENTY_EPSILON {
+            let u_sq = cos_sq_alpha * (WGS84_A * WGS84_A - WGS84_B * WGS84_B)
+                / (WGS84_B * WGS84_B);
+            let a = 1.
+                + u_sq / 16384.
+                    * (4096. + u_sq * (-768. + u_sq * (320. - 175. * u_sq)));
+            let b = u_sq / 1024.
+                * (256. + u_sq * (-128. + u_sq * (74. - 47. * u_sq)));
+            let delta_sigma = b
+                * sin_sigma
+                * (cos_2sigma_m
+                    + b / 4.
+                        * (cos_sigma
+                            * (-1. + 2. * cos_2sigma_m * cos_2sigma_m)
+                            - b / 6.
+                                * cos_2sigma_m
+                                * (-3. + 4. * sin_sigma * sin_sigma)
+                                * (-3. + 4. * cos_2sigma_m * cos_2sigma_m)));
+
+            return Some(WGS84_B * a * (sigma - delta_sigma));
+        }
+    }
+
+    None
+}
@@ -1,18 +1,27 @@
 use super::{
-    faceijk::FaceIJK, to_positive_angle, Vec2d, Vec3d, AP7_ROT_RADS, EPSILON,
+    distance::{self, DistanceMethod},
+    faceijk::FaceIJK,
+    sum_of_products, to_positive_angle, Vec2d, Vec3d, AP7_ROT_RADS, EPSILON,
     INV_RES0_U_GNOMONIC, SQRT7_POWERS,
 };
 use crate::{
-    error::InvalidLatLng,
+    error::{InvalidLatLng, ParseLatLngError},
     face,
     math::{acos, asin, atan2, cos, mul_add, sin, sqrt, tan},
     CellIndex, Face, Resolution, EARTH_RADIUS_KM, TWO_PI,
 };
+use alloc::vec::Vec;
 use core::{
     f64::consts::{FRAC_PI_2, PI},
     fmt,
+    str::FromStr,
 };
 use float_eq::float_eq;
+#[cfg(feature = "rayon")]
+use rayon::iter::{
+    IndexedParallelIterator, IntoParallelRefIterator, ParallelExtend,
+    ParallelIterator,
+};
 
 /// Epsilon of ~0.1mm in degrees.
 const EPSILON_DEG: f64 = 0.000000001;
@@ -20,6 +29,10 @@ const EPSILON_DEG: f64 = 0.000000001;
 /// Same as `EPSILON_DEG`, but in radians.
 const EPSILON_RAD: f64 = EPSILON_DEG * PI / 180.0;
 
+/// Minimum number of points indexed by a single thread in a batch.
+#[cfg(feature = "rayon")]
+const PAR_BATCH_MIN_LEN: usize = 4096;
+
 /// Latitude/longitude.
 ///
 /// The coordinate reference system (CRS) is sphere coordinates with the
@@ -81,6 +94,88 @@ impl LatLng {
         Ok(Self { lat, lng })
     }
 
+    /// Initializes coordinates, in bulk, from parallel arrays of latitudes
+    /// and longitudes in degrees.
+    ///
+    /// # Errors
+    ///
+    /// The position of the first invalid coordinate, along with the
+    /// [`InvalidLatLng`] error. Arrays of different lengths are reported as
+    /// an invalid coordinate at the end of the shortest one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::LatLng;
+    ///
+    /// let lats = [48.864716, 31.224361];
+    /// let lngs = [2.349014, 121.469170];
+    /// let coords = LatLng::from_degrees_slice(&lats, &lngs)
+    ///     .map_err(|(_, err)| err)?;
+    /// assert_eq!(coords.len(), 2);
+    ///
+    /// let result = LatLng::from_degrees_slice(&[0., f64::NAN], &[0., 0.]);
+    /// assert_eq!(result.map_err(|(i, _)| i), Err(1));
+    /// # Ok::<(), h3o::error::InvalidLatLng>(())
+    /// ```
+    pub fn from_degrees_slice(
+        lats: &[f64],
+        lngs: &[f64],
+    ) -> Result<Vec<Self>, (usize, InvalidLatLng)> {
+        if lats.len() != lngs.len() {
+            let index = lats.len().min(lngs.len());
+            // Report earlier failures first.
+            Self::from_degrees_slice(&lats[..index], &lngs[..index])?;
+            return Err((
+                index,
+                InvalidLatLng::new(f64::NAN, "missing coordinate"),
+            ));
+        }
+
+        lats.iter()
+            .zip(lngs)
+            .enumerate()
+            .map(|(i, (&lat, &lng))| {
+                Self::new(lat, lng).map_err(|err| (i, err))
+            })
+            .collect()
+    }
+
+    /// Returns a displayable `lat,lng` representation, in decimal degrees.
+    ///
+    /// The output can be parsed back, losslessly, with [`str::parse`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let ll = h3o::LatLng::new(48.864716, 2.349014)?;
+    /// assert_eq!(ll.display_decimal().to_string(), "48.864716,2.349014");
+    /// # Ok::<(), h3o::error::InvalidLatLng>(())
+    /// ```
+    #[must_use]
+    pub fn display_decimal(self) -> impl fmt::Display {
+        Decimal(self)
+    }
+
+    /// Returns a displayable degrees-minutes-seconds representation.
+    ///
+    /// Seconds are rounded to the millisecond of arc (~3cm).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let ll = h3o::LatLng::new(48.864716, 2.349014)?;
+    /// assert_eq!(
+    ///     ll.display_dms().to_string(),
+    ///     r#"48°51'52.978"N, 2°20'56.450"E"#
+    /// );
+    /// # Ok::<(), h3o::error::InvalidLatLng>(())
+    /// ```
+    #[must_use]
+    pub fn display_dms(self) -> impl fmt::Display {
+        Dms(self)
+    }
+
     /// Latitude, in degrees.
     ///
     /// # Example
@@ -206,6 +301,183 @@ impl LatLng {
         self.distance_km(other) * 1000.
     }
 
+    /// The signed distance, in meters, from this coordinate to the great
+    /// circle going through `a` and `b`.
+    ///
+    /// The distance is negative when the coordinate is on the left of the
+    /// path from `a` to `b`, and positive when it's on the right. If `a` and
+    /// `b` are identical or antipodal, the distance to `a` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::LatLng;
+    ///
+    /// let a = LatLng::new(0., 0.)?;
+    /// let b = LatLng::new(0., 10.)?;
+    /// let point = LatLng::new(1., 5.)?;
+    /// assert!(point.cross_track_distance_m(a, b) < 0.);
+    /// # Ok::<(), h3o::error::InvalidLatLng>(())
+    /// ```
+    #[must_use]
+    pub fn cross_track_distance_m(self, a: Self, b: Self) -> f64 {
+        crate::geodesic::cross_track_rads(self, a, b) * EARTH_RADIUS_KM * 1000.
+    }
+
+    /// Returns the coordinate as a unit vector, in Cartesian coordinates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{LatLng, Vec3d};
+    ///
+    /// let v = LatLng::new(0., 0.)?.to_vec3();
+    /// assert_eq!(v, Vec3d::new(1., 0., 0.));
+    /// # Ok::<(), h3o::error::InvalidLatLng>(())
+    /// ```
+    #[must_use]
+    pub fn to_vec3(self) -> Vec3d {
+        Vec3d::from(self)
+    }
+
+    /// The initial bearing of the great circle path from this coordinate to
+    /// `other`, in radians.
+    ///
+    /// The bearing is measured clockwise from the north, in `[0, 2π)`. It is
+    /// `0` when the coordinates are identical.
+    ///
+    /// From a pole, the bearing doesn't depend on the destination: every
+    /// direction is south (`π`) from the North Pole, and north (`0`) from the
+    /// South Pole.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::LatLng;
+    /// use std::f64::consts::{FRAC_PI_2, PI};
+    ///
+    /// let src = LatLng::new(0., 0.)?;
+    /// let dst = LatLng::new(0., 10.)?;
+    /// assert_eq!(src.azimuth_rads(dst), FRAC_PI_2);
+    ///
+    /// let north_pole = LatLng::new(90., 0.)?;
+    /// assert_eq!(north_pole.azimuth_rads(dst), PI);
+    /// # Ok::<(), h3o::error::InvalidLatLng>(())
+    /// ```
+    #[must_use]
+    pub fn azimuth_rads(self, other: Self) -> f64 {
+        if self == other {
+            return 0.;
+        }
+        if float_eq!(self.lat.abs(), FRAC_PI_2, abs <= EPSILON_RAD) {
+            return if self.lat > 0. { PI } else { 0. };
+        }
+        to_positive_angle(self.azimuth(&other))
+    }
+
+    /// Computes the coordinate reached by traveling along a great circle
+    /// from this coordinate, with the given initial bearing (in radians,
+    /// clockwise from the north) and distance (in meters).
+    ///
+    /// A negative distance travels in the opposite direction.
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidLatLng`] when the bearing or the distance is not a finite
+    /// number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::LatLng;
+    ///
+    /// let src = LatLng::new(48.864716, 2.349014)?;
+    /// let dst = src.offset(1., 10_000.)?;
+    /// assert!((src.distance_m(dst) - 10_000.).abs() < 1e-6);
+    /// assert!((src.azimuth_rads(dst) - 1.).abs() < 1e-9);
+    /// # Ok::<(), h3o::error::InvalidLatLng>(())
+    /// ```
+    pub fn offset(
+        self,
+        azimuth_rads: f64,
+        distance_m: f64,
+    ) -> Result<Self, InvalidLatLng> {
+        if !azimuth_rads.is_finite() {
+            return Err(InvalidLatLng::new(azimuth_rads, "infinite azimuth"));
+        }
+        if !distance_m.is_finite() {
+            return Err(InvalidLatLng::new(distance_m, "infinite distance"));
+        }
+
+        let distance = distance_m / 1000. / EARTH_RADIUS_KM;
+        let lat = asin(
+            mul_add(
+                sin(self.lat),
+                cos(distance),
+                cos(self.lat) * sin(distance) * cos(azimuth_rads),
+            )
+            .clamp(-1., 1.),
+        );
+        let lng = self.lng
+            + atan2(
+                sin(azimuth_rads) * sin(distance) * cos(self.lat),
+                mul_add(-sin(self.lat), sin(lat), cos(distance)),
+            );
+
+        // Wrap the longitude into [-π, π].
+        Self::from_radians(lat, atan2(sin(lng), cos(lng)))
+    }
+
+    /// The distance, in kilometers, between two coordinates using the
+    /// specified method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{DistanceMethod, LatLng};
+    ///
+    /// let src = LatLng::new(48.864716, 2.349014)?;
+    /// let dst = LatLng::new(31.224361, 121.469170)?;
+    ///
+    /// let distance = src.distance_km_with(dst, DistanceMethod::Vincenty);
+    /// # Ok::<(), h3o::error::InvalidLatLng>(())
+    /// ```
+    #[must_use]
+    pub fn distance_km_with(self, other: Self, method: DistanceMethod) -> f64 {
+        self.distance_m_with(other, method) / 1000.
+    }
+
+    /// The distance, in meters, between two coordinates using the specified
+    /// method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{DistanceMethod, LatLng};
+    ///
+    /// let src = LatLng::new(48.864716, 2.349014)?;
+    /// let dst = LatLng::new(31.224361, 121.469170)?;
+    ///
+    /// assert_eq!(
+    ///     src.distance_m_with(dst, DistanceMethod::Haversine),
+    ///     src.distance_m(dst)
+    /// );
+    /// # Ok::<(), h3o::error::InvalidLatLng>(())
+    /// ```
+    #[must_use]
+    pub fn distance_m_with(self, other: Self, method: DistanceMethod) -> f64 {
+        match method {
+            DistanceMethod::Haversine => self.distance_m(other),
+            DistanceMethod::SphericalLawOfCosines => {
+                distance::law_of_cosines_rads(self, other)
+                    * EARTH_RADIUS_KM
+                    * 1000.
+            }
+            DistanceMethod::Vincenty => distance::vincenty_m(self, other)
+                .unwrap_or_else(|| self.distance_m(other)),
+        }
+    }
+
     /// Indexes the location at the specified resolution, returning the index of
     /// the cell containing the location.
     ///
@@ -221,6 +493,113 @@ impl LatLng {
         self.to_face_ijk(resolution).to_cell(resolution)
     }
 
+    /// Indexes the location at several resolutions at once.
+    ///
+    /// This is faster than calling [`Self::to_cell`] for each resolution,
+    /// since most of the computation is shared, and yields the same cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{LatLng, Resolution};
+    ///
+    /// let ll = LatLng::new(48.864716, 2.349014)?;
+    /// let cells = ll
+    ///     .to_cells([Resolution::Seven, Resolution::Nine, Resolution::Eleven])
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(cells[1], ll.to_cell(Resolution::Nine));
+    /// # Ok::<(), h3o::error::InvalidLatLng>(())
+    /// ```
+    pub fn to_cells(
+        self,
+        resolutions: impl IntoIterator<Item = Resolution>,
+    ) -> impl Iterator<Item = CellIndex> {
+        let (face, distance) = self.closest_face();
+        let polar = self.to_polar(face, distance).map(|(r, theta)| {
+            let class3 = theta - AP7_ROT_RADS;
+            (r, (cos(theta), sin(theta)), (cos(class3), sin(class3)))
+        });
+
+        resolutions.into_iter().map(move |resolution| {
+            let coord =
+                polar.map_or(Vec2d::new(0., 0.), |(r, class2, class3)| {
+                    let r = r * SQRT7_POWERS[usize::from(resolution)];
+                    let (cos, sin) = if resolution.is_class3() {
+                        class3
+                    } else {
+                        class2
+                    };
+                    Vec2d::new(r * cos, r * sin)
+                });
+            FaceIJK::new(face, coord.into()).to_cell(resolution)
+        })
+    }
+
+    /// Indexes a batch of coordinates, given as `(lat, lng)` degrees, at the
+    /// specified resolution.
+    ///
+    /// The cells are appended to `out`, in the same order as the points, and
+    /// are the same as the ones returned by [`Self::to_cell`]. When the
+    /// `rayon` feature is enabled, large batches are indexed in parallel:
+    /// otherwise, this is equivalent to calling [`Self::to_cell`] in a loop.
+    ///
+    /// # Errors
+    ///
+    /// The position of the first invalid point, along with the
+    /// [`InvalidLatLng`] error. In that case, `out` is left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{LatLng, Resolution};
+    ///
+    /// let points = [(48.864716, 2.349014), (45.764043, 4.835659)];
+    /// let mut cells = Vec::new();
+    /// LatLng::to_cells_batch(&points, Resolution::Nine, &mut cells)
+    ///     .map_err(|(_, err)| err)?;
+    ///
+    /// let ll = LatLng::new(45.764043, 4.835659)?;
+    /// assert_eq!(cells[1], ll.to_cell(Resolution::Nine));
+    /// # Ok::<(), h3o::error::InvalidLatLng>(())
+    /// ```
+    pub fn to_cells_batch(
+        points: &[(f64, f64)],
+        resolution: Resolution,
+        out: &mut Vec<CellIndex>,
+    ) -> Result<(), (usize, InvalidLatLng)> {
+        #[cfg(feature = "rayon")]
+        if points.len() >= PAR_BATCH_MIN_LEN {
+            // Validate the whole batch upfront, to report the first invalid
+            // point regardless of the scheduling.
+            for (i, &(lat, lng)) in points.iter().enumerate() {
+                Self::new(lat, lng).map_err(|err| (i, err))?;
+            }
+            out.par_extend(
+                points.par_iter().with_min_len(PAR_BATCH_MIN_LEN).map(
+                    |&(lat, lng)| {
+                        Self::new_unchecked(lat.to_radians(), lng.to_radians())
+                            .to_cell(resolution)
+                    },
+                ),
+            );
+            return Ok(());
+        }
+
+        let len = out.len();
+        out.reserve(points.len());
+        for (i, &(lat, lng)) in points.iter().enumerate() {
+            match Self::new(lat, lng) {
+                Ok(ll) => out.push(ll.to_cell(resolution)),
+                Err(err) => {
+                    out.truncate(len);
+                    return Err((i, err));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Encodes a coordinate on the sphere to the `FaceIJK` address of the
     /// containing cell at the specified resolution.
     ///
@@ -236,36 +615,40 @@ impl LatLng {
         face: Face,
         distance: f64,
     ) -> Vec2d {
-        let face = usize::from(face);
-
-        let r = {
-            // cos(r) = 1 - 2 * sin^2(r/2) = 1 - 2 * (sqd / 4) = 1 - sqd/2
-            let r = acos(1. - distance / 2.);
+        let Some((r, mut theta)) = self.to_polar(face, distance) else {
+            return Vec2d::new(0., 0.);
+        };
+        // Scale for current resolution length `u`.
+        let r = r * SQRT7_POWERS[usize::from(resolution)];
+        // Adjust `theta` for Class III.
+        if resolution.is_class3() {
+            theta -= AP7_ROT_RADS;
+        }
 
-            if r < EPSILON {
-                return Vec2d::new(0., 0.);
-            }
+        // Convert to local x, y.
+        Vec2d::new(r * cos(theta), r * sin(theta))
+    }
 
-            // Perform gnomonic scaling of `r` (`tan(r)`) and scale for current
-            // resolution length `u`.
-            (tan(r) * INV_RES0_U_GNOMONIC)
-                * SQRT7_POWERS[usize::from(resolution)]
-        };
+    /// Computes the resolution-independent part of the gnomonic projection on
+    /// the given face: the (unscaled) radius and the counter-clockwise angle
+    /// from the Class II i-axis.
+    ///
+    /// Returns `None` if the coordinate is at the center of the face.
+    fn to_polar(self, face: Face, distance: f64) -> Option<(f64, f64)> {
+        let face = usize::from(face);
 
-        let theta = {
-            // Compute counter-clockwise `theta` from Class II i-axis.
-            let mut theta = face::AXES_AZ_RADS_CII[face][0]
-                - face::CENTER_GEO[face].azimuth(&self);
+        // cos(r) = 1 - 2 * sin^2(r/2) = 1 - 2 * (sqd / 4) = 1 - sqd/2
+        let r = acos(1. - distance / 2.);
+        if r < EPSILON {
+            return None;
+        }
+        // Perform gnomonic scaling of `r` (`tan(r)`).
+        let r = tan(r) * INV_RES0_U_GNOMONIC;
 
-            // Adjust `theta` for Class III.
-            if resolution.is_class3() {
-                theta -= AP7_ROT_RADS;
-            }
-            theta
-        };
+        let theta = face::AXES_AZ_RADS_CII[face][0]
+            - face::CENTER_GEO[face].azimuth(&self);
 
-        // Convert to local x, y.
-        Vec2d::new(r * cos(theta), r * sin(theta))
+        Some((r, theta))
     }
 
     /// Finds the closest icosahedral face from the current coordinate.
@@ -302,11 +685,10 @@ impl LatLng {
     pub(crate) fn azimuth(self, other: &Self) -> f64 {
         atan2(
             cos(other.lat) * sin(other.lng - self.lng),
-            mul_add(
-                cos(self.lat),
-                sin(other.lat),
-                -sin(self.lat) * cos(other.lat) * cos(other.lng - self.lng),
-            ),
+            sum_of_products([
+                (cos(self.lat), sin(other.lat)),
+                (-sin(self.lat) * cos(other.lat), cos(other.lng - self.lng)),
+            ]),
         )
     }
 
@@ -330,11 +712,10 @@ impl LatLng {
             }
         } else {
             asin(
-                mul_add(
-                    sin(self.lat),
-                    cos(distance),
-                    cos(self.lat) * sin(distance) * cos(azimuth),
-                )
+                sum_of_products([
+                    (sin(self.lat), cos(distance)),
+                    (cos(self.lat) * sin(distance), cos(azimuth)),
+                ])
                 .clamp(-1., 1.),
             )
         };
@@ -352,8 +733,10 @@ impl LatLng {
         } else {
             let sinlng =
                 (sin(azimuth) * sin(distance) / cos(lat)).clamp(-1., 1.);
-            let coslng = mul_add(sin(self.lat), sin(-lat), cos(distance))
-                / cos(self.lat)
+            let coslng = sum_of_products([
+                (sin(self.lat), sin(-lat)),
+                (cos(distance), 1.),
+            ]) / cos(self.lat)
                 / cos(lat);
             self.lng + atan2(sinlng, coslng)
         };
@@ -429,6 +812,25 @@ impl fmt::Display for LatLng {
     }
 }
 
+impl FromStr for LatLng {
+    type Err = ParseLatLngError;
+
+    /// Parses a `lat,lng` string, where each component is either in decimal
+    /// degrees (e.g. `-33.8688`) or in degrees-minutes-seconds (e.g.
+    /// `33°52'7.68"S`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (lat, lng) =
+            s.split_once(',').ok_or(ParseLatLngError::Separator)?;
+        if lng.contains(',') {
+            return Err(ParseLatLngError::Separator);
+        }
+        let lat = parse_component(lat, Axis::Latitude)?;
+        let lng = parse_component(lng, Axis::Longitude)?;
+
+        Self::new(lat, lng).map_err(|_| ParseLatLngError::Number)
+    }
+}
+
 impl fmt::Debug for LatLng {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("LatLng")
@@ -450,6 +852,32 @@ impl From<LatLng> for geo::Coord {
     }
 }
 
+#[cfg(feature = "geozero")]
+impl geozero::GeozeroGeometry for LatLng {
+    fn process_geom<P: geozero::GeomProcessor>(
+        &self,
+        processor: &mut P,
+    ) -> geozero::error::Result<()> {
+        processor.point_begin(0)?;
+        process_coord(*self, 0, processor)?;
+        processor.point_end(0)
+    }
+}
+
+/// Feeds a coordinate, in degrees, to a `geozero` processor.
+#[cfg(feature = "geozero")]
+pub fn process_coord<P: geozero::GeomProcessor>(
+    ll: LatLng,
+    idx: usize,
+    processor: &mut P,
+) -> geozero::error::Result<()> {
+    if processor.multi_dim() {
+        processor.coordinate(ll.lng(), ll.lat(), None, None, None, None, idx)
+    } else {
+        processor.xy(ll.lng(), ll.lat(), idx)
+    }
+}
+
 #[cfg(feature = "typed_floats")]
 mod typed_floats {
     // Types for readability
@@ -489,3 +917,148 @@ impl<'a> arbitrary::Arbitrary<'a> for LatLng {
 #[cfg(test)]
 #[path = "./latlng_tests.rs"]
 mod tests;
+
+// -----------------------------------------------------------------------------
+
+/// Displays a coordinate as `lat,lng`, in decimal degrees.
+struct Decimal(LatLng);
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.0.lat(), self.0.lng())
+    }
+}
+
+/// Displays a coordinate in degrees-minutes-seconds.
+struct Dms(LatLng);
+
+impl fmt::Display for Dms {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lat = self.0.lat();
+        let lng = self.0.lng();
+
+        write_dms(f, lat, if lat < 0. { 'S' } else { 'N' })?;
+        write!(f, ", ")?;
+        write_dms(f, lng, if lng < 0. { 'W' } else { 'E' })
+    }
+}
+
+/// Writes an angle in degrees-minutes-seconds, followed by its hemisphere.
+fn write_dms(
+    f: &mut fmt::Formatter<'_>,
+    value: f64,
+    hemisphere: char,
+) -> fmt::Result {
+    // Work on milliseconds of arc, to properly carry the rounding.
+    #[expect(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "absolute value of a finite angle in degrees"
+    )]
+    let millis = crate::math::round(value.abs() * 3_600_000.) as u64;
+    let (degrees, millis) = (millis / 3_600_000, millis % 3_600_000);
+    let (minutes, millis) = (millis / 60_000, millis % 60_000);
+    let (seconds, millis) = (millis / 1000, millis % 1000);
+
+    write!(f, "{degrees}°{minutes}'{seconds}.{millis:03}\"{hemisphere}")
+}
+
+/// Coordinate axis, used to validate the range and hemisphere of components.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    Latitude,
+    Longitude,
+}
+
+/// Parses a coordinate component, in decimal degrees or
+/// degrees-minutes-seconds, with an optional hemisphere.
+fn parse_component(s: &str, axis: Axis) -> Result<f64, ParseLatLngError> {
+    let s = s.trim();
+
+    // Hemisphere, either as a prefix or a suffix.
+    let is_hemisphere = |c: char| "NSEWnsew".contains(c);
+    let (s, hemisphere) =
+        match (s.strip_prefix(is_hemisphere), s.strip_suffix(is_hemisphere)) {
+            (Some(rest), _) => (rest, s.chars().next()),
+            (None, Some(rest)) => (rest, s.chars().next_back()),
+            (None, None) => (s, None),
+        };
+    let sign = match hemisphere.map(|c| c.to_ascii_uppercase()) {
+        None | Some('N') if axis == Axis::Latitude => 1.,
+        Some('S') if axis == Axis::Latitude => -1.,
+        None | Some('E') if axis == Axis::Longitude => 1.,
+        Some('W') if axis == Axis::Longitude => -1.,
+        _ => return Err(ParseLatLngError::Hemisphere),
+    };
+
+    let s = s.trim();
+    let (s, negative) = s.strip_prefix('-').map_or_else(
+        || (s.strip_prefix('+').unwrap_or(s), false),
+        |s| (s, true),
+    );
+    if negative && hemisphere.is_some() {
+        return Err(ParseLatLngError::Hemisphere);
+    }
+
+    let value = parse_dms(s)?;
+    let max = match axis {
+        Axis::Latitude => 90.,
+        Axis::Longitude => 180.,
+    };
+    if value > max {
+        return Err(ParseLatLngError::OutOfRange);
+    }
+
+    Ok(if negative { -value } else { sign * value })
+}
+
+/// Parses an unsigned angle, in decimal degrees or degrees-minutes-seconds.
+fn parse_dms(s: &str) -> Result<f64, ParseLatLngError> {
+    let number = |s: &str| {
+        let s = s.trim();
+        // Reject signs and the special values accepted by `f64::from_str`.
+        if !s.starts_with(|c: char| c.is_ascii_digit() || c == '.') {
+            return Err(ParseLatLngError::Number);
+        }
+        s.parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite())
+            .ok_or(ParseLatLngError::Number)
+    };
+
+    let Some((degrees, rest)) = s.split_once(['°', 'º']) else {
+        return number(s);
+    };
+    // Only the last component can have a fractional part.
+    let rest = rest.trim();
+    if degrees.contains('.') && !rest.is_empty() {
+        return Err(ParseLatLngError::Number);
+    }
+    let degrees = number(degrees)?;
+    if rest.is_empty() {
+        return Ok(degrees);
+    }
+
+    let (minutes, rest) = rest
+        .split_once(['\'', '′'])
+        .ok_or(ParseLatLngError::Number)?;
+    let rest = rest.trim();
+    if minutes.contains('.') && !rest.is_empty() {
+        return Err(ParseLatLngError::Number);
+    }
+    let minutes = number(minutes)?;
+    let seconds = if rest.is_empty() {
+        0.
+    } else {
+        let seconds = rest
+            .strip_suffix(['"', '″'])
+            .ok_or(ParseLatLngError::Number)?;
+        number(seconds)?
+    };
+
+    if minutes >= 60. || seconds >= 60. {
+        return Err(ParseLatLngError::OutOfRange);
+    }
+
+    Ok(degrees + minutes / 60. + seconds / 3600.)
+}
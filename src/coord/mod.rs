@@ -3,6 +3,7 @@
 //! See [Coordinate systems](https://h3geo.org/docs/next/core-library/coordsystems)
 
 mod cube;
+mod distance;
 mod faceijk;
 mod ijk;
 mod latlng;
@@ -11,15 +12,18 @@ mod vec2d;
 mod vec3d;
 
 pub use cube::CoordCube;
+pub use distance::DistanceMethod;
 pub use faceijk::{FaceIJK, Overage};
 pub use ijk::{CoordIJ, CoordIJK};
+#[cfg(feature = "geozero")]
+pub use latlng::process_coord;
 pub use latlng::LatLng;
 pub use localij::{LocalIJ, LocalIJK};
 pub use vec3d::Vec3d;
 
 use vec2d::Vec2d;
 
-use crate::TWO_PI;
+use crate::{math::mul_add, TWO_PI};
 
 // -----------------------------------------------------------------------------
 
@@ -94,3 +98,38 @@ pub fn to_positive_angle(mut angle: f64) -> f64 {
 
     angle
 }
+
+/// Computes the sum of the products of each pair of terms.
+///
+/// With the `fma` feature, the sum is compensated: the rounding error of each
+/// product is recovered exactly with a fused multiply-add, and the additions
+/// use the Neumaier summation. The result is then as accurate as if computed
+/// with twice the working precision, even when the terms cancel each other.
+pub fn sum_of_products<const N: usize>(terms: [(f64, f64); N]) -> f64 {
+    #[cfg(feature = "fma")]
+    {
+        use crate::math::abs;
+
+        let (sum, error) =
+            terms.into_iter().fold((0., 0.), |(sum, error), (a, b)| {
+                let product = a * b;
+                let total = sum + product;
+                let lost = if abs(sum) >= abs(product) {
+                    (sum - total) + product
+                } else {
+                    (product - total) + sum
+                };
+                (total, error + lost + mul_add(a, b, -product))
+            });
+        sum + error
+    }
+    #[cfg(not(feature = "fma"))]
+    {
+        let Some((&(a, b), rest)) = terms.split_last() else {
+            return 0.;
+        };
+        rest.iter()
+            .rev()
+            .fold(a * b, |sum, &(a, b)| mul_add(a, b, sum))
+    }
+}
@@ -1,6 +1,15 @@
-use crate::math::mul_add;
+use super::sum_of_products;
+use crate::{
+    math::{asin, atan2, mul_add, sqrt},
+    LatLng,
+};
 
 /// 3D floating-point vector.
+///
+/// Used to represent points of the unit sphere as Cartesian coordinates (see
+/// [`LatLng::to_vec3`](crate::LatLng::to_vec3)), with the `x` axis going
+/// through (0, 0), the `y` axis through (0, 90) and the `z` axis through the
+/// North pole.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Vec3d {
     /// `x` component.
@@ -13,17 +22,151 @@ pub struct Vec3d {
 
 impl Vec3d {
     /// Initializes a new 3D vector with the specified component values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let v = h3o::Vec3d::new(0., 0., 1.);
+    /// ```
+    #[must_use]
     pub const fn new(x: f64, y: f64, z: f64) -> Self {
         Self { x, y, z }
     }
 
     /// Computes the square of the distance between two 3D coordinates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::Vec3d;
+    ///
+    /// let v1 = Vec3d::new(0., 0., 0.);
+    /// let v2 = Vec3d::new(0., 1., 1.);
+    /// assert_eq!(v1.distance(&v2), 2.);
+    /// ```
+    #[must_use]
     pub fn distance(&self, other: &Self) -> f64 {
         let x_diff = self.x - other.x;
         let y_diff = self.y - other.y;
         let z_diff = self.z - other.z;
 
-        mul_add(x_diff, x_diff, mul_add(y_diff, y_diff, z_diff * z_diff))
+        sum_of_products([(x_diff, x_diff), (y_diff, y_diff), (z_diff, z_diff)])
+    }
+
+    /// Computes the dot product of two 3D vectors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::Vec3d;
+    ///
+    /// let v1 = Vec3d::new(1., 2., 3.);
+    /// let v2 = Vec3d::new(4., -5., 6.);
+    /// assert_eq!(v1.dot(&v2), 12.);
+    /// ```
+    #[must_use]
+    pub fn dot(&self, other: &Self) -> f64 {
+        sum_of_products([
+            (self.x, other.x),
+            (self.y, other.y),
+            (self.z, other.z),
+        ])
+    }
+
+    /// Computes the cross product of two 3D vectors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::Vec3d;
+    ///
+    /// let x = Vec3d::new(1., 0., 0.);
+    /// let y = Vec3d::new(0., 1., 0.);
+    /// assert_eq!(x.cross(&y), Vec3d::new(0., 0., 1.));
+    /// ```
+    #[must_use]
+    pub fn cross(&self, other: &Self) -> Self {
+        Self::new(
+            sum_of_products([(self.y, other.z), (-self.z, other.y)]),
+            sum_of_products([(self.z, other.x), (-self.x, other.z)]),
+            sum_of_products([(self.x, other.y), (-self.y, other.x)]),
+        )
+    }
+
+    /// Returns the length of the vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::Vec3d;
+    ///
+    /// let v = Vec3d::new(2., 3., 6.);
+    /// assert_eq!(v.norm(), 7.);
+    /// ```
+    #[must_use]
+    pub fn norm(&self) -> f64 {
+        sqrt(self.dot(self))
+    }
+
+    /// Returns the unit vector with the same direction.
+    ///
+    /// The components of the result are NaN for the null vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::Vec3d;
+    ///
+    /// let v = Vec3d::new(0., 3., 4.).normalize();
+    /// assert_eq!(v, Vec3d::new(0., 0.6, 0.8));
+    /// ```
+    #[must_use]
+    pub fn normalize(&self) -> Self {
+        let norm = self.norm();
+        Self::new(self.x / norm, self.y / norm, self.z / norm)
+    }
+
+    /// Returns the component of the vector orthogonal to the unit vector
+    /// `axis`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::Vec3d;
+    ///
+    /// let v = Vec3d::new(1., 2., 3.);
+    /// let z = Vec3d::new(0., 0., 1.);
+    /// assert_eq!(v.reject(&z), Vec3d::new(1., 2., 0.));
+    /// ```
+    #[must_use]
+    pub fn reject(&self, axis: &Self) -> Self {
+        let dot = self.dot(axis);
+        Self::new(
+            mul_add(-dot, axis.x, self.x),
+            mul_add(-dot, axis.y, self.y),
+            mul_add(-dot, axis.z, self.z),
+        )
+    }
+
+    /// Converts a unit vector into spherical coordinates.
+    ///
+    /// This is the inverse of [`LatLng::to_vec3`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{LatLng, Vec3d};
+    ///
+    /// let ll = Vec3d::new(0., 0., 1.).to_latlng();
+    /// assert_eq!(ll, LatLng::new(90., 0.)?);
+    /// # Ok::<(), h3o::error::InvalidLatLng>(())
+    /// ```
+    #[must_use]
+    pub fn to_latlng(&self) -> LatLng {
+        LatLng::new_unchecked(
+            asin(self.z.clamp(-1., 1.)),
+            atan2(self.y, self.x),
+        )
     }
 }
 
@@ -40,3 +40,54 @@ fn distance() {
         "distance to <1,1,2> is 6"
     );
 }
+
+#[test]
+fn cross() {
+    let x = Vec3d::new(1., 0., 0.);
+    let y = Vec3d::new(0., 1., 0.);
+    let z = Vec3d::new(0., 0., 1.);
+
+    assert_eq!(x.cross(&y), z, "x × y = z");
+    assert_eq!(y.cross(&x), Vec3d::new(0., 0., -1.), "y × x = -z");
+    assert_eq!(x.cross(&x), Vec3d::new(0., 0., 0.), "x × x = 0");
+}
+
+#[test]
+fn normalize() {
+    let v = Vec3d::new(1., 2., 2.).normalize();
+
+    assert_float_eq!(v.norm(), 1., abs <= f64::EPSILON, "unit length");
+    assert_float_eq!(v.x, 1. / 3., abs <= f64::EPSILON, "x component");
+    assert!(Vec3d::new(0., 0., 0.).normalize().x.is_nan(), "null vector");
+}
+
+#[test]
+fn reject() {
+    let v = Vec3d::new(3., -1., 2.);
+    let axis = Vec3d::new(1., 1., 1.).normalize();
+    let rejection = v.reject(&axis);
+
+    assert_float_eq!(
+        rejection.dot(&axis),
+        0.,
+        abs <= 1e-15,
+        "orthogonal to the axis"
+    );
+}
+
+#[test]
+fn to_latlng_roundtrip() {
+    let ll = LatLng::new(48.854501508, 2.37304743).expect("ll");
+
+    assert_eq!(ll.to_vec3().to_latlng(), ll);
+}
+
+#[cfg(feature = "fma")]
+#[test]
+fn dot_compensated() {
+    // The plain sum rounds `1e16 + 1` to `1e16` and returns 0.
+    let v1 = Vec3d::new(1e16, 1., -1e16);
+    let v2 = Vec3d::new(1., 1., 1.);
+
+    assert_float_eq!(v1.dot(&v2), 1., abs <= 0.);
+}
@@ -0,0 +1,189 @@
+use crate::CellIndex;
+use alloc::vec::Vec;
+
+/// Differences between two coverages, as computed by [`coverage_delta`].
+///
+/// Each set is made of non-overlapping cells, at mixed resolutions: the
+/// coarsest cells of the inputs are preserved whenever possible.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CoverageDelta {
+    /// Cells only covered by the new coverage.
+    pub added: Vec<CellIndex>,
+    /// Cells only covered by the old coverage.
+    pub removed: Vec<CellIndex>,
+    /// Cells covered by both coverages.
+    pub retained: Vec<CellIndex>,
+}
+
+impl CoverageDelta {
+    /// Returns summary statistics about the differences.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{coverage_delta, CellIndex, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let child = index.center_child(Resolution::Eleven).expect("child");
+    /// let delta = coverage_delta([index], [child]);
+    ///
+    /// let summary = delta.summary();
+    /// assert!(summary.removed_km2 > summary.retained_km2);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn summary(&self) -> DeltaSummary {
+        let area = |cells: &[CellIndex]| {
+            cells.iter().map(|cell| cell.area_km2()).sum::<f64>()
+        };
+
+        DeltaSummary {
+            added_km2: area(&self.added),
+            removed_km2: area(&self.removed),
+            retained_km2: area(&self.retained),
+        }
+    }
+}
+
+/// Summary statistics of a [`CoverageDelta`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DeltaSummary {
+    /// Area, in km², only covered by the new coverage.
+    pub added_km2: f64,
+    /// Area, in km², only covered by the old coverage.
+    pub removed_km2: f64,
+    /// Area, in km², covered by both coverages.
+    pub retained_km2: f64,
+}
+
+/// Computes the differences between two coverages.
+///
+/// The coverages can be made of cells at mixed resolutions (e.g. compacted
+/// sets): cells are only split when they partially overlap a cell of the
+/// other coverage, which keeps the memory usage proportional to the size of
+/// the inputs and of the differences, rather than to the covered area.
+///
+/// Duplicate cells, and cells already covered by one of their ancestors, are
+/// ignored.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{coverage_delta, CellIndex, Resolution};
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let child = index.center_child(Resolution::Eleven).expect("child");
+/// let neighbor = CellIndex::try_from(0x8a1fb46622d7fff)?;
+///
+/// let delta = coverage_delta([index], [child, neighbor]);
+/// assert_eq!(delta.added, vec![neighbor]);
+/// assert_eq!(delta.removed.len(), 6);
+/// assert_eq!(delta.retained, vec![child]);
+/// # Ok::<(), h3o::error::InvalidCellIndex>(())
+/// ```
+#[must_use]
+pub fn coverage_delta(
+    before: impl IntoIterator<Item = CellIndex>,
+    after: impl IntoIterator<Item = CellIndex>,
+) -> CoverageDelta {
+    let before = normalize(before);
+    let after = normalize(after);
+    let mut delta = CoverageDelta::default();
+
+    for &cell in &before {
+        if let Some(inner) = descendants(cell, &after) {
+            subtract(cell, inner, &mut delta.removed, &mut delta.retained);
+        } else {
+            delta.retained.push(cell);
+        }
+    }
+    // Overlaps are already accounted for, only keep the new parts.
+    let mut ignored = Vec::new();
+    for &cell in &after {
+        if let Some(inner) = descendants(cell, &before) {
+            subtract(cell, inner, &mut delta.added, &mut ignored);
+            ignored.clear();
+        }
+    }
+
+    delta
+}
+
+// -----------------------------------------------------------------------------
+
+/// Returns the sort key of a cell.
+///
+/// Cells are ordered while ignoring the resolution, which puts the
+/// descendants of a cell right before it.
+fn key(cell: CellIndex) -> u64 {
+    h3o_bit::clr_resolution(u64::from(cell))
+}
+
+/// Returns the smallest key of the descendants of a cell.
+fn first_key(cell: CellIndex) -> u64 {
+    // Unused directions are set to 7, clear them to get the first child of
+    // every subsequent resolution.
+    let offset = cell.resolution().direction_offset();
+    key(cell) & !((1 << offset) - 1)
+}
+
+/// Sorts the cells and removes the ones already covered by another cell.
+fn normalize(cells: impl IntoIterator<Item = CellIndex>) -> Vec<CellIndex> {
+    let mut cells = cells.into_iter().collect::<Vec<_>>();
+    cells.sort_unstable();
+    cells.dedup();
+
+    let mut normalized = Vec::<CellIndex>::with_capacity(cells.len());
+    for cell in cells {
+        // Descendants are sorted right before their ancestor.
+        let first = first_key(cell);
+        while normalized.last().is_some_and(|&last| key(last) >= first) {
+            normalized.pop();
+        }
+        normalized.push(cell);
+    }
+
+    normalized
+}
+
+/// Returns the cells (of a normalized set) that are descendants of `cell`.
+///
+/// Returns `None` if `cell` is covered by one of them (or an ancestor).
+fn descendants(cell: CellIndex, cells: &[CellIndex]) -> Option<&[CellIndex]> {
+    let end = cells.partition_point(|&other| key(other) < key(cell));
+    if cells
+        .get(end)
+        .is_some_and(|&other| first_key(other) <= key(cell))
+    {
+        return None;
+    }
+
+    let first = first_key(cell);
+    let start = cells[..end].partition_point(|&other| key(other) < first);
+    Some(&cells[start..end])
+}
+
+/// Splits `cell` into the parts outside and inside the set of descendants.
+fn subtract(
+    cell: CellIndex,
+    inner: &[CellIndex],
+    outside: &mut Vec<CellIndex>,
+    inside: &mut Vec<CellIndex>,
+) {
+    if inner.is_empty() {
+        outside.push(cell);
+        return;
+    }
+    if inner == [cell] {
+        inside.push(cell);
+        return;
+    }
+
+    let resolution = cell.resolution().succ().expect("non-leaf cell");
+    for child in cell.children(resolution) {
+        let (first, last) = (first_key(child), key(child));
+        let start = inner.partition_point(|&other| key(other) < first);
+        let end = inner.partition_point(|&other| key(other) <= last);
+        subtract(child, &inner[start..end], outside, inside);
+    }
+}
@@ -0,0 +1,36 @@
+use core::{error::Error, fmt};
+
+/// Errors occurring while looking up a cell relative (parent, child, edge,
+/// vertex, ...).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CellLookupError {
+    /// The target resolution is finer than the one of the cell.
+    FinerResolution,
+    /// The target resolution is coarser than the one of the cell.
+    CoarserResolution,
+    /// The cells have different resolutions.
+    ResolutionMismatch,
+    /// The cells are not neighbors.
+    NotNeighbor,
+    /// The position (child position, vertex number, ...) is out of range.
+    OutOfRange,
+}
+
+impl fmt::Display for CellLookupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::FinerResolution => write!(f, "finer target resolution"),
+            Self::CoarserResolution => write!(f, "coarser target resolution"),
+            Self::ResolutionMismatch => write!(f, "resolution mismatch"),
+            Self::NotNeighbor => write!(f, "cells are not neighbors"),
+            Self::OutOfRange => write!(f, "position out of range"),
+        }
+    }
+}
+
+impl Error for CellLookupError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
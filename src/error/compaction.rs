@@ -8,6 +8,8 @@ pub enum CompactionError {
     HeterogeneousResolution,
     /// Input set contains duplicate indices.
     DuplicateInput,
+    /// Input stream isn't sorted.
+    UnsortedInput,
 }
 
 impl fmt::Display for CompactionError {
@@ -17,6 +19,7 @@ impl fmt::Display for CompactionError {
                 write!(f, "heterogeneous resolution")
             }
             Self::DuplicateInput => write!(f, "duplicate indices"),
+            Self::UnsortedInput => write!(f, "unsorted indices"),
         }
     }
 }
@@ -0,0 +1,49 @@
+use crate::error::InvalidCellIndex;
+use core::{error::Error, fmt};
+
+/// Errors occurring while reading or writing `GeoJSON`.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum GeoJsonError {
+    /// Malformed JSON.
+    Json(serde_json::Error),
+    /// Input isn't a valid `FeatureCollection`.
+    InvalidStructure(&'static str),
+    /// The feature at the given position has no index property.
+    MissingIndex(usize),
+    /// The feature at the given position has an invalid index property.
+    InvalidIndex(usize, InvalidCellIndex),
+}
+
+impl fmt::Display for GeoJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Json(ref err) => write!(f, "malformed JSON: {err}"),
+            Self::InvalidStructure(reason) => {
+                write!(f, "invalid FeatureCollection: {reason}")
+            }
+            Self::MissingIndex(feature) => {
+                write!(f, "missing cell index in feature {feature}")
+            }
+            Self::InvalidIndex(feature, err) => {
+                write!(f, "invalid cell index in feature {feature}: {err}")
+            }
+        }
+    }
+}
+
+impl Error for GeoJsonError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            Self::Json(ref err) => Some(err),
+            Self::InvalidIndex(_, ref err) => Some(err),
+            Self::InvalidStructure(_) | Self::MissingIndex(_) => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for GeoJsonError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::Json(value)
+    }
+}
@@ -78,6 +78,8 @@ pub enum DissolutionError {
     UnsupportedResolution,
     /// Input set contains duplicate cell indexes.
     DuplicateInput,
+    /// Input cells and their labels have different lengths.
+    LengthMismatch,
 }
 
 impl fmt::Display for DissolutionError {
@@ -87,6 +89,9 @@ impl fmt::Display for DissolutionError {
                 write!(f, "unsupported resolution")
             }
             Self::DuplicateInput => write!(f, "duplicate indices"),
+            Self::LengthMismatch => {
+                write!(f, "cells and labels have different lengths")
+            }
         }
     }
 }
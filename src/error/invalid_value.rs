@@ -1,3 +1,4 @@
+use crate::Kernel;
 use core::{error::Error, fmt};
 
 // Macro to declare type-specific InvalidValue error type.
@@ -46,6 +47,7 @@ macro_rules! invalid_value_error {
 
 invalid_value_error!("resolution", InvalidResolution, Option<u8>);
 invalid_value_error!("cell index", InvalidCellIndex, Option<u64>);
+invalid_value_error!("index", InvalidIndex, u64);
 invalid_value_error!("vertex index", InvalidVertexIndex, Option<u64>);
 invalid_value_error!(
     "directed edge index",
@@ -58,3 +60,4 @@ invalid_value_error!("cell vertex", InvalidVertex, u8);
 invalid_value_error!("icosahedron face", InvalidFace, u8);
 invalid_value_error!("base cell", InvalidBaseCell, u8);
 invalid_value_error!("direction", InvalidDirection, u8);
+invalid_value_error!("kernel", InvalidKernel, Kernel);
@@ -1,26 +1,36 @@
 //! H3O error types.
 
+mod cell_lookup;
 mod compaction;
 mod hex_grid;
 mod invalid_value;
 mod localij;
+mod parse_latlng;
 mod resolution_mismatch;
+mod treemap;
 
+#[cfg(feature = "geojson")]
+mod geojson;
 #[cfg(feature = "geo")]
 mod geom;
 
 #[cfg(test)]
 mod tests;
 
+pub use cell_lookup::CellLookupError;
 pub use compaction::CompactionError;
 pub use hex_grid::HexGridError;
 pub use invalid_value::{
     InvalidBaseCell, InvalidCellIndex, InvalidDirectedEdgeIndex,
-    InvalidDirection, InvalidEdge, InvalidFace, InvalidLatLng,
-    InvalidResolution, InvalidVertex, InvalidVertexIndex,
+    InvalidDirection, InvalidEdge, InvalidFace, InvalidIndex, InvalidKernel,
+    InvalidLatLng, InvalidResolution, InvalidVertex, InvalidVertexIndex,
 };
 pub use localij::LocalIjError;
+pub use parse_latlng::ParseLatLngError;
 pub use resolution_mismatch::ResolutionMismatch;
+pub use treemap::InvalidTreemap;
 
+#[cfg(feature = "geojson")]
+pub use geojson::GeoJsonError;
 #[cfg(feature = "geo")]
 pub use geom::{DissolutionError, InvalidGeometry, PlotterError};
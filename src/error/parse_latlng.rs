@@ -0,0 +1,32 @@
+use core::{error::Error, fmt};
+
+/// Errors occurring while parsing a coordinate from a string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseLatLngError {
+    /// Latitude and longitude are not separated by a single comma.
+    Separator,
+    /// A component is not a valid number (or degrees-minutes-seconds).
+    Number,
+    /// Hemisphere doesn't match the component, or conflicts with the sign.
+    Hemisphere,
+    /// A component (degrees, minutes or seconds) is out of range.
+    OutOfRange,
+}
+
+impl fmt::Display for ParseLatLngError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Separator => write!(f, "expected `lat,lng`"),
+            Self::Number => write!(f, "invalid number"),
+            Self::Hemisphere => write!(f, "invalid hemisphere"),
+            Self::OutOfRange => write!(f, "value out of range"),
+        }
+    }
+}
+
+impl Error for ParseLatLngError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
@@ -1,23 +1,54 @@
+#[cfg(feature = "geojson")]
+use crate::error::GeoJsonError;
 use crate::error::{
-    CompactionError, HexGridError, InvalidBaseCell, InvalidCellIndex,
-    InvalidDirectedEdgeIndex, InvalidDirection, InvalidEdge, InvalidFace,
-    InvalidLatLng, InvalidResolution, InvalidVertex, InvalidVertexIndex,
-    LocalIjError, ResolutionMismatch,
+    CellLookupError, CompactionError, HexGridError, InvalidBaseCell,
+    InvalidCellIndex, InvalidDirectedEdgeIndex, InvalidDirection, InvalidEdge,
+    InvalidFace, InvalidIndex, InvalidKernel, InvalidLatLng, InvalidResolution,
+    InvalidTreemap, InvalidVertex, InvalidVertexIndex, LocalIjError,
+    ParseLatLngError, ResolutionMismatch,
 };
 #[cfg(feature = "geo")]
 use crate::error::{DissolutionError, InvalidGeometry, PlotterError};
+use crate::Kernel;
 use alloc::string::ToString;
 use core::error::Error as _;
 
+const COMPACTION_ERRORS: [CompactionError; 3] = [
+    CompactionError::HeterogeneousResolution,
+    CompactionError::DuplicateInput,
+    CompactionError::UnsortedInput,
+];
+
+const CELL_LOOKUP_ERRORS: [CellLookupError; 5] = [
+    CellLookupError::FinerResolution,
+    CellLookupError::CoarserResolution,
+    CellLookupError::ResolutionMismatch,
+    CellLookupError::NotNeighbor,
+    CellLookupError::OutOfRange,
+];
+
+#[cfg(feature = "geo")]
+const DISSOLUTION_ERRORS: [DissolutionError; 3] = [
+    DissolutionError::UnsupportedResolution,
+    DissolutionError::DuplicateInput,
+    DissolutionError::LengthMismatch,
+];
+
+const PARSE_LATLNG_ERRORS: [ParseLatLngError; 4] = [
+    ParseLatLngError::Separator,
+    ParseLatLngError::Number,
+    ParseLatLngError::Hemisphere,
+    ParseLatLngError::OutOfRange,
+];
+
 // All error must have a non-empty display.
 #[test]
 fn display() {
     let hex_grid = HexGridError::new("error");
 
-    assert!(!CompactionError::HeterogeneousResolution
-        .to_string()
-        .is_empty());
-    assert!(!CompactionError::DuplicateInput.to_string().is_empty());
+    for error in COMPACTION_ERRORS {
+        assert!(!error.to_string().is_empty());
+    }
 
     assert!(!hex_grid.to_string().is_empty());
 
@@ -46,16 +77,16 @@ fn display() {
 
     assert!(!ResolutionMismatch.to_string().is_empty());
 
+    for error in PARSE_LATLNG_ERRORS {
+        assert!(!error.to_string().is_empty());
+    }
+
     #[cfg(feature = "geo")]
     {
         let invalid_geometry = InvalidGeometry::new("error");
         let local_ij = LocalIjError::Pentagon;
 
         assert!(!invalid_geometry.to_string().is_empty());
-        assert!(!DissolutionError::UnsupportedResolution
-            .to_string()
-            .is_empty());
-        assert!(!DissolutionError::DuplicateInput.to_string().is_empty());
 
         assert!(!PlotterError::from(invalid_geometry).to_string().is_empty());
         assert!(!PlotterError::from(local_ij).to_string().is_empty());
@@ -66,8 +97,9 @@ fn display() {
 fn source() {
     let hex_grid = HexGridError::new("error");
 
-    assert!(CompactionError::HeterogeneousResolution.source().is_none());
-    assert!(CompactionError::DuplicateInput.source().is_none());
+    for error in COMPACTION_ERRORS {
+        assert!(error.source().is_none());
+    }
 
     assert!(hex_grid.source().is_none());
 
@@ -90,15 +122,79 @@ fn source() {
 
     assert!(ResolutionMismatch.source().is_none());
 
+    for error in PARSE_LATLNG_ERRORS {
+        assert!(error.source().is_none());
+    }
+
     #[cfg(feature = "geo")]
     {
         let invalid_geometry = InvalidGeometry::new("error");
         let local_ij = LocalIjError::Pentagon;
 
         assert!(invalid_geometry.source().is_none());
-        assert!(DissolutionError::UnsupportedResolution.source().is_none());
-        assert!(DissolutionError::DuplicateInput.source().is_none());
         assert!(PlotterError::from(invalid_geometry).source().is_some());
         assert!(PlotterError::from(local_ij).source().is_some());
     }
 }
+
+#[test]
+fn cell_lookup() {
+    for error in CELL_LOOKUP_ERRORS {
+        assert!(!error.to_string().is_empty());
+        assert!(error.source().is_none());
+    }
+}
+
+#[cfg(feature = "geo")]
+#[test]
+fn dissolution() {
+    for error in DISSOLUTION_ERRORS {
+        assert!(!error.to_string().is_empty());
+        assert!(error.source().is_none());
+    }
+}
+
+#[test]
+fn index() {
+    let error = InvalidIndex::new(0, "error");
+
+    assert!(!error.to_string().is_empty());
+    assert!(error.source().is_none());
+}
+
+#[test]
+fn treemap() {
+    let error = InvalidTreemap::new("error");
+
+    assert!(!error.to_string().is_empty());
+    assert!(error.source().is_none());
+}
+
+#[test]
+fn kernel() {
+    let error = InvalidKernel::new(Kernel::Gaussian { sigma: 0. }, "error");
+
+    assert!(!error.to_string().is_empty());
+    assert!(error.source().is_none());
+}
+
+#[cfg(feature = "geojson")]
+#[test]
+fn geojson() {
+    let json = || serde_json::from_str::<u8>("").expect_err("invalid JSON");
+    let index = InvalidCellIndex::new(Some(0), "error");
+    let errors = [
+        GeoJsonError::from(json()),
+        GeoJsonError::InvalidStructure("error"),
+        GeoJsonError::MissingIndex(0),
+        GeoJsonError::InvalidIndex(0, index),
+    ];
+
+    for error in &errors {
+        assert!(!error.to_string().is_empty());
+    }
+    assert!(errors[0].source().is_some());
+    assert!(errors[1].source().is_none());
+    assert!(errors[2].source().is_none());
+    assert!(errors[3].source().is_some());
+}
@@ -0,0 +1,27 @@
+use core::{error::Error, fmt};
+
+/// Errors occurring while deserializing a
+/// [`CellTreemap`](crate::CellTreemap).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidTreemap {
+    reason: &'static str,
+}
+
+impl InvalidTreemap {
+    /// Initializes a new [`InvalidTreemap`] with the given error message.
+    pub(crate) const fn new(reason: &'static str) -> Self {
+        Self { reason }
+    }
+}
+
+impl fmt::Display for InvalidTreemap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid treemap: {}", self.reason)
+    }
+}
+
+impl Error for InvalidTreemap {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
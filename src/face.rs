@@ -2,8 +2,11 @@
 
 use crate::{
     coord::{CoordIJK, LatLng, Vec3d},
-    error, NUM_ICOSA_FACES,
+    error, CellIndex, Resolution, Set, NUM_ICOSA_FACES,
 };
+#[cfg(feature = "std")]
+use ahash::HashSetExt;
+use alloc::{collections::VecDeque, vec::Vec};
 use core::fmt;
 
 // -----------------------------------------------------------------------------
@@ -28,6 +31,50 @@ impl Face {
         debug_assert!(value < NUM_ICOSA_FACES, "face out of range");
         Self(value as u8)
     }
+
+    /// Returns the cells, at the given resolution, that intersect this face.
+    ///
+    /// A cell intersects the face if the face is part of its
+    /// [`icosahedron_faces`](CellIndex::icosahedron_faces): this includes
+    /// the cells centered on the face, and the ones overlapping it from a
+    /// neighboring face.
+    ///
+    /// Note that the number of cells grows quickly with the resolution (about
+    /// 1/20 of the cells of the resolution).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{Face, Resolution};
+    ///
+    /// let face = Face::try_from(3)?;
+    /// let cells = face.cells(Resolution::Two);
+    /// # Ok::<(), h3o::error::InvalidFace>(())
+    /// ```
+    #[must_use]
+    pub fn cells(self, resolution: Resolution) -> Vec<CellIndex> {
+        // The cells intersecting a face form a connected region: flood it
+        // from the center of the face.
+        let origin = CENTER_GEO[usize::from(self)].to_cell(resolution);
+        let mut cells = Vec::new();
+        let mut seen = Set::new();
+        let mut candidates = VecDeque::from([origin]);
+        seen.insert(origin);
+
+        while let Some(cell) = candidates.pop_front() {
+            if !cell.icosahedron_faces().contains(self) {
+                continue;
+            }
+            cells.push(cell);
+            for neighbor in cell.grid_disk_safe(1) {
+                if seen.insert(neighbor) {
+                    candidates.push_back(neighbor);
+                }
+            }
+        }
+
+        cells
+    }
 }
 
 impl From<Face> for usize {
@@ -0,0 +1,473 @@
+use crate::{
+    coord::Vec3d,
+    math::{asin, atan2, ceil, cos, mul_add, sin, sqrt},
+    CellIndex, LatLng, Resolution, Set, EARTH_RADIUS_KM,
+};
+use alloc::{collections::VecDeque, vec::Vec};
+use core::f64::consts::FRAC_PI_2;
+
+/// Number of samples per edge length when walking along the geodesic.
+const SAMPLES_PER_EDGE: f64 = 8.;
+
+/// Returns the cells along the great circle arc from `a` to `b`.
+///
+/// The result always contains the connected chain of cells crossed by the
+/// arc, from the cell of `a` to the cell of `b`. In addition, every cell
+/// whose center lies within `max_cross_track_m` meters of the arc is
+/// included, which allows to build corridors of any width.
+///
+/// Cells are sorted by their position along the arc: with a zero tolerance,
+/// consecutive cells are neighbors.
+///
+/// Unlike [`CellIndex::grid_path_cells`], which follows a straight line in
+/// the grid space, the result follows the actual geodesic and never fails.
+/// For antipodal points, the geodesic going through the North Pole is used
+/// (or through the meridian 0 if the points are the poles).
+///
+/// # Example
+///
+/// ```
+/// use h3o::{cells_along_geodesic, LatLng, Resolution};
+///
+/// let a = LatLng::new(48.864716, 2.349014)?;
+/// let b = LatLng::new(51.507351, -0.127758)?;
+/// let cells = cells_along_geodesic(a, b, Resolution::Six, 0.);
+/// assert_eq!(cells.first().copied(), Some(a.to_cell(Resolution::Six)));
+/// assert_eq!(cells.last().copied(), Some(b.to_cell(Resolution::Six)));
+/// # Ok::<(), h3o::error::InvalidLatLng>(())
+/// ```
+#[must_use]
+pub fn cells_along_geodesic(
+    a: LatLng,
+    b: LatLng,
+    resolution: Resolution,
+    max_cross_track_m: f64,
+) -> Vec<CellIndex> {
+    let arc = Arc::new(a, b);
+    let tolerance = (max_cross_track_m / 1000. / EARTH_RADIUS_KM).max(0.);
+
+    // Walk along the arc, bridging the cells that are only connected through
+    // a vertex (or skipped because of a too short crossing).
+    let step = resolution.edge_length_rads() / SAMPLES_PER_EDGE;
+    #[expect(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "number of samples is small and positive"
+    )]
+    let count = (arc.length / step) as u64 + 1;
+    let mut cells = Vec::<(f64, CellIndex)>::new();
+    for i in 0..=count {
+        #[expect(clippy::cast_precision_loss, reason = "count is small")]
+        let position = arc.length * (i as f64) / (count as f64);
+        let cell = arc.point_at(position).to_cell(resolution);
+        let Some(&(previous_position, previous)) = cells.last() else {
+            cells.push((position, cell));
+            continue;
+        };
+        if cell == previous {
+            continue;
+        }
+        if !previous.is_neighbor_with(cell).unwrap_or(true) {
+            if let Some(bridge) = arc.bridge(previous, cell) {
+                let middle = f64::midpoint(previous_position, position);
+                cells.push((middle, bridge));
+            }
+        }
+        cells.push((position, cell));
+    }
+
+    // Grow the chain with the cells close enough to the arc.
+    let mut seen = cells.iter().map(|&(_, cell)| cell).collect::<Set<_>>();
+    let mut candidates =
+        cells.iter().map(|&(_, cell)| cell).collect::<VecDeque<_>>();
+    while let Some(cell) = candidates.pop_front() {
+        for neighbor in cell.grid_disk_safe(1) {
+            if !seen.insert(neighbor) {
+                continue;
+            }
+            let center = Vec3d::from(LatLng::from(neighbor));
+            let position = arc.along_track(&center);
+            if (0. ..=arc.length).contains(&position)
+                && arc.cross_track(&center) <= tolerance
+            {
+                cells.push((position, neighbor));
+                candidates.push_back(neighbor);
+            }
+        }
+    }
+
+    cells.sort_by(|a, b| a.0.total_cmp(&b.0));
+    cells.into_iter().map(|(_, cell)| cell).collect()
+}
+
+/// Returns the intersection of the great circle going through `a1` and `a2`
+/// with the one going through `b1` and `b2`.
+///
+/// Two distinct great circles always intersect at two antipodal points: the
+/// one closest to the given points is returned (its antipode is the other
+/// solution).
+///
+/// Returns `None` if one of the great circles is undefined (identical or
+/// antipodal points) or if both great circles are the same.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{great_circle_intersection, LatLng};
+///
+/// let a1 = LatLng::new(0., -10.)?;
+/// let a2 = LatLng::new(0., 10.)?;
+/// let b1 = LatLng::new(-10., 0.)?;
+/// let b2 = LatLng::new(10., 0.)?;
+/// let intersection = great_circle_intersection(a1, a2, b1, b2);
+/// assert_eq!(intersection.map(|ll| ll.lng()), Some(0.));
+/// # Ok::<(), h3o::error::InvalidLatLng>(())
+/// ```
+#[must_use]
+pub fn great_circle_intersection(
+    a1: LatLng,
+    a2: LatLng,
+    b1: LatLng,
+    b2: LatLng,
+) -> Option<LatLng> {
+    let points = [a1, a2, b1, b2].map(Vec3d::from);
+    let normal_a = pole(&points[0], &points[1])?;
+    let normal_b = pole(&points[2], &points[3])?;
+    let mut intersection = pole(&normal_a, &normal_b)?;
+
+    // Pick the solution on the side of the input points.
+    let centroid = points.iter().fold(Vec3d::new(0., 0., 0.), |acc, point| {
+        Vec3d::new(acc.x + point.x, acc.y + point.y, acc.z + point.z)
+    });
+    if intersection.dot(&centroid) < 0. {
+        intersection =
+            Vec3d::new(-intersection.x, -intersection.y, -intersection.z);
+    }
+
+    Some(intersection.to_latlng())
+}
+
+/// Returns the cells, at the given resolution, whose center lies on the side
+/// of the great circle pointed by `normal`, i.e. in the hemisphere centered
+/// on the pole of the great circle.
+///
+/// The normal doesn't have to be a unit vector, but a null one selects
+/// nothing.
+///
+/// Cells centered exactly on the great circle are included: the hemispheres
+/// of opposite normals cover the whole grid. For instance, using the subsolar
+/// point as normal returns the day side of the terminator.
+///
+/// Cells are streamed by walking down the hierarchy from the base cells:
+/// branches entirely on one side of the great circle are either skipped or
+/// returned without further checks.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{cells_on_side_of_great_circle, LatLng, Resolution, Vec3d};
+///
+/// // Normal of the equatorial plane.
+/// let normal = Vec3d::new(0., 0., 1.);
+/// let northern = cells_on_side_of_great_circle(normal, Resolution::Two)
+///     .collect::<Vec<_>>();
+/// assert!(northern.iter().all(|&cell| LatLng::from(cell).lat() >= 0.));
+/// ```
+pub fn cells_on_side_of_great_circle(
+    normal: Vec3d,
+    resolution: Resolution,
+) -> impl Iterator<Item = CellIndex> {
+    let pole = normal.normalize();
+    let mut candidates = CellIndex::base_cells().collect::<Vec<_>>();
+
+    // Yields the roots of the subtrees lying in the hemisphere.
+    core::iter::from_fn(move || {
+        while let Some(cell) = candidates.pop() {
+            let center = LatLng::from(cell);
+            let side = pole.dot(&Vec3d::from(center));
+            let cell_resolution = cell.resolution();
+            if cell_resolution == resolution {
+                if side >= 0. {
+                    return Some(cell);
+                }
+                continue;
+            }
+
+            // The descendants' centers lie within twice the cell radius, so
+            // their side is known when the center is far enough from the
+            // great circle.
+            let margin = cell
+                .boundary()
+                .iter()
+                .map(|vertex| center.distance_rads(*vertex))
+                .fold(0., f64::max);
+            let threshold = sin((2. * margin).min(FRAC_PI_2));
+            if side > threshold {
+                return Some(cell);
+            }
+            if side >= -threshold {
+                let child_resolution =
+                    cell_resolution.succ().expect("finer resolution");
+                candidates.extend(cell.children(child_resolution));
+            }
+        }
+        None
+    })
+    .flat_map(move |cell| cell.children(resolution))
+}
+
+/// Computes the great circle distance, in meters, from `origin` to the center
+/// of each cell.
+///
+/// Results are the same as [`LatLng::distance_m`], but the trigonometry of
+/// the origin is only computed once for the whole batch.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{distance_field, CellIndex, LatLng};
+///
+/// let origin = LatLng::new(48.864716, 2.349014)?;
+/// let cells = [CellIndex::try_from(0x8a1fb46622dffff)?];
+/// let distances = distance_field(origin, &cells);
+/// assert_eq!(distances, vec![origin.distance_m(LatLng::from(cells[0]))]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[must_use]
+pub fn distance_field(origin: LatLng, cells: &[CellIndex]) -> Vec<f64> {
+    let (lat, lng) = (origin.lat_radians(), origin.lng_radians());
+    let cos_lat = cos(lat);
+
+    cells
+        .iter()
+        .map(|&cell| {
+            let center = LatLng::from(cell);
+            let sin_lat = sin((center.lat_radians() - lat) / 2.);
+            let sin_lng = sin((center.lng_radians() - lng) / 2.);
+            let a = mul_add(
+                sin_lat,
+                sin_lat,
+                cos_lat * cos(center.lat_radians()) * sin_lng * sin_lng,
+            );
+            2. * atan2(sqrt(a), sqrt(1. - a)) * EARTH_RADIUS_KM * 1000.
+        })
+        .collect()
+}
+
+/// Computes the spherical centroid of the cells, weighted by their values.
+///
+/// Cell centers are averaged as 3D unit vectors (each scaled by its weight),
+/// then projected back on the sphere: unlike a naive average of the
+/// latitudes and longitudes, this is correct across the antimeridian and
+/// near the poles.
+///
+/// Weights are expected to be non-negative. Returns `None` if there is no
+/// cell, or if the weighted centers cancel each other out (e.g. zero total
+/// weight, or antipodal cells with the same weight).
+///
+/// # Example
+///
+/// ```
+/// use h3o::{weighted_centroid, LatLng, Resolution};
+///
+/// let a = LatLng::new(0., 179.)?.to_cell(Resolution::Five);
+/// let b = LatLng::new(0., -179.)?.to_cell(Resolution::Five);
+/// let centroid = weighted_centroid([(a, 1.), (b, 1.)]).expect("centroid");
+/// assert!(centroid.lng().abs() > 179.);
+/// # Ok::<(), h3o::error::InvalidLatLng>(())
+/// ```
+#[must_use]
+pub fn weighted_centroid(
+    values: impl IntoIterator<Item = (CellIndex, f64)>,
+) -> Option<LatLng> {
+    let sum = values.into_iter().fold(
+        Vec3d::new(0., 0., 0.),
+        |acc, (cell, weight)| {
+            let center = Vec3d::from(LatLng::from(cell));
+            Vec3d::new(
+                mul_add(weight, center.x, acc.x),
+                mul_add(weight, center.y, acc.y),
+                mul_add(weight, center.z, acc.z),
+            )
+        },
+    );
+
+    // Also rejects NaN.
+    (sum.norm() > 1e-12).then(|| sum.normalize().to_latlng())
+}
+
+/// Returns the signed distance (in radians) from `point` to the great circle
+/// going through `a` and `b`: negative on the left, positive on the right.
+///
+/// If `a` and `b` don't define a great circle, the distance to `a` is
+/// returned.
+pub fn cross_track_rads(point: LatLng, a: LatLng, b: LatLng) -> f64 {
+    pole(&Vec3d::from(a), &Vec3d::from(b)).map_or_else(
+        || point.distance_rads(a),
+        |normal| -asin(Vec3d::from(point).dot(&normal).clamp(-1., 1.)),
+    )
+}
+
+/// Returns the distance (in radians) from `point` to the great circle arc
+/// going from `a` to `b` (shortest path).
+pub fn arc_distance_rads(point: LatLng, a: LatLng, b: LatLng) -> f64 {
+    let (p, start, end) = (Vec3d::from(point), Vec3d::from(a), Vec3d::from(b));
+    if let Some(normal) = pole(&start, &end) {
+        // The closest point of the great circle is within the arc.
+        let foot = p.reject(&normal);
+        if start.cross(&foot).dot(&normal) >= 0.
+            && foot.cross(&end).dot(&normal) >= 0.
+        {
+            return asin(p.dot(&normal).clamp(-1., 1.)).abs();
+        }
+    }
+
+    point.distance_rads(a).min(point.distance_rads(b))
+}
+
+/// Returns `count` points evenly spaced along the great circle arc from `a` to
+/// `b`, starting at `a` (`b` is excluded).
+pub fn arc_points(
+    a: LatLng,
+    b: LatLng,
+    count: u64,
+) -> impl Iterator<Item = LatLng> {
+    let arc = Arc::new(a, b);
+    (0..count).map(move |i| {
+        #[expect(clippy::cast_precision_loss, reason = "count is small")]
+        let position = arc.length * (i as f64) / (count as f64);
+        arc.point_at(position)
+    })
+}
+
+/// Interpolates points along the great circle arcs between consecutive
+/// vertices, so that no segment is longer than `max_segment_rads`.
+///
+/// If `closed`, the arc from the last vertex back to the first one is also
+/// densified (without repeating the first vertex at the end).
+///
+/// A non-positive (or NaN) `max_segment_rads` disables the densification.
+pub fn densify(
+    vertices: &[LatLng],
+    max_segment_rads: f64,
+    closed: bool,
+) -> Vec<LatLng> {
+    let Some(&last) = vertices.last() else {
+        return Vec::new();
+    };
+    let mut points = Vec::with_capacity(vertices.len());
+    let closing = closed.then(|| (last, vertices[0]));
+    let arcs = vertices
+        .windows(2)
+        .map(|pair| (pair[0], pair[1]))
+        .chain(closing);
+    for (a, b) in arcs {
+        let ratio = a.distance_rads(b) / max_segment_rads;
+        let count = if ratio.is_finite() && ratio > 1. {
+            #[expect(
+                clippy::cast_possible_truncation,
+                clippy::cast_sign_loss,
+                reason = "ratio is finite and positive"
+            )]
+            let count = ceil(ratio) as u64;
+            count
+        } else {
+            1
+        };
+        // Keep the exact vertex rather than its interpolated approximation.
+        points.push(a);
+        points.extend(arc_points(a, b, count).skip(1));
+    }
+    if !closed {
+        points.push(last);
+    }
+
+    points
+}
+
+// -----------------------------------------------------------------------------
+
+/// A great circle arc, as a start point and a tangent direction on the unit
+/// sphere.
+struct Arc {
+    start: Vec3d,
+    tangent: Vec3d,
+    /// Pole of the great circle.
+    normal: Vec3d,
+    /// Length, in radians.
+    length: f64,
+}
+
+impl Arc {
+    fn new(a: LatLng, b: LatLng) -> Self {
+        let start = Vec3d::from(a);
+        let end = Vec3d::from(b);
+        let length = a.distance_rads(b);
+
+        // Project `end` on the tangent plane at `start`.
+        let mut tangent = end.reject(&start);
+        if tangent.norm() < 1e-12 {
+            // Same or antipodal points: head north (or along meridian 0 from
+            // the poles).
+            let axis = if 1. - start.z.abs() < 1e-12 {
+                Vec3d::new(1., 0., 0.)
+            } else {
+                Vec3d::new(0., 0., 1.)
+            };
+            tangent = axis.reject(&start);
+        }
+        let tangent = tangent.normalize();
+        let normal = start.cross(&tangent);
+
+        Self {
+            start,
+            tangent,
+            normal,
+            length,
+        }
+    }
+
+    /// Returns the point at the given distance (in radians) from the start.
+    fn point_at(&self, position: f64) -> LatLng {
+        let (sin, cos) = (sin(position), cos(position));
+        Vec3d::new(
+            mul_add(cos, self.start.x, sin * self.tangent.x),
+            mul_add(cos, self.start.y, sin * self.tangent.y),
+            mul_add(cos, self.start.z, sin * self.tangent.z),
+        )
+        .to_latlng()
+    }
+
+    /// Returns the distance (in radians) from the start of the projection of
+    /// the point on the great circle.
+    fn along_track(&self, point: &Vec3d) -> f64 {
+        atan2(point.dot(&self.tangent), point.dot(&self.start))
+    }
+
+    /// Returns the distance (in radians) of the point to the great circle.
+    fn cross_track(&self, point: &Vec3d) -> f64 {
+        asin(point.dot(&self.normal).clamp(-1., 1.)).abs()
+    }
+
+    /// Returns the common neighbor of two cells that is the closest to the
+    /// great circle.
+    fn bridge(&self, from: CellIndex, to: CellIndex) -> Option<CellIndex> {
+        from.grid_disk_safe(1)
+            .filter(|&cell| {
+                cell != from && cell.is_neighbor_with(to).unwrap_or(false)
+            })
+            .map(|cell| {
+                let center = Vec3d::from(LatLng::from(cell));
+                (self.cross_track(&center), cell)
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, cell)| cell)
+    }
+}
+
+/// Returns the unit normal of the plane going through `a`, `b` and the
+/// origin, if any.
+fn pole(a: &Vec3d, b: &Vec3d) -> Option<Vec3d> {
+    let normal = a.cross(b);
+    (normal.norm() >= 1e-12).then(|| normal.normalize())
+}
@@ -0,0 +1,340 @@
+//! `GeoJSON` import and export of H3 cells.
+//!
+//! Reads the features of a `FeatureCollection` whose properties carry an H3
+//! cell index, either as an hexadecimal string (e.g. `"8a1fb46622dffff"`) or
+//! as an integer. The geometry of the features is ignored.
+//!
+//! With the `geo` feature, cells and shapes (e.g. the output of
+//! [`Solvent`](crate::geom::Solvent)) can also be written as polygon
+//! features, and polygons can be read back (e.g. as the input of a
+//! [`Tiler`](crate::geom::Tiler)).
+
+use crate::{
+    error::{GeoJsonError, InvalidCellIndex},
+    CellIndex,
+};
+#[cfg(feature = "geo")]
+use geo::{orient::Direction, LineString, MultiPolygon, Orient, Polygon};
+use serde_json::{Map, Value};
+use std::io::Read;
+#[cfg(feature = "geo")]
+use std::io::Write;
+
+/// Default name of the property holding the cell index.
+pub const DEFAULT_INDEX_KEY: &str = "h3";
+
+/// Reads the cells of a `GeoJSON` `FeatureCollection`.
+///
+/// Every feature must have a valid cell index stored in the property `key`.
+/// Returns the cells along with the properties of their feature (index
+/// included), in the order of the features.
+///
+/// # Errors
+///
+/// [`GeoJsonError`] if the input isn't a valid `FeatureCollection` or if a
+/// feature has a missing or invalid cell index.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{geojson, CellIndex};
+///
+/// let input = r#"{
+///   "type": "FeatureCollection",
+///   "features": [{
+///     "type": "Feature",
+///     "geometry": null,
+///     "properties": { "h3": "8a1fb46622dffff", "count": 42 }
+///   }]
+/// }"#;
+/// let key = geojson::DEFAULT_INDEX_KEY;
+/// let cells = geojson::read_cells(input.as_bytes(), key)?;
+/// assert_eq!(cells[0].0, CellIndex::try_from(0x8a1fb46622dffff)?);
+/// assert_eq!(cells[0].1["count"], 42);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn read_cells(
+    reader: impl Read,
+    key: &str,
+) -> Result<Vec<(CellIndex, Value)>, GeoJsonError> {
+    read_features(reader)?
+        .into_iter()
+        .enumerate()
+        .map(|(position, mut feature)| {
+            let properties = match feature.remove("properties") {
+                Some(Value::Object(properties)) => properties,
+                Some(Value::Null) | None => Map::new(),
+                Some(_) => {
+                    return Err(GeoJsonError::InvalidStructure(
+                        "properties is not an object",
+                    ))
+                }
+            };
+            let cell = properties
+                .get(key)
+                .ok_or(GeoJsonError::MissingIndex(position))
+                .and_then(|index| {
+                    parse_index(index).map_err(|err| {
+                        GeoJsonError::InvalidIndex(position, err)
+                    })
+                })?;
+
+            Ok((cell, Value::Object(properties)))
+        })
+        .collect()
+}
+
+/// Reads the polygons of a `GeoJSON` `FeatureCollection`.
+///
+/// Both `Polygon` and `MultiPolygon` geometries are supported (the latter
+/// being split into its polygons), and features without geometry are
+/// skipped. Coordinates are in degrees, ready to be fed to a
+/// [`Tiler`](crate::geom::Tiler).
+///
+/// # Errors
+///
+/// [`GeoJsonError`] if the input isn't a valid `FeatureCollection` or if a
+/// feature has a malformed or unsupported geometry.
+///
+/// # Example
+///
+/// ```
+/// use h3o::geojson;
+///
+/// let input = r#"{
+///   "type": "FeatureCollection",
+///   "features": [{
+///     "type": "Feature",
+///     "geometry": {
+///       "type": "Polygon",
+///       "coordinates": [[[2.3, 48.8], [2.4, 48.8], [2.4, 48.9], [2.3, 48.8]]]
+///     },
+///     "properties": null
+///   }]
+/// }"#;
+/// let polygons = geojson::read_polygons(input.as_bytes())?;
+/// assert_eq!(polygons.len(), 1);
+/// # Ok::<(), h3o::error::GeoJsonError>(())
+/// ```
+#[cfg(feature = "geo")]
+pub fn read_polygons(reader: impl Read) -> Result<Vec<Polygon>, GeoJsonError> {
+    let mut polygons = Vec::new();
+    for mut feature in read_features(reader)? {
+        let geometry = match feature.remove("geometry") {
+            Some(Value::Object(geometry)) => geometry,
+            Some(Value::Null) | None => continue,
+            Some(_) => {
+                return Err(GeoJsonError::InvalidStructure(
+                    "geometry is not an object",
+                ))
+            }
+        };
+        let coordinates = geometry.get("coordinates").ok_or(
+            GeoJsonError::InvalidStructure("missing geometry coordinates"),
+        )?;
+        match geometry.get("type").and_then(Value::as_str) {
+            Some("Polygon") => polygons.push(parse_polygon(coordinates)?),
+            Some("MultiPolygon") => {
+                for polygon in as_array(coordinates)? {
+                    polygons.push(parse_polygon(polygon)?);
+                }
+            }
+            _ => {
+                return Err(GeoJsonError::InvalidStructure(
+                    "unsupported geometry",
+                ))
+            }
+        }
+    }
+    Ok(polygons)
+}
+
+/// Writes the polygons of a shape (e.g. the output of
+/// [`Solvent::dissolve`](crate::geom::Solvent::dissolve)) as a `GeoJSON`
+/// `FeatureCollection`, one feature per polygon.
+///
+/// Coordinates are expected to be in degrees, and rings are oriented as
+/// mandated by RFC 7946 (counter-clockwise exterior rings, clockwise holes).
+///
+/// # Errors
+///
+/// [`GeoJsonError::Json`] if the output cannot be written.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{geojson, geom::SolventBuilder, CellIndex};
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let shape = SolventBuilder::new().build().dissolve(index.grid_disk::<Vec<_>>(2))?;
+///
+/// let mut output = Vec::new();
+/// geojson::write_polygons(&mut output, &shape)?;
+/// assert_eq!(geojson::read_polygons(output.as_slice())?.len(), 1);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "geo")]
+pub fn write_polygons(
+    writer: impl Write,
+    shape: &MultiPolygon,
+) -> Result<(), GeoJsonError> {
+    let features = shape
+        .iter()
+        .map(|polygon| polygon_feature(polygon, Map::new()));
+    write_features(writer, features)
+}
+
+/// Writes cells as a `GeoJSON` `FeatureCollection`, one feature per cell.
+///
+/// When `key` is provided, the cell index is stored, as an hexadecimal
+/// string, in the property of that name (so that the cells can be read back
+/// through [`read_cells`]).
+///
+/// # Errors
+///
+/// [`GeoJsonError::Json`] if the output cannot be written.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{geojson, CellIndex};
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let key = geojson::DEFAULT_INDEX_KEY;
+///
+/// let mut output = Vec::new();
+/// geojson::write_cells(&mut output, [index], Some(key))?;
+/// let cells = geojson::read_cells(output.as_slice(), key)?;
+/// assert_eq!(cells[0].0, index);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "geo")]
+pub fn write_cells(
+    writer: impl Write,
+    cells: impl IntoIterator<Item = CellIndex>,
+    key: Option<&str>,
+) -> Result<(), GeoJsonError> {
+    let features = cells.into_iter().map(|cell| {
+        let mut properties = Map::new();
+        if let Some(key) = key {
+            properties.insert(key.to_owned(), Value::String(cell.to_string()));
+        }
+        polygon_feature(&Polygon::from(cell), properties)
+    });
+    write_features(writer, features)
+}
+
+// -----------------------------------------------------------------------------
+
+/// Reads the features of a `FeatureCollection`.
+fn read_features(
+    reader: impl Read,
+) -> Result<Vec<Map<String, Value>>, GeoJsonError> {
+    let value = serde_json::from_reader::<_, Value>(reader)?;
+    let Value::Object(mut collection) = value else {
+        return Err(GeoJsonError::InvalidStructure("not an object"));
+    };
+    if collection.get("type").and_then(Value::as_str)
+        != Some("FeatureCollection")
+    {
+        return Err(GeoJsonError::InvalidStructure("not a FeatureCollection"));
+    }
+    let Some(Value::Array(features)) = collection.remove("features") else {
+        return Err(GeoJsonError::InvalidStructure("missing features"));
+    };
+
+    features
+        .into_iter()
+        .map(|feature| match feature {
+            Value::Object(feature) => Ok(feature),
+            _ => {
+                Err(GeoJsonError::InvalidStructure("feature is not an object"))
+            }
+        })
+        .collect()
+}
+
+/// Writes the features as a `FeatureCollection`.
+#[cfg(feature = "geo")]
+fn write_features(
+    writer: impl Write,
+    features: impl Iterator<Item = Value>,
+) -> Result<(), GeoJsonError> {
+    let collection = serde_json::json!({
+        "type": "FeatureCollection",
+        "features": features.collect::<Vec<_>>(),
+    });
+    serde_json::to_writer(writer, &collection)?;
+    Ok(())
+}
+
+/// Builds a `Polygon` feature.
+#[cfg(feature = "geo")]
+fn polygon_feature(polygon: &Polygon, properties: Map<String, Value>) -> Value {
+    let polygon = polygon.orient(Direction::Default);
+    let rings = std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .map(|ring| {
+            ring.coords()
+                .map(|coord| serde_json::json!([coord.x, coord.y]))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::json!({
+        "type": "Feature",
+        "geometry": { "type": "Polygon", "coordinates": rings },
+        "properties": Value::Object(properties),
+    })
+}
+
+/// Parses the coordinates of a `Polygon` geometry.
+#[cfg(feature = "geo")]
+fn parse_polygon(value: &Value) -> Result<Polygon, GeoJsonError> {
+    let mut rings = as_array(value)?
+        .iter()
+        .map(|ring| {
+            as_array(ring)?
+                .iter()
+                .map(|position| {
+                    let position = as_array(position)?;
+                    match (
+                        position.first().and_then(Value::as_f64),
+                        position.get(1).and_then(Value::as_f64),
+                    ) {
+                        (Some(x), Some(y)) => Ok(geo::coord! { x: x, y: y }),
+                        _ => Err(GeoJsonError::InvalidStructure(
+                            "invalid position",
+                        )),
+                    }
+                })
+                .collect::<Result<LineString, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if rings.is_empty() {
+        return Err(GeoJsonError::InvalidStructure("polygon without ring"));
+    }
+    let exterior = rings.remove(0);
+
+    Ok(Polygon::new(exterior, rings))
+}
+
+/// Returns the array held by the value.
+#[cfg(feature = "geo")]
+fn as_array(value: &Value) -> Result<&Vec<Value>, GeoJsonError> {
+    value
+        .as_array()
+        .ok_or(GeoJsonError::InvalidStructure("invalid coordinates"))
+}
+
+/// Parses a cell index stored either as an hexadecimal string or a number.
+fn parse_index(value: &Value) -> Result<CellIndex, InvalidCellIndex> {
+    match *value {
+        Value::String(ref index) => index.parse(),
+        Value::Number(ref index) => index.as_u64().map_or_else(
+            || Err(InvalidCellIndex::new(None, "not an unsigned integer")),
+            CellIndex::try_from,
+        ),
+        _ => Err(InvalidCellIndex::new(None, "not a string nor a number")),
+    }
+}
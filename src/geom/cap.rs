@@ -0,0 +1,182 @@
+use super::ContainmentMode;
+use crate::{math::cos, CellIndex, LatLng, Resolution, Vec3d};
+use std::f64::consts::PI;
+
+/// Returns the cells, at the given resolution, covering the spherical cap
+/// (i.e. the circle on the sphere) centered on `center`.
+///
+/// The containment tests are exact: cell edges are treated as great circle
+/// arcs and every test boils down to dot products between unit vectors.
+/// Cells are streamed by walking down the hierarchy from the base cells,
+/// returning or skipping whole branches when they are far enough from the
+/// edge of the cap.
+///
+/// A negative (or NaN) radius results in an empty covering, whereas a radius
+/// greater than or equal to π covers the whole sphere.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{
+///     geom::{cap_to_cells, ContainmentMode},
+///     LatLng, Resolution,
+/// };
+///
+/// let center = LatLng::new(48.864716, 2.349014)?;
+/// // ~10km around the center.
+/// let radius = 10. / h3o::EARTH_RADIUS_KM;
+/// let cells = cap_to_cells(
+///     center,
+///     radius,
+///     Resolution::Seven,
+///     ContainmentMode::Covers,
+/// )
+/// .collect::<Vec<_>>();
+/// assert!(cells.contains(&center.to_cell(Resolution::Seven)));
+/// # Ok::<(), h3o::error::InvalidLatLng>(())
+/// ```
+pub fn cap_to_cells(
+    center: LatLng,
+    angular_radius_rads: f64,
+    resolution: Resolution,
+    mode: ContainmentMode,
+) -> impl Iterator<Item = CellIndex> {
+    let cap = Cap::new(center, angular_radius_rads);
+    let mut candidates = if angular_radius_rads >= 0. {
+        CellIndex::base_cells().collect()
+    } else {
+        Vec::new()
+    };
+
+    // Yields the roots of the subtrees to return.
+    std::iter::from_fn(move || {
+        while let Some(cell) = candidates.pop() {
+            let cell_resolution = cell.resolution();
+            if cell_resolution == resolution {
+                if cap.matches(cell, mode) {
+                    return Some(cell);
+                }
+                continue;
+            }
+
+            // Descendants, and their boundaries, stay within twice the cell
+            // radius from its center.
+            let cell_center = LatLng::from(cell);
+            let margin = 2.
+                * cell
+                    .boundary()
+                    .iter()
+                    .map(|vertex| cell_center.distance_rads(*vertex))
+                    .fold(0., f64::max);
+            let distance = center.distance_rads(cell_center);
+            if distance + margin < cap.radius {
+                return Some(cell);
+            }
+            if distance - margin <= cap.radius {
+                let child_resolution =
+                    cell_resolution.succ().expect("finer resolution");
+                candidates.extend(cell.children(child_resolution));
+            }
+        }
+        None
+    })
+    .flat_map(move |cell| cell.children(resolution))
+}
+
+/// A spherical cap.
+struct Cap {
+    /// Center of the cap.
+    center: LatLng,
+    /// Center of the cap, as a unit vector.
+    axis: Vec3d,
+    /// Angular radius, in radians.
+    radius: f64,
+    /// Cosine of the angular radius: a unit vector is in the cap if its dot
+    /// product with the axis is greater than or equal to this value.
+    min_dot: f64,
+}
+
+impl Cap {
+    fn new(center: LatLng, radius: f64) -> Self {
+        // Above π, the cap covers the whole sphere.
+        let radius = if radius >= PI { f64::INFINITY } else { radius };
+        Self {
+            center,
+            axis: center.to_vec3(),
+            radius,
+            min_dot: cos(radius.min(PI)),
+        }
+    }
+
+    /// Applies the containment mode to the cell.
+    fn matches(&self, cell: CellIndex, mode: ContainmentMode) -> bool {
+        match mode {
+            ContainmentMode::ContainsCentroid => {
+                self.axis.dot(&cell.center_vec3()) >= self.min_dot
+            }
+            ContainmentMode::ContainsBoundary => {
+                // The boundary being in the cap isn't enough when the cap is
+                // larger than a hemisphere: the cell may contain the hole.
+                let antipode =
+                    Vec3d::new(-self.axis.x, -self.axis.y, -self.axis.z);
+                let hole_in_cell = self.radius.is_finite()
+                    && antipode.to_latlng().to_cell(cell.resolution()) == cell;
+                !hole_in_cell
+                    && edges(cell).all(|(start, end)| {
+                        -arc_max_dot(&start, &end, &antipode) >= self.min_dot
+                    })
+            }
+            ContainmentMode::IntersectsBoundary => {
+                self.intersects_boundary(cell)
+            }
+            ContainmentMode::Covers => {
+                self.intersects_boundary(cell)
+                    || self.center.to_cell(cell.resolution()) == cell
+            }
+        }
+    }
+
+    /// Returns true if the cell boundary intersects the cap.
+    fn intersects_boundary(&self, cell: CellIndex) -> bool {
+        edges(cell).any(|(start, end)| {
+            arc_max_dot(&start, &end, &self.axis) >= self.min_dot
+        })
+    }
+}
+
+/// Returns the edges of the cell, as pairs of unit vectors.
+fn edges(cell: CellIndex) -> impl Iterator<Item = (Vec3d, Vec3d)> {
+    let vertices = cell
+        .boundary()
+        .iter()
+        .map(|vertex| vertex.to_vec3())
+        .collect::<Vec<_>>();
+    let count = vertices.len();
+    (0..count).map(move |i| (vertices[i], vertices[(i + 1) % count]))
+}
+
+/// Returns the maximum dot product between `axis` and the points of the
+/// great circle arc going from `start` to `end`.
+fn arc_max_dot(start: &Vec3d, end: &Vec3d, axis: &Vec3d) -> f64 {
+    let max = start.dot(axis).max(end.dot(axis));
+    let normal = start.cross(end);
+    if normal.norm() < 1e-12 {
+        return max;
+    }
+
+    // The closest point of the great circle is the projection of the axis on
+    // its plane: only relevant if it lies on the arc.
+    let projection = axis.reject(&normal.normalize());
+    if projection.norm() < 1e-12 {
+        return max;
+    }
+    let closest = projection.normalize();
+    let is_on_arc = start.cross(&closest).dot(&normal) >= 0.
+        && closest.cross(end).dot(&normal) >= 0.;
+
+    if is_on_arc {
+        max.max(closest.dot(axis))
+    } else {
+        max
+    }
+}
@@ -0,0 +1,117 @@
+//! Compatibility layer with the polyfill API of h3o 0.6.
+//!
+//! This is a thin wrapper around the [`Tiler`], meant to ease the migration
+//! of downstream crates. New code should use the [`Tiler`] directly.
+
+use super::{ContainmentMode, Tiler, TilerBuilder};
+use crate::{CellIndex, Resolution};
+use geo::{MultiPolygon, Polygon, Rect, Triangle};
+
+/// Polyfill configuration.
+///
+/// See [`TilerBuilder`] for its replacement.
+#[deprecated(since = "0.8.0", note = "use `TilerBuilder` instead")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PolyfillConfig {
+    resolution: Resolution,
+    containment: ContainmentMode,
+}
+
+#[expect(deprecated, reason = "compatibility layer")]
+impl PolyfillConfig {
+    /// Instantiates a new polyfill configuration, using the
+    /// [`ContainmentMode::ContainsCentroid`] mode.
+    #[must_use]
+    pub const fn new(resolution: Resolution) -> Self {
+        Self {
+            resolution,
+            containment: ContainmentMode::ContainsCentroid,
+        }
+    }
+
+    /// Sets the containment mode defining if a cell is in a polygon or not.
+    #[must_use]
+    pub const fn containment_mode(mut self, mode: ContainmentMode) -> Self {
+        self.containment = mode;
+        self
+    }
+
+    // Returns a tiler for the given polygons, if they are all valid.
+    fn tiler(
+        self,
+        polygons: impl IntoIterator<Item = Polygon>,
+    ) -> Option<Tiler> {
+        let mut tiler = TilerBuilder::new(self.resolution)
+            .containment_mode(self.containment)
+            .build();
+        tiler.add_batch(polygons).ok()?;
+        Some(tiler)
+    }
+}
+
+/// A trait to convert a geometry (coordinates in degrees) into a set of
+/// cells.
+///
+/// Unlike the geometry wrappers of h3o 0.6, `geo` types are not validated on
+/// construction: an invalid geometry results in an empty set of cells. Use a
+/// [`Tiler`] to get an explicit error instead.
+///
+/// # Example
+///
+/// ```
+/// # #![expect(deprecated)]
+/// use geo::Rect;
+/// use h3o::{
+///     geom::{ContainmentMode, PolyfillConfig, ToCells},
+///     Resolution,
+/// };
+///
+/// let rect = Rect::new((2.33, 48.84), (2.36, 48.87));
+/// let config = PolyfillConfig::new(Resolution::Seven)
+///     .containment_mode(ContainmentMode::Covers);
+/// let cells = rect.to_cells(config).collect::<Vec<_>>();
+/// assert!(cells.len() <= rect.max_cells_count(config));
+/// ```
+#[deprecated(since = "0.8.0", note = "use `Tiler` instead")]
+#[expect(deprecated, reason = "compatibility layer")]
+pub trait ToCells {
+    /// Returns an upper bound to the number of cells returned by `to_cells`.
+    ///
+    /// Can be used to preallocate memory for [`Self::to_cells`].
+    fn max_cells_count(&self, config: PolyfillConfig) -> usize;
+
+    /// Computes the coverage of the input using cell indexes.
+    fn to_cells(
+        &self,
+        config: PolyfillConfig,
+    ) -> Box<dyn Iterator<Item = CellIndex> + '_>;
+}
+
+// Implements `ToCells` on top of a conversion into polygons.
+macro_rules! impl_to_cells {
+    ($type:ty, $polygons:expr) => {
+        #[expect(deprecated, reason = "compatibility layer")]
+        impl ToCells for $type {
+            fn max_cells_count(&self, config: PolyfillConfig) -> usize {
+                config
+                    .tiler($polygons(self))
+                    .map_or(0, |tiler| tiler.coverage_size_hint())
+            }
+
+            fn to_cells(
+                &self,
+                config: PolyfillConfig,
+            ) -> Box<dyn Iterator<Item = CellIndex> + '_> {
+                match config.tiler($polygons(self)) {
+                    Some(tiler) => Box::new(tiler.into_coverage()),
+                    None => Box::new(core::iter::empty()),
+                }
+            }
+        }
+    };
+}
+
+impl_to_cells!(Polygon, |polygon: &Polygon| [polygon.clone()]);
+impl_to_cells!(MultiPolygon, |polygons: &MultiPolygon| polygons.0.clone());
+impl_to_cells!(Rect, |rect: &Rect| [rect.to_polygon()]);
+impl_to_cells!(Triangle, |triangle: &Triangle| [triangle.to_polygon()]);
@@ -0,0 +1,113 @@
+use crate::{DirectedEdgeIndex, VertexIndex};
+use ahash::{HashMap, HashMapExt, HashSet};
+use geo::{Coord, LineString};
+
+/// Chains the directed edges sharing an endpoint into linestrings.
+///
+/// Edges are joined head to tail (i.e. direction is preserved), and lines are
+/// broken at the junctions (vertices where more than one line starts or ends).
+/// Closed loops are returned as closed linestrings.
+///
+/// Coordinates are in degrees and the order of the output is unspecified, but
+/// closed loops always start from their smallest vertex index.
+///
+/// Useful to export the frontiers of a set of cells as clean lines.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{geom::edges_to_linestrings, CellIndex};
+/// use std::collections::HashSet;
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let cells = index.grid_disk::<HashSet<_>>(1);
+/// // Frontier of the disk.
+/// let edges = cells
+///     .iter()
+///     .flat_map(|cell| cell.edges())
+///     .filter(|edge| !cells.contains(&edge.destination()));
+/// let lines = edges_to_linestrings(edges);
+/// assert_eq!(lines.len(), 1);
+/// # Ok::<(), h3o::error::InvalidCellIndex>(())
+/// ```
+#[must_use]
+pub fn edges_to_linestrings(
+    edges: impl IntoIterator<Item = DirectedEdgeIndex>,
+) -> Vec<LineString> {
+    let mut outgoing = HashMap::<VertexIndex, Vec<_>>::new();
+    let mut in_degree = HashMap::<VertexIndex, usize>::new();
+    for edge in edges {
+        let (from, to) = edge.vertexes();
+        outgoing.entry(from).or_default().push((to, edge));
+        *in_degree.entry(to).or_default() += 1;
+    }
+
+    // A line goes through a vertex only if it's the sole way in and out.
+    let junctions = outgoing
+        .iter()
+        .filter_map(|(&vertex, edges)| {
+            (edges.len() != 1 || in_degree.get(&vertex) != Some(&1))
+                .then_some(vertex)
+        })
+        .collect::<HashSet<_>>();
+
+    let mut lines = Vec::new();
+    // Open lines start from a junction...
+    for &start in &junctions {
+        while let Some(line) = walk(&mut outgoing, start, &junctions) {
+            lines.push(line);
+        }
+    }
+    // ... the remaining edges form closed loops, each one starting from its
+    // smallest vertex (the walk consumes the other vertices of the loop).
+    let mut starts = outgoing.keys().copied().collect::<Vec<_>>();
+    starts.sort_unstable();
+    for start in starts {
+        lines.extend(walk(&mut outgoing, start, &junctions));
+    }
+
+    lines
+}
+
+// Follows the edges from `start` until a dead-end, a junction or a loop.
+fn walk(
+    outgoing: &mut HashMap<VertexIndex, Vec<(VertexIndex, DirectedEdgeIndex)>>,
+    start: VertexIndex,
+    junctions: &HashSet<VertexIndex>,
+) -> Option<LineString> {
+    let mut coords = Vec::<Coord>::new();
+    let mut vertex = start;
+
+    while let Some((to, edge)) = pop_edge(outgoing, vertex) {
+        let boundary = edge.boundary();
+        // Skip the start vertex, already added by the previous edge.
+        let skip = usize::from(!coords.is_empty());
+        coords.extend(boundary.iter().skip(skip).copied().map(Coord::from));
+        vertex = to;
+        if vertex == start {
+            // Close the loop exactly (the coordinates of the shared vertex may
+            // slightly differ from one cell to another).
+            let first = coords[0];
+            *coords.last_mut().expect("non-empty line") = first;
+            break;
+        }
+        if junctions.contains(&vertex) {
+            break;
+        }
+    }
+
+    (!coords.is_empty()).then(|| LineString::new(coords))
+}
+
+// Removes and returns an edge starting at the given vertex, if any.
+fn pop_edge(
+    outgoing: &mut HashMap<VertexIndex, Vec<(VertexIndex, DirectedEdgeIndex)>>,
+    vertex: VertexIndex,
+) -> Option<(VertexIndex, DirectedEdgeIndex)> {
+    let edges = outgoing.get_mut(&vertex)?;
+    let edge = edges.pop();
+    if edges.is_empty() {
+        outgoing.remove(&vertex);
+    }
+    edge
+}
@@ -0,0 +1,345 @@
+use crate::CellIndex;
+use alloc::sync::Arc;
+use arrow_buffer::{Buffer, OffsetBuffer};
+use arrow_data::ArrayData;
+use arrow_schema::{DataType, Field, FieldRef};
+use std::collections::HashMap;
+
+/// Number of dimensions of the coordinates (interleaved `x` and `y`).
+const DIMENSIONS: i32 = 2;
+
+/// Coordinate reference system of the exported coordinates.
+const CRS: &str = r#"{"crs":"OGC:CRS84"}"#;
+
+/// A builder of `GeoArrow` polygon arrays.
+///
+/// Each pushed cell or polygon is one row of the resulting array, encoded
+/// using the native `geoarrow.polygon` layout (interleaved coordinates, ring
+/// offsets and polygon offsets) which can be handed off without copy to any
+/// Arrow-aware consumer (e.g. through `arrow_array::make_array`).
+///
+/// Coordinates are in degrees, as `(longitude, latitude)` pairs.
+#[derive(Debug, Clone, Default)]
+pub struct PolygonArrayBuilder {
+    parts: Parts,
+}
+
+impl PolygonArrayBuilder {
+    /// Initializes a new empty builder.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::geom::PolygonArrayBuilder;
+    ///
+    /// let builder = PolygonArrayBuilder::new();
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends the outline of a cell as a new polygon.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::PolygonArrayBuilder, CellIndex};
+    ///
+    /// let mut builder = PolygonArrayBuilder::new();
+    /// builder.push_cell(CellIndex::try_from(0x8a1fb46622dffff)?);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn push_cell(&mut self, cell: CellIndex) {
+        self.parts.push_cell(cell);
+    }
+
+    /// Appends a polygon.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::PolygonArrayBuilder, CellIndex};
+    ///
+    /// let cell = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let mut builder = PolygonArrayBuilder::new();
+    /// builder.push_polygon(&geo::Polygon::from(cell));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn push_polygon(&mut self, polygon: &geo::Polygon) {
+        self.parts.push_polygon(polygon);
+    }
+
+    /// Returns the number of polygons pushed so far.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.parts.polygon_lengths.len()
+    }
+
+    /// Returns true if no polygon has been pushed yet.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the field describing the arrays produced by this builder.
+    ///
+    /// The field carries the `geoarrow.polygon` extension metadata.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::geom::PolygonArrayBuilder;
+    ///
+    /// let field = PolygonArrayBuilder::field("geometry");
+    /// ```
+    #[must_use]
+    pub fn field(name: &str) -> Field {
+        extension_field(name, polygon_type(), "geoarrow.polygon")
+    }
+
+    /// Builds the `GeoArrow` polygon array.
+    ///
+    /// The returned array data can be wrapped into any Arrow array type without
+    /// copying the buffers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::PolygonArrayBuilder, CellIndex};
+    ///
+    /// let cell = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let mut builder = PolygonArrayBuilder::new();
+    /// builder.extend(cell.children(h3o::Resolution::Eleven));
+    /// let array = arrow_array::make_array(builder.finish());
+    /// assert_eq!(array.len(), 7);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn finish(self) -> ArrayData {
+        self.parts.into_polygons()
+    }
+}
+
+impl Extend<CellIndex> for PolygonArrayBuilder {
+    fn extend<T: IntoIterator<Item = CellIndex>>(&mut self, iter: T) {
+        for cell in iter {
+            self.push_cell(cell);
+        }
+    }
+}
+
+/// A builder of `GeoArrow` multipolygon arrays.
+///
+/// Each pushed shape is one row of the resulting array, encoded using the
+/// native `geoarrow.multipolygon` layout. This is the natural output format
+/// for the shapes computed by [`Solvent`](super::Solvent).
+///
+/// Coordinates are in degrees, as `(longitude, latitude)` pairs.
+#[derive(Debug, Clone, Default)]
+pub struct MultiPolygonArrayBuilder {
+    parts: Parts,
+    multipolygon_lengths: Vec<usize>,
+}
+
+impl MultiPolygonArrayBuilder {
+    /// Initializes a new empty builder.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::geom::MultiPolygonArrayBuilder;
+    ///
+    /// let builder = MultiPolygonArrayBuilder::new();
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a multipolygon.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{
+    ///     geom::{MultiPolygonArrayBuilder, SolventBuilder},
+    ///     CellIndex,
+    /// };
+    ///
+    /// let cell = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let solvent = SolventBuilder::new().build();
+    /// let shape = solvent.dissolve(cell.grid_disk::<Vec<_>>(2))?;
+    ///
+    /// let mut builder = MultiPolygonArrayBuilder::new();
+    /// builder.push_multipolygon(&shape);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn push_multipolygon(&mut self, multipolygon: &geo::MultiPolygon) {
+        for polygon in multipolygon {
+            self.parts.push_polygon(polygon);
+        }
+        self.multipolygon_lengths.push(multipolygon.0.len());
+    }
+
+    /// Returns the number of multipolygons pushed so far.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.multipolygon_lengths.len()
+    }
+
+    /// Returns true if no multipolygon has been pushed yet.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the field describing the arrays produced by this builder.
+    ///
+    /// The field carries the `geoarrow.multipolygon` extension metadata.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::geom::MultiPolygonArrayBuilder;
+    ///
+    /// let field = MultiPolygonArrayBuilder::field("geometry");
+    /// ```
+    #[must_use]
+    pub fn field(name: &str) -> Field {
+        extension_field(name, multipolygon_type(), "geoarrow.multipolygon")
+    }
+
+    /// Builds the `GeoArrow` multipolygon array.
+    ///
+    /// The returned array data can be wrapped into any Arrow array type without
+    /// copying the buffers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::geom::MultiPolygonArrayBuilder;
+    ///
+    /// let array = MultiPolygonArrayBuilder::new().finish();
+    /// assert!(array.is_empty());
+    /// ```
+    #[must_use]
+    pub fn finish(self) -> ArrayData {
+        let polygons = self.parts.into_polygons();
+        list(multipolygon_type(), self.multipolygon_lengths, polygons)
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// Flattened coordinates and rings of a sequence of polygons.
+#[derive(Debug, Clone, Default)]
+struct Parts {
+    /// Interleaved coordinates.
+    coords: Vec<f64>,
+    /// Number of vertices of each ring.
+    ring_lengths: Vec<usize>,
+    /// Number of rings of each polygon.
+    polygon_lengths: Vec<usize>,
+}
+
+impl Parts {
+    fn push_cell(&mut self, cell: CellIndex) {
+        let boundary = cell.boundary();
+        let vertices = boundary.iter().chain(boundary.iter().take(1));
+        for vertex in vertices {
+            self.coords.extend([vertex.lng(), vertex.lat()]);
+        }
+        self.ring_lengths.push(boundary.len() + 1);
+        self.polygon_lengths.push(1);
+    }
+
+    fn push_polygon(&mut self, polygon: &geo::Polygon) {
+        let rings = core::iter::once(polygon.exterior())
+            .chain(polygon.interiors())
+            // Polygons built by `geo` always have closed rings, except the
+            // empty ones.
+            .filter(|ring| !ring.0.is_empty());
+        let mut count = 0;
+        for ring in rings {
+            for coord in ring {
+                self.coords.extend([coord.x, coord.y]);
+            }
+            self.ring_lengths.push(ring.0.len());
+            count += 1;
+        }
+        self.polygon_lengths.push(count);
+    }
+
+    fn into_polygons(self) -> ArrayData {
+        let count = self.coords.len() / 2;
+        let values = ArrayData::builder(DataType::Float64)
+            .len(self.coords.len())
+            .add_buffer(Buffer::from_vec(self.coords))
+            .build()
+            .expect("valid coordinates");
+        let coords = ArrayData::builder(coords_type())
+            .len(count)
+            .add_child_data(values)
+            .build()
+            .expect("valid interleaved coordinates");
+        let rings = list(rings_type(), self.ring_lengths, coords);
+        list(polygon_type(), self.polygon_lengths, rings)
+    }
+}
+
+/// Wraps the `values` into a list array with the given lengths.
+fn list(
+    data_type: DataType,
+    lengths: Vec<usize>,
+    values: ArrayData,
+) -> ArrayData {
+    let len = lengths.len();
+    let offsets = OffsetBuffer::<i32>::from_lengths(lengths);
+
+    ArrayData::builder(data_type)
+        .len(len)
+        .add_buffer(offsets.into_inner().into_inner())
+        .add_child_data(values)
+        .build()
+        .expect("valid list layout")
+}
+
+fn coords_field() -> FieldRef {
+    Arc::new(Field::new("xy", DataType::Float64, false))
+}
+
+fn vertices_field() -> FieldRef {
+    Arc::new(Field::new("vertices", coords_type(), false))
+}
+
+fn rings_field() -> FieldRef {
+    Arc::new(Field::new("rings", rings_type(), false))
+}
+
+fn polygons_field() -> FieldRef {
+    Arc::new(Field::new("polygons", polygon_type(), false))
+}
+
+fn coords_type() -> DataType {
+    DataType::FixedSizeList(coords_field(), DIMENSIONS)
+}
+
+fn rings_type() -> DataType {
+    DataType::List(vertices_field())
+}
+
+fn polygon_type() -> DataType {
+    DataType::List(rings_field())
+}
+
+fn multipolygon_type() -> DataType {
+    DataType::List(polygons_field())
+}
+
+fn extension_field(name: &str, data_type: DataType, extension: &str) -> Field {
+    Field::new(name, data_type, false).with_metadata(HashMap::from([
+        ("ARROW:extension:name".to_owned(), extension.to_owned()),
+        ("ARROW:extension:metadata".to_owned(), CRS.to_owned()),
+    ]))
+}
@@ -1,17 +1,42 @@
 //! Bridge between H3 entities and geometrical shapes.
 
+mod cap;
+mod compat;
+mod edges;
+#[cfg(feature = "geoarrow")]
+mod geoarrow;
 mod plotter;
 mod ring_hierarchy;
+#[cfg(feature = "rstar")]
+mod rtree;
 mod solvent;
+mod sphere;
 mod tiler;
+#[cfg(feature = "geozero")]
+mod tiler_sink;
 mod vertex_graph;
+#[cfg(feature = "wkb")]
+mod wkb;
+#[cfg(feature = "wkt")]
+mod wkt;
 
-use ring_hierarchy::RingHierarchy;
-use vertex_graph::VertexGraph;
-
-pub use plotter::{Plotter, PlotterBuilder};
-pub use solvent::{Solvent, SolventBuilder};
-pub use tiler::{ContainmentMode, Tiler, TilerBuilder};
+pub use cap::cap_to_cells;
+#[expect(deprecated, reason = "compatibility layer")]
+pub use compat::{PolyfillConfig, ToCells};
+pub use edges::edges_to_linestrings;
+#[cfg(feature = "geoarrow")]
+pub use geoarrow::{MultiPolygonArrayBuilder, PolygonArrayBuilder};
+pub use plotter::{PlotMode, Plotter, PlotterBuilder};
+pub use ring_hierarchy::RingHierarchy;
+#[cfg(feature = "rstar")]
+pub use rtree::RTreeCell;
+pub use solvent::{BoundarySegment, ClusterSummary, Solvent, SolventBuilder};
+pub use tiler::{
+    ContainmentMode, CoverageReport, HolePolicy, Tiler, TilerBuilder,
+};
+#[cfg(feature = "geozero")]
+pub use tiler_sink::TilerSink;
+pub use vertex_graph::{Node, VertexGraph};
 
 // Check that the coordinate are finite and in a legit range.
 fn coord_is_valid(coord: geo::Coord) -> bool {
@@ -26,6 +51,32 @@ fn coord_is_valid(coord: geo::Coord) -> bool {
         && coord.y <= PI
 }
 
+// Extracts the polygons of a geometry decoded from WKB or WKT.
+//
+// Empty polygons are skipped, and coordinates must be in WGS84 (SRID 4326, or
+// 0 when undefined).
+#[cfg(any(feature = "wkb", feature = "wkt"))]
+fn decoded_polygons(
+    geometry: geo::Geometry,
+    srid: Option<i32>,
+) -> Result<Vec<geo::Polygon>, crate::error::InvalidGeometry> {
+    use crate::error::InvalidGeometry;
+
+    if !matches!(srid, None | Some(0 | 4326)) {
+        return Err(InvalidGeometry::new("unsupported SRID, expected 4326"));
+    }
+
+    let polygons = match geometry {
+        geo::Geometry::Polygon(polygon) => vec![polygon],
+        geo::Geometry::MultiPolygon(polygons) => polygons.0,
+        _ => return Err(InvalidGeometry::new("unsupported geometry type")),
+    };
+    Ok(polygons
+        .into_iter()
+        .filter(|polygon| !polygon.exterior().0.is_empty())
+        .collect())
+}
+
 // Return the immediate neighbors, no memory allocations.
 fn neighbors(cell: crate::CellIndex, scratchpad: &mut [u64]) -> usize {
     let mut count = 0;
@@ -1,9 +1,10 @@
 use crate::{
     error::{InvalidGeometry, PlotterError},
+    geodesic::cells_along_geodesic,
     index::GridPathCells,
     CellIndex, LatLng, Resolution,
 };
-use geo::{Line, ToRadians as _};
+use geo::{Line, LineString, MultiLineString, ToRadians as _};
 
 /// A plotter that produces H3 cell indexes along given lines.
 ///
@@ -26,7 +27,11 @@ use geo::{Line, ToRadians as _};
 pub struct Plotter {
     resolution: Resolution,
     convert_to_rads: bool,
-    paths: Vec<GridPathCells>,
+    mode: PlotMode,
+    /// Grid paths, flagged when they continue the previous one.
+    paths: Vec<(GridPathCells, bool)>,
+    /// Great circle arcs, flagged when they continue the previous one.
+    arcs: Vec<(LatLng, LatLng, bool)>,
 }
 
 impl Plotter {
@@ -36,7 +41,17 @@ impl Plotter {
     ///
     /// [`PlotterError`] if the line is invalid or cannot be handled (cf.
     /// [`grid_path_cells`](CellIndex::grid_path_cells) limitations).
-    pub fn add(&mut self, mut line: Line) -> Result<(), PlotterError> {
+    pub fn add(&mut self, line: Line) -> Result<(), PlotterError> {
+        self.push(line, false)
+    }
+
+    // Adds a line, optionally joined to the previous one (i.e. its starting
+    // cell is the last cell of the previous line, and is thus skipped).
+    fn push(
+        &mut self,
+        mut line: Line,
+        joined: bool,
+    ) -> Result<(), PlotterError> {
         if self.convert_to_rads {
             line.to_radians_in_place();
         }
@@ -44,13 +59,20 @@ impl Plotter {
 
         // Expect valid coordinates, checked by `check_coords` above.
         let start = LatLng::from_radians(line.start.y, line.start.x)
-            .expect("valid start")
-            .to_cell(self.resolution);
-        let end = LatLng::from_radians(line.end.y, line.end.x)
-            .expect("valid end")
-            .to_cell(self.resolution);
+            .expect("valid start");
+        let end =
+            LatLng::from_radians(line.end.y, line.end.x).expect("valid end");
 
-        self.paths.push(GridPathCells::new(start, end)?);
+        match self.mode {
+            PlotMode::GridPath => {
+                let path = GridPathCells::new(
+                    start.to_cell(self.resolution),
+                    end.to_cell(self.resolution),
+                )?;
+                self.paths.push((path, joined));
+            }
+            PlotMode::Geodesic => self.arcs.push((start, end, joined)),
+        }
 
         Ok(())
     }
@@ -72,15 +94,96 @@ impl Plotter {
         Ok(())
     }
 
+    /// Adds a `LineString` to plot.
+    ///
+    /// Since consecutive segments share their endpoints, the cells of a line
+    /// string form a connected path: every consecutive pair of cells are
+    /// neighbors (the cell at the junction of two segments is returned once).
+    ///
+    /// # Errors
+    ///
+    /// [`PlotterError`] if the line is invalid or cannot be handled (cf.
+    /// [`grid_path_cells`](CellIndex::grid_path_cells) limitations).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::line_string;
+    /// use h3o::{
+    ///     geom::{PlotMode, PlotterBuilder},
+    ///     Resolution,
+    /// };
+    ///
+    /// let mut plotter = PlotterBuilder::new(Resolution::Ten)
+    ///     .plot_mode(PlotMode::Geodesic)
+    ///     .build();
+    /// plotter.add_line_string(&line_string![
+    ///     (x: 2.363503198417334,  y: 48.8203086545891),
+    ///     (x: 2.3730684893043588, y: 48.85398407690437),
+    ///     (x: 2.334964762310932,  y: 48.870861968772914),
+    /// ])?;
+    ///
+    /// let cells = plotter.plot().collect::<Result<Vec<_>, _>>()?;
+    /// assert!(cells.windows(2).all(|pair| pair[0]
+    ///     .is_neighbor_with(pair[1])
+    ///     .unwrap_or_default()));
+    /// # Ok::<(), h3o::error::PlotterError>(())
+    /// ```
+    pub fn add_line_string(
+        &mut self,
+        line_string: &LineString,
+    ) -> Result<(), PlotterError> {
+        for (i, line) in line_string.lines().enumerate() {
+            self.push(line, i != 0)?;
+        }
+
+        Ok(())
+    }
+
+    /// Adds a `MultiLineString` to plot.
+    ///
+    /// Each line string is plotted as a connected path, but distinct line
+    /// strings are not connected together.
+    ///
+    /// # Errors
+    ///
+    /// [`PlotterError`] if a line is invalid or cannot be handled (cf.
+    /// [`grid_path_cells`](CellIndex::grid_path_cells) limitations).
+    pub fn add_multi_line_string(
+        &mut self,
+        multi_line_string: &MultiLineString,
+    ) -> Result<(), PlotterError> {
+        for line_string in multi_line_string {
+            self.add_line_string(line_string)?;
+        }
+
+        Ok(())
+    }
+
     /// Plot the hexagons along the lines.
     ///
-    /// Note that this functions suffers from the same limitation as
-    /// [`grid_path_cells`](CellIndex::grid_path_cells).
+    /// Cells are returned in the order of the lines.
+    ///
+    /// Note that, in [`PlotMode::GridPath`] mode, this functions suffers from
+    /// the same limitation as [`grid_path_cells`](CellIndex::grid_path_cells).
     pub fn plot(self) -> impl Iterator<Item = Result<CellIndex, PlotterError>> {
-        self.paths
+        let resolution = self.resolution;
+        let paths = self
+            .paths
+            .into_iter()
+            .flat_map(|(path, joined)| path.skip(usize::from(joined)))
+            .map(|res| res.map_err(Into::into));
+        let arcs = self
+            .arcs
             .into_iter()
-            .flatten()
-            .map(|res| res.map_err(Into::into))
+            .flat_map(move |(start, end, joined)| {
+                cells_along_geodesic(start, end, resolution, 0.)
+                    .into_iter()
+                    .skip(usize::from(joined))
+            })
+            .map(Ok);
+
+        paths.chain(arcs)
     }
 
     // Check that the line's coordinates are valid.
@@ -104,6 +207,7 @@ impl Plotter {
 pub struct PlotterBuilder {
     resolution: Resolution,
     convert_to_rads: bool,
+    mode: PlotMode,
 }
 
 impl PlotterBuilder {
@@ -113,6 +217,7 @@ impl PlotterBuilder {
         Self {
             resolution,
             convert_to_rads: true,
+            mode: PlotMode::GridPath,
         }
     }
 
@@ -123,13 +228,48 @@ impl PlotterBuilder {
         self
     }
 
+    /// Set the mode used to trace the lines.
+    #[must_use]
+    pub const fn plot_mode(mut self, mode: PlotMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
     /// Builds the plotter.
     #[must_use]
     pub const fn build(self) -> Plotter {
         Plotter {
             resolution: self.resolution,
             convert_to_rads: self.convert_to_rads,
+            mode: self.mode,
             paths: Vec::new(),
+            arcs: Vec::new(),
         }
     }
 }
+
+// -----------------------------------------------------------------------------
+
+/// Mode used to trace the cells along a line.
+///
+/// In both modes, the cells of a line form a connected path (every
+/// consecutive pair of cells are neighbors).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PlotMode {
+    /// This mode follows the straight line, in the grid space, between the
+    /// cells of the line endpoints (cf.
+    /// [`grid_path_cells`](CellIndex::grid_path_cells)).
+    ///
+    /// This is the fastest option, but the path may deviate from the actual
+    /// line and cannot be computed across pentagon distortions.
+    GridPath,
+
+    /// This mode selects the cells crossed by the line, following the
+    /// great circle arc between its endpoints (cf.
+    /// [`cells_along_geodesic`](crate::cells_along_geodesic)).
+    ///
+    /// This is slower, but the path follows the actual line and it never
+    /// fails.
+    Geodesic,
+}
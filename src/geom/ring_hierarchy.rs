@@ -2,6 +2,29 @@ use geo::{Contains, Coord, LineString, MultiPolygon, Polygon};
 use std::{iter::Peekable, vec};
 
 /// A rings hierarchy.
+///
+/// Given a set of non-overlapping rings (e.g. the outlines of a set of cells),
+/// computes which ring is nested in which one, to know which rings are holes
+/// and to which exterior they belong.
+///
+/// Coordinates are expected to be in degrees: rings crossing the antimeridian
+/// are detected (and handled) using the length of their edges.
+///
+/// ```rust
+/// use geo::{LineString, MultiPolygon};
+/// use h3o::geom::RingHierarchy;
+///
+/// let hierarchy = RingHierarchy::new(vec![
+///     LineString::from(vec![(2., 4.), (4., 4.), (2., 2.), (2., 4.)]),
+///     LineString::from(vec![(1., 1.), (1., 5.), (5., 5.), (1., 1.)]),
+/// ]);
+/// assert!(hierarchy.is_hole(0));
+/// assert_eq!(hierarchy.parent(0), Some(1));
+///
+/// let geom = MultiPolygon::from(hierarchy);
+/// assert_eq!(geom.0.len(), 1);
+/// ```
+#[derive(Debug, Clone)]
 pub struct RingHierarchy {
     /// Rings geometry.
     rings: Vec<LineString<f64>>,
@@ -46,10 +69,31 @@ pub struct RingHierarchy {
     /// └───┴───┴───┴───┴───┘
     /// ```
     matrix: Vec<bool>,
+
+    /// Nesting depth of each ring.
+    depths: Vec<usize>,
+
+    /// Ring directly containing each ring, if any.
+    parents: Vec<Option<usize>>,
 }
 
 impl RingHierarchy {
     /// Builds a new hierarchy of rings.
+    ///
+    /// Rings are identified by their position in the input list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use h3o::geom::RingHierarchy;
+    ///
+    /// let hierarchy = RingHierarchy::new(vec![
+    ///     LineString::from(vec![(1., 1.), (1., 3.), (3., 1.), (1., 1.)]),
+    /// ]);
+    /// assert_eq!(hierarchy.len(), 1);
+    /// ```
+    #[must_use]
     pub fn new(rings: Vec<LineString<f64>>) -> Self {
         let is_assigned = vec![false; rings.len()];
         let is_transmeridian = rings
@@ -89,15 +133,228 @@ impl RingHierarchy {
             }
         }
 
+        // The depth of a ring is the number of rings that contains it.
+        let count = rings.len();
+        let depths = (0..count)
+            .map(|id| {
+                matrix[id * count..(id + 1) * count]
+                    .iter()
+                    .filter(|&&is_contained| is_contained)
+                    .count()
+            })
+            .collect::<Vec<_>>();
+        // The direct parent is the deepest of the containing rings.
+        let parents = (0..count)
+            .map(|id| {
+                (0..count).find(|&i| {
+                    matrix[id * count + i] && depths[i] + 1 == depths[id]
+                })
+            })
+            .collect();
+
         Self {
             rings,
             is_assigned,
             matrix,
+            depths,
+            parents,
         }
     }
 
+    /// Returns the number of rings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::geom::RingHierarchy;
+    ///
+    /// let hierarchy = RingHierarchy::new(Vec::new());
+    /// assert_eq!(hierarchy.len(), 0);
+    /// ```
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.rings.len()
+    }
+
+    /// Returns true if there is no ring.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::geom::RingHierarchy;
+    ///
+    /// let hierarchy = RingHierarchy::new(Vec::new());
+    /// assert!(hierarchy.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.rings.is_empty()
+    }
+
+    /// Tests if the ring `outer` contains the ring `inner`, directly or not.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the ring ID is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use h3o::geom::RingHierarchy;
+    ///
+    /// let hierarchy = RingHierarchy::new(vec![
+    ///     LineString::from(vec![(3., 4.), (4., 4.), (3., 3.), (3., 4.)]),
+    ///     LineString::from(vec![(2., 5.), (5., 5.), (2., 2.), (2., 5.)]),
+    ///     LineString::from(vec![(1., 1.), (1., 6.), (6., 6.), (1., 1.)]),
+    /// ]);
+    /// assert!(hierarchy.contains(2, 0));
+    /// assert!(!hierarchy.contains(0, 2));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, outer: usize, inner: usize) -> bool {
+        assert!(outer < self.len() && inner < self.len(), "invalid ring ID");
+        self.matrix[inner * self.rings.len() + outer]
+    }
+
+    /// Returns the nesting depth of a ring, i.e. the number of rings that
+    /// contains it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ring ID is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use h3o::geom::RingHierarchy;
+    ///
+    /// let hierarchy = RingHierarchy::new(vec![
+    ///     LineString::from(vec![(3., 4.), (4., 4.), (3., 3.), (3., 4.)]),
+    ///     LineString::from(vec![(2., 5.), (5., 5.), (2., 2.), (2., 5.)]),
+    ///     LineString::from(vec![(1., 1.), (1., 6.), (6., 6.), (1., 1.)]),
+    /// ]);
+    /// assert_eq!(hierarchy.depth(0), 2);
+    /// assert_eq!(hierarchy.depth(2), 0);
+    /// ```
+    #[must_use]
+    pub fn depth(&self, id: usize) -> usize {
+        assert!(id < self.len(), "invalid ring ID");
+        self.depths[id]
+    }
+
+    /// Returns the ring directly containing the given one, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ring ID is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use h3o::geom::RingHierarchy;
+    ///
+    /// let hierarchy = RingHierarchy::new(vec![
+    ///     LineString::from(vec![(3., 4.), (4., 4.), (3., 3.), (3., 4.)]),
+    ///     LineString::from(vec![(2., 5.), (5., 5.), (2., 2.), (2., 5.)]),
+    ///     LineString::from(vec![(1., 1.), (1., 6.), (6., 6.), (1., 1.)]),
+    /// ]);
+    /// assert_eq!(hierarchy.parent(0), Some(1));
+    /// assert_eq!(hierarchy.parent(2), None);
+    /// ```
+    #[must_use]
+    pub fn parent(&self, id: usize) -> Option<usize> {
+        assert!(id < self.len(), "invalid ring ID");
+        self.parents[id]
+    }
+
+    /// Tests if the given ring is a hole (i.e. it has an odd nesting depth).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ring ID is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use h3o::geom::RingHierarchy;
+    ///
+    /// let hierarchy = RingHierarchy::new(vec![
+    ///     LineString::from(vec![(2., 4.), (4., 4.), (2., 2.), (2., 4.)]),
+    ///     LineString::from(vec![(1., 1.), (1., 5.), (5., 5.), (1., 1.)]),
+    /// ]);
+    /// assert!(hierarchy.is_hole(0));
+    /// assert!(!hierarchy.is_hole(1));
+    /// ```
+    #[must_use]
+    pub fn is_hole(&self, id: usize) -> bool {
+        self.depth(id) % 2 == 1
+    }
+
+    /// Returns the holes of the given exterior ring.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ring ID is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use h3o::geom::RingHierarchy;
+    ///
+    /// let hierarchy = RingHierarchy::new(vec![
+    ///     LineString::from(vec![(2., 4.), (4., 4.), (2., 2.), (2., 4.)]),
+    ///     LineString::from(vec![(1., 1.), (1., 5.), (5., 5.), (1., 1.)]),
+    /// ]);
+    /// assert_eq!(hierarchy.holes(1).collect::<Vec<_>>(), vec![0]);
+    /// ```
+    pub fn holes(&self, id: usize) -> impl Iterator<Item = usize> + '_ {
+        let is_exterior = !self.is_hole(id);
+        (0..self.len())
+            .filter(move |&i| is_exterior && self.parents[i] == Some(id))
+    }
+
+    /// Returns the rings, indexed by ID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use h3o::geom::RingHierarchy;
+    ///
+    /// let ring =
+    ///     LineString::from(vec![(1., 1.), (1., 3.), (3., 1.), (1., 1.)]);
+    /// let hierarchy = RingHierarchy::new(vec![ring.clone()]);
+    /// assert_eq!(hierarchy.rings(), &[ring]);
+    /// ```
+    #[must_use]
+    pub fn rings(&self) -> &[LineString<f64>] {
+        &self.rings
+    }
+
     /// Consumes the hierarchy into a stream of Polygon.
-    pub fn into_iter(mut self) -> impl Iterator<Item = Polygon<f64>> {
+    ///
+    /// Rings at an even nesting depth are the exteriors and those at an odd
+    /// depth are their holes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use h3o::geom::RingHierarchy;
+    ///
+    /// let hierarchy = RingHierarchy::new(vec![
+    ///     LineString::from(vec![(2., 4.), (4., 4.), (2., 2.), (2., 4.)]),
+    ///     LineString::from(vec![(1., 1.), (1., 5.), (5., 5.), (1., 1.)]),
+    /// ]);
+    /// let polygons = hierarchy.into_polygons().collect::<Vec<_>>();
+    /// assert_eq!(polygons.len(), 1);
+    /// ```
+    pub fn into_polygons(mut self) -> impl Iterator<Item = Polygon<f64>> {
         type OuterRingIterator =
             Peekable<vec::IntoIter<(usize, LineString<f64>)>>;
 
@@ -106,7 +363,7 @@ impl RingHierarchy {
 
         std::iter::from_fn(move || {
             // If the current layer is exhausted, peel the next one.
-            if outers.as_mut().map_or(true, |rings| rings.peek().is_none()) {
+            if outers.as_mut().is_none_or(|rings| rings.peek().is_none()) {
                 outers = self
                     .peel_outers()
                     .map(|rings| rings.into_iter().peekable());
@@ -188,11 +445,6 @@ impl RingHierarchy {
         rings
     }
 
-    /// Tests if `outer` contains `inner`.
-    fn contains(&self, outer: usize, inner: usize) -> bool {
-        self.matrix[inner * self.rings.len() + outer]
-    }
-
     /// Tests if the given ring is an outer ring (e.g. row `id` is all false).
     fn is_outer(&self, id: usize) -> bool {
         self.count_parents(id) == 0
@@ -218,7 +470,7 @@ impl RingHierarchy {
 
 impl From<RingHierarchy> for MultiPolygon<f64> {
     fn from(value: RingHierarchy) -> Self {
-        Self(value.into_iter().collect())
+        Self(value.into_polygons().collect())
     }
 }
 
@@ -0,0 +1,88 @@
+use crate::CellIndex;
+use geo::{Distance as _, Euclidean, Intersects as _, Point, Polygon};
+use rstar::{Envelope as _, PointDistance, RTreeObject, AABB};
+
+/// A cell that can be stored in an [`rstar::RTree`].
+///
+/// The envelope is the bounding box of the cell boundary, and the distances
+/// are computed against the cell polygon, using planar coordinates in
+/// degrees (`[longitude, latitude]`), as with the `geo` conversions.
+///
+/// Note that cells crossing the antimeridian have an envelope spanning most of
+/// the longitudes.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{geom::RTreeCell, CellIndex, Resolution};
+/// use rstar::RTree;
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let tree = RTree::bulk_load(
+///     index
+///         .children(Resolution::Twelve)
+///         .map(RTreeCell::from)
+///         .collect(),
+/// );
+/// let nearest = tree.nearest_neighbor(&[-122.0265, 37.3855]);
+/// # Ok::<(), h3o::error::InvalidCellIndex>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RTreeCell {
+    cell: CellIndex,
+    envelope: AABB<[f64; 2]>,
+}
+
+impl RTreeCell {
+    /// Returns the wrapped cell index.
+    #[must_use]
+    pub const fn cell(&self) -> CellIndex {
+        self.cell
+    }
+}
+
+impl From<CellIndex> for RTreeCell {
+    fn from(value: CellIndex) -> Self {
+        let (mut min, mut max) = ([f64::MAX; 2], [f64::MIN; 2]);
+        for ll in value.boundary().iter() {
+            let coord = [ll.lng(), ll.lat()];
+            for i in 0..2 {
+                min[i] = min[i].min(coord[i]);
+                max[i] = max[i].max(coord[i]);
+            }
+        }
+
+        Self {
+            cell: value,
+            envelope: AABB::from_corners(min, max),
+        }
+    }
+}
+
+impl From<RTreeCell> for CellIndex {
+    fn from(value: RTreeCell) -> Self {
+        value.cell
+    }
+}
+
+impl RTreeObject for RTreeCell {
+    type Envelope = AABB<[f64; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        self.envelope
+    }
+}
+
+impl PointDistance for RTreeCell {
+    fn distance_2(&self, point: &[f64; 2]) -> f64 {
+        let polygon = Polygon::from(self.cell);
+        let distance = Euclidean::distance(&Point::from(*point), &polygon);
+        distance * distance
+    }
+
+    fn contains_point(&self, point: &[f64; 2]) -> bool {
+        // Cheap rejection before the exact check.
+        self.envelope.contains_point(point)
+            && Polygon::from(self.cell).intersects(&Point::from(*point))
+    }
+}
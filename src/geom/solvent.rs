@@ -1,6 +1,15 @@
-use super::VertexGraph;
-use crate::{error::DissolutionError, CellIndex, Resolution};
-use geo::MultiPolygon;
+use super::{edges_to_linestrings, vertex_graph::Scratchpad, VertexGraph};
+use crate::{
+    error::DissolutionError, geodesic::weighted_centroid, CellIndex,
+    DirectedEdgeIndex, LatLng, Resolution,
+};
+use ahash::{HashSet, HashSetExt};
+use geo::{Coord, LineString, MultiLineString, MultiPolygon};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+    hash::BuildHasher,
+};
 
 /// A solvent that dissolves a set of H3 cell indexes into a `MultiPolygon`
 /// representing the outlines of the set.
@@ -8,6 +17,7 @@ use geo::MultiPolygon;
 pub struct Solvent {
     input_mode: InputMode,
     check_duplicate: bool,
+    deterministic_output: bool,
 }
 
 impl Solvent {
@@ -47,7 +57,424 @@ impl Solvent {
             }
         }?;
 
-        Ok(graph.into())
+        let mut geom = MultiPolygon::from(graph);
+        if self.deterministic_output {
+            normalize(&mut geom);
+        }
+
+        Ok(geom)
+    }
+
+    /// Creates a [`MultiLineString`](geo::MultiLineString) by merging the
+    /// contiguous directed edges.
+    ///
+    /// Edges are joined at their shared vertices, head to tail (i.e. the
+    /// direction of the edges is preserved), and the lines are broken where
+    /// several of them meet. The order of the lines is unspecified, unless
+    /// deterministic output is enabled.
+    ///
+    /// Input mode is ignored: edges can have any resolution (edges of
+    /// different resolutions are never joined).
+    ///
+    /// # Errors
+    ///
+    /// All edges must be unique (unless duplicate detection is disabled),
+    /// otherwise [`DissolutionError`](DissolutionError) is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::SolventBuilder, CellIndex};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let solvent = SolventBuilder::new().build();
+    /// let geom = solvent.dissolve_edges(index.edges())?;
+    /// // The edges of a cell form a single closed line.
+    /// assert_eq!(geom.0.len(), 1);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn dissolve_edges(
+        &self,
+        edges: impl IntoIterator<Item = DirectedEdgeIndex>,
+    ) -> Result<MultiLineString, DissolutionError> {
+        let lines = if self.check_duplicate {
+            let mut seen = HashSet::new();
+            let edges = edges
+                .into_iter()
+                .map(|edge| {
+                    if seen.insert(edge) {
+                        Ok(edge)
+                    } else {
+                        Err(DissolutionError::DuplicateInput)
+                    }
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            edges_to_linestrings(edges)
+        } else {
+            edges_to_linestrings(edges)
+        };
+
+        let mut geom = MultiLineString::new(lines);
+        if self.deterministic_output {
+            geom.0.sort_unstable_by(|a, b| cmp_coords(&a.0, &b.0));
+        }
+
+        Ok(geom)
+    }
+
+    /// Returns the segments making the outlines of a set of cells, each one
+    /// annotated with the cell and the edge it comes from.
+    ///
+    /// Unlike [`Self::dissolve`], segments are not merged: this allows to
+    /// style the boundaries according to the attributes of the cells on each
+    /// side (the outside one being the destination of the edge).
+    ///
+    /// Segments are produced in the order of the input cells. Input mode is
+    /// ignored: cells must have the same resolution.
+    ///
+    /// # Errors
+    ///
+    /// All cell indexes must be unique (unless duplicate detection is
+    /// disabled) and have the same resolution, otherwise
+    /// [`DissolutionError`](DissolutionError) is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::SolventBuilder, CellIndex};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let cells = index.grid_disk::<Vec<_>>(1);
+    /// let solvent = SolventBuilder::new().build();
+    /// let segments = solvent.outline_segments(cells)?;
+    /// assert_eq!(segments.len(), 18);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn outline_segments(
+        &self,
+        cells: impl IntoIterator<Item = CellIndex>,
+    ) -> Result<Vec<BoundarySegment>, DissolutionError> {
+        let cells = cells.into_iter().collect::<Vec<_>>();
+        let resolution = cells.first().map(|cell| cell.resolution());
+        let mut set = HashSet::with_capacity(cells.len());
+        for &cell in &cells {
+            if Some(cell.resolution()) != resolution {
+                return Err(DissolutionError::UnsupportedResolution);
+            }
+            if !set.insert(cell) && self.check_duplicate {
+                return Err(DissolutionError::DuplicateInput);
+            }
+        }
+
+        Ok(cells
+            .iter()
+            .flat_map(|&cell| {
+                cell.edges()
+                    .filter(|edge| !set.contains(&edge.destination()))
+                    .map(move |edge| BoundarySegment {
+                        cell,
+                        edge,
+                        line: edge
+                            .boundary()
+                            .iter()
+                            .copied()
+                            .map(Coord::from)
+                            .collect(),
+                    })
+            })
+            .collect())
+    }
+
+    /// Computes the filled contours of per-cell values.
+    ///
+    /// For each threshold, returns the shape of the cells whose value is
+    /// greater than or equal to it (NaN values never are). The shapes of
+    /// increasing thresholds are thus nested into each other.
+    ///
+    /// # Errors
+    ///
+    /// The cells must have the expected resolution, otherwise
+    /// [`DissolutionError`](DissolutionError) is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::SolventBuilder, CellIndex};
+    /// use std::collections::HashMap;
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let values = index
+    ///     .grid_disk_distances::<Vec<_>>(3)
+    ///     .into_iter()
+    ///     .map(|(cell, distance)| (cell, 3. - f64::from(distance)))
+    ///     .collect::<HashMap<_, _>>();
+    ///
+    /// let solvent = SolventBuilder::new().build();
+    /// let contours = solvent.contours(&values, &[1., 2.])?;
+    /// assert_eq!(contours.len(), 2);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn contours<S: BuildHasher>(
+        &self,
+        values: &HashMap<CellIndex, f64, S>,
+        thresholds: &[f64],
+    ) -> Result<Vec<MultiPolygon>, DissolutionError> {
+        thresholds
+            .iter()
+            .map(|&threshold| {
+                self.dissolve(select(values, move |value| value >= threshold))
+            })
+            .collect()
+    }
+
+    /// Computes the contour bands of per-cell values.
+    ///
+    /// Thresholds are expected in ascending order: the i-th band is the
+    /// shape of the cells whose value is in `[thresholds[i],
+    /// thresholds[i + 1])`, the last band being unbounded.
+    ///
+    /// # Errors
+    ///
+    /// The cells must have the expected resolution, otherwise
+    /// [`DissolutionError`](DissolutionError) is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::SolventBuilder, CellIndex};
+    /// use std::collections::HashMap;
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let values = index
+    ///     .grid_disk_distances::<Vec<_>>(3)
+    ///     .into_iter()
+    ///     .map(|(cell, distance)| (cell, 3. - f64::from(distance)))
+    ///     .collect::<HashMap<_, _>>();
+    ///
+    /// let solvent = SolventBuilder::new().build();
+    /// let bands = solvent.contour_bands(&values, &[1., 2.])?;
+    /// // The band between 1 and 2 is a ring.
+    /// assert_eq!(bands[0].0[0].interiors().len(), 1);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn contour_bands<S: BuildHasher>(
+        &self,
+        values: &HashMap<CellIndex, f64, S>,
+        thresholds: &[f64],
+    ) -> Result<Vec<MultiPolygon>, DissolutionError> {
+        thresholds
+            .iter()
+            .enumerate()
+            .map(|(i, &low)| {
+                let high = thresholds.get(i + 1).copied();
+                self.dissolve(select(values, move |value| {
+                    value >= low && high.is_none_or(|high| value < high)
+                }))
+            })
+            .collect()
+    }
+
+    /// Computes the isolines of per-cell values.
+    ///
+    /// For each threshold, returns the outlines (outer rings and holes) of
+    /// the matching filled contour (cf. [`Self::contours`]), as closed lines.
+    ///
+    /// # Errors
+    ///
+    /// The cells must have the expected resolution, otherwise
+    /// [`DissolutionError`](DissolutionError) is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::SolventBuilder, CellIndex};
+    /// use std::collections::HashMap;
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let values = index
+    ///     .grid_disk_distances::<Vec<_>>(3)
+    ///     .into_iter()
+    ///     .map(|(cell, distance)| (cell, 3. - f64::from(distance)))
+    ///     .collect::<HashMap<_, _>>();
+    ///
+    /// let solvent = SolventBuilder::new().build();
+    /// let isolines = solvent.isolines(&values, &[2.])?;
+    /// assert_eq!(isolines[0].0.len(), 1);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn isolines<S: BuildHasher>(
+        &self,
+        values: &HashMap<CellIndex, f64, S>,
+        thresholds: &[f64],
+    ) -> Result<Vec<MultiLineString>, DissolutionError> {
+        Ok(self
+            .contours(values, thresholds)?
+            .into_iter()
+            .map(|shape| {
+                shape
+                    .into_iter()
+                    .flat_map(|polygon| {
+                        let (exterior, interiors) = polygon.into_inner();
+                        std::iter::once(exterior).chain(interiors)
+                    })
+                    .collect()
+            })
+            .collect())
+    }
+
+    /// Summarizes each cluster of a labeled set of cells (e.g. the output
+    /// of [`cluster_cells`](crate::cluster_cells)): its dissolved shape,
+    /// centroid, area and number of cells.
+    ///
+    /// Cells and labels are matched by position, and unlabeled cells (i.e.
+    /// noise) are skipped. Every cluster is dissolved at once, in a single
+    /// pass over the cells, and the summaries are sorted by label.
+    ///
+    /// Input mode is ignored: the cells of a cluster must have the same
+    /// resolution.
+    ///
+    /// # Errors
+    ///
+    /// There must be one label per cell, all labeled cell indexes must be
+    /// unique (unless duplicate detection is disabled) and the cells of a
+    /// cluster must have the same resolution, otherwise
+    /// [`DissolutionError`](DissolutionError) is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{cluster_cells, geom::SolventBuilder, CellIndex};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let cells = index.grid_disk::<Vec<_>>(1);
+    /// let labels = cluster_cells(&cells, 3, 1);
+    ///
+    /// let solvent = SolventBuilder::new().build();
+    /// let clusters = solvent.summarize_clusters(&cells, &labels)?;
+    /// assert_eq!(clusters.len(), 1);
+    /// assert_eq!(clusters[0].cell_count, 7);
+    /// assert_eq!(clusters[0].shape.0.len(), 1);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn summarize_clusters(
+        &self,
+        cells: &[CellIndex],
+        labels: &[Option<usize>],
+    ) -> Result<Vec<ClusterSummary>, DissolutionError> {
+        if cells.len() != labels.len() {
+            return Err(DissolutionError::LengthMismatch);
+        }
+        let labeled = cells
+            .iter()
+            .zip(labels)
+            .filter_map(|(&cell, &label)| Some((cell, label?)));
+
+        if self.check_duplicate {
+            let mut seen = HashSet::with_capacity(cells.len());
+            if !labeled.clone().all(|(cell, _)| seen.insert(cell)) {
+                return Err(DissolutionError::DuplicateInput);
+            }
+        }
+
+        let mut clusters = BTreeMap::new();
+        let mut scratchpad = Scratchpad::new();
+        for (cell, label) in labeled {
+            let cluster = clusters
+                .entry(label)
+                .or_insert_with(|| ClusterAccumulator::new(cell));
+            if cell.resolution() != cluster.resolution {
+                return Err(DissolutionError::UnsupportedResolution);
+            }
+
+            cluster.graph.insert_cell_edges(cell, &mut scratchpad);
+            cluster.areas.push((cell, cell.area_km2()));
+        }
+
+        Ok(clusters
+            .into_iter()
+            .map(|(label, cluster)| {
+                let mut shape = MultiPolygon::from(cluster.graph);
+                if self.deterministic_output {
+                    normalize(&mut shape);
+                }
+                let area_km2 =
+                    cluster.areas.iter().map(|&(_, area)| area).sum();
+                let cell_count = cluster.areas.len();
+                // Only a cluster spanning the whole sphere can be that
+                // balanced.
+                let centroid = weighted_centroid(cluster.areas)
+                    .unwrap_or_else(|| LatLng::from(cluster.first));
+
+                ClusterSummary {
+                    label,
+                    shape,
+                    centroid,
+                    area_km2,
+                    cell_count,
+                }
+            })
+            .collect())
+    }
+}
+
+/// Returns the cells whose value matches the predicate.
+fn select<'a, S>(
+    values: &'a HashMap<CellIndex, f64, S>,
+    predicate: impl Fn(f64) -> bool + 'a,
+) -> impl Iterator<Item = CellIndex> + 'a {
+    values
+        .iter()
+        .filter(move |&(_, &value)| predicate(value))
+        .map(|(&cell, _)| cell)
+}
+
+/// A segment of the outline of a set of cells.
+///
+/// See [`Solvent::outline_segments`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundarySegment {
+    /// Cell of the set the segment belongs to.
+    pub cell: CellIndex,
+    /// Edge of the cell the segment comes from, pointing outside of the set.
+    pub edge: DirectedEdgeIndex,
+    /// Coordinates of the segment, in degrees.
+    pub line: LineString,
+}
+
+/// Summary of a cluster of cells.
+///
+/// See [`Solvent::summarize_clusters`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClusterSummary {
+    /// Label of the cluster.
+    pub label: usize,
+    /// Outline of the cluster.
+    pub shape: MultiPolygon,
+    /// Spherical centroid of the cells, weighted by their area.
+    pub centroid: LatLng,
+    /// Area of the cluster, in km².
+    pub area_km2: f64,
+    /// Number of cells in the cluster.
+    pub cell_count: usize,
+}
+
+/// Accumulates the summary of a cluster, one cell at a time.
+struct ClusterAccumulator {
+    first: CellIndex,
+    resolution: Resolution,
+    graph: VertexGraph,
+    /// Cells of the cluster, along with their area in km².
+    areas: Vec<(CellIndex, f64)>,
+}
+
+impl ClusterAccumulator {
+    fn new(first: CellIndex) -> Self {
+        Self {
+            first,
+            resolution: first.resolution(),
+            graph: VertexGraph::new(first.resolution()),
+            areas: Vec::new(),
+        }
     }
 }
 
@@ -58,6 +485,7 @@ impl Solvent {
 pub struct SolventBuilder {
     input_mode: InputMode,
     check_duplicate: bool,
+    deterministic_output: bool,
 }
 
 impl Default for SolventBuilder {
@@ -73,6 +501,7 @@ impl SolventBuilder {
         Self {
             input_mode: InputMode::Homogeneous,
             check_duplicate: true,
+            deterministic_output: false,
         }
     }
 
@@ -111,16 +540,79 @@ impl SolventBuilder {
         self
     }
 
+    /// Normalize the output, to get the same geometry from one build to
+    /// another.
+    ///
+    /// By default, the order of the polygons, of the holes and the starting
+    /// point of the rings depend on the iteration order of hash maps, which
+    /// isn't stable across builds (the hash seed is generated at compile
+    /// time). This is fixed by sorting the output, which has a modest cost
+    /// compared to the dissolution itself.
+    #[must_use]
+    pub const fn enable_deterministic_output(mut self) -> Self {
+        self.deterministic_output = true;
+        self
+    }
+
     /// Builds the plotter.
     #[must_use]
     pub const fn build(self) -> Solvent {
         Solvent {
             input_mode: self.input_mode,
             check_duplicate: self.check_duplicate,
+            deterministic_output: self.deterministic_output,
         }
     }
 }
 
+// Makes the geometry independent from the hash iteration order.
+//
+// Rings start at their smallest coordinate (orientation is preserved), and
+// both holes and polygons are sorted by their first coordinate.
+fn normalize(geom: &mut MultiPolygon) {
+    for polygon in &mut geom.0 {
+        polygon.exterior_mut(normalize_ring);
+        polygon.interiors_mut(|interiors| {
+            for ring in interiors.iter_mut() {
+                normalize_ring(ring);
+            }
+            interiors.sort_unstable_by(|a, b| cmp_coord(&a.0[0], &b.0[0]));
+        });
+    }
+    geom.0.sort_unstable_by(|a, b| {
+        cmp_coord(&a.exterior().0[0], &b.exterior().0[0])
+    });
+}
+
+// Rotates a closed ring so that it starts at its smallest coordinate.
+fn normalize_ring(ring: &mut LineString) {
+    let Some(last) = ring.0.len().checked_sub(1) else {
+        return;
+    };
+    // Drop the closing coordinate (duplicate of the first one).
+    let coords = &mut ring.0[..last];
+    let start = coords
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| cmp_coord(a, b))
+        .map_or(0, |(idx, _)| idx);
+    coords.rotate_left(start);
+    ring.0[last] = ring.0[0];
+}
+
+fn cmp_coord(a: &Coord, b: &Coord) -> Ordering {
+    a.x.total_cmp(&b.x).then_with(|| a.y.total_cmp(&b.y))
+}
+
+// Lexicographic order on the coordinates of two lines.
+fn cmp_coords(a: &[Coord], b: &[Coord]) -> Ordering {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| cmp_coord(a, b))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| a.len().cmp(&b.len()))
+}
+
 #[derive(Debug, Clone, Copy)]
 enum InputMode {
     /// An homogeneous set of cells.
@@ -0,0 +1,178 @@
+use crate::{
+    coord::Vec3d,
+    math::{atan2, mul_add},
+    LatLng,
+};
+use geo::{Coord, LineString, MultiPolygon};
+
+/// A polygon whose edges are great circle arcs.
+///
+/// When `is_oriented` is true, the interior of a ring is on the left of its
+/// edges: counter-clockwise exterior rings and clockwise interior rings (as
+/// specified by RFC 7946), which allows rings larger than a hemisphere.
+/// Otherwise, the interior of a ring is its smallest side, whatever its
+/// orientation.
+pub struct SphericalPolygon {
+    exterior: SphericalRing,
+    interiors: Vec<SphericalRing>,
+    is_oriented: bool,
+}
+
+impl SphericalPolygon {
+    /// Converts every polygon of a planar shape, in radians.
+    pub fn from_multipolygon(
+        geom: &MultiPolygon,
+        is_oriented: bool,
+    ) -> Vec<Self> {
+        geom.iter()
+            .map(|polygon| Self {
+                exterior: SphericalRing::new(polygon.exterior(), is_oriented),
+                interiors: polygon
+                    .interiors()
+                    .iter()
+                    .map(|ring| SphericalRing::new(ring, is_oriented))
+                    .collect(),
+                is_oriented,
+            })
+            .collect()
+    }
+
+    /// Returns true if the point is inside the polygon.
+    pub fn contains(&self, point: &Vec3d) -> bool {
+        if !self.exterior.contains(point) {
+            return false;
+        }
+        // Oriented holes contain what's outside of them.
+        if self.is_oriented {
+            self.interiors.iter().all(|ring| ring.contains(point))
+        } else {
+            !self.interiors.iter().any(|ring| ring.contains(point))
+        }
+    }
+}
+
+/// A ring whose edges are great circle arcs.
+struct SphericalRing {
+    /// Vertices, the first one being repeated at the end.
+    vertices: Vec<Vec3d>,
+    /// A reference point, opposite to the center of the vertices.
+    reference: Vec3d,
+    /// Whether the reference point is inside the ring.
+    is_reference_inside: bool,
+}
+
+impl SphericalRing {
+    fn new(ring: &LineString, is_oriented: bool) -> Self {
+        let vertices = ring.coords().copied().map(to_vec3d).collect::<Vec<_>>();
+        let center = center(&vertices);
+
+        // The side opposite to the center is the largest one: it's outside,
+        // unless the ring is oriented clockwise around its center.
+        let is_reference_inside =
+            is_oriented && winding_angle(&vertices, &center) < 0.;
+
+        Self {
+            vertices,
+            reference: Vec3d::new(-center.x, -center.y, -center.z),
+            is_reference_inside,
+        }
+    }
+
+    /// Returns true if the point is inside the ring.
+    ///
+    /// Counts the edges crossed by the arc going from the reference point to
+    /// the tested point.
+    fn contains(&self, point: &Vec3d) -> bool {
+        let crossings = self
+            .vertices
+            .windows(2)
+            .filter(|edge| crosses(&self.reference, point, &edge[0], &edge[1]))
+            .count();
+
+        (crossings % 2 == 1) != self.is_reference_inside
+    }
+}
+
+/// Returns true if the ring, in radians, goes clockwise around the center of
+/// its vertices (i.e. it's larger than a hemisphere, once oriented).
+pub fn is_clockwise(ring: &LineString) -> bool {
+    let vertices = ring.coords().copied().map(to_vec3d).collect::<Vec<_>>();
+
+    winding_angle(&vertices, &center(&vertices)) < 0.
+}
+
+/// Returns the area, in radians², of a closed ring in radians.
+///
+/// The ring is split into a fan of spherical triangles, whose signed areas
+/// are given by the Van Oosterom-Strackee formula, so concave rings are
+/// supported as long as they are smaller than a hemisphere.
+pub fn ring_area(ring: &LineString) -> f64 {
+    let vertices = ring.coords().copied().map(to_vec3d).collect::<Vec<_>>();
+    let Some((origin, rest)) = vertices.split_first() else {
+        return 0.;
+    };
+
+    rest.windows(2)
+        .map(|edge| {
+            let (a, b) = (&edge[0], &edge[1]);
+            let numerator = origin.dot(&a.cross(b));
+            let denominator = 1. + origin.dot(a) + a.dot(b) + b.dot(origin);
+            2. * atan2(numerator, denominator)
+        })
+        .sum::<f64>()
+        .abs()
+}
+
+/// Returns the normalized center of a closed ring.
+fn center(vertices: &[Vec3d]) -> Vec3d {
+    let sum =
+        vertices
+            .iter()
+            .skip(1)
+            .fold(Vec3d::new(0., 0., 0.), |acc, vertex| {
+                Vec3d::new(acc.x + vertex.x, acc.y + vertex.y, acc.z + vertex.z)
+            });
+    if sum.norm() < 1e-12 {
+        // Degenerate ring (e.g. along a great circle): any pole will do.
+        return Vec3d::new(0., 0., 1.);
+    }
+
+    sum.normalize()
+}
+
+/// Returns the sum of the signed angles, as seen from `point`, between the
+/// consecutive vertices of a closed ring: ±2π if the ring goes
+/// (counter-)clockwise around the point, 0 if it doesn't.
+fn winding_angle(vertices: &[Vec3d], point: &Vec3d) -> f64 {
+    vertices
+        .windows(2)
+        .map(|edge| {
+            let (a, b) = (&edge[0], &edge[1]);
+            // Angle between the projections of `a` and `b` on the plane
+            // tangent at `point`.
+            let sin = point.dot(&a.cross(b));
+            let cos = mul_add(-a.dot(point), b.dot(point), a.dot(b));
+            atan2(sin, cos)
+        })
+        .sum()
+}
+
+/// Converts a coordinate, in radians, into a unit vector.
+pub fn to_vec3d(coord: Coord) -> Vec3d {
+    Vec3d::from(LatLng::new_unchecked(coord.y, coord.x))
+}
+
+/// Returns true if the arcs `ab` and `cd` cross at a point interior to both.
+fn crosses(a: &Vec3d, b: &Vec3d, c: &Vec3d, d: &Vec3d) -> bool {
+    let ab = a.cross(b);
+    let acb = -ab.dot(c);
+    let bda = ab.dot(d);
+    if acb * bda <= 0. {
+        return false;
+    }
+
+    let cd = c.cross(d);
+    let cbd = -cd.dot(b);
+    let dac = cd.dot(a);
+    acb * cbd > 0. && acb * dac > 0.
+}
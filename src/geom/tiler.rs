@@ -1,6 +1,12 @@
-use super::neighbors;
-use crate::{error::InvalidGeometry, CellIndex, LatLng, Resolution, TWO_PI};
-use ahash::{HashSet, HashSetExt};
+use super::{
+    neighbors,
+    sphere::{self, SphericalPolygon},
+};
+use crate::{
+    coord::Vec3d, error::InvalidGeometry, geodesic, math::cos, CellIndex,
+    LatLng, Resolution, TWO_PI,
+};
+use ahash::{HashMap, HashMapExt, HashSet, HashSetExt};
 use either::Either;
 use float_eq::float_eq;
 use geo::{
@@ -8,22 +14,39 @@ use geo::{
         coordinate_position::{coord_pos_relative_to_ring, CoordPos},
         relate::PreparedGeometry,
     },
-    coord, BooleanOps as _, BoundingRect as _, Centroid as _, Coord,
-    CoordsIter as _, Intersects, Line, LineString, MultiPolygon, Polygon, Rect,
-    Relate as _, ToRadians as _,
+    coord, Area as _, BooleanOps as _, BoundingRect as _, Centroid as _,
+    ChamberlainDuquetteArea as _, Coord, CoordsIter as _, Intersects, Line,
+    LineString, MultiPolygon, Polygon, Rect, Relate as _, ToDegrees as _,
+    ToRadians as _,
+};
+#[cfg(feature = "rayon")]
+use rayon::{
+    iter::{IntoParallelRefIterator, ParallelIterator},
+    slice::{ParallelSlice, ParallelSliceMut},
 };
 use std::{
     cmp,
     f64::consts::{FRAC_PI_2, PI},
+    hash::BuildHasher,
 };
 
 /// A tiler that produces an H3 coverage of the given shapes.
 #[derive(Debug, Clone)]
+#[expect(clippy::struct_excessive_bools, reason = "independent options")]
 pub struct Tiler {
     resolution: Resolution,
     containment_mode: ContainmentMode,
     convert_to_rads: bool,
     transmeridian_heuristic_enabled: bool,
+    robust_predicates_enabled: bool,
+    spherical_containment_enabled: bool,
+    large_polygons_enabled: bool,
+    even_odd_rule_enabled: bool,
+    union_enabled: bool,
+    deterministic_output_enabled: bool,
+    small_hole_policy: Option<HolePolicy>,
+    /// Holes smaller than a cell, handled by the `small_hole_policy`.
+    small_holes: Vec<(LineString, Rect)>,
     geom: MultiPolygon,
 }
 
@@ -45,11 +68,38 @@ impl Tiler {
             ring_is_valid(interior)?;
         }
 
-        // Identify and fix transmeridian polygon if necessary.
-        if self.transmeridian_heuristic_enabled && is_transmeridian(&polygon) {
+        // Set aside the holes smaller than a cell.
+        if self.small_hole_policy.is_some() {
+            let cell_area = self.resolution.area_rads2();
+            let area = if self.is_spherical() {
+                sphere::ring_area
+            } else {
+                ring_area
+            };
+            let (exterior, interiors) = polygon.into_inner();
+            let (small, large) = interiors
+                .into_iter()
+                .partition::<Vec<_>, _>(|ring| area(ring) < cell_area);
+            self.small_holes.extend(small.into_iter().map(|ring| {
+                let bbox = ring.bounding_rect().expect("hole bbox");
+                (ring, bbox)
+            }));
+            polygon = Polygon::new(exterior, large);
+        }
+
+        // Identify and fix transmeridian polygon if necessary (great circle
+        // arcs are never ambiguous).
+        if self.transmeridian_heuristic_enabled
+            && !self.is_spherical()
+            && is_transmeridian(&polygon)
+        {
+            // Boolean operations already apply the even-odd rule.
             for fixed_polygon in fix_transmeridian(polygon).0 {
                 self.geom.0.push(fixed_polygon);
             }
+        } else if self.even_odd_rule_enabled && !self.large_polygons_enabled {
+            let cleaned = polygon.union(&MultiPolygon::new(Vec::new()));
+            self.geom.0.extend(cleaned.0);
         } else {
             self.geom.0.push(polygon);
         }
@@ -72,6 +122,66 @@ impl Tiler {
         Ok(())
     }
 
+    /// Adds a polygon or a multipolygon, encoded as WKT, to tile.
+    ///
+    /// The `PostGIS` flavor (EWKT, with a `SRID=...;` prefix) is accepted as
+    /// well. Coordinates are read as longitude (x) and latitude (y), Z and M
+    /// ordinates are ignored.
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidGeometry`] if the text is malformed, isn't a (multi)polygon,
+    /// has a SRID other than 4326 (or 0), or if one of the polygon is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use h3o::{geom::TilerBuilder, Resolution};
+    ///
+    /// let mut tiler = TilerBuilder::new(Resolution::Seven).build();
+    /// tiler.add_wkt("SRID=4326;POLYGON((0 0, 1 1, 1 0, 0 0))")?;
+    /// let cells = tiler.into_coverage().collect::<Vec<_>>();
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    #[cfg(feature = "wkt")]
+    pub fn add_wkt(&mut self, wkt: &str) -> Result<(), InvalidGeometry> {
+        self.add_batch(super::wkt::decode(wkt)?)
+    }
+
+    /// Adds a polygon or a multipolygon, encoded as WKB, to tile.
+    ///
+    /// Both byte orders are supported, as well as the `PostGIS` flavor (EWKB,
+    /// with an optional SRID). Coordinates are read as longitude (x) and
+    /// latitude (y), Z and M ordinates are ignored.
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidGeometry`] if the bytes are malformed, don't encode a
+    /// (multi)polygon, have a SRID other than 4326 (or 0), or if one of the
+    /// polygon is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use h3o::{geom::TilerBuilder, Resolution};
+    ///
+    /// // Little endian polygon, with one ring of 4 points.
+    /// let mut wkb = vec![1, 3, 0, 0, 0, 1, 0, 0, 0, 4, 0, 0, 0];
+    /// for (x, y) in [(0., 0.), (1., 1.), (1., 0.), (0., 0.)] {
+    ///     wkb.extend(f64::to_le_bytes(x));
+    ///     wkb.extend(f64::to_le_bytes(y));
+    /// }
+    ///
+    /// let mut tiler = TilerBuilder::new(Resolution::Seven).build();
+    /// tiler.add_wkb(&wkb)?;
+    /// let cells = tiler.into_coverage().collect::<Vec<_>>();
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    #[cfg(feature = "wkb")]
+    pub fn add_wkb(&mut self, wkb: &[u8]) -> Result<(), InvalidGeometry> {
+        self.add_batch(super::wkb::decode(wkb)?)
+    }
+
     /// Returns an upper bound to the number of cells returned by `into_coverage`.
     ///
     /// # Example
@@ -100,6 +210,15 @@ impl Tiler {
         self.geom
             .iter()
             .map(|polygon| {
+                // Inverted polygon: covers most of the globe.
+                if self.is_spherical()
+                    && self.large_polygons_enabled
+                    && sphere::is_clockwise(polygon.exterior())
+                {
+                    return usize::try_from(self.resolution.cell_count())
+                        .unwrap_or(usize::MAX);
+                }
+
                 let estimated_count = bbox_hex_estimate(
                     &polygon.bounding_rect().expect("valid polygon"),
                     self.resolution,
@@ -129,7 +248,11 @@ impl Tiler {
     /// Computes the cell coverage of the geometries.
     ///
     /// The output may contain duplicate indexes in case of overlapping input
-    /// geometries/depending on the selected containment mode.
+    /// geometries/depending on the selected containment mode (unless
+    /// [`TilerBuilder::enable_union`] is set).
+    ///
+    /// The output order is unspecified (unless
+    /// [`TilerBuilder::enable_deterministic_output`] is set).
     ///
     /// # Example
     ///
@@ -151,6 +274,162 @@ impl Tiler {
     /// # Ok::<(), h3o::error::InvalidGeometry>(())
     /// ```
     pub fn into_coverage(self) -> impl Iterator<Item = CellIndex> {
+        if self.deterministic_output_enabled {
+            let mut cells = self.unordered_coverage().collect::<Vec<_>>();
+            cells.sort_unstable();
+            Either::Left(cells.into_iter())
+        } else {
+            Either::Right(self.unordered_coverage())
+        }
+    }
+
+    /// Computes the cell coverage of the geometries, in parallel.
+    ///
+    /// The outlines tracing, the containment checks and the inward
+    /// propagation (one layer at a time) are spread over the `rayon` thread
+    /// pool. The resulting cells are the same as the ones returned by
+    /// [`Self::into_coverage`], duplicates included.
+    ///
+    /// The output order is unspecified (unless
+    /// [`TilerBuilder::enable_deterministic_output`] is set).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use geo::{LineString, Polygon};
+    /// use h3o::{geom::TilerBuilder, Resolution};
+    ///
+    /// let polygon = Polygon::new(
+    ///     LineString::from(vec![(0., 0.), (1., 1.), (1., 0.), (0., 0.)]),
+    ///     vec![],
+    /// );
+    /// let mut tiler = TilerBuilder::new(Resolution::Ten).build();
+    /// tiler.add(polygon)?;
+    ///
+    /// let cells = tiler.into_coverage_par();
+    ///
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[must_use]
+    pub fn into_coverage_par(mut self) -> Vec<CellIndex> {
+        if self.union_enabled && !self.large_polygons_enabled {
+            self.geom = union_all(std::mem::take(&mut self.geom.0));
+        }
+        let excluded = self.small_holes_exclusion();
+
+        // First, compute the outline (one ring per task), buffered with the
+        // immediate neighbors.
+        let is_spherical = self.is_spherical();
+        let rings =
+            self.interiors().chain(self.exteriors()).collect::<Vec<_>>();
+        let traced = rings
+            .par_iter()
+            .flat_map_iter(|ring| {
+                get_edge_cells(ring, self.resolution, is_spherical)
+            })
+            .collect::<Vec<_>>();
+        let mut seen = par_neighbors(&traced);
+        let mut outlines = self.par_matches(&seen);
+
+        if outlines.is_empty()
+            && self.containment_mode == ContainmentMode::Covers
+        {
+            let centroid = self.geom.centroid().expect("centroid");
+            return vec![LatLng::from_radians(centroid.y(), centroid.x())
+                .expect("valid coordinate")
+                .to_cell(self.resolution)];
+        }
+
+        // Next, compute the outermost layer of inner cells to seed the
+        // propagation step.
+        let outline_cells =
+            outlines.iter().map(|&(cell, _)| cell).collect::<Vec<_>>();
+        let mut layer = par_neighbors(&outline_cells);
+        layer.retain(|cell| seen.binary_search(cell).is_err());
+        let mut candidates = self.par_matches(&layer);
+        seen.extend(layer);
+        seen.par_sort_unstable();
+
+        if self.containment_mode == ContainmentMode::ContainsBoundary {
+            outlines.retain(|&(_, is_fully_contained)| is_fully_contained);
+            candidates.retain(|&(_, is_fully_contained)| is_fully_contained);
+        }
+
+        // Last step: inward propagation from the outermost layers, one layer
+        // at a time (only the previous layer is needed to avoid going back).
+        let mut cells = outlines
+            .into_iter()
+            .chain(candidates.iter().copied())
+            .map(|(cell, _)| cell)
+            .collect::<Vec<_>>();
+        let mut candidates = candidates
+            .into_iter()
+            .map(|(cell, _)| cell)
+            .collect::<Vec<_>>();
+        while !candidates.is_empty() {
+            let next_seen = par_neighbors(&candidates);
+            candidates = next_seen
+                .par_iter()
+                .copied()
+                .filter(|cell| seen.binary_search(cell).is_err())
+                .collect();
+            cells.extend_from_slice(&candidates);
+            seen = next_seen;
+        }
+
+        cells.retain(|cell| !excluded.contains(cell));
+        if self.deterministic_output_enabled {
+            cells.par_sort_unstable();
+        }
+        cells
+    }
+
+    /// Computes the compacted cell coverage of the geometries.
+    ///
+    /// The result is the same as compacting (cf. [`CellIndex::compact`]) the
+    /// output of [`Self::into_coverage`], except that duplicates are removed.
+    /// The full coverage is still computed first: the compaction then groups
+    /// the cells by parent level by level, without sorting them.
+    ///
+    /// The output order is unspecified (unless
+    /// [`TilerBuilder::enable_deterministic_output`] is set).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use geo::{LineString, Polygon};
+    /// use h3o::{geom::TilerBuilder, Resolution};
+    ///
+    /// let polygon = Polygon::new(
+    ///     LineString::from(vec![(0., 0.), (1., 1.), (1., 0.), (0., 0.)]),
+    ///     vec![],
+    /// );
+    /// let mut tiler = TilerBuilder::new(Resolution::Ten).build();
+    /// tiler.add(polygon)?;
+    ///
+    /// let cells = tiler.into_compact_coverage().collect::<Vec<_>>();
+    /// assert!(cells.iter().any(|cell| cell.resolution() < Resolution::Ten));
+    ///
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    pub fn into_compact_coverage(self) -> impl Iterator<Item = CellIndex> {
+        let resolution = self.resolution;
+        let deterministic_output = self.deterministic_output_enabled;
+
+        let mut cells = compact_unordered(
+            self.unordered_coverage().collect::<HashSet<_>>(),
+            resolution,
+        );
+        if deterministic_output {
+            cells.sort_unstable();
+        }
+
+        cells.into_iter()
+    }
+
+    // Computes the cell coverage, in an order depending on the hash iteration.
+    fn unordered_coverage(mut self) -> impl Iterator<Item = CellIndex> {
         // This implementation traces the outlines of the polygon's rings, fill one
         // layer of internal cells and then propagate inwards until the whole area
         // is covered.
@@ -159,8 +438,18 @@ impl Tiler {
         // Point-in-Polygon checks, inward propagation doesn't (since we're bounded
         // by the outlines) which make this approach relatively efficient.
 
-        let predicate =
-            ContainmentPredicate::new(&self.geom, self.containment_mode);
+        if self.union_enabled && !self.large_polygons_enabled {
+            self.geom = union_all(std::mem::take(&mut self.geom.0));
+        }
+
+        let predicate = ContainmentPredicate::new(
+            &self.geom,
+            self.containment_mode,
+            self.robust_predicates_enabled,
+            self.is_spherical(),
+            self.large_polygons_enabled,
+        );
+        let excluded = self.small_holes_exclusion();
         // Set used for dedup.
         let mut seen = HashSet::new();
         // Scratchpad memory to store a cell and its immediate neighbors.
@@ -209,8 +498,10 @@ impl Tiler {
             }
 
             for &(cell, _) in &candidates {
+                // Planar geometry doesn't match the great circle edges.
                 debug_assert!(
-                    self.geom.relate(&cell_boundary(cell)).is_covers(),
+                    self.is_spherical()
+                        || self.geom.relate(&cell_boundary(cell)).is_covers(),
                     "cell index {cell} in polygon"
                 );
 
@@ -240,10 +531,312 @@ impl Tiler {
             outlines
                 .into_iter()
                 .chain(inward_propagation.flatten())
-                .map(|(cell, _)| cell),
+                .map(|(cell, _)| cell)
+                .filter(move |cell| !excluded.contains(cell)),
+        )
+    }
+
+    /// Computes the cell coverage of the geometries, along with a report on
+    /// its completeness.
+    ///
+    /// This is meant for QA pipelines that need to prove the coverage rather
+    /// than assume it: with [`ContainmentMode::Covers`], the residual is
+    /// expected to be empty.
+    ///
+    /// Note that the report is computed using planar geometries, and is
+    /// expensive compared to the tiling itself.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use geo::{LineString, Polygon};
+    /// use h3o::{geom::{ContainmentMode, TilerBuilder}, Resolution};
+    ///
+    /// let polygon = Polygon::new(
+    ///     LineString::from(vec![(0., 0.), (1., 1.), (1., 0.), (0., 0.)]),
+    ///     vec![],
+    /// );
+    /// let mut tiler = TilerBuilder::new(Resolution::Seven)
+    ///     .containment_mode(ContainmentMode::Covers)
+    ///     .build();
+    /// tiler.add(polygon)?;
+    ///
+    /// let (cells, report) = tiler.into_coverage_with_report();
+    /// assert!(report.residual.0.is_empty());
+    /// assert_eq!(report.residual_area_km2, 0.);
+    ///
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    #[must_use]
+    pub fn into_coverage_with_report(self) -> (Vec<CellIndex>, CoverageReport) {
+        // Every cell intersecting the geometries, without any exclusion.
+        let mut candidates = self.clone();
+        candidates.containment_mode = ContainmentMode::Covers;
+        candidates.small_holes.clear();
+
+        let geom = self.geom.clone();
+        let cells = self.into_coverage().collect::<Vec<_>>();
+
+        let prepared_geom = PreparedGeometry::from(&geom);
+        let boundary_only = cells
+            .iter()
+            .copied()
+            .filter(|&cell| {
+                prepared_geom.relate(&cell_boundary(cell)).is_touches()
+            })
+            .collect();
+
+        // The residual is computed cell by cell, on the intersecting cells
+        // left out of the coverage: subtracting the union of the selected
+        // cells from the geometries would leave slivers along the shared
+        // edges.
+        let covered = cells.iter().copied().collect::<HashSet<_>>();
+        let mut uncovered = candidates
+            .into_coverage()
+            .filter(|cell| !covered.contains(cell))
+            .collect::<Vec<_>>();
+        uncovered.sort_unstable();
+        uncovered.dedup();
+        let mut residual = MultiPolygon::new(
+            uncovered
+                .into_iter()
+                .flat_map(|cell| geom.intersection(&cell_boundary(cell)).0)
+                .collect(),
+        );
+        residual.to_degrees_in_place();
+        let residual_area_km2 =
+            residual.chamberlain_duquette_unsigned_area() / 1_000_000.;
+
+        (
+            cells,
+            CoverageReport {
+                residual,
+                residual_area_km2,
+                boundary_only,
+            },
         )
     }
 
+    /// Computes the cell coverage of the geometries, along with the
+    /// intersection of each cell with the geometries.
+    ///
+    /// The geometries are prepared only once, and cells that are fully
+    /// contained are returned as-is without any clipping: this is way faster
+    /// than clipping the geometries for every cell downstream.
+    ///
+    /// Intersections are in degrees, and may be empty for cells that only
+    /// touch the geometries. Overlapping geometries are merged beforehand,
+    /// and the holes smaller than a cell are carved out of the intersections
+    /// whatever the [`HolePolicy`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use geo::{LineString, Polygon};
+    /// use h3o::{geom::TilerBuilder, Resolution};
+    ///
+    /// let polygon = Polygon::new(
+    ///     LineString::from(vec![(0., 0.), (1., 1.), (1., 0.), (0., 0.)]),
+    ///     vec![],
+    /// );
+    /// let mut tiler = TilerBuilder::new(Resolution::Seven).build();
+    /// tiler.add(polygon)?;
+    ///
+    /// for (cell, clipped) in tiler.into_clipped_coverage() {
+    ///     println!("{cell}: {clipped:?}");
+    /// }
+    ///
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    pub fn into_clipped_coverage(
+        self,
+    ) -> impl Iterator<Item = (CellIndex, MultiPolygon)> {
+        // The clean-up doesn't preserve the orientation of the rings.
+        let mut geom = if self.large_polygons_enabled {
+            self.geom.clone()
+        } else {
+            union_all(self.geom.0.clone())
+        };
+        if !self.small_holes.is_empty() {
+            let holes = self
+                .small_holes
+                .iter()
+                .map(|(ring, _)| Polygon::new(ring.clone(), Vec::new()));
+            geom = geom.difference(&union_all(holes.collect()));
+        }
+        let prepared_geom = PreparedGeometry::from(geom.clone());
+        let polygons = geom
+            .0
+            .iter()
+            .map(|polygon| {
+                (
+                    polygon.bounding_rect().expect("valid polygon"),
+                    polygon.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        self.into_coverage().map(move |cell| {
+            let boundary = cell_boundary(cell);
+            let mut clipped = if prepared_geom.relate(&boundary).is_covers() {
+                boundary
+            } else {
+                // Only clip the polygons that may intersect the cell.
+                let bbox = boundary.bounding_rect().expect("cell bbox");
+                let candidates = polygons
+                    .iter()
+                    .filter(|(rect, _)| rect.intersects(&bbox))
+                    .map(|(_, polygon)| polygon.clone())
+                    .collect::<MultiPolygon>();
+                candidates.intersection(&boundary)
+            };
+            clipped.to_degrees_in_place();
+            (cell, clipped)
+        })
+    }
+
+    /// Replaces the geometries to tile, and updates their coverage
+    /// accordingly.
+    ///
+    /// `coverage` must be the coverage of the current geometries (e.g. as
+    /// returned by [`Self::into_coverage`] on a clone of the tiler). Only the
+    /// cells around the area that differs between the current and the new
+    /// geometries are re-tiled, which makes local edits (e.g. moving a vertex)
+    /// of large geometries way cheaper than a full re-tiling.
+    ///
+    /// Note that great circle edges (i.e. large polygons or spherical
+    /// containment) don't support incremental updates and trigger a full
+    /// re-tiling.
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidGeometry`] if one of the polygon is invalid, in which case
+    /// both the tiler and the coverage are left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use geo::{LineString, Polygon};
+    /// use h3o::{geom::TilerBuilder, Resolution};
+    /// use std::collections::HashSet;
+    ///
+    /// let polygon = Polygon::new(
+    ///     LineString::from(vec![(0., 0.), (1., 1.), (1., 0.), (0., 0.)]),
+    ///     vec![],
+    /// );
+    /// let mut tiler = TilerBuilder::new(Resolution::Seven).build();
+    /// tiler.add(polygon)?;
+    /// let mut coverage = tiler.clone().into_coverage().collect::<HashSet<_>>();
+    ///
+    /// // Move a vertex.
+    /// let polygon = Polygon::new(
+    ///     LineString::from(vec![(0., 0.), (1., 1.2), (1., 0.), (0., 0.)]),
+    ///     vec![],
+    /// );
+    /// tiler.update_coverage([polygon], &mut coverage)?;
+    ///
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    pub fn update_coverage<S: BuildHasher>(
+        &mut self,
+        geoms: impl IntoIterator<Item = Polygon>,
+        coverage: &mut std::collections::HashSet<CellIndex, S>,
+    ) -> Result<(), InvalidGeometry> {
+        let previous_geom =
+            std::mem::replace(&mut self.geom, MultiPolygon::new(Vec::new()));
+        let previous_holes = std::mem::take(&mut self.small_holes);
+        if let Err(err) = self.add_batch(geoms) {
+            self.geom = previous_geom;
+            self.small_holes = previous_holes;
+            return Err(err);
+        }
+
+        if self.large_polygons_enabled || self.is_spherical() {
+            coverage.clear();
+            coverage.extend(self.clone().into_coverage());
+            return Ok(());
+        }
+
+        let affected =
+            match self.affected_cells(&previous_geom, &previous_holes) {
+                Ok(Some(affected)) => affected,
+                Ok(None) => return Ok(()),
+                Err(err) => {
+                    self.geom = previous_geom;
+                    self.small_holes = previous_holes;
+                    return Err(err);
+                }
+            };
+
+        let geom = if self.union_enabled {
+            union_all(self.geom.0.clone())
+        } else {
+            self.geom.clone()
+        };
+        let predicate = ContainmentPredicate::new(
+            &geom,
+            self.containment_mode,
+            self.robust_predicates_enabled,
+            false,
+            false,
+        );
+        let excluded = self.small_holes_exclusion();
+        for cell in affected {
+            let result = predicate.apply(cell);
+            let is_covered =
+                if self.containment_mode == ContainmentMode::ContainsBoundary {
+                    result.is_fully_contained
+                } else {
+                    result.is_a_match
+                };
+            if is_covered && !excluded.contains(&cell) {
+                coverage.insert(cell);
+            } else {
+                coverage.remove(&cell);
+            }
+        }
+
+        Ok(())
+    }
+
+    // Return the cells whose coverage may have changed since the given
+    // geometries, if any.
+    fn affected_cells(
+        &self,
+        previous_geom: &MultiPolygon,
+        previous_holes: &[(LineString, Rect)],
+    ) -> Result<Option<HashSet<CellIndex>>, InvalidGeometry> {
+        // Area where the coverage may differ, including the small holes that
+        // are tiled separately.
+        let mut changed = previous_geom.xor(&self.geom);
+        if previous_holes != self.small_holes {
+            let holes = previous_holes
+                .iter()
+                .chain(&self.small_holes)
+                .map(|(ring, _)| Polygon::new(ring.clone(), Vec::new()));
+            changed = changed.union(&union_all(holes.collect()));
+        }
+        if changed.0.is_empty() {
+            return Ok(None);
+        }
+
+        // Cells overlapping the changed area, with a margin to account for
+        // the approximations of the outline tracing.
+        let mut region = TilerBuilder::new(self.resolution)
+            .disable_radians_conversion()
+            .disable_transmeridian_heuristic()
+            .containment_mode(ContainmentMode::Covers)
+            .build();
+        region.add_batch(changed)?;
+
+        Ok(Some(
+            region
+                .into_coverage()
+                .flat_map(|cell| cell.grid_disk_safe(1))
+                .collect(),
+        ))
+    }
+
     // Return the cell indexes that traces the ring outline.
     fn hex_outline(
         &self,
@@ -259,10 +852,11 @@ impl Tiler {
             reason = "needed because mutable borrow"
         )]
         // Compute the set of cells making the outlines of the polygon.
+        let is_spherical = self.is_spherical();
         let outlines = self
             .interiors()
             .chain(self.exteriors())
-            .flat_map(|ring| get_edge_cells(ring, resolution))
+            .flat_map(|ring| get_edge_cells(ring, resolution, is_spherical))
             .filter(|cell| already_seen.insert(*cell))
             .collect::<Vec<_>>();
         // Reset the `already_seen` set: content can't be trusted because we
@@ -294,6 +888,77 @@ impl Tiler {
         })
     }
 
+    // Returns the cells matching the containment predicate, along with their
+    // full containment status.
+    #[cfg(feature = "rayon")]
+    fn par_matches(&self, cells: &[CellIndex]) -> Vec<(CellIndex, bool)> {
+        // Prepared geometries aren't thread-safe: use one predicate per task.
+        let chunk_len = cells.len().div_ceil(rayon::current_num_threads());
+        cells
+            .par_chunks(chunk_len.max(1))
+            .flat_map_iter(|chunk| {
+                let predicate = ContainmentPredicate::new(
+                    &self.geom,
+                    self.containment_mode,
+                    self.robust_predicates_enabled,
+                    self.is_spherical(),
+                    self.large_polygons_enabled,
+                );
+                chunk.iter().filter_map(move |&cell| {
+                    let result = predicate.apply(cell);
+                    result
+                        .is_a_match
+                        .then_some((cell, result.is_fully_contained))
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the cells excluded by the holes smaller than a cell.
+    fn small_holes_exclusion(&self) -> HashSet<CellIndex> {
+        let mut excluded = HashSet::new();
+        let Some(policy) = self.small_hole_policy else {
+            return excluded;
+        };
+        let mut scratchpad = [0; 7];
+
+        for (ring, bbox) in &self.small_holes {
+            for cell in get_edge_cells(ring, self.resolution, false) {
+                let count = neighbors(cell, &mut scratchpad);
+                for &candidate in &scratchpad[0..count] {
+                    // SAFETY: candidate comes from `ring_disk_*`.
+                    let index = CellIndex::new_unchecked(candidate);
+                    let is_excluded = match policy {
+                        HolePolicy::Ignore => false,
+                        HolePolicy::ExcludeCentroid => {
+                            let ll = LatLng::from(index);
+                            let coord = coord! {
+                                x: ll.lng_radians(),
+                                y: ll.lat_radians(),
+                            };
+                            ring_contains_centroid(ring, bbox, coord)
+                        }
+                        HolePolicy::ExcludeIntersecting => {
+                            let hole = Polygon::new(ring.clone(), Vec::new());
+                            cell_boundary(index).intersects(&hole)
+                        }
+                    };
+                    if is_excluded {
+                        excluded.insert(index);
+                    }
+                }
+            }
+        }
+
+        excluded
+    }
+
+    /// Returns true if the containment tests are done on the sphere.
+    fn is_spherical(&self) -> bool {
+        self.spherical_containment_enabled
+            && self.containment_mode == ContainmentMode::ContainsCentroid
+    }
+
     /// Returns the exterior rings of each polygon.
     fn exteriors(&self) -> impl Iterator<Item = &LineString> {
         self.geom.0.iter().map(Polygon::exterior)
@@ -311,11 +976,19 @@ impl Tiler {
 // -----------------------------------------------------------------------------
 
 /// A builder to configure a tiler.
+#[expect(clippy::struct_excessive_bools, reason = "independent options")]
 pub struct TilerBuilder {
     resolution: Resolution,
     containment_mode: ContainmentMode,
     convert_to_rads: bool,
     transmeridian_heuristic_enabled: bool,
+    robust_predicates_enabled: bool,
+    spherical_containment_enabled: bool,
+    large_polygons_enabled: bool,
+    even_odd_rule_enabled: bool,
+    union_enabled: bool,
+    deterministic_output_enabled: bool,
+    small_hole_policy: Option<HolePolicy>,
 }
 
 impl TilerBuilder {
@@ -327,6 +1000,13 @@ impl TilerBuilder {
             containment_mode: ContainmentMode::ContainsCentroid,
             convert_to_rads: true,
             transmeridian_heuristic_enabled: true,
+            robust_predicates_enabled: false,
+            spherical_containment_enabled: false,
+            large_polygons_enabled: false,
+            even_odd_rule_enabled: false,
+            union_enabled: false,
+            deterministic_output_enabled: false,
+            small_hole_policy: None,
         }
     }
 
@@ -356,6 +1036,116 @@ impl TilerBuilder {
         self
     }
 
+    /// Enable the robust point-in-polygon checks of the
+    /// [`ContainmentMode::ContainsCentroid`] mode.
+    ///
+    /// Cell centers are tested using exact orientation predicates, and the
+    /// ones lying exactly on an edge are assigned deterministically to a
+    /// single side (left and bottom edges are inside, right and top edges are
+    /// outside). This guarantees that adjacent polygons never share nor drop
+    /// a cell, even when their common edge goes through a cell center.
+    #[must_use]
+    pub const fn enable_robust_predicates(mut self) -> Self {
+        self.robust_predicates_enabled = true;
+        self
+    }
+
+    /// Enable the spherical point-in-polygon checks of the
+    /// [`ContainmentMode::ContainsCentroid`] mode (other modes are not
+    /// affected).
+    ///
+    /// By default, the edges of the polygons are straight lines in the
+    /// longitude/latitude plane. With this option, they are great circle arcs
+    /// instead, which matters for large polygons (long edges can deviate by
+    /// hundreds of kilometers). Edges always follow the shortest arc, so the
+    /// transmeridian heuristic is not needed.
+    ///
+    /// Rings must be smaller than a hemisphere.
+    #[must_use]
+    pub const fn enable_spherical_containment(mut self) -> Self {
+        self.spherical_containment_enabled = true;
+        self
+    }
+
+    /// Enable the support of polygons larger than a hemisphere.
+    ///
+    /// The interior of the polygons is defined by the orientation of their
+    /// rings, as specified by RFC 7946: exterior rings are counter-clockwise
+    /// and interior rings are clockwise. A clockwise exterior ring thus covers
+    /// everything *outside* of it (e.g. "the whole world except this
+    /// exclusion zone").
+    ///
+    /// Implies [`Self::enable_spherical_containment`], and only works with
+    /// the [`ContainmentMode::ContainsCentroid`] mode.
+    #[must_use]
+    pub const fn enable_large_polygons(mut self) -> Self {
+        self.spherical_containment_enabled = true;
+        self.large_polygons_enabled = true;
+        self
+    }
+
+    /// Interpret the rings with the even-odd fill rule.
+    ///
+    /// Polygons are cleaned up on insertion, which makes self-intersecting
+    /// rings (e.g. bowties or pentagrams) well-defined: an area is inside if
+    /// it's enclosed an odd number of times. Without this option, coverage of
+    /// such polygons depends on the containment mode.
+    ///
+    /// Every [`ContainmentMode`] is affected, but only for the areas enclosed
+    /// several times (e.g. the center of a pentagram): the lobes of a bowtie
+    /// are enclosed once, and are thus tiled the same way with or without
+    /// this option.
+    ///
+    /// Ignored when [`Self::enable_large_polygons`] is set, because the
+    /// clean-up doesn't preserve the orientation of the rings.
+    #[must_use]
+    pub const fn enable_even_odd_rule(mut self) -> Self {
+        self.even_odd_rule_enabled = true;
+        self
+    }
+
+    /// Merge the input polygons before computing the coverage.
+    ///
+    /// Overlapping or adjacent polygons then produce each cell only once,
+    /// which avoids having to deduplicate the output (except for the cells
+    /// crossing the antimeridian, as transmeridian polygons are split).
+    ///
+    /// Ignored when [`Self::enable_large_polygons`] is set, because the
+    /// union doesn't preserve the orientation of the rings.
+    #[must_use]
+    pub const fn enable_union(mut self) -> Self {
+        self.union_enabled = true;
+        self
+    }
+
+    /// Sort the coverage, to get the same output from one build to another.
+    ///
+    /// By default, the output order depends on the iteration order of hash
+    /// sets, which isn't stable across builds (the hash seed is generated at
+    /// compile time). Sorting requires collecting the whole coverage in memory
+    /// first (instead of producing it lazily), plus an `O(n log n)` step.
+    #[must_use]
+    pub const fn enable_deterministic_output(mut self) -> Self {
+        self.deterministic_output_enabled = true;
+        self
+    }
+
+    /// Set the policy applied to the holes smaller than a cell.
+    ///
+    /// By default, such holes are handled like the others, and the result
+    /// depends on the containment mode (e.g. they are ignored by
+    /// `IntersectsBoundary` but exclude the cell they're in with
+    /// `ContainsBoundary`). With an explicit policy, the behavior is the same
+    /// whatever the containment mode.
+    ///
+    /// A hole is smaller than a cell if its area is below the average cell
+    /// area at the tiler resolution.
+    #[must_use]
+    pub const fn small_hole_policy(mut self, policy: HolePolicy) -> Self {
+        self.small_hole_policy = Some(policy);
+        self
+    }
+
     /// Builds the plotter.
     #[must_use]
     pub fn build(self) -> Tiler {
@@ -365,6 +1155,14 @@ impl TilerBuilder {
             convert_to_rads: self.convert_to_rads,
             transmeridian_heuristic_enabled: self
                 .transmeridian_heuristic_enabled,
+            robust_predicates_enabled: self.robust_predicates_enabled,
+            spherical_containment_enabled: self.spherical_containment_enabled,
+            large_polygons_enabled: self.large_polygons_enabled,
+            even_odd_rule_enabled: self.even_odd_rule_enabled,
+            union_enabled: self.union_enabled,
+            deterministic_output_enabled: self.deterministic_output_enabled,
+            small_hole_policy: self.small_hole_policy,
+            small_holes: Vec::new(),
             geom: MultiPolygon::new(Vec::new()),
         }
     }
@@ -415,6 +1213,33 @@ pub enum ContainmentMode {
     Covers,
 }
 
+/// Completeness report of a coverage.
+///
+/// See [`Tiler::into_coverage_with_report`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoverageReport {
+    /// Parts of the geometries left uncovered, in degrees.
+    pub residual: MultiPolygon,
+    /// Area of the uncovered parts, in km².
+    pub residual_area_km2: f64,
+    /// Cells that only touch the geometries (i.e. no area in common).
+    pub boundary_only: Vec<CellIndex>,
+}
+
+/// Policy applied to the holes smaller than a cell.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HolePolicy {
+    /// Small holes are ignored: cells are selected as if they weren't there.
+    Ignore,
+
+    /// Cells whose centroid is inside a small hole are excluded.
+    ExcludeCentroid,
+
+    /// Cells intersecting a small hole, even partially, are excluded.
+    ExcludeIntersecting,
+}
+
 /// The result of a predicate application.
 struct PredicateResult {
     /// Whether the cell is a match for this predicate.
@@ -427,7 +1252,8 @@ struct PredicateResult {
 }
 
 enum ContainmentPredicate<'geom> {
-    ContainsCentroid(&'geom MultiPolygon, MultiBBoxes),
+    ContainsCentroid(&'geom MultiPolygon, MultiBBoxes, RingContains),
+    ContainsCentroidSpherical(Vec<SphericalPolygon>),
     IntersectsBoundary(PreparedGeometry<'geom>),
 }
 
@@ -436,8 +1262,17 @@ impl<'geom> ContainmentPredicate<'geom> {
     fn new(
         geom: &'geom MultiPolygon,
         containment_mode: ContainmentMode,
+        is_robust: bool,
+        is_spherical: bool,
+        is_oriented: bool,
     ) -> Self {
         match containment_mode {
+            // Great circle edges, on the unit sphere.
+            ContainmentMode::ContainsCentroid if is_spherical => {
+                Self::ContainsCentroidSpherical(
+                    SphericalPolygon::from_multipolygon(geom, is_oriented),
+                )
+            }
             // For this one we can use our good ol' PIP-based approach.
             ContainmentMode::ContainsCentroid => {
                 // Pre-compute the bounding boxes for each ring.
@@ -459,7 +1294,13 @@ impl<'geom> ContainmentPredicate<'geom> {
                         .collect(),
                 );
 
-                Self::ContainsCentroid(geom, bboxes)
+                let ring_contains: RingContains = if is_robust {
+                    ring_contains_centroid_robust
+                } else {
+                    ring_contains_centroid
+                };
+
+                Self::ContainsCentroid(geom, bboxes, ring_contains)
             }
             // For the others, using a related-based approach boosted by a
             // PreparedGeometry is the way to go.
@@ -475,7 +1316,7 @@ impl<'geom> ContainmentPredicate<'geom> {
     /// Applies the predicate with the given cell.
     fn apply(&self, cell: CellIndex) -> PredicateResult {
         match self {
-            Self::ContainsCentroid(geom, bboxes) => {
+            Self::ContainsCentroid(geom, bboxes, ring_contains_centroid) => {
                 let ll = LatLng::from(cell);
                 let coord = coord! { x: ll.lng_radians(), y: ll.lat_radians() };
 
@@ -501,6 +1342,16 @@ impl<'geom> ContainmentPredicate<'geom> {
                     is_fully_contained: true,
                 }
             }
+            Self::ContainsCentroidSpherical(polygons) => {
+                let point = Vec3d::from(LatLng::from(cell));
+
+                PredicateResult {
+                    is_a_match: polygons
+                        .iter()
+                        .any(|polygon| polygon.contains(&point)),
+                    is_fully_contained: true,
+                }
+            }
             Self::IntersectsBoundary(geom) => {
                 let boundary = cell_boundary(cell);
                 let relation = geom.relate(&boundary);
@@ -516,6 +1367,42 @@ impl<'geom> ContainmentPredicate<'geom> {
 
 // -----------------------------------------------------------------------------
 
+// Compact a set of unique cells, all at the given resolution, by replacing the
+// complete sets of siblings by their parent, one level at a time.
+fn compact_unordered(
+    mut layer: HashSet<CellIndex>,
+    mut resolution: Resolution,
+) -> Vec<CellIndex> {
+    let mut compacted = Vec::new();
+
+    while let Some(parent_resolution) = resolution.pred() {
+        let mut counts = HashMap::<CellIndex, u64>::new();
+        for cell in &layer {
+            let parent = cell.parent(parent_resolution).expect("coarser");
+            *counts.entry(parent).or_default() += 1;
+        }
+
+        let mut parents = HashSet::new();
+        for cell in layer.drain() {
+            let parent = cell.parent(parent_resolution).expect("coarser");
+            if counts[&parent] == parent.children_count(resolution) {
+                parents.insert(parent);
+            } else {
+                compacted.push(cell);
+            }
+        }
+
+        if parents.is_empty() {
+            return compacted;
+        }
+        layer = parents;
+        resolution = parent_resolution;
+    }
+    compacted.extend(layer);
+
+    compacted
+}
+
 // Compute the outermost layer of inner cells.
 //
 // Those are the last ones that requires a PiP check, due to their
@@ -547,20 +1434,53 @@ fn outermost_inner_cells(
     })
 }
 
+// Return the sorted and deduplicated cells and immediate neighbors.
+#[cfg(feature = "rayon")]
+fn par_neighbors(cells: &[CellIndex]) -> Vec<CellIndex> {
+    let mut result = cells
+        .par_iter()
+        .flat_map_iter(|&cell| {
+            let mut scratchpad = [0; 7];
+            let count = neighbors(cell, &mut scratchpad);
+            // SAFETY: candidate comes from `ring_disk_*`.
+            scratchpad
+                .into_iter()
+                .take(count)
+                .map(CellIndex::new_unchecked)
+        })
+        .collect::<Vec<_>>();
+    result.par_sort_unstable();
+    result.dedup();
+    result
+}
+
 // Return the cell indexes that traces the ring outline (rough approximation)
 fn get_edge_cells(
     ring: &LineString,
     resolution: Resolution,
+    is_spherical: bool,
 ) -> impl Iterator<Item = CellIndex> + '_ {
     ring.lines().flat_map(move |line @ Line { start, end }| {
         let count = line_hex_estimate(&line, resolution);
 
+        // Follow the great circle arc.
+        if is_spherical {
+            let start = LatLng::from_radians(start.y, start.x)
+                .expect("finite line-start coordinate");
+            let end = LatLng::from_radians(end.y, end.x)
+                .expect("finite line-end coordinate");
+            return Either::Left(
+                geodesic::arc_points(start, end, count)
+                    .map(move |ll| ll.to_cell(resolution)),
+            );
+        }
+
         assert!(count <= 1 << f64::MANTISSA_DIGITS);
         #[expect(
             clippy::cast_precision_loss,
             reason = "cannot happen thanks to assert above"
         )]
-        (0..count).map(move |i| {
+        Either::Right((0..count).map(move |i| {
             let i = i as f64;
             let count = count as f64;
 
@@ -570,7 +1490,7 @@ fn get_edge_cells(
             LatLng::from_radians(lat, lng)
                 .expect("finite line coordinate")
                 .to_cell(resolution)
-        })
+        }))
     })
 }
 
@@ -745,6 +1665,37 @@ fn fix_clipping_boundary(geom: &mut MultiPolygon, is_west: bool) {
     }
 }
 
+// Approximate spherical area of a ring, in radians².
+fn ring_area(ring: &LineString) -> f64 {
+    let (min, max) = {
+        let bbox = ring.bounding_rect().expect("ring bbox");
+        (bbox.min().y, bbox.max().y)
+    };
+    // Longitudes shrink with the latitude.
+    Polygon::new(ring.clone(), Vec::new()).unsigned_area()
+        * cos(f64::midpoint(min, max))
+}
+
+// Merge polygons together, pairwise to keep the operands balanced.
+fn union_all(polygons: Vec<Polygon>) -> MultiPolygon {
+    let mut parts = polygons
+        .into_iter()
+        .map(|polygon| MultiPolygon::new(vec![polygon]))
+        .collect::<Vec<_>>();
+
+    while parts.len() > 1 {
+        parts = parts
+            .chunks(2)
+            .map(|pair| match pair {
+                [lhs, rhs] => lhs.union(rhs),
+                _ => pair[0].clone(),
+            })
+            .collect();
+    }
+
+    parts.pop().unwrap_or_else(|| MultiPolygon::new(Vec::new()))
+}
+
 // Check that a polygon ring is valid.
 pub fn ring_is_valid(ring: &LineString) -> Result<(), InvalidGeometry> {
     // Closed ring have at least 4 coordinate (e.g. triangle).
@@ -822,6 +1773,9 @@ struct BBoxes {
     interiors: Vec<Rect>,
 }
 
+/// Point-in-Polygon check for a ring.
+type RingContains = fn(&LineString, &Rect, Coord) -> bool;
+
 // Simple Point-in-Polygon check for a ring.
 fn ring_contains_centroid(
     ring: &LineString,
@@ -852,6 +1806,52 @@ fn ring_contains_centroid(
     }
 }
 
+// Point-in-Polygon check for a ring, using exact predicates.
+//
+// Uses the crossing rules of the winding number algorithm without the
+// on-boundary short-circuit, which assigns the points lying on an edge to
+// exactly one side: the ones on left/bottom edges are inside, the ones on
+// right/top edges are outside.
+fn ring_contains_centroid_robust(
+    ring: &LineString,
+    bbox: &Rect,
+    coord: Coord,
+) -> bool {
+    if !bbox.intersects(&coord) {
+        return false;
+    }
+
+    let point = robust::Coord {
+        x: coord.x,
+        y: coord.y,
+    };
+    let mut winding_number = 0_i32;
+    for line in ring.lines() {
+        let orientation = robust::orient2d(
+            robust::Coord {
+                x: line.start.x,
+                y: line.start.y,
+            },
+            robust::Coord {
+                x: line.end.x,
+                y: line.end.y,
+            },
+            point,
+        );
+        // Upward edges include their start and exclude their end, downward
+        // edges exclude their start and include their end.
+        if line.start.y <= coord.y {
+            if line.end.y > coord.y && orientation > 0. {
+                winding_number += 1;
+            }
+        } else if line.end.y <= coord.y && orientation < 0. {
+            winding_number -= 1;
+        }
+    }
+
+    winding_number != 0
+}
+
 // Return the cell boundary, in radians.
 fn cell_boundary(cell: CellIndex) -> MultiPolygon {
     let boundary = LineString(
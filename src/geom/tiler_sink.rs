@@ -0,0 +1,139 @@
+use super::Tiler;
+use geo::{Coord, LineString, Polygon};
+use geozero::{
+    error::{GeozeroError, Result},
+    FeatureProcessor, GeomProcessor, PropertyProcessor,
+};
+use std::mem;
+
+/// A `geozero` processor that feeds the polygons it receives to a [`Tiler`].
+///
+/// This allows to tile shapes coming from any `geozero`-supported format
+/// (`GeoJSON`, WKB, `FlatGeobuf`, ...) without having to convert them to `geo`
+/// geometries first.
+///
+/// Only areal geometries are supported: encountering a point or a line
+/// results in an error.
+///
+/// # Example
+///
+/// ```
+/// use geozero::{geojson::GeoJson, GeozeroGeometry};
+/// use h3o::{
+///     geom::{TilerBuilder, TilerSink},
+///     Resolution,
+/// };
+///
+/// let geojson = GeoJson(
+///     r#"{"type": "Polygon", "coordinates": [[[0, 0], [1, 1], [1, 0], [0, 0]]]}"#,
+/// );
+/// let mut tiler = TilerBuilder::new(Resolution::Seven).build();
+/// geojson.process_geom(&mut TilerSink::new(&mut tiler))?;
+/// let cells = tiler.into_coverage().collect::<Vec<_>>();
+/// # Ok::<(), geozero::error::GeozeroError>(())
+/// ```
+#[derive(Debug)]
+pub struct TilerSink<'a> {
+    tiler: &'a mut Tiler,
+    /// Ring being built.
+    ring: Vec<Coord>,
+    /// Rings of the polygon being built.
+    rings: Vec<LineString>,
+}
+
+impl<'a> TilerSink<'a> {
+    /// Initializes a new sink, feeding the given tiler.
+    #[must_use]
+    pub const fn new(tiler: &'a mut Tiler) -> Self {
+        Self {
+            tiler,
+            ring: Vec::new(),
+            rings: Vec::new(),
+        }
+    }
+}
+
+impl GeomProcessor for TilerSink<'_> {
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+        self.ring.push(Coord { x, y });
+        Ok(())
+    }
+
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        _z: Option<f64>,
+        _m: Option<f64>,
+        _t: Option<f64>,
+        _tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        self.xy(x, y, idx)
+    }
+
+    fn point_begin(&mut self, _idx: usize) -> Result<()> {
+        Err(unsupported("point"))
+    }
+
+    fn multipoint_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        Err(unsupported("multipoint"))
+    }
+
+    fn linestring_begin(
+        &mut self,
+        tagged: bool,
+        size: usize,
+        _idx: usize,
+    ) -> Result<()> {
+        // Untagged linestrings are polygon rings.
+        if tagged {
+            return Err(unsupported("linestring"));
+        }
+        self.ring.reserve(size);
+        Ok(())
+    }
+
+    fn linestring_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
+        let ring = mem::take(&mut self.ring);
+        self.rings.push(LineString::new(ring));
+        Ok(())
+    }
+
+    fn multilinestring_begin(
+        &mut self,
+        _size: usize,
+        _idx: usize,
+    ) -> Result<()> {
+        Err(unsupported("multilinestring"))
+    }
+
+    fn polygon_begin(
+        &mut self,
+        _tagged: bool,
+        size: usize,
+        _idx: usize,
+    ) -> Result<()> {
+        self.rings.reserve(size);
+        Ok(())
+    }
+
+    fn polygon_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
+        let mut rings = mem::take(&mut self.rings).into_iter();
+        let exterior = rings.next().unwrap_or_else(|| LineString::new(vec![]));
+        let polygon = Polygon::new(exterior, rings.collect());
+
+        self.tiler
+            .add(polygon)
+            .map_err(|err| GeozeroError::Geometry(err.to_string()))
+    }
+}
+
+impl PropertyProcessor for TilerSink<'_> {}
+
+impl FeatureProcessor for TilerSink<'_> {}
+
+/// Returns the error raised for non-areal geometries.
+fn unsupported(kind: &str) -> GeozeroError {
+    GeozeroError::Geometry(format!("unsupported geometry type: {kind}"))
+}
@@ -7,22 +7,121 @@ use either::Either;
 use geo::{LineString, MultiPolygon, Polygon};
 use std::collections::hash_map::Entry;
 
-/// A single node in a vertex graph.
-#[derive(Debug, Eq, PartialEq, Hash)]
+/// A single node in a vertex graph, i.e. a directed edge between two vertices.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub struct Node {
     from: VertexIndex,
     to: VertexIndex,
 }
 
+impl Node {
+    /// Initializes a new node, going from `from` to `to`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::Node, CellIndex};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let vertexes = index.vertexes().collect::<Vec<_>>();
+    /// let node = Node::new(vertexes[0], vertexes[1]);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub const fn new(from: VertexIndex, to: VertexIndex) -> Self {
+        Self { from, to }
+    }
+
+    /// Returns the origin of the edge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::Node, CellIndex};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let vertexes = index.vertexes().collect::<Vec<_>>();
+    /// let node = Node::new(vertexes[0], vertexes[1]);
+    /// assert_eq!(node.origin(), vertexes[0]);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub const fn origin(&self) -> VertexIndex {
+        self.from
+    }
+
+    /// Returns the destination of the edge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::Node, CellIndex};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let vertexes = index.vertexes().collect::<Vec<_>>();
+    /// let node = Node::new(vertexes[0], vertexes[1]);
+    /// assert_eq!(node.destination(), vertexes[1]);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub const fn destination(&self) -> VertexIndex {
+        self.to
+    }
+}
+
 /// A data structure to store a graph of vertices.
-#[derive(Default)]
+///
+/// This is the building block used by the [`Solvent`](super::Solvent): cell
+/// edges are inserted one by one, edges shared by two cells cancel each other
+/// out and the remaining ones form the outlines of the set.
+///
+/// It can be used to implement custom outline algorithms (e.g. partial
+/// dissolves).
+///
+/// ```rust
+/// use h3o::{geom::VertexGraph, CellIndex, Resolution};
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let mut graph = VertexGraph::new(Resolution::Ten);
+/// for cell in index.grid_disk::<Vec<_>>(1) {
+///     graph.insert_cell(cell)?;
+/// }
+/// let rings = graph.into_rings();
+/// assert_eq!(rings.len(), 1);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone)]
 pub struct VertexGraph {
     nodes: HashMap<VertexIndex, Vec<VertexIndex>>,
     distortions: HashMap<Node, LatLng>,
+    resolution: Resolution,
     is_class3: bool,
+    /// Reused by `insert_cell`, to save an allocation per cell.
+    scratchpad: Scratchpad,
 }
 
 impl VertexGraph {
+    /// Initializes an empty graph for the cells of the given resolution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::VertexGraph, Resolution};
+    ///
+    /// let graph = VertexGraph::new(Resolution::Ten);
+    /// assert!(graph.is_empty());
+    /// ```
+    #[must_use]
+    pub fn new(resolution: Resolution) -> Self {
+        Self {
+            nodes: HashMap::new(),
+            distortions: HashMap::new(),
+            resolution,
+            is_class3: resolution.is_class3(),
+            scratchpad: Scratchpad::default(),
+        }
+    }
+
     /// Initializes a new `VertexGraph` from a set of homogeneous cells.
     ///
     /// # Notes
@@ -31,6 +130,22 @@ impl VertexGraph {
     /// performed, which implies an eager consumption of the iterator upfront,
     /// incurring memory overhead and losing the lazyness of the iterator-based
     /// approach.
+    ///
+    /// # Errors
+    ///
+    /// All cell indexes must be unique and have the same resolution,
+    /// otherwise [`DissolutionError`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::VertexGraph, CellIndex};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let cells = index.grid_disk::<Vec<_>>(1);
+    /// let graph = VertexGraph::from_homogeneous(cells, true)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
     pub fn from_homogeneous(
         cells: impl IntoIterator<Item = CellIndex>,
         check_duplicate: bool,
@@ -46,11 +161,7 @@ impl VertexGraph {
         let resolution = first.map_or(Resolution::Zero, CellIndex::resolution);
         let cells = first.into_iter().chain(cells);
 
-        let mut graph = Self {
-            nodes: HashMap::new(),
-            distortions: HashMap::new(),
-            is_class3: resolution.is_class3(),
-        };
+        let mut graph = Self::new(resolution);
 
         // Scratchpad to reuse memory allocations.
         let mut scratchpad = Scratchpad::new();
@@ -58,19 +169,7 @@ impl VertexGraph {
             if cell.resolution() != resolution {
                 return Err(DissolutionError::UnsupportedResolution);
             }
-
-            scratchpad.compute_vertexes(cell);
-            for pair in scratchpad.vertexes.windows(2) {
-                graph.insert(&Node {
-                    from: pair[0],
-                    to: pair[1],
-                });
-            }
-
-            // Keep track of distortions vertices when necessary.
-            if graph.is_class3 && cell.icosahedron_faces().len() > 1 {
-                graph.index_distortions(cell, &scratchpad.vertexes);
-            }
+            graph.insert_cell_edges(cell, &mut scratchpad);
         }
 
         Ok(graph)
@@ -84,6 +183,23 @@ impl VertexGraph {
     /// performed, which implies an eager consumption of the iterator upfront,
     /// incurring memory overhead and losing the lazyness of the iterator-based
     /// approach.
+    ///
+    /// # Errors
+    ///
+    /// All cell indexes must be unique and have a resolution coarser or equal
+    /// to `resolution`, otherwise [`DissolutionError`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::VertexGraph, CellIndex, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let cells = index.grid_disk::<Vec<_>>(1);
+    /// let graph =
+    ///     VertexGraph::from_heterogeneous(cells, Resolution::Eleven, true)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
     pub fn from_heterogeneous(
         cells: impl IntoIterator<Item = CellIndex>,
         resolution: Resolution,
@@ -94,19 +210,13 @@ impl VertexGraph {
             if cells.iter().any(|cell| cell.resolution() > resolution) {
                 return Err(DissolutionError::UnsupportedResolution);
             }
-            check_duplicates(
-                cells.iter().flat_map(|cell| cell.children(resolution)),
-            )?;
+            check_overlaps(&cells)?;
             Either::Left(cells.into_iter())
         } else {
             Either::Right(cells.into_iter())
         };
 
-        let mut graph = Self {
-            nodes: HashMap::new(),
-            distortions: HashMap::new(),
-            is_class3: resolution.is_class3(),
-        };
+        let mut graph = Self::new(resolution);
 
         // Scratchpad to reuse memory allocations.
         let mut scratchpad = Scratchpad::new();
@@ -116,18 +226,7 @@ impl VertexGraph {
                     graph.insert_large_cell(cell, resolution, &mut scratchpad);
                 }
                 std::cmp::Ordering::Equal => {
-                    scratchpad.compute_vertexes(cell);
-                    for pair in scratchpad.vertexes.windows(2) {
-                        graph.insert(&Node {
-                            from: pair[0],
-                            to: pair[1],
-                        });
-                    }
-
-                    // Keep track of distortions vertices when necessary.
-                    if graph.is_class3 && cell.icosahedron_faces().len() > 1 {
-                        graph.index_distortions(cell, &scratchpad.vertexes);
-                    }
+                    graph.insert_cell_edges(cell, &mut scratchpad);
                 }
                 std::cmp::Ordering::Greater => {
                     return Err(DissolutionError::UnsupportedResolution);
@@ -138,7 +237,56 @@ impl VertexGraph {
         Ok(graph)
     }
 
+    /// Adds the edges of a cell to the graph.
+    ///
+    /// # Errors
+    ///
+    /// [`DissolutionError::UnsupportedResolution`] if the cell resolution
+    /// doesn't match the graph one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::VertexGraph, CellIndex, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let mut graph = VertexGraph::new(Resolution::Ten);
+    /// graph.insert_cell(index)?;
+    /// assert!(!graph.is_empty());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn insert_cell(
+        &mut self,
+        cell: CellIndex,
+    ) -> Result<(), DissolutionError> {
+        if cell.resolution() != self.resolution {
+            return Err(DissolutionError::UnsupportedResolution);
+        }
+        let mut scratchpad = std::mem::take(&mut self.scratchpad);
+        self.insert_cell_edges(cell, &mut scratchpad);
+        self.scratchpad = scratchpad;
+
+        Ok(())
+    }
+
     /// Adds an edge to the graph.
+    ///
+    /// If the reversed edge is already in the graph (i.e. the edge is shared
+    /// by two cells), both are removed instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::{Node, VertexGraph}, CellIndex, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let vertexes = index.vertexes().collect::<Vec<_>>();
+    /// let mut graph = VertexGraph::new(Resolution::Ten);
+    /// graph.insert(&Node::new(vertexes[0], vertexes[1]));
+    /// graph.insert(&Node::new(vertexes[1], vertexes[0]));
+    /// assert!(graph.is_empty());
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
     pub fn insert(&mut self, node: &Node) {
         // First lookup the reversed edge.
         // If we've seen this edge already, it will be reversed.
@@ -169,6 +317,21 @@ impl VertexGraph {
     }
 
     /// Removes a node from the graph.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::{Node, VertexGraph}, CellIndex, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let vertexes = index.vertexes().collect::<Vec<_>>();
+    /// let node = Node::new(vertexes[0], vertexes[1]);
+    /// let mut graph = VertexGraph::new(Resolution::Ten);
+    /// graph.insert(&node);
+    /// graph.remove(&node);
+    /// assert!(graph.is_empty());
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
     pub fn remove(&mut self, node: &Node) {
         if let Entry::Occupied(mut entry) = self.nodes.entry(node.from) {
             if let Some(pos) =
@@ -184,15 +347,119 @@ impl VertexGraph {
     }
 
     /// Finds a vertex node starting at the given vertex, if it exists.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::{Node, VertexGraph}, CellIndex, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let vertexes = index.vertexes().collect::<Vec<_>>();
+    /// let node = Node::new(vertexes[0], vertexes[1]);
+    /// let mut graph = VertexGraph::new(Resolution::Ten);
+    /// graph.insert(&node);
+    /// assert_eq!(graph.get_from_vertex(vertexes[0]), Some(node));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
     pub fn get_from_vertex(&self, from: VertexIndex) -> Option<Node> {
         self.nodes.get(&from).map(|to| Node { from, to: to[0] })
     }
 
     /// Returns true if the graph is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::VertexGraph, Resolution};
+    ///
+    /// let graph = VertexGraph::new(Resolution::Ten);
+    /// assert!(graph.is_empty());
+    /// ```
+    #[must_use]
     pub fn is_empty(&self) -> bool {
         self.nodes.is_empty()
     }
 
+    /// Extracts the loops of the graph, as rings of coordinates (in degrees).
+    ///
+    /// Note that the rings are not explicitly closed (i.e. the first
+    /// coordinate isn't repeated at the end).
+    ///
+    /// Rings are not sorted, and the holes are not associated to their outer
+    /// ring: see [`RingHierarchy`](super::RingHierarchy) for that.
+    ///
+    /// Paths that don't loop back to their starting vertex (e.g. when edges
+    /// have been inserted manually) are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::VertexGraph, CellIndex, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let mut graph = VertexGraph::new(Resolution::Ten);
+    /// for cell in index.grid_ring_fast(1) {
+    ///     graph.insert_cell(cell.expect("cell"))?;
+    /// }
+    /// // Exterior ring + hole.
+    /// assert_eq!(graph.into_rings().len(), 2);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn into_rings(mut self) -> Vec<LineString> {
+        let mut rings = Vec::new();
+        let mut coords = Vec::new();
+
+        while !self.is_empty() {
+            let (&from, to) =
+                self.nodes.iter().next().expect("non-empty graph");
+            let mut node = Node { from, to: to[0] };
+            let is_closed = loop {
+                coords.push(LatLng::from(node.from).into());
+                // Inject distortion vertex, if any.
+                if self.is_class3 {
+                    if let Some(distortion) = self.distortions.remove(&node) {
+                        coords.push(distortion.into());
+                    }
+                }
+
+                let to = node.to;
+                self.remove(&node);
+                match self.get_from_vertex(to) {
+                    Some(next_node) => node = next_node,
+                    None => break to == from,
+                }
+            };
+            if is_closed {
+                rings.push(LineString::new(coords.clone()));
+            }
+            coords.clear();
+        }
+
+        rings
+    }
+
+    /// Adds the edges of a cell, assuming it has the right resolution.
+    pub(super) fn insert_cell_edges(
+        &mut self,
+        cell: CellIndex,
+        scratchpad: &mut Scratchpad,
+    ) {
+        scratchpad.compute_vertexes(cell);
+        for pair in scratchpad.vertexes.windows(2) {
+            self.insert(&Node {
+                from: pair[0],
+                to: pair[1],
+            });
+        }
+
+        // Keep track of distortions vertices when necessary.
+        if self.is_class3 && cell.icosahedron_faces().len() > 1 {
+            self.index_distortions(cell, &scratchpad.vertexes);
+        }
+    }
+
     /// Efficient insertion of cells larger than the target resolution.
     fn insert_large_cell(
         &mut self,
@@ -271,39 +538,13 @@ impl VertexGraph {
 }
 
 impl From<VertexGraph> for MultiPolygon<f64> {
-    fn from(mut value: VertexGraph) -> Self {
+    fn from(value: VertexGraph) -> Self {
         // No vertex, no shape.
         if value.is_empty() {
             return Self::new(Vec::new());
         }
 
-        let mut rings = Vec::new();
-        let mut coords = Vec::new();
-
-        while !value.is_empty() {
-            let (&from, to) =
-                value.nodes.iter().next().expect("non-empty graph");
-            let mut node = Node { from, to: to[0] };
-            loop {
-                coords.push(LatLng::from(node.from).into());
-                // Inject distortion vertex, if any.
-                if value.is_class3 {
-                    if let Some(distortion) = value.distortions.remove(&node) {
-                        coords.push(distortion.into());
-                    }
-                }
-
-                let to = node.to;
-                value.remove(&node);
-                match value.get_from_vertex(to) {
-                    Some(next_node) => node = next_node,
-                    None => break,
-                }
-            }
-            assert!(coords.len() >= 4);
-            rings.push(LineString::new(coords.clone()));
-            coords.clear();
-        }
+        let mut rings = value.into_rings();
 
         // If we have a single ring, the resulting shape is obvious.
         if rings.len() == 1 {
@@ -319,14 +560,15 @@ impl From<VertexGraph> for MultiPolygon<f64> {
 
 // -----------------------------------------------------------------------------
 
-struct Scratchpad {
+#[derive(Debug, Clone, Default)]
+pub(super) struct Scratchpad {
     neighbors: [u64; 7],
     vertexes: Vec<VertexIndex>,
     blacklist: HashSet<Node>,
 }
 
 impl Scratchpad {
-    fn new() -> Self {
+    pub(super) fn new() -> Self {
         Self {
             // 6 neighbors + self.
             neighbors: [0; 7],
@@ -346,36 +588,78 @@ impl Scratchpad {
     }
 }
 
+// Computes the children of `cell`, at the given resolution, that lie on its
+// boundary, along with their neighbors that are also children of `cell`.
+//
+// Instead of expanding every child, the boundary is refined one resolution at
+// a time: the neighbors of a child always belong to its parent or to the
+// neighbors of its parent, so the boundary children at a given resolution
+// are children of the boundary cells at the previous one. This makes the cost
+// proportional to the perimeter of the cell instead of its area.
 fn compute_large_cell_boundary(
     cell: CellIndex,
     resolution: Resolution,
     scratchpad: &mut [u64],
 ) -> HashMap<CellIndex, Vec<CellIndex>> {
-    let cells = cell.children(resolution).collect::<HashSet<_>>();
+    let ancestor_resolution = cell.resolution();
+    let is_inside =
+        |index: CellIndex| index.parent(ancestor_resolution) == Some(cell);
+
+    let mut boundary = vec![cell];
+    for child_resolution in Resolution::range(
+        ancestor_resolution.succ().expect("finer resolution"),
+        resolution,
+    ) {
+        boundary = boundary
+            .iter()
+            .flat_map(|cell| cell.children(child_resolution))
+            .filter(|&child| {
+                let count = neighbors(child, scratchpad);
+                scratchpad[0..count].iter().any(|&neighbor| {
+                    !is_inside(CellIndex::new_unchecked(neighbor))
+                })
+            })
+            .collect();
+    }
 
-    cells
-        .iter()
-        .copied()
-        .filter_map(|cell| {
+    boundary
+        .into_iter()
+        .map(|cell| {
             let count = neighbors(cell, scratchpad);
-            let is_boundary = scratchpad[0..count].iter().any(|neighbor| {
-                !cells.contains(&CellIndex::new_unchecked(*neighbor))
-            });
-
-            is_boundary.then(|| {
-                let neighbors = scratchpad[0..count]
-                    .iter()
-                    .filter_map(|&neighbor| {
-                        let index = CellIndex::new_unchecked(neighbor);
-                        cells.contains(&index).then_some(index)
-                    })
-                    .collect::<Vec<_>>();
-                (cell, neighbors)
-            })
+            let neighbors = scratchpad[0..count]
+                .iter()
+                .filter_map(|&neighbor| {
+                    let index = CellIndex::new_unchecked(neighbor);
+                    is_inside(index).then_some(index)
+                })
+                .collect::<Vec<_>>();
+            (cell, neighbors)
         })
         .collect()
 }
 
+// Checks that no cell is duplicated or contained in another one.
+//
+// Works directly on the heterogeneous cells, without expanding them.
+fn check_overlaps(cells: &[CellIndex]) -> Result<(), DissolutionError> {
+    let set = check_duplicates(cells.iter().copied())?;
+
+    for cell in cells {
+        let has_ancestor =
+            Resolution::range(Resolution::Zero, cell.resolution())
+                .take(usize::from(u8::from(cell.resolution())))
+                .any(|resolution| {
+                    cell.parent(resolution)
+                        .is_some_and(|parent| set.contains(&parent))
+                });
+        if has_ancestor {
+            return Err(DissolutionError::DuplicateInput);
+        }
+    }
+
+    Ok(())
+}
+
 fn check_duplicates(
     cells: impl IntoIterator<Item = CellIndex>,
 ) -> Result<HashSet<CellIndex>, DissolutionError> {
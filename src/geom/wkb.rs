@@ -0,0 +1,73 @@
+//! WKB decoding, limited to polygons and multipolygons.
+//!
+//! The parsing is done by `geozero`. Both the ISO flavor and the extended one
+//! from `PostGIS` (EWKB, with an optional SRID) are supported. Z and M
+//! ordinates are skipped.
+
+use crate::error::InvalidGeometry;
+use geo::{Geometry, Polygon};
+use geozero::wkb::{FromWkb, WkbDialect};
+use std::io::Cursor;
+
+/// EWKB flags for the presence of Z ordinates, M ordinates and SRID.
+const EWKB_FLAGS: u32 = 0xe000_0000;
+/// EWKB flag for the presence of a SRID.
+const EWKB_SRID: u32 = 0x2000_0000;
+
+/// Decodes a WKB polygon or multipolygon.
+///
+/// Empty polygons are skipped.
+pub fn decode(bytes: &[u8]) -> Result<Vec<Polygon>, InvalidGeometry> {
+    let (dialect, srid) = header(bytes)?;
+
+    let mut cursor = Cursor::new(bytes);
+    let geometry = Geometry::from_wkb(&mut cursor, dialect)
+        .map_err(|_| InvalidGeometry::new("malformed WKB geometry"))?;
+    if usize::try_from(cursor.position()) != Ok(bytes.len()) {
+        return Err(InvalidGeometry::new("trailing bytes after WKB geometry"));
+    }
+
+    super::decoded_polygons(geometry, srid)
+}
+
+/// Reads the geometry header, returning the WKB flavor and the SRID, if any.
+fn header(bytes: &[u8]) -> Result<(WkbDialect, Option<i32>), InvalidGeometry> {
+    let truncated = || InvalidGeometry::new("truncated WKB geometry");
+    let word = |offset: usize| {
+        bytes
+            .get(offset..offset + 4)
+            .and_then(|word| <[u8; 4]>::try_from(word).ok())
+            .ok_or_else(truncated)
+    };
+
+    let little_endian = match bytes.first() {
+        Some(0) => false,
+        Some(1) => true,
+        Some(_) => return Err(InvalidGeometry::new("invalid WKB byte order")),
+        None => return Err(truncated()),
+    };
+    let kind = word(1).map(|word| {
+        if little_endian {
+            u32::from_le_bytes(word)
+        } else {
+            u32::from_be_bytes(word)
+        }
+    })?;
+
+    if kind & EWKB_FLAGS == 0 {
+        return Ok((WkbDialect::Wkb, None));
+    }
+    let srid = (kind & EWKB_SRID != 0)
+        .then(|| {
+            word(5).map(|word| {
+                if little_endian {
+                    i32::from_le_bytes(word)
+                } else {
+                    i32::from_be_bytes(word)
+                }
+            })
+        })
+        .transpose()?;
+
+    Ok((WkbDialect::Ewkb, srid))
+}
@@ -0,0 +1,51 @@
+//! WKT decoding, limited to polygons and multipolygons.
+//!
+//! The parsing is done by `geozero`, the extended flavor from `PostGIS` (EWKT,
+//! with a `SRID=...;` prefix) is handled here. Z and M ordinates are skipped.
+
+use crate::error::InvalidGeometry;
+use geo::Polygon;
+use geozero::{wkt::Wkt, ToGeo};
+
+/// EWKT prefix, holding the SRID.
+const EWKT_SRID: &str = "SRID=";
+/// Keyword for empty geometries.
+const EMPTY: &str = "EMPTY";
+
+/// Decodes a WKT polygon or multipolygon.
+///
+/// Empty polygons are skipped.
+pub fn decode(wkt: &str) -> Result<Vec<Polygon>, InvalidGeometry> {
+    let wkt = wkt.trim_start();
+    let (srid, wkt) = match wkt.get(..EWKT_SRID.len()) {
+        Some(prefix) if prefix.eq_ignore_ascii_case(EWKT_SRID) => {
+            let (srid, wkt) = wkt
+                .get(EWKT_SRID.len()..)
+                .and_then(|ewkt| ewkt.split_once(';'))
+                .ok_or_else(|| InvalidGeometry::new("malformed EWKT prefix"))?;
+            let srid = srid
+                .trim()
+                .parse()
+                .map_err(|_| InvalidGeometry::new("invalid EWKT SRID"))?;
+            (Some(srid), wkt)
+        }
+        _ => (None, wkt),
+    };
+
+    // The parser stops at the end of the geometry, ignoring what follows.
+    let wkt = wkt.trim_end();
+    let is_complete = wkt.ends_with(')')
+        || wkt.len().checked_sub(EMPTY.len()).is_some_and(|start| {
+            wkt.get(start..)
+                .is_some_and(|suffix| suffix.eq_ignore_ascii_case(EMPTY))
+        });
+    if !is_complete {
+        return Err(InvalidGeometry::new("trailing text after WKT geometry"));
+    }
+
+    let geometry = Wkt(wkt)
+        .to_geo()
+        .map_err(|_| InvalidGeometry::new("malformed WKT geometry"))?;
+
+    super::decoded_polygons(geometry, srid)
+}
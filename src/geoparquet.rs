@@ -0,0 +1,201 @@
+//! `GeoParquet` export of H3 coverages.
+//!
+//! Each cell is written as a row containing its outline (as a WKB polygon, in
+//! the `geometry` column), its hexadecimal index (in the `cell` column) and
+//! user-provided properties.
+
+use crate::{bbox, CellIndex, LatLng};
+use arrow_array::{ArrayRef, BinaryArray, RecordBatch, StringArray};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use parquet::{
+    arrow::ArrowWriter, errors::ParquetError,
+    file::properties::WriterProperties, format::KeyValue,
+};
+use std::{io::Write, sync::Arc};
+
+/// Name of the geometry column.
+const GEOMETRY_COLUMN: &str = "geometry";
+/// Name of the cell index column.
+const CELL_COLUMN: &str = "cell";
+
+/// WKB byte order marker for little endian.
+const WKB_LITTLE_ENDIAN: u8 = 1;
+/// WKB geometry type for polygons.
+const WKB_POLYGON: u32 = 3;
+
+/// A writer of H3 coverages to `GeoParquet` files.
+///
+/// The geometry is stored in WGS84 (the default CRS of `GeoParquet`) and the
+/// bounding box of the whole coverage is recorded in the file metadata, so
+/// the output can be directly opened in QGIS, `GeoPandas`, ...
+///
+/// # Example
+///
+/// ```
+/// use arrow_array::{ArrayRef, UInt32Array};
+/// use arrow_schema::{DataType, Field, Schema};
+/// use h3o::{geoparquet::GeoParquetWriter, CellIndex, Resolution};
+/// use std::sync::Arc;
+///
+/// let cells = CellIndex::try_from(0x8a1fb46622dffff)?
+///     .children(Resolution::Eleven)
+///     .collect::<Vec<_>>();
+/// let counts: ArrayRef = Arc::new(UInt32Array::from(vec![1, 2, 3, 4, 5, 6, 7]));
+///
+/// let schema = Schema::new(vec![Field::new("count", DataType::UInt32, false)]);
+/// let mut writer = GeoParquetWriter::try_new(Vec::new(), &schema)?;
+/// writer.write(&cells, &[counts])?;
+/// let bytes = writer.close()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct GeoParquetWriter<W: Write + Send> {
+    writer: ArrowWriter<W>,
+    schema: SchemaRef,
+    /// Bounding box of the written cells (south-west and north-east
+    /// corners).
+    bbox: Option<(LatLng, LatLng)>,
+}
+
+impl<W: Write + Send> GeoParquetWriter<W> {
+    /// Initializes a new writer, with the given schema for the properties.
+    ///
+    /// # Errors
+    ///
+    /// [`ParquetError`] if the properties schema clashes with the reserved
+    /// `geometry` and `cell` columns, or if the writer cannot be initialized.
+    pub fn try_new(
+        writer: W,
+        properties: &Schema,
+    ) -> Result<Self, ParquetError> {
+        Self::try_new_with_properties(writer, properties, None)
+    }
+
+    /// Initializes a new writer, with the given schema for the properties and
+    /// the given Parquet writer properties.
+    ///
+    /// # Errors
+    ///
+    /// [`ParquetError`] if the properties schema clashes with the reserved
+    /// `geometry` and `cell` columns, or if the writer cannot be initialized.
+    pub fn try_new_with_properties(
+        writer: W,
+        properties: &Schema,
+        options: Option<WriterProperties>,
+    ) -> Result<Self, ParquetError> {
+        if properties.fields().iter().any(|field| {
+            field.name() == GEOMETRY_COLUMN || field.name() == CELL_COLUMN
+        }) {
+            return Err(ParquetError::General(format!(
+                "`{GEOMETRY_COLUMN}` and `{CELL_COLUMN}` are reserved columns"
+            )));
+        }
+
+        let fields = [
+            Arc::new(Field::new(GEOMETRY_COLUMN, DataType::Binary, false)),
+            Arc::new(Field::new(CELL_COLUMN, DataType::Utf8, false)),
+        ]
+        .into_iter()
+        .chain(properties.fields().iter().cloned())
+        .collect::<Vec<_>>();
+        let schema = Arc::new(Schema::new_with_metadata(
+            fields,
+            properties.metadata().clone(),
+        ));
+
+        Ok(Self {
+            writer: ArrowWriter::try_new(writer, Arc::clone(&schema), options)?,
+            schema,
+            bbox: None,
+        })
+    }
+
+    /// Writes a batch of cells, along with their properties.
+    ///
+    /// `properties` contains one column per field of the properties schema,
+    /// each column having one row per cell.
+    ///
+    /// # Errors
+    ///
+    /// [`ParquetError`] if the properties don't match the schema or the number
+    /// of cells, or if the write fails.
+    pub fn write(
+        &mut self,
+        cells: &[CellIndex],
+        properties: &[ArrayRef],
+    ) -> Result<(), ParquetError> {
+        let geometry =
+            BinaryArray::from_iter_values(cells.iter().copied().map(encode));
+        let ids = StringArray::from_iter_values(
+            cells.iter().map(ToString::to_string),
+        );
+
+        let columns =
+            [Arc::new(geometry) as ArrayRef, Arc::new(ids) as ArrayRef]
+                .into_iter()
+                .chain(properties.iter().cloned())
+                .collect::<Vec<_>>();
+        let batch = RecordBatch::try_new(Arc::clone(&self.schema), columns)?;
+        self.writer.write(&batch)?;
+
+        // Only account for the cells actually written.
+        if let Some(batch_bbox) = bbox::coverage_bbox(cells.iter().copied()) {
+            self.bbox = Some(self.bbox.map_or(batch_bbox, |current| {
+                bbox::merge_bbox(current, batch_bbox)
+            }));
+        }
+
+        Ok(())
+    }
+
+    /// Writes the `GeoParquet` metadata, closes the file and returns the
+    /// underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// [`ParquetError`] if the file cannot be finalized.
+    pub fn close(mut self) -> Result<W, ParquetError> {
+        let metadata = geo_metadata(self.bbox);
+        self.writer.append_key_value_metadata(KeyValue::new(
+            "geo".to_owned(),
+            metadata,
+        ));
+        self.writer.into_inner()
+    }
+}
+
+/// Encodes the cell boundary as a WKB polygon.
+fn encode(cell: CellIndex) -> Vec<u8> {
+    let boundary = cell.boundary();
+    // Close the ring, as required by the WKB format.
+    let ring = boundary.iter().chain(boundary.first());
+    let count = u32::try_from(boundary.len() + 1).expect("small boundary");
+
+    let mut wkb = Vec::with_capacity(13 + 16 * (boundary.len() + 1));
+    wkb.push(WKB_LITTLE_ENDIAN);
+    wkb.extend_from_slice(&WKB_POLYGON.to_le_bytes());
+    wkb.extend_from_slice(&1_u32.to_le_bytes());
+    wkb.extend_from_slice(&count.to_le_bytes());
+    for ll in ring {
+        wkb.extend_from_slice(&ll.lng().to_le_bytes());
+        wkb.extend_from_slice(&ll.lat().to_le_bytes());
+    }
+
+    wkb
+}
+
+/// Returns the JSON `GeoParquet` metadata.
+///
+/// The CRS is omitted, which means `OGC:CRS84` (i.e. WGS84, longitude first).
+/// As allowed by the specification, `xmin` is greater than `xmax` when the
+/// bounding box crosses the antimeridian.
+fn geo_metadata(bbox: Option<(LatLng, LatLng)>) -> String {
+    let bbox = bbox.map_or_else(String::new, |(min, max)| {
+        let (xmin, ymin, xmax, ymax) =
+            (min.lng(), min.lat(), max.lng(), max.lat());
+        format!(r#","bbox":[{xmin},{ymin},{xmax},{ymax}]"#)
+    });
+
+    format!(
+        r#"{{"version":"1.1.0","primary_column":"{GEOMETRY_COLUMN}","columns":{{"{GEOMETRY_COLUMN}":{{"encoding":"WKB","geometry_types":["Polygon"]{bbox}}}}}}}"#
+    )
+}
@@ -0,0 +1,65 @@
+use crate::{adjacency_pairs, CellIndex, DirectedEdgeIndex};
+use ahash::{HashMap, HashMapExt};
+use petgraph::{graph::Graph, EdgeType};
+
+/// Builds a `petgraph` graph from a set of cells.
+///
+/// Cells are the nodes (in ascending order, duplicates ignored) and the
+/// adjacency between them the edges: an undirected graph gets one edge per
+/// pair of neighbors, a directed one gets an edge in each direction. Cells at
+/// different resolutions are never adjacent.
+///
+/// Edges are weighted by `weight`, from the directed edge between the cells
+/// (e.g. `DirectedEdgeIndex::length_m` for a graph weighted by edge length,
+/// or `|_| ()` for an unweighted one).
+///
+/// # Example
+///
+/// ```
+/// use h3o::{to_petgraph, CellIndex};
+/// use petgraph::graph::UnGraph;
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let cells = index.grid_disk::<Vec<_>>(1);
+/// let graph: UnGraph<_, _> = to_petgraph(&cells, |edge| edge.length_m());
+///
+/// assert_eq!(graph.node_count(), 7);
+/// assert_eq!(graph.edge_count(), 12);
+/// # Ok::<(), h3o::error::InvalidCellIndex>(())
+/// ```
+pub fn to_petgraph<Ty, W>(
+    cells: &[CellIndex],
+    mut weight: impl FnMut(DirectedEdgeIndex) -> W,
+) -> Graph<CellIndex, W, Ty>
+where
+    Ty: EdgeType,
+{
+    let mut nodes = cells.to_vec();
+    nodes.sort_unstable();
+    nodes.dedup();
+
+    let mut pairs = adjacency_pairs(&nodes);
+    pairs.sort_unstable();
+
+    let edge_count = if Ty::is_directed() {
+        pairs.len() * 2
+    } else {
+        pairs.len()
+    };
+    let mut graph = Graph::with_capacity(nodes.len(), edge_count);
+    let mut ids = HashMap::with_capacity(nodes.len());
+    for cell in nodes {
+        ids.insert(cell, graph.add_node(cell));
+    }
+
+    for (a, b) in pairs {
+        let edge = a.edge(b).expect("neighbors");
+        graph.add_edge(ids[&a], ids[&b], weight(edge));
+        if Ty::is_directed() {
+            let edge = b.edge(a).expect("neighbors");
+            graph.add_edge(ids[&b], ids[&a], weight(edge));
+        }
+    }
+
+    graph
+}
@@ -0,0 +1,126 @@
+//! Weighted shortest path over the grid.
+
+use crate::{CellIndex, DirectedEdgeIndex, Map};
+use alloc::{collections::BinaryHeap, vec::Vec};
+use core::cmp::Ordering;
+
+/// Finds the cheapest path between two cells, using the A* algorithm.
+///
+/// `min_step_cost` is a lower bound of the cost of any step, used to scale
+/// the grid distance heuristic (0 falls back on Dijkstra's algorithm).
+/// Steps for which `cost` returns `None`, a negative or a NaN value are
+/// considered blocked.
+pub fn shortest_path(
+    from: CellIndex,
+    to: CellIndex,
+    min_step_cost: f64,
+    mut cost: impl FnMut(CellIndex, CellIndex) -> Option<f64>,
+) -> Option<(Vec<CellIndex>, f64)> {
+    if from.resolution() != to.resolution() {
+        return None;
+    }
+    let min_step_cost = if min_step_cost.is_finite() && min_step_cost > 0. {
+        min_step_cost
+    } else {
+        0.
+    };
+    // Grid distance is only a lower bound when it can be computed.
+    let heuristic = |cell: CellIndex| {
+        if min_step_cost == 0. {
+            return 0.;
+        }
+        cell.grid_distance(to)
+            .map_or(0., |distance| f64::from(distance) * min_step_cost)
+    };
+
+    // Best known cost and predecessor of each reached cell.
+    let mut best = Map::<CellIndex, (f64, Option<CellIndex>)>::default();
+    let mut queue = BinaryHeap::new();
+    best.insert(from, (0., None));
+    queue.push(Candidate {
+        priority: heuristic(from),
+        cost: 0.,
+        cell: from,
+    });
+
+    while let Some(Candidate {
+        cost: total, cell, ..
+    }) = queue.pop()
+    {
+        if cell == to {
+            return Some((path(&best, to), total));
+        }
+        // Skip the outdated entries.
+        if best.get(&cell).is_some_and(|&(known, _)| total > known) {
+            continue;
+        }
+
+        for neighbor in cell.edges().map(DirectedEdgeIndex::destination) {
+            let Some(step) = cost(cell, neighbor).filter(|&step| step >= 0.)
+            else {
+                continue;
+            };
+            let next = total + step;
+            if best.get(&neighbor).is_some_and(|&(known, _)| known <= next) {
+                continue;
+            }
+            best.insert(neighbor, (next, Some(cell)));
+            queue.push(Candidate {
+                priority: next + heuristic(neighbor),
+                cost: next,
+                cell: neighbor,
+            });
+        }
+    }
+
+    None
+}
+
+/// Rebuilds the path leading to the given cell.
+fn path(
+    best: &Map<CellIndex, (f64, Option<CellIndex>)>,
+    to: CellIndex,
+) -> Vec<CellIndex> {
+    let mut path = Vec::new();
+    let mut current = Some(to);
+    while let Some(cell) = current {
+        path.push(cell);
+        current = best.get(&cell).and_then(|&(_, previous)| previous);
+    }
+    path.reverse();
+    path
+}
+
+// -----------------------------------------------------------------------------
+
+/// A cell to explore, ordered by priority (lowest first).
+#[derive(Debug, Clone, Copy)]
+struct Candidate {
+    priority: f64,
+    cost: f64,
+    cell: CellIndex,
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed, to get a min-heap.
+        other
+            .priority
+            .total_cmp(&self.priority)
+            .then_with(|| other.cell.cmp(&self.cell))
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
@@ -1,15 +1,7 @@
-use crate::{CellIndex, Direction};
-use alloc::collections::VecDeque;
-
+use crate::{CellIndex, Direction, Set};
 #[cfg(feature = "std")]
-use ahash::{HashSet, HashSetExt};
-#[cfg(not(feature = "std"))]
-use alloc::collections::BTreeSet;
-
-#[cfg(not(feature = "std"))]
-type Set<K> = BTreeSet<K>;
-#[cfg(feature = "std")]
-type Set<K> = HashSet<K>;
+use ahash::HashSetExt;
+use alloc::collections::VecDeque;
 
 /// Direction to the next ring.
 const NEXT_RING_DIRECTION: Direction = Direction::I;
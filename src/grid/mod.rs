@@ -1,5 +1,7 @@
 mod algo;
+mod astar;
 mod iterator;
 
 pub use algo::{direction_for_neighbor, neighbor_rotations};
+pub use astar::shortest_path;
 pub use iterator::{DiskDistancesSafe, DiskDistancesUnsafe, RingUnsafe};
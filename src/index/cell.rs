@@ -1,24 +1,38 @@
-use super::{Children, GridPathCells, Triangle};
+use super::{AllAt, Children, CompactStreaming, GridPathCells, Triangle};
 use crate::{
-    coord::{CoordIJ, CoordIJK, FaceIJK, LocalIJK, Overage},
+    coord::{CoordIJ, CoordIJK, FaceIJK, LocalIJK, Overage, Vec3d},
     error::{
-        CompactionError, HexGridError, InvalidCellIndex, LocalIjError,
-        ResolutionMismatch,
+        CellLookupError, CompactionError, HexGridError, InvalidCellIndex,
+        InvalidKernel, LocalIjError, ResolutionMismatch,
     },
-    grid,
+    geodesic, grid,
     index::{bits, IndexMode},
     BaseCell, Boundary, DirectedEdgeIndex, Direction, Edge, ExtendedResolution,
-    FaceSet, LatLng, LocalIJ, Resolution, Vertex, VertexIndex, CCW, CW,
-    DEFAULT_CELL_INDEX, EARTH_RADIUS_KM, NUM_HEX_VERTS, NUM_PENT_VERTS,
+    FaceSet, Kernel, LatLng, LocalIJ, Resolution, Set, Vertex, VertexIndex,
+    Winding, CCW, CW, DEFAULT_CELL_INDEX, EARTH_RADIUS_KM, NUM_HEX_VERTS,
+    NUM_PENT_VERTS,
 };
-use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use ahash::HashSetExt;
+use alloc::{format, string::String, vec, vec::Vec};
 use core::{
     cmp::Ordering,
-    fmt, iter,
+    f64::consts::PI,
+    fmt,
+    hash::{Hash, Hasher},
+    iter,
     num::{NonZeroU64, NonZeroU8},
+    ops::RangeInclusive,
     str::FromStr,
 };
 use either::Either;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+/// Number of digits of the base cell, in a token.
+const TOKEN_BASE_CELL_LEN: usize = 3;
+/// Number of digits of a token (base cell and 15 directions).
+const TOKEN_LEN: usize = TOKEN_BASE_CELL_LEN + 15;
 
 /// Lookup table for number of children for hexagonal cells.
 // 7.pow(resolution_delta)
@@ -170,7 +184,7 @@ const BASE_CELL: BaseCell = BaseCell::new_unchecked(2);
 /// - [H3 Index Representations](https://h3geo.org/docs/core-library/h3Indexing)
 /// - [H3 Index Bit Layout](https://observablehq.com/@nrabinowitz/h3-index-bit-layout?collection=@nrabinowitz/h3)
 /// - [H3 Index Inspector](https://observablehq.com/@nrabinowitz/h3-index-inspector?collection=@nrabinowitz/h3)
-#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Clone, Copy, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct CellIndex(NonZeroU64);
@@ -256,6 +270,57 @@ impl CellIndex {
         self.area_km2() * 1000. * 1000.
     }
 
+    /// Returns the center of this H3 cell, guaranteed to be indexed back into
+    /// this cell.
+    ///
+    /// The round trip `cell → LatLng → cell` at the same resolution is stable
+    /// at every resolution, including for pentagons and for centers near a
+    /// face edge: downstream code (e.g. deduplication through the center
+    /// point) can rely on it. This was checked exhaustively up to resolution 7,
+    /// and is tested on pentagons and face edges down to resolution 15.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, LatLng};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let center = index.center_snapped();
+    /// assert_eq!(center.to_cell(index.resolution()), index);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn center_snapped(self) -> LatLng {
+        let center = LatLng::from(self);
+        debug_assert_eq!(center.to_cell(self.resolution()), self, "{self}");
+        center
+    }
+
+    /// Computes the compactness of this H3 cell, as its isoperimetric quotient
+    /// (`4πA / P²`).
+    ///
+    /// The value is 1 for a disk, and around 0.9 for a regular hexagon: the
+    /// further below, the more distorted the cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let compactness = index.compactness();
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn compactness(self) -> f64 {
+        let boundary = self.boundary();
+        let perimeter = (0..boundary.len())
+            .map(|i| {
+                boundary[i].distance_rads(boundary[(i + 1) % boundary.len()])
+            })
+            .sum::<f64>();
+
+        4. * PI * self.area_rads2() / (perimeter * perimeter)
+    }
+
     /// Finds all icosahedron faces intersected this cell index
     ///
     /// # Example
@@ -391,6 +456,31 @@ impl CellIndex {
             })
     }
 
+    /// Returns the directions of the cell, from the resolution 1 down to the
+    /// resolution of the cell (i.e. the path from its base cell).
+    ///
+    /// This is the inverse of [`Self::from_path`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, Direction};
+    ///
+    /// let index = CellIndex::try_from(0x851fb467fffffff)?;
+    /// let path = index.path().collect::<Vec<_>>();
+    /// assert_eq!(path.len(), 5);
+    /// assert_eq!(path[4], Direction::K);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn path(self) -> impl Iterator<Item = Direction> {
+        Resolution::range(Resolution::One, self.resolution()).map(
+            move |resolution| {
+                let value = bits::get_direction(self.0.get(), resolution);
+                Direction::new_unchecked(value)
+            },
+        )
+    }
+
     /// Returns the parent, at the specified resolution, of the cell.
     ///
     /// # Example
@@ -407,10 +497,133 @@ impl CellIndex {
     /// ```
     #[must_use]
     pub fn parent(self, resolution: Resolution) -> Option<Self> {
-        (resolution <= self.resolution()).then(|| {
-            let bits = bits::set_resolution(self.0.get(), resolution);
-            Self::new_unchecked(bits::set_unused(bits, resolution))
-        })
+        self.try_parent(resolution).ok()
+    }
+
+    /// Returns the parent, at the specified resolution, of the cell.
+    ///
+    /// # Errors
+    ///
+    /// [`CellLookupError::FinerResolution`] if the resolution is finer than
+    /// the one of the cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{error::CellLookupError, CellIndex, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x851fb467fffffff)?;
+    /// assert_eq!(
+    ///     index.try_parent(Resolution::Ten),
+    ///     Err(CellLookupError::FinerResolution)
+    /// );
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn try_parent(
+        self,
+        resolution: Resolution,
+    ) -> Result<Self, CellLookupError> {
+        if resolution > self.resolution() {
+            return Err(CellLookupError::FinerResolution);
+        }
+        let bits = bits::set_resolution(self.0.get(), resolution);
+        Ok(Self::new_unchecked(bits::set_unused(bits, resolution)))
+    }
+
+    /// Returns the ancestors of the cell, from its direct parent up to the
+    /// resolution 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let ancestors = index.ancestors().collect::<Vec<_>>();
+    /// assert_eq!(ancestors.len(), 10);
+    /// assert_eq!(ancestors.first().copied(), index.parent(Resolution::Nine));
+    /// assert_eq!(ancestors.last().copied(), index.parent(Resolution::Zero));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn ancestors(self) -> impl Iterator<Item = Self> {
+        Resolution::range(Resolution::Zero, self.resolution())
+            .rev()
+            .skip(1)
+            .map(move |resolution| {
+                let bits = bits::set_resolution(self.0.get(), resolution);
+                Self::new_unchecked(bits::set_unused(bits, resolution))
+            })
+    }
+
+    /// Returns true if `prefix` is the cell itself or one of its ancestors.
+    ///
+    /// In other words, the path of `prefix` (base cell and directions) is a
+    /// prefix of the path of this cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let parent = index.parent(Resolution::Five).expect("parent");
+    /// assert!(index.starts_with(parent));
+    /// assert!(index.starts_with(index));
+    /// assert!(!parent.starts_with(index));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn starts_with(self, prefix: Self) -> bool {
+        self.parent(prefix.resolution()) == Some(prefix)
+    }
+
+    /// Returns the sibling of the cell (i.e. the child of the same parent) in
+    /// the given direction.
+    ///
+    /// Returns `None` for base cells, and for the deleted K direction of
+    /// pentagons.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, Direction};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// assert_eq!(
+    ///     index.sibling(Direction::IJ),
+    ///     CellIndex::try_from(0x8a1fb46622f7fff).ok()
+    /// );
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn sibling(self, direction: Direction) -> Option<Self> {
+        let resolution = self.resolution();
+        let parent = self.parent(resolution.pred()?)?;
+        if direction == Direction::K && parent.is_pentagon() {
+            return None;
+        }
+
+        let bits =
+            bits::set_direction(self.0.get(), direction.into(), resolution);
+        Some(Self::new_unchecked(bits))
+    }
+
+    /// Returns the other children of the parent of the cell.
+    ///
+    /// There are 6 siblings, or 5 if the parent is a pentagon, and none for
+    /// base cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// assert_eq!(index.siblings().count(), 6);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn siblings(self) -> impl Iterator<Item = Self> {
+        Direction::iter()
+            .filter_map(move |direction| self.sibling(direction))
+            .filter(move |&sibling| sibling != self)
     }
 
     /// Returns the center child index at the specified resolution.
@@ -439,6 +652,57 @@ impl CellIndex {
         })
     }
 
+    /// Returns the cell, at the specified resolution, containing the center
+    /// of this cell.
+    ///
+    /// Unlike [`Self::parent`] and [`Self::center_child`], which follow the
+    /// hierarchy of the grid, this is based on the actual position of the
+    /// center: since H3 cells don't exactly nest, the results may differ.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, LatLng, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let cell = index.recenter_at(Resolution::Five);
+    /// assert_eq!(cell, LatLng::from(index).to_cell(Resolution::Five));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn recenter_at(self, resolution: Resolution) -> Self {
+        if resolution == self.resolution() {
+            return self;
+        }
+        LatLng::from(self).to_cell(resolution)
+    }
+
+    /// Returns the cell, at the same resolution, containing the antipodal
+    /// point of the center of this cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, LatLng};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let antipode = index.antipode();
+    /// let distance = LatLng::from(index).distance_km(antipode.into());
+    /// assert!(distance > 20_000.);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn antipode(self) -> Self {
+        let ll = LatLng::from(self);
+        let lng = if ll.lng_radians() > 0. {
+            ll.lng_radians() - PI
+        } else {
+            ll.lng_radians() + PI
+        };
+
+        LatLng::new_unchecked(-ll.lat_radians(), lng).to_cell(self.resolution())
+    }
+
     /// Returns the exact number of children for a cell at a given resolution.
     ///
     /// # Example
@@ -567,9 +831,42 @@ impl CellIndex {
     #[must_use]
     pub fn child_at(
         self,
-        mut position: u64,
+        position: u64,
         resolution: Resolution,
     ) -> Option<Self> {
+        self.try_child_at(position, resolution).ok()
+    }
+
+    /// Returns the child cell at a given position within an ordered list of
+    /// all children at the specified resolution.
+    ///
+    /// # Errors
+    ///
+    /// [`CellLookupError::CoarserResolution`] if the resolution is coarser
+    /// than the one of the cell, and [`CellLookupError::OutOfRange`] if the
+    /// position is greater than or equal to the number of children.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{error::CellLookupError, CellIndex, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x881fb46623fffff)?;
+    /// assert_eq!(
+    ///     index.try_child_at(24, Resolution::Five),
+    ///     Err(CellLookupError::CoarserResolution)
+    /// );
+    /// assert_eq!(
+    ///     index.try_child_at(49, Resolution::Ten),
+    ///     Err(CellLookupError::OutOfRange)
+    /// );
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn try_child_at(
+        self,
+        mut position: u64,
+        resolution: Resolution,
+    ) -> Result<Self, CellLookupError> {
         #[expect(
             clippy::cast_possible_truncation,
             reason = "safe thx to assert"
@@ -579,10 +876,11 @@ impl CellIndex {
             bits::set_direction(bits, digit as u8, resolution)
         }
 
-        if resolution < self.resolution()
-            || position >= self.children_count(resolution)
-        {
-            return None;
+        if resolution < self.resolution() {
+            return Err(CellLookupError::CoarserResolution);
+        }
+        if position >= self.children_count(resolution) {
+            return Err(CellLookupError::OutOfRange);
         }
 
         let mut child = bits::set_resolution(self.0.get(), resolution);
@@ -614,7 +912,45 @@ impl CellIndex {
             position %= count;
         }
 
-        Some(Self::new_unchecked(child))
+        Ok(Self::new_unchecked(child))
+    }
+
+    /// Returns every `stride`-th child, at the specified resolution, of the
+    /// cell index.
+    ///
+    /// Children are yielded in child-position order (cf.
+    /// [`Self::child_position`]), starting with the first one, and are
+    /// computed directly from their position: the skipped children are never
+    /// enumerated, which makes it cheap to sample huge subtrees.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, Resolution};
+    /// use std::num::NonZeroU64;
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let stride = NonZeroU64::new(1000).expect("non-zero");
+    /// let samples = index
+    ///     .descendants_stride(Resolution::Fifteen, stride)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(samples.len(), 17);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn descendants_stride(
+        self,
+        resolution: Resolution,
+        stride: NonZeroU64,
+    ) -> impl Iterator<Item = Self> {
+        let count = self.children_count(resolution);
+        iter::successors(Some(0), move |&position: &u64| {
+            position.checked_add(stride.get())
+        })
+        .take_while(move |&position| position < count)
+        .map(move |position| {
+            self.child_at(position, resolution)
+                .expect("position within bounds")
+        })
     }
 
     /// Return the children, at the specified resolution, of the cell index.
@@ -635,6 +971,56 @@ impl CellIndex {
         Children::new(self, resolution)
     }
 
+    /// Returns the range of raw indexes containing the descendants of the
+    /// cell at the given resolution.
+    ///
+    /// Raw indexes are laid out, from the most significant bit, as: 1 reserved
+    /// bit, 4 bits of mode, 3 reserved bits, 4 bits of resolution, 7 bits of
+    /// base cell and 15 directions of 3 bits each (unused ones set to `7`).
+    /// Thus, in a store sorted by raw index (or by its big-endian bytes, e.g.
+    /// `u64::to_be_bytes`), the descendants at a given resolution are
+    /// contiguous, and `range.start()..=range.end()` is the exact span to
+    /// scan.
+    ///
+    /// Both bounds are valid cell indexes (the center child and the last
+    /// child). Note that for pentagons the range also spans indexes that are
+    /// not valid cells (the deleted `K` subsequence), which a store never
+    /// contains anyway.
+    ///
+    /// Since the resolution is part of the index, descendants at different
+    /// resolutions live in disjoint ranges: scanning the whole subtree
+    /// requires one range per resolution.
+    ///
+    /// Returns `None` if the resolution is coarser than the one of the cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let range = index.descendant_key_range(Resolution::Eleven);
+    /// assert_eq!(range, Some(0x8b1fb46622d8fff..=0x8b1fb46622defff));
+    /// assert!(index
+    ///     .children(Resolution::Eleven)
+    ///     .all(|child| range.as_ref().unwrap().contains(&u64::from(child))));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn descendant_key_range(
+        self,
+        resolution: Resolution,
+    ) -> Option<RangeInclusive<u64>> {
+        let start = u64::from(self.center_child(resolution)?);
+        let end = Resolution::range(self.resolution(), resolution)
+            .skip(1)
+            .fold(start, |bits, resolution| {
+                bits::set_direction(bits, Direction::IJ.into(), resolution)
+            });
+
+        Some(start..=end)
+    }
+
     /// Compresses a set of unique cell indexes all at the same resolution.
     ///
     /// The indexes are compressed by pruning full child branches to the parent
@@ -712,6 +1098,40 @@ impl CellIndex {
         Ok(())
     }
 
+    /// Compresses a sorted stream of unique cell indexes all at the same
+    /// resolution.
+    ///
+    /// Unlike [`Self::compact`], the cells are neither collected nor sorted:
+    /// compacted cells are produced incrementally, in order, as soon as they
+    /// can't be merged with the upcoming ones. The memory usage is bounded
+    /// (a few siblings per resolution), regardless of the size of the input.
+    ///
+    /// # Errors
+    ///
+    /// A [`CompactionError`] is returned, and the iteration stops, as soon as
+    /// a cell has a different resolution, is a duplicate or is out of order.
+    /// The previously returned cells are not guaranteed to be fully
+    /// compacted in such case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let parent = index.parent(Resolution::Nine).expect("parent");
+    /// let compacted =
+    ///     CellIndex::compact_streaming(parent.children(Resolution::Ten))
+    ///         .collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(compacted, vec![parent]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn compact_streaming(
+        cells: impl IntoIterator<Item = Self>,
+    ) -> impl Iterator<Item = Result<Self, CompactionError>> {
+        CompactStreaming::new(cells.into_iter())
+    }
+
     /// Computes the exact size of the uncompacted set of cells.
     ///
     /// # Example
@@ -756,8 +1176,82 @@ impl CellIndex {
             .flat_map(move |index| index.children(resolution))
     }
 
+    /// Computes the centroid of a set of cells, weighted by their area.
+    ///
+    /// The centroid is computed on the sphere (cf. [`weighted_centroid`]),
+    /// thus it's correct across the antimeridian and near the poles. Cells
+    /// can be at different resolutions (e.g. a compacted set).
+    ///
+    /// Returns `None` if the set is empty, or if the cells are evenly spread
+    /// over the globe (e.g. every base cell).
+    ///
+    /// Note that the centroid of a non-convex set may lie outside of it (cf.
+    /// [`Self::representative_point`]).
+    ///
+    /// [`weighted_centroid`]: crate::weighted_centroid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, LatLng};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let centroid = CellIndex::centroid_of(index.grid_disk::<Vec<_>>(2));
+    /// assert!(centroid.expect("centroid").distance_m(index.into()) < 1.);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn centroid_of(
+        cells: impl IntoIterator<Item = Self>,
+    ) -> Option<LatLng> {
+        geodesic::weighted_centroid(
+            cells.into_iter().map(|cell| (cell, cell.area_rads2())),
+        )
+    }
+
+    /// Returns a point guaranteed to be inside the shape of a set of cells.
+    ///
+    /// The point is the center of the cell closest to the centroid of the
+    /// set (cf. [`Self::centroid_of`]), so it's as central as possible while
+    /// being inside the set even if it isn't convex (or has holes).
+    ///
+    /// Returns `None` if the set is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::CellIndex;
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// // A ring: its centroid is in the hole.
+    /// let ring = index.grid_ring_fast(2).collect::<Option<Vec<_>>>();
+    /// let ring = ring.expect("ring");
+    ///
+    /// let point = CellIndex::representative_point(ring.iter().copied());
+    /// let point = point.expect("point");
+    /// assert!(ring.contains(&point.to_cell(index.resolution())));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn representative_point(
+        cells: impl IntoIterator<Item = Self>,
+    ) -> Option<LatLng> {
+        let cells = cells.into_iter().collect::<Vec<_>>();
+        let Some(centroid) = Self::centroid_of(cells.iter().copied()) else {
+            // Degenerate case: any cell will do.
+            return cells.first().copied().map(LatLng::from);
+        };
+
+        cells.into_iter().map(LatLng::from).min_by(|a, b| {
+            centroid
+                .distance_rads(*a)
+                .total_cmp(&centroid.distance_rads(*b))
+        })
+    }
+
     /// Computes the cell boundary, in spherical coordinates, of this index.
     ///
+    /// Vertices are in counter-clockwise order (cf.
+    /// [`Self::boundary_oriented`]).
+    ///
     /// # Example
     ///
     /// ```
@@ -777,6 +1271,99 @@ impl CellIndex {
         }
     }
 
+    /// Computes the cell boundary, in spherical coordinates, of this index
+    /// with the vertices in the given order.
+    ///
+    /// [`Self::boundary`] is always counter-clockwise, this allows to get
+    /// clockwise rings without relying on that. Distortion vertices (added on
+    /// the edges of cells crossing an icosahedron edge) stay in sequence
+    /// along their edge in both orientations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, Winding};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let ccw = index.boundary_oriented(Winding::CounterClockwise);
+    /// let cw = index.boundary_oriented(Winding::Clockwise);
+    /// assert!(ccw.iter().eq(cw.iter().rev()));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn boundary_oriented(self, winding: Winding) -> Boundary {
+        let mut boundary = self.boundary();
+        if winding == Winding::Clockwise {
+            boundary.reverse();
+        }
+        boundary
+    }
+
+    /// Computes the cell boundary, with additional points interpolated along
+    /// the great circle arcs between the vertices.
+    ///
+    /// Cell edges are geodesics: drawing them as straight lines between the
+    /// vertices (e.g. on a map) deviates visibly from the actual boundary at
+    /// low resolutions. Points are added so that no segment is longer than
+    /// `max_segment_rads` (a non-positive value disables the densification).
+    ///
+    /// As for [`Self::boundary`], vertices are in counter-clockwise order
+    /// and the ring isn't closed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x8009fffffffffff)?;
+    /// let boundary = index.boundary_densified(0.01);
+    /// assert!(boundary.len() > index.boundary().len());
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn boundary_densified(self, max_segment_rads: f64) -> Vec<LatLng> {
+        geodesic::densify(&self.boundary(), max_segment_rads, true)
+    }
+
+    /// Returns the center of the cell as a unit vector, in Cartesian
+    /// coordinates.
+    ///
+    /// Same as converting the center with [`LatLng::to_vec3`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, LatLng};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// assert_eq!(index.center_vec3(), LatLng::from(index).to_vec3());
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn center_vec3(self) -> Vec3d {
+        LatLng::from(self).to_vec3()
+    }
+
+    /// Returns the initial bearing of the great circle path from the center
+    /// of this cell to the center of `other`, in radians.
+    ///
+    /// See [`LatLng::azimuth_rads`] for the details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::CellIndex;
+    ///
+    /// let src = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let dst = CellIndex::try_from(0x8a1fb46622d7fff)?;
+    /// let bearing = src.bearing_to(dst).to_degrees();
+    /// assert!((0. ..360.).contains(&bearing));
+    /// assert_eq!(src.bearing_to(src), 0.);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn bearing_to(self, other: Self) -> f64 {
+        LatLng::from(self).azimuth_rads(LatLng::from(other))
+    }
+
     /// Returns all the base cell indexes.
     ///
     /// # Example
@@ -811,15 +1398,47 @@ impl CellIndex {
     /// ```
     #[must_use]
     pub fn edge(self, destination: Self) -> Option<DirectedEdgeIndex> {
+        self.try_edge(destination).ok()
+    }
+
+    /// Returns the edge between the current cell and the specified destination.
+    ///
+    /// # Errors
+    ///
+    /// [`CellLookupError::ResolutionMismatch`] if the cells have different
+    /// resolutions, and [`CellLookupError::NotNeighbor`] if they don't share
+    /// an edge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{error::CellLookupError, CellIndex};
+    ///
+    /// let src = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let dst = CellIndex::try_from(0x8a1fb4644937fff)?;
+    /// assert_eq!(src.try_edge(dst), Err(CellLookupError::NotNeighbor));
+    ///
+    /// let dst = CellIndex::try_from(0x851fb467fffffff)?;
+    /// assert_eq!(src.try_edge(dst), Err(CellLookupError::ResolutionMismatch));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn try_edge(
+        self,
+        destination: Self,
+    ) -> Result<DirectedEdgeIndex, CellLookupError> {
+        if self.resolution() != destination.resolution() {
+            return Err(CellLookupError::ResolutionMismatch);
+        }
+
         // Determine the IJK direction from the origin to the destination
-        grid::direction_for_neighbor(self, destination).map(|direction| {
-            let bits = bits::set_mode(u64::from(self), IndexMode::DirectedEdge);
-            // SAFETY: `direction_for_neighbor` always return valid edge value.
-            DirectedEdgeIndex::new_unchecked(bits::set_edge(
-                bits,
-                Edge::new_unchecked(direction.into()),
-            ))
-        })
+        let direction = grid::direction_for_neighbor(self, destination)
+            .ok_or(CellLookupError::NotNeighbor)?;
+        let bits = bits::set_mode(u64::from(self), IndexMode::DirectedEdge);
+        // SAFETY: `direction_for_neighbor` always return valid edge value.
+        Ok(DirectedEdgeIndex::new_unchecked(bits::set_edge(
+            bits,
+            Edge::new_unchecked(direction.into()),
+        )))
     }
 
     /// Returns all of the directed edges from the current index.
@@ -842,6 +1461,27 @@ impl CellIndex {
             })
     }
 
+    /// Returns the neighbors of the current index, along with the direction
+    /// of the edge they lie across.
+    ///
+    /// Pentagons have no neighbor in the `K` direction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// for (direction, neighbor) in index.neighbors_with_direction() {
+    ///     println!("{neighbor} is in direction {direction}");
+    /// }
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn neighbors_with_direction(
+        self,
+    ) -> impl Iterator<Item = (Direction, Self)> {
+        self.edges()
+            .map(|edge| (Direction::from(edge.edge()), edge.destination()))
+    }
+
     /// Get the specified vertex of this cell.
     ///
     /// # Example
@@ -858,12 +1498,38 @@ impl CellIndex {
     /// ```
     #[must_use]
     pub fn vertex(self, vertex: Vertex) -> Option<VertexIndex> {
+        self.try_vertex(vertex).ok()
+    }
+
+    /// Get the specified vertex of this cell.
+    ///
+    /// # Errors
+    ///
+    /// [`CellLookupError::OutOfRange`] if the cell doesn't have such vertex
+    /// (i.e. the sixth vertex of a pentagon).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{error::CellLookupError, CellIndex, Vertex};
+    ///
+    /// let pentagon = CellIndex::try_from(0x8009fffffffffff)?;
+    /// assert_eq!(
+    ///     pentagon.try_vertex(Vertex::try_from(5)?),
+    ///     Err(CellLookupError::OutOfRange)
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_vertex(
+        self,
+        vertex: Vertex,
+    ) -> Result<VertexIndex, CellLookupError> {
         let vertex_count = self.vertex_count();
         let resolution = self.resolution();
 
         // Check for invalid vertexes.
         if u8::from(vertex) >= vertex_count {
-            return None;
+            return Err(CellLookupError::OutOfRange);
         }
 
         // Default the owner and vertex number to current cell.
@@ -950,7 +1616,7 @@ impl CellIndex {
 
         // Create the vertex index
         let bits = bits::set_mode(owner.into(), IndexMode::Vertex);
-        Some(VertexIndex::new_unchecked(bits::set_vertex(
+        Ok(VertexIndex::new_unchecked(bits::set_vertex(
             bits,
             owner_vertex,
         )))
@@ -1073,8 +1739,46 @@ impl CellIndex {
             .unwrap_or_else(|| self.grid_disk_distances_safe(k).collect())
     }
 
+    /// Produce cells within distance `k` of the current cell, weighted by the
+    /// given kernel according to their distance.
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidKernel`] if the kernel parameters
+    /// are invalid (e.g. a gaussian with a null standard deviation).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, Kernel};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let weights = index.grid_disk_weighted::<Vec<_>>(2, Kernel::Linear)?;
+    /// assert_eq!(weights[0], (index, 1.));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn grid_disk_weighted<T>(
+        self,
+        k: u32,
+        kernel: Kernel,
+    ) -> Result<T, InvalidKernel>
+    where
+        T: FromIterator<(Self, f64)>,
+    {
+        let kernel = kernel.validate()?;
+
+        Ok(self
+            .grid_disk_distances::<Vec<_>>(k)
+            .into_iter()
+            .map(|(cell, distance)| (cell, kernel.weight(distance, k)))
+            .collect())
+    }
+
     /// Safe but slow version of [`Self::grid_disk_distances_fast`].
     ///
+    /// Cells are grouped by increasing distance: every cell at distance `d` is
+    /// produced before any cell at distance `d + 1`.
+    ///
     /// # Example
     ///
     /// ```
@@ -1092,70 +1796,279 @@ impl CellIndex {
         Either::Left(grid::DiskDistancesSafe::new(self, k))
     }
 
+    /// Produces the cells within distance `k` of the current cell, one ring
+    /// at a time.
+    ///
+    /// The `n`-th item contains the cells at exactly grid distance `n`
+    /// (starting with the current cell alone), unlike
+    /// [`Self::grid_ring_fast`] this never fails around pentagons.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let rings = index.grid_rings(2).collect::<Vec<_>>();
+    /// assert_eq!(rings.len(), 3);
+    /// assert_eq!(rings[0], vec![index]);
+    /// assert_eq!(rings[2].len(), 12);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn grid_rings(self, k: u32) -> impl Iterator<Item = Vec<Self>> {
+        let mut cells = self.grid_disk_distances::<Vec<_>>(k);
+        // Both algorithms already group by ring, stable sort is cheap then.
+        cells.sort_by_key(|&(_, distance)| distance);
+
+        let mut cells = cells.into_iter().peekable();
+        iter::from_fn(move || {
+            let (cell, distance) = cells.next()?;
+            let mut ring = Vec::from([cell]);
+            while let Some((cell, _)) =
+                cells.next_if(|&(_, other)| other == distance)
+            {
+                ring.push(cell);
+            }
+            Some(ring)
+        })
+    }
+
     /// Produce cells and their distances from the current cell, up to distance
     /// `k`.
     ///
     /// `0-ring` is defined as the current cell, `1-ring` is defined as `0-ring`
     /// and all neighboring indexes, and so on.
     ///
-    /// This function fails (i.e. returns a None item) when a pentagon (or a
-    /// pentagon distortion) is encountered.
-    /// When this happen, the previously returned items should be treated as
-    /// invalid.
+    /// This function fails (i.e. returns a None item) when a pentagon (or a
+    /// pentagon distortion) is encountered.
+    /// When this happen, the previously returned items should be treated as
+    /// invalid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let cells_and_dists = index.grid_disk_distances_fast(2)
+    ///     .collect::<Option<Vec<_>>>()
+    ///     .unwrap_or_default();
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn grid_disk_distances_fast(
+        self,
+        k: u32,
+    ) -> impl Iterator<Item = Option<(Self, u32)>> {
+        if k == 0 {
+            return Either::Right(if self.is_pentagon() {
+                Either::Right(iter::once(None))
+            } else {
+                Either::Left(iter::once(Some((self, 0))))
+            });
+        }
+        Either::Left(grid::DiskDistancesUnsafe::new(self, k))
+    }
+
+    /// Takes an list of cell indexes and a max `k-ring` and returns a stream of
+    /// cell indexes sorted first by the original cell index and then by the
+    /// grid `k-ring` (0 to max).
+    ///
+    ///
+    /// This function fails (i.e. returns a None item) when a pentagon (or a
+    /// pentagon distortion) is encountered.
+    /// When this happen, the previously returned cells should be treated as
+    /// invalid and discarded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::CellIndex;
+    ///
+    /// let indexes = vec![
+    ///     CellIndex::try_from(0x8a1fb46622dffff)?,
+    ///     CellIndex::try_from(0x8a1fb46622d7fff)?,
+    /// ];
+    /// let cells = CellIndex::grid_disks_fast(indexes, 2)
+    ///     .collect::<Option<Vec<_>>>()
+    ///     .unwrap_or_default();
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    pub fn grid_disks_fast(
+        indexes: impl IntoIterator<Item = Self>,
+        k: u32,
+    ) -> impl Iterator<Item = Option<Self>> {
+        indexes
+            .into_iter()
+            .flat_map(move |index| index.grid_disk_fast(k))
+    }
+
+    /// Returns the cells within `k` steps of any of the given origins, without
+    /// duplicates.
+    ///
+    /// Unlike [`Self::grid_disks_fast`], this function never fails: origins
+    /// near a pentagon fall back on the safe (but slower) algorithm, without
+    /// impacting the others.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::CellIndex;
+    /// use std::collections::HashSet;
+    ///
+    /// let indexes = vec![
+    ///     CellIndex::try_from(0x8a1fb46622dffff)?,
+    ///     CellIndex::try_from(0x8a1fb46622d7fff)?,
+    /// ];
+    /// let cells = CellIndex::grid_disks::<HashSet<_>>(indexes, 2);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn grid_disks<T>(origins: impl IntoIterator<Item = Self>, k: u32) -> T
+    where
+        T: FromIterator<Self>,
+    {
+        let mut seen = Set::new();
+        Self::grid_disks_grouped(origins, k)
+            .flat_map(|(_, cells)| cells)
+            .filter(|&cell| seen.insert(cell))
+            .collect()
+    }
+
+    /// Returns the cells within `k` steps of each of the given origins.
+    ///
+    /// Cells are grouped by origin, in the order of the input, and a cell
+    /// may appear in several groups.
+    ///
+    /// Like [`Self::grid_disks`], this function never fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::CellIndex;
+    ///
+    /// let indexes = vec![
+    ///     CellIndex::try_from(0x8a1fb46622dffff)?,
+    ///     CellIndex::try_from(0x8a1fb46622d7fff)?,
+    /// ];
+    /// for (origin, cells) in CellIndex::grid_disks_grouped(indexes, 2) {
+    ///     assert_eq!(cells.len(), 19);
+    /// }
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn grid_disks_grouped(
+        origins: impl IntoIterator<Item = Self>,
+        k: u32,
+    ) -> impl Iterator<Item = (Self, Vec<Self>)> {
+        origins
+            .into_iter()
+            .map(move |origin| (origin, origin.grid_disk(k)))
+    }
+
+    /// Returns the cells within `k` steps of each of the given origins, as a
+    /// parallel iterator.
+    ///
+    /// This is the parallel counterpart of [`Self::grid_disks_grouped`]:
+    /// collecting the iterator preserves the order of the input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::CellIndex;
+    /// use rayon::iter::ParallelIterator;
+    ///
+    /// let indexes = vec![
+    ///     CellIndex::try_from(0x8a1fb46622dffff)?,
+    ///     CellIndex::try_from(0x8a1fb46622d7fff)?,
+    /// ];
+    /// let disks = CellIndex::par_grid_disks_grouped(indexes, 2)
+    ///     .collect::<Vec<_>>();
+    /// assert!(disks.iter().all(|(_, cells)| cells.len() == 19));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[must_use]
+    pub fn par_grid_disks_grouped(
+        origins: impl IntoParallelIterator<Item = Self>,
+        k: u32,
+    ) -> impl ParallelIterator<Item = (Self, Vec<Self>)> {
+        origins
+            .into_par_iter()
+            .map(move |origin| (origin, origin.grid_disk(k)))
+    }
+
+    /// Returns the cells whose center lies within `distance_m` meters of the
+    /// current cell (the cell itself included).
+    ///
+    /// The distance is measured from the boundary of the cell, along great
+    /// circles, which makes it suitable for metric radii where deriving `k`
+    /// for [`Self::grid_disk`] would be imprecise (cells size varies across
+    /// the globe).
     ///
     /// # Example
     ///
     /// ```
-    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
-    /// let cells_and_dists = index.grid_disk_distances_fast(2)
-    ///     .collect::<Option<Vec<_>>>()
-    ///     .unwrap_or_default();
+    /// use h3o::CellIndex;
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let cells = index.buffer_m::<Vec<_>>(500.);
+    /// assert!(cells.contains(&index));
     /// # Ok::<(), h3o::error::InvalidCellIndex>(())
     /// ```
-    pub fn grid_disk_distances_fast(
-        self,
-        k: u32,
-    ) -> impl Iterator<Item = Option<(Self, u32)>> {
-        if k == 0 {
-            return Either::Right(if self.is_pentagon() {
-                Either::Right(iter::once(None))
-            } else {
-                Either::Left(iter::once(Some((self, 0))))
-            });
+    #[must_use]
+    pub fn buffer_m<T>(self, distance_m: f64) -> T
+    where
+        T: FromIterator<Self>,
+    {
+        let distance_rads = distance_m / 1000. / EARTH_RADIUS_KM;
+        let boundary = self.boundary();
+        let is_within = |cell: Self| {
+            let center = LatLng::from(cell);
+            (0..boundary.len()).any(|i| {
+                let (a, b) = (boundary[i], boundary[(i + 1) % boundary.len()]);
+                geodesic::arc_distance_rads(center, a, b) <= distance_rads
+            })
+        };
+
+        // Grow from the cell, as long as the centers are close enough.
+        let mut cells = vec![self];
+        let mut seen = Set::new();
+        seen.insert(self);
+        let mut i = 0;
+        while let Some(&cell) = cells.get(i) {
+            for neighbor in cell.grid_disk_safe(1) {
+                if seen.insert(neighbor) && is_within(neighbor) {
+                    cells.push(neighbor);
+                }
+            }
+            i += 1;
         }
-        Either::Left(grid::DiskDistancesUnsafe::new(self, k))
+
+        cells.into_iter().collect()
     }
 
-    /// Takes an list of cell indexes and a max `k-ring` and returns a stream of
-    /// cell indexes sorted first by the original cell index and then by the
-    /// grid `k-ring` (0 to max).
-    ///
+    /// Produce the "hollow" ring of cells at exactly grid distance `k` from the
+    /// current cell.
     ///
-    /// This function fails (i.e. returns a None item) when a pentagon (or a
-    /// pentagon distortion) is encountered.
-    /// When this happen, the previously returned cells should be treated as
-    /// invalid and discarded.
+    /// This function is a convenience helper that tries
+    /// [`Self::grid_ring_fast`] first and then fallback on a slower, but
+    /// always correct, algorithm if the former fails.
     ///
     /// # Example
     ///
     /// ```
-    /// use h3o::CellIndex;
-    ///
-    /// let indexes = vec![
-    ///     CellIndex::try_from(0x8a1fb46622dffff)?,
-    ///     CellIndex::try_from(0x8a1fb46622d7fff)?,
-    /// ];
-    /// let cells = CellIndex::grid_disks_fast(indexes, 2)
-    ///     .collect::<Option<Vec<_>>>()
-    ///     .unwrap_or_default();
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let cells = index.grid_ring::<Vec<_>>(2);
+    /// assert_eq!(cells.len(), 12);
     /// # Ok::<(), h3o::error::InvalidCellIndex>(())
-    pub fn grid_disks_fast(
-        indexes: impl IntoIterator<Item = Self>,
-        k: u32,
-    ) -> impl Iterator<Item = Option<Self>> {
-        indexes
-            .into_iter()
-            .flat_map(move |index| index.grid_disk_fast(k))
+    /// ```
+    #[must_use]
+    pub fn grid_ring<T>(self, k: u32) -> T
+    where
+        T: FromIterator<Self>,
+    {
+        self.grid_ring_fast(k)
+            .collect::<Option<T>>()
+            .unwrap_or_else(|| {
+                self.grid_disk_distances_safe(k)
+                    .filter_map(|(cell, distance)| {
+                        (distance == k).then_some(cell)
+                    })
+                    .collect()
+            })
     }
 
     /// Returns the "hollow" ring of hexagons at exactly grid distance `k` from
@@ -1218,6 +2131,55 @@ impl CellIndex {
         Ok(src.coord().distance(dst.coord()))
     }
 
+    /// Returns the grid distance from the current cell to the nearest cell of
+    /// a set, or `None` if there is none within distance `max_k`.
+    ///
+    /// The set is given as a membership test (e.g. `|cell| set.contains(&cell)`)
+    /// and is probed one ring at a time, starting with the current cell, so
+    /// the cost only depends on the distance to the nearest member, not on
+    /// the size of the set.
+    ///
+    /// Unlike [`Self::grid_distance`], this never fails around pentagons.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::CellIndex;
+    /// use std::collections::HashSet;
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let set = HashSet::from([CellIndex::try_from(0x8a1fb46622d7fff)?]);
+    /// let contains = |cell| set.contains(&cell);
+    /// assert_eq!(index.grid_distance_to_set(contains, 10), Some(1));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn grid_distance_to_set(
+        self,
+        mut contains: impl FnMut(Self) -> bool,
+        max_k: u32,
+    ) -> Option<u32> {
+        let mut ring = vec![self];
+        let mut seen = Set::new();
+        seen.insert(self);
+
+        for k in 0..=max_k {
+            if ring.iter().any(|&cell| contains(cell)) {
+                return Some(k);
+            }
+            if k == max_k {
+                break;
+            }
+            ring = ring
+                .into_iter()
+                .flat_map(|cell| cell.grid_disk_safe(1))
+                .filter(|&cell| seen.insert(cell))
+                .collect();
+        }
+
+        None
+    }
+
     /// Computes the number of indexes in a line from the current index to the
     /// end one.
     ///
@@ -1282,6 +2244,61 @@ impl CellIndex {
         GridPathCells::new(self, to)
     }
 
+    /// Returns the cheapest path of cells between the given cells (both
+    /// included), along with its total cost.
+    ///
+    /// The cost of each step (from a cell to one of its neighbors) is given by
+    /// `cost`, which allows per-cell costs (e.g. from the destination cell),
+    /// per-edge costs (e.g. through [`EdgeWeights::cost`]) as well as blocked
+    /// cells or edges (by returning `None`). Negative and NaN costs are
+    /// treated as blocked steps.
+    ///
+    /// The search uses the A* algorithm, where `min_step_cost` is a lower
+    /// bound of the cost of any step (used to scale the grid distance
+    /// heuristic). A bound larger than the actual costs may lead to a
+    /// suboptimal path, while 0 is always safe (but explores more cells).
+    ///
+    /// Returns `None` if the destination can't be reached (e.g. resolution
+    /// mismatch, or every path is blocked).
+    ///
+    /// Note that when the destination is unreachable, every reachable cell
+    /// is explored: the cost function should block the cells outside of the
+    /// area of interest.
+    ///
+    /// [`EdgeWeights::cost`]: crate::EdgeWeights::cost
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::CellIndex;
+    ///
+    /// let src = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let dst = src.grid_disk::<Vec<_>>(3).pop().expect("dst");
+    /// let area = src.grid_disk::<Vec<_>>(5);
+    ///
+    /// // Block the straight line.
+    /// let line = src.grid_path_cells(dst)?.collect::<Result<Vec<_>, _>>()?;
+    /// let blocked = &line[1..line.len() - 1];
+    /// let (path, cost) = src
+    ///     .grid_path_weighted(dst, 1., |_, to| {
+    ///         (area.contains(&to) && !blocked.contains(&to)).then_some(1.)
+    ///     })
+    ///     .expect("reachable");
+    /// assert_eq!(path.first(), Some(&src));
+    /// assert_eq!(path.last(), Some(&dst));
+    /// assert!(path.len() > line.len());
+    /// assert_eq!(cost, (path.len() - 1) as f64);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn grid_path_weighted(
+        self,
+        to: Self,
+        min_step_cost: f64,
+        cost: impl FnMut(Self, Self) -> Option<f64>,
+    ) -> Option<(Vec<Self>, f64)> {
+        grid::shortest_path(self, to, min_step_cost, cost)
+    }
+
     /// Returns whether or not the provided cell index is a neighbor of the
     /// current one.
     ///
@@ -1405,6 +2422,44 @@ impl CellIndex {
         Ok(LocalIJ::new(lijk.anchor, coord))
     }
 
+    /// Returns the cell reached by moving `di` steps along the `I` axis and
+    /// `dj` steps along the `J` axis, in the local `IJ` coordinate system
+    /// anchored by the current cell.
+    ///
+    /// This function's output is not guaranteed to be compatible across
+    /// different versions of H3.
+    ///
+    /// # Errors
+    ///
+    /// Failure may occur if the target is too far away or if the move crosses
+    /// a pentagon distortion.
+    /// In such case, [`LocalIjError::Pentagon`] or [`LocalIjError::HexGrid`] is
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::CellIndex;
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let moved = index.grid_move(1, 0)?;
+    /// assert_eq!(index.grid_distance(moved)?, 1);
+    /// assert_eq!(moved.grid_move(-1, 0)?, index);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn grid_move(self, di: i32, dj: i32) -> Result<Self, LocalIjError> {
+        let origin = self.to_local_ij(self)?;
+        let coord = origin
+            .coord
+            .i
+            .checked_add(di)
+            .zip(origin.coord.j.checked_add(dj))
+            .map(|(i, j)| CoordIJ::new(i, j))
+            .ok_or_else(|| HexGridError::new("IJ coordinates overflow"))?;
+
+        Self::try_from(LocalIJ::new(origin.anchor, coord))
+    }
+
     /// Returns the next cell, in term of ordering.
     ///
     /// Returns `None` if `self` is the last cell at this resolution.
@@ -1429,8 +2484,11 @@ impl CellIndex {
 
         // Find the first non-IJ direction (e.g. can be ++ w/o carry).
         // First in term of bit offset, then convert to resolution offset.
+        // Capped at the resolution: the carry must not spill over the base
+        // cell and resolution bits, even if they look like IJ directions.
         let bitpos = (bits ^ IJ_MASK).trailing_zeros() as usize;
-        let respos = bitpos / h3o_bit::DIRECTION_BITSIZE;
+        let respos =
+            (bitpos / h3o_bit::DIRECTION_BITSIZE).min(usize::from(resolution));
 
         // Clear directions affected by the carry propagation.
         let mask = !((1 << (respos * h3o_bit::DIRECTION_BITSIZE)) - 1);
@@ -1479,8 +2537,11 @@ impl CellIndex {
 
         // Find the first non-zero direction (e.g. can be -- w/o carry).
         // First in term of bit offset, then convert to resolution offset.
+        // Capped at the resolution: the carry must not spill over the base
+        // cell and resolution bits.
         let bitpos = bits.trailing_zeros() as usize;
-        let respos = bitpos / h3o_bit::DIRECTION_BITSIZE;
+        let respos =
+            (bitpos / h3o_bit::DIRECTION_BITSIZE).min(usize::from(resolution));
 
         // Set directions affected by the carry propagation.
         let mask = (1 << (respos * h3o_bit::DIRECTION_BITSIZE)) - 1;
@@ -1538,6 +2599,318 @@ impl CellIndex {
         Self::new_unchecked(bits::set_unused(bits, resolution))
     }
 
+    /// Returns every cell index at the given resolution, in order (i.e. from
+    /// [`Self::first`] to [`Self::last`]).
+    ///
+    /// The iterator knows its exact length (cf. [`Resolution::cell_count`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, Resolution};
+    ///
+    /// let cells = CellIndex::all_at(Resolution::One);
+    /// assert_eq!(cells.len(), 842);
+    /// assert_eq!(cells.filter(|cell| cell.is_pentagon()).count(), 12);
+    /// ```
+    #[must_use]
+    pub fn all_at(
+        resolution: Resolution,
+    ) -> impl ExactSizeIterator<Item = Self> {
+        AllAt::new(resolution)
+    }
+
+    /// Returns every cell index at the given resolution, as a parallel
+    /// iterator.
+    ///
+    /// The work is split by base cell and digit prefixes, allowing
+    /// multi-core global sweeps. Collecting the iterator preserves the order
+    /// of [`Self::all_at`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, Resolution};
+    /// use rayon::iter::ParallelIterator;
+    ///
+    /// let pentagons = CellIndex::par_all_at(Resolution::Three)
+    ///     .filter(|cell| cell.is_pentagon())
+    ///     .count();
+    /// assert_eq!(pentagons, 12);
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[must_use]
+    pub fn par_all_at(
+        resolution: Resolution,
+    ) -> impl ParallelIterator<Item = Self> {
+        // Resolution 2 gives ~6k prefixes: enough to balance the load.
+        let prefixes =
+            AllAt::new(resolution.min(Resolution::Two)).collect::<Vec<_>>();
+        prefixes
+            .into_par_iter()
+            .flat_map_iter(move |prefix| prefix.children(resolution))
+    }
+
+    /// Returns a dense key for the cell at the given resolution.
+    ///
+    /// The key only contains the base cell followed by exactly `resolution`
+    /// directions (3 bits each), without the mode, the resolution nor the
+    /// unused directions. As such, it's only meaningful for a given resolution
+    /// (which must be provided back to [`Self::unpack`]).
+    ///
+    /// If `resolution` is coarser than the cell's resolution, the key of its
+    /// parent at `resolution` is returned. If it's finer, the key of its center
+    /// child at `resolution` is returned.
+    ///
+    /// Keys preserve the cell ordering, and the key of a parent is a prefix of
+    /// the key of its children (e.g. `child_key >> 3 == parent_key` for a
+    /// direct child), making them suitable for compact columns or trie keys.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let key = index.pack(Resolution::Ten);
+    /// assert_eq!(key, 0o176643142133);
+    /// assert_eq!(CellIndex::unpack(key, Resolution::Ten)?, index);
+    ///
+    /// assert_eq!(index.pack(Resolution::Nine), key >> 3);
+    /// assert_eq!(index.pack(Resolution::Eleven), key << 3);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn pack(self, resolution: Resolution) -> u64 {
+        let coarsest = resolution.min(self.resolution());
+        let width = usize::from(coarsest) * h3o_bit::DIRECTION_BITSIZE;
+        let directions = (self.0.get() & bits::DIRECTIONS_MASK)
+            >> coarsest.direction_offset();
+        let key = (u64::from(u8::from(self.base_cell())) << width) | directions;
+
+        // Pad with center directions down to the requested resolution.
+        let padding = usize::from(resolution) - usize::from(coarsest);
+        key << (padding * h3o_bit::DIRECTION_BITSIZE)
+    }
+
+    /// Rebuilds a cell index from a dense key produced by [`Self::pack`].
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidCellIndex`] if the key doesn't represent a valid cell at the
+    /// given resolution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, Resolution};
+    ///
+    /// let index = CellIndex::unpack(0o176643142133, Resolution::Ten)?;
+    /// assert_eq!(index, CellIndex::try_from(0x8a1fb46622dffff)?);
+    ///
+    /// // Directions must be in [0; 6].
+    /// assert!(CellIndex::unpack(0o177, Resolution::One).is_err());
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn unpack(
+        key: u64,
+        resolution: Resolution,
+    ) -> Result<Self, InvalidCellIndex> {
+        let width = usize::from(resolution) * h3o_bit::DIRECTION_BITSIZE;
+        let base_cell = u8::try_from(key >> width)
+            .ok()
+            .and_then(|value| BaseCell::try_from(value).ok())
+            .ok_or_else(|| {
+                InvalidCellIndex::new(Some(key), "invalid base cell")
+            })?;
+        let directions = key & ((1 << width) - 1);
+
+        let bits = h3o_bit::set_base_cell(DEFAULT_CELL_INDEX, base_cell.into());
+        let bits = bits::set_resolution(bits, resolution);
+        let offset = resolution.direction_offset();
+        let bits =
+            (bits & !(((1 << width) - 1) << offset)) | (directions << offset);
+
+        Self::try_from(bits)
+            .map_err(|err| InvalidCellIndex::new(Some(key), err.reason))
+    }
+
+    /// Builds a cell index from its base cell and the directions leading to
+    /// it, one per resolution (so the resolution of the cell is the number of
+    /// directions).
+    ///
+    /// This is the inverse of [`Self::path`].
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidCellIndex`] if there are more than 15 directions, or if the
+    /// path doesn't lead to a valid cell (e.g. the `K` child of a pentagon).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{BaseCell, CellIndex, Direction};
+    ///
+    /// let base = BaseCell::try_from(15)?;
+    /// let path = [Direction::K, Direction::IK, Direction::Center];
+    /// let index = CellIndex::from_path(base, &path)?;
+    /// assert!(index.path().eq(path));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_path(
+        base: BaseCell,
+        directions: &[Direction],
+    ) -> Result<Self, InvalidCellIndex> {
+        let resolution = u8::try_from(directions.len())
+            .ok()
+            .and_then(|value| Resolution::try_from(value).ok())
+            .ok_or_else(|| InvalidCellIndex::new(None, "path too long"))?;
+
+        let bits = h3o_bit::set_base_cell(DEFAULT_CELL_INDEX, base.into());
+        let bits = Resolution::range(Resolution::One, resolution)
+            .zip(directions)
+            .fold(
+                bits::set_resolution(bits, resolution),
+                |bits, (resolution, &direction)| {
+                    bits::set_direction(bits, direction.into(), resolution)
+                },
+            );
+
+        Self::try_from(bits)
+            .map_err(|err| InvalidCellIndex::new(None, err.reason))
+    }
+
+    /// Returns a fixed-length string token for the cell, suitable for
+    /// storage in tools that can only sort or filter strings (e.g. BI tools
+    /// or data warehouses).
+    ///
+    /// The token is made of 18 ASCII digits: the base cell (3 decimal
+    /// digits), followed by the 15 directions (1 octal digit each), where the
+    /// unused ones are set to `7`. Thus:
+    /// - tokens collate (bytewise) like cell indexes (cf. the `Ord` impl).
+    /// - the tokens of the descendants of a cell, at any resolution, start
+    ///   with the [`Self::token_prefix`] of the cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::CellIndex;
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// assert_eq!(index.to_token(), "015664314213377777");
+    /// assert_eq!(CellIndex::from_token(&index.to_token())?, index);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn to_token(self) -> String {
+        format!(
+            "{:03}{:015o}",
+            u8::from(self.base_cell()),
+            u64::from(self) & bits::DIRECTIONS_MASK
+        )
+    }
+
+    /// Returns the prefix shared by the tokens of the cell and of all its
+    /// descendants.
+    ///
+    /// See [`Self::to_token`] for the details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let parent = index.parent(Resolution::Five).expect("parent");
+    /// assert_eq!(parent.token_prefix(), "01566431");
+    /// assert!(index.to_token().starts_with(&parent.token_prefix()));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn token_prefix(self) -> String {
+        let mut token = self.to_token();
+        token.truncate(TOKEN_BASE_CELL_LEN + usize::from(self.resolution()));
+        token
+    }
+
+    /// Parses a token produced by [`Self::to_token`].
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidCellIndex`] if the token is malformed or doesn't represent a
+    /// valid cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::CellIndex;
+    ///
+    /// let index = CellIndex::from_token("015664314213377777")?;
+    /// assert_eq!(index, CellIndex::try_from(0x8a1fb46622dffff)?);
+    /// assert!(CellIndex::from_token("015755034213").is_err());
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn from_token(token: &str) -> Result<Self, InvalidCellIndex> {
+        let invalid = || InvalidCellIndex::new(None, "invalid token");
+        if token.len() != TOKEN_LEN
+            || !token.bytes().all(|byte| byte.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+        let (base_cell, directions) = token.split_at(TOKEN_BASE_CELL_LEN);
+        let base_cell = base_cell
+            .parse::<u8>()
+            .ok()
+            .and_then(|value| BaseCell::try_from(value).ok())
+            .ok_or_else(invalid)?;
+        let directions =
+            u64::from_str_radix(directions, 8).map_err(|_| invalid())?;
+        // Unused directions are trailing 7s, which is never a valid one.
+        let unused = directions.trailing_ones() / 3;
+        let resolution =
+            u8::try_from(u32::from(h3o_bit::MAX_RESOLUTION) - unused)
+                .ok()
+                .and_then(|value| Resolution::try_from(value).ok())
+                .ok_or_else(invalid)?;
+
+        let bits = h3o_bit::set_base_cell(DEFAULT_CELL_INDEX, base_cell.into());
+        let bits = bits::set_resolution(bits, resolution);
+        let bits = (bits & !bits::DIRECTIONS_MASK) | directions;
+
+        Self::try_from(bits)
+            .map_err(|err| InvalidCellIndex::new(Some(bits), err.reason))
+    }
+
+    /// Returns a well-mixed 64-bit hash of the cell index.
+    ///
+    /// The raw index is a poor hash value: most of its high bits are constant
+    /// and, for coarse resolutions, the low bits are all set to `1`. This
+    /// function scrambles the bits (through the finalizer of `MurmurHash3`)
+    /// while staying bijective, so two different cells never share a key.
+    ///
+    /// This is also the value fed to the hasher by the `Hash` implementation,
+    /// which makes identity hashers (e.g. `nohash-hasher`) usable with cell
+    /// indexes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let other = h3o::CellIndex::try_from(0x8a1fb46622d7fff)?;
+    /// assert_ne!(index.hash_key(), other.hash_key());
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub const fn hash_key(self) -> u64 {
+        let mut key = self.0.get();
+        key ^= key >> 33;
+        key = key.wrapping_mul(0xff51_afd7_ed55_8ccd);
+        key ^= key >> 33;
+        key = key.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+        key ^= key >> 33;
+        key
+    }
+
     pub(crate) fn new_unchecked(value: u64) -> Self {
         debug_assert!(Self::try_from(value).is_ok(), "invalid cell index");
         Self(NonZeroU64::new(value).expect("valid cell index"))
@@ -1749,6 +3122,15 @@ impl CellIndex {
     }
 }
 
+impl Hash for CellIndex {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash_key());
+    }
+}
+
+#[cfg(feature = "nohash")]
+impl nohash_hasher::IsEnabled for CellIndex {}
+
 impl Ord for CellIndex {
     fn cmp(&self, other: &Self) -> Ordering {
         // Compare while ignoring the resolution to get the right ordering.
@@ -1982,6 +3364,26 @@ impl From<CellIndex> for geo::Polygon {
     }
 }
 
+#[cfg(feature = "geozero")]
+impl geozero::GeozeroGeometry for CellIndex {
+    fn process_geom<P: geozero::GeomProcessor>(
+        &self,
+        processor: &mut P,
+    ) -> geozero::error::Result<()> {
+        let boundary = self.boundary();
+        // Close the ring, as expected by most formats.
+        let ring = boundary.iter().chain(boundary.first());
+
+        processor.polygon_begin(true, 1, 0)?;
+        processor.linestring_begin(false, boundary.len() + 1, 0)?;
+        for (idx, ll) in ring.enumerate() {
+            crate::coord::process_coord(*ll, idx, processor)?;
+        }
+        processor.linestring_end(false, 0)?;
+        processor.polygon_end(true, 0)
+    }
+}
+
 #[cfg(feature = "arbitrary")]
 impl<'a> arbitrary::Arbitrary<'a> for CellIndex {
     fn arbitrary(
@@ -1,8 +1,11 @@
 use super::{bits, IndexMode};
 use crate::{
-    coord::FaceIJK, error, grid, Boundary, CellIndex, Direction,
-    EARTH_RADIUS_KM,
+    coord::FaceIJK, error, geodesic, grid, Boundary, CellIndex, Direction,
+    DistanceMethod, LatLng, EARTH_RADIUS_KM,
 };
+#[cfg(feature = "geo")]
+use crate::{Vertex, VertexIndex};
+use alloc::vec::Vec;
 use core::{cmp::Ordering, fmt, num::NonZeroU64, str::FromStr};
 
 /// Minimum value for a cell edge.
@@ -197,6 +200,39 @@ impl DirectedEdgeIndex {
         }
     }
 
+    /// Returns the coordinates defining the directed edge, with additional
+    /// points interpolated along the great circle arcs between them.
+    ///
+    /// Points are added so that no segment is longer than `max_segment_rads`
+    /// (a non-positive value disables the densification).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::DirectedEdgeIndex::try_from(0x13a194e699ab7fff)?;
+    /// let boundary = index.boundary_densified(1e-5);
+    /// assert!(boundary.len() > index.boundary().len());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn boundary_densified(self, max_segment_rads: f64) -> Vec<LatLng> {
+        geodesic::densify(&self.boundary(), max_segment_rads, false)
+    }
+
+    /// Returns the topological vertexes (start and end) of the directed edge.
+    #[cfg(feature = "geo")]
+    pub(crate) fn vertexes(self) -> (VertexIndex, VertexIndex) {
+        let origin = self.origin();
+        let vertex_count = if origin.is_pentagon() { 5 } else { 6 };
+        let start = Direction::from(self.edge()).vertex(origin);
+        let end = Vertex::new_unchecked((u8::from(start) + 1) % vertex_count);
+
+        (
+            origin.vertex(start).expect("start vertex"),
+            origin.vertex(end).expect("end vertex"),
+        )
+    }
+
     /// Computes the length of this directed edge, in radians.
     ///
     /// # Example
@@ -243,6 +279,32 @@ impl DirectedEdgeIndex {
         self.length_km() * 1000.
     }
 
+    /// Computes the length of this directed edge on the WGS84 ellipsoid, in
+    /// meters.
+    ///
+    /// Unlike [`Self::length_m`], which works on the authalic sphere, this
+    /// uses Vincenty's formula (see [`DistanceMethod::Vincenty`]): slower, but
+    /// more accurate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::DirectedEdgeIndex::try_from(0x13a194e699ab7fff)?;
+    /// let length = index.length_geodesic_m();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn length_geodesic_m(self) -> f64 {
+        let boundary = self.boundary();
+
+        (0..boundary.len() - 1)
+            .map(|i| {
+                boundary[i]
+                    .distance_m_with(boundary[i + 1], DistanceMethod::Vincenty)
+            })
+            .sum()
+    }
+
     /// Initializes a new edge index a value that may be invalid.
     ///
     /// # Safety
@@ -372,6 +434,22 @@ impl From<DirectedEdgeIndex> for geo::Line {
     }
 }
 
+#[cfg(feature = "geozero")]
+impl geozero::GeozeroGeometry for DirectedEdgeIndex {
+    fn process_geom<P: geozero::GeomProcessor>(
+        &self,
+        processor: &mut P,
+    ) -> geozero::error::Result<()> {
+        let boundary = self.boundary();
+
+        processor.linestring_begin(true, boundary.len(), 0)?;
+        for (idx, ll) in boundary.iter().enumerate() {
+            crate::coord::process_coord(*ll, idx, processor)?;
+        }
+        processor.linestring_end(true, 0)
+    }
+}
+
 #[cfg(feature = "arbitrary")]
 impl<'a> arbitrary::Arbitrary<'a> for DirectedEdgeIndex {
     fn arbitrary(
@@ -1,11 +1,12 @@
 use super::CellIndex;
 use crate::{
     coord::{CoordCube, CoordIJK, LocalIJK},
-    error::LocalIjError,
+    error::{CompactionError, LocalIjError},
     index::bits,
     Direction, Resolution,
 };
-use core::cmp::max;
+use alloc::{collections::VecDeque, vec::Vec};
+use core::cmp::{max, Ordering};
 
 /// Iterator over a children cell index at a given resolution.
 pub struct Children {
@@ -111,6 +112,47 @@ impl ExactSizeIterator for Children {}
 
 // -----------------------------------------------------------------------------
 
+/// Iterator over every cell index at a given resolution.
+#[derive(Clone, Debug)]
+pub struct AllAt {
+    /// Next cell index, if any.
+    next: Option<CellIndex>,
+    /// Remaining cell indexes.
+    count: u64,
+}
+
+impl AllAt {
+    /// Returns an iterator over every cell index at the given resolution.
+    pub fn new(resolution: Resolution) -> Self {
+        Self {
+            next: Some(CellIndex::first(resolution)),
+            count: resolution.cell_count(),
+        }
+    }
+}
+
+impl Iterator for AllAt {
+    type Item = CellIndex;
+
+    fn next(&mut self) -> Option<CellIndex> {
+        let index = self.next?;
+        // Stop on the count as well, to keep the iterator consistent with
+        // its length (and never underflow).
+        self.count = self.count.saturating_sub(1);
+        self.next = (self.count != 0).then(|| index.succ()).flatten();
+        Some(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = usize::try_from(self.count).unwrap_or(usize::MAX);
+        (count, Some(count))
+    }
+}
+
+impl ExactSizeIterator for AllAt {}
+
+// -----------------------------------------------------------------------------
+
 /// Return the starting state for the listing process.
 fn get_starting_state(index: CellIndex, resolution: Resolution) -> u64 {
     let parent_resolution = index.resolution();
@@ -228,3 +270,146 @@ impl Iterator for GridPathCells {
 }
 
 impl ExactSizeIterator for GridPathCells {}
+
+// -----------------------------------------------------------------------------
+
+/// Direction of the first sibling.
+const CENTER: u8 = Direction::Center as u8;
+/// Direction of the last sibling.
+const LAST_DIRECTION: u8 = Direction::IJ as u8;
+
+/// Iterator compacting a sorted stream of cell indexes.
+///
+/// Pending siblings are buffered per resolution, and a group is only kept
+/// while it can still be completed (i.e. while the incoming cells are its
+/// next siblings, in order), so the memory usage is bounded by the number of
+/// resolutions.
+pub struct CompactStreaming<I> {
+    /// Input cells, sorted.
+    cells: I,
+    /// Previous input cell.
+    previous: Option<CellIndex>,
+    /// Pending siblings, indexed by resolution.
+    levels: [Vec<CellIndex>; 16],
+    /// Compacted cells, ready to be returned.
+    output: VecDeque<CellIndex>,
+    /// Whether the input has been consumed (or an error occurred).
+    is_done: bool,
+}
+
+impl<I> CompactStreaming<I>
+where
+    I: Iterator<Item = CellIndex>,
+{
+    /// Returns an iterator compacting the given sorted cells.
+    pub fn new(cells: I) -> Self {
+        Self {
+            cells,
+            previous: None,
+            levels: Default::default(),
+            output: VecDeque::new(),
+            is_done: false,
+        }
+    }
+
+    /// Checks that the cell can follow the previous one.
+    fn check(&mut self, cell: CellIndex) -> Result<(), CompactionError> {
+        if let Some(previous) = self.previous {
+            if cell.resolution() != previous.resolution() {
+                return Err(CompactionError::HeterogeneousResolution);
+            }
+            match cell.cmp(&previous) {
+                Ordering::Less => return Err(CompactionError::UnsortedInput),
+                Ordering::Equal => return Err(CompactionError::DuplicateInput),
+                Ordering::Greater => (),
+            }
+        }
+        self.previous = Some(cell);
+        Ok(())
+    }
+
+    /// Adds a cell, merging the complete groups of siblings into their parent.
+    fn push(&mut self, mut cell: CellIndex) {
+        loop {
+            let resolution = cell.resolution();
+            let Some(parent_resolution) = resolution.pred() else {
+                // Base cells cannot be compacted.
+                self.flush(resolution);
+                self.output.push_back(cell);
+                return;
+            };
+            let direction = bits::get_direction(cell.into(), resolution);
+            let level = &self.levels[usize::from(resolution)];
+            let is_next = level.last().map_or(direction == CENTER, |&last| {
+                let parent =
+                    last.parent(parent_resolution).expect("parent resolution");
+                cell.parent(parent_resolution) == Some(parent)
+                    && direction == next_direction(last, parent)
+            });
+
+            if !is_next {
+                // The pending groups can't be completed anymore.
+                self.flush(resolution);
+                if direction == CENTER {
+                    self.levels[usize::from(resolution)].push(cell);
+                } else {
+                    self.output.push_back(cell);
+                }
+                return;
+            }
+
+            self.levels[usize::from(resolution)].push(cell);
+            if direction != LAST_DIRECTION {
+                return;
+            }
+            // Group is complete, replace it by its parent.
+            self.levels[usize::from(resolution)].clear();
+            cell = cell.parent(parent_resolution).expect("parent resolution");
+        }
+    }
+
+    /// Moves the pending cells, up to the given resolution, to the output.
+    fn flush(&mut self, resolution: Resolution) {
+        for level in &mut self.levels[..=usize::from(resolution)] {
+            self.output.extend(level.drain(..));
+        }
+    }
+}
+
+impl<I> Iterator for CompactStreaming<I>
+where
+    I: Iterator<Item = CellIndex>,
+{
+    type Item = Result<CellIndex, CompactionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(cell) = self.output.pop_front() {
+                return Some(Ok(cell));
+            }
+            if self.is_done {
+                return None;
+            }
+            if let Some(cell) = self.cells.next() {
+                if let Err(err) = self.check(cell) {
+                    self.is_done = true;
+                    return Some(Err(err));
+                }
+                self.push(cell);
+            } else {
+                self.is_done = true;
+                self.flush(Resolution::Fifteen);
+            }
+        }
+    }
+}
+
+/// Returns the direction of the sibling following `cell`.
+fn next_direction(cell: CellIndex, parent: CellIndex) -> u8 {
+    let direction = bits::get_direction(cell.into(), cell.resolution()) + 1;
+    // Pentagons don't have a child in the K direction.
+    if parent.is_pentagon() && direction == u8::from(Direction::K) {
+        return direction + 1;
+    }
+    direction
+}
@@ -339,6 +339,16 @@ impl From<VertexIndex> for geo::Point {
     }
 }
 
+#[cfg(feature = "geozero")]
+impl geozero::GeozeroGeometry for VertexIndex {
+    fn process_geom<P: geozero::GeomProcessor>(
+        &self,
+        processor: &mut P,
+    ) -> geozero::error::Result<()> {
+        geozero::GeozeroGeometry::process_geom(&LatLng::from(*self), processor)
+    }
+}
+
 #[cfg(test)]
 #[path = "./vertex_tests.rs"]
 mod tests;
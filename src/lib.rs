@@ -12,12 +12,79 @@
 //!     APIs, `std` causes error types to implement the `std::error::Error` trait.
 //!     Enabling `std` will also result in performance optimizations.
 //!
+//!     Without it, the crate is `no_std` (float math goes through `libm`)
+//!     and the whole core API (indexing, inspection, hierarchy, grid
+//!     traversal, compaction, ...) stays available. An allocator is still
+//!     required though (`alloc` crate), as some functions return vectors.
+//!
+//! * **arrow** -
+//!     When enabled, compute kernels operating on Arrow arrays of H3 cell
+//!     indexes are available in the `arrow` module. Implies `std`.
+//!
+//! * **fma** -
+//!     When enabled, `no_std` builds use a fused multiply-add (through `libm`)
+//!     in the coordinate conversions, like `std` builds always do, and the
+//!     sums of products of those conversions are compensated. This is slower,
+//!     but reduces the rounding errors on the cells boundaries.
+//!
+//!     Results are no longer bit-identical to the default build (and thus to
+//!     libh3): on the boundaries of the resolution 4 cells, about 9% of the
+//!     coordinates move, by at most 2.3e-14 radians. In particular, a vertex
+//!     shared by two cells may get slightly different coordinates on each
+//!     side, so geometric predicates on shared edges (e.g. touching cells in
+//!     `Tiler::into_coverage_with_report`) can change.
+//!
 //! * **geo** -
 //!     When enabled, you'll be able to convert lists of H3 cell indexes from and
 //!     into geometric shapes. Also enables the `GeoJSON` support. Requires `std`.
 //!
+//! * **geoarrow** -
+//!     When enabled, cells and shapes can be exported as `GeoArrow` native
+//!     arrays (coordinates and offsets buffers). Implies `geo`.
+//!
+//! * **geojson** -
+//!     When enabled, cells carried by `GeoJSON` features can be read through
+//!     the `geojson` module. Combined with `geo`, cells and shapes can also be
+//!     written as, and polygons read from, `GeoJSON` features. Implies `std`.
+//!
+//! * **geoparquet** -
+//!     When enabled, H3 coverages can be written as `GeoParquet` files through
+//!     the `geoparquet` module. Implies `std`.
+//!
+//! * **geozero** -
+//!     When enabled, H3 index types implement `geozero::GeozeroGeometry` and
+//!     the `Tiler` can be fed from any `geozero` data source. Implies `geo`.
+//!
+//! * **petgraph** -
+//!     When enabled, coverages can be converted into `petgraph` graphs (cells
+//!     as nodes, adjacency as edges) through `to_petgraph`. Implies `std`.
+//!
+//! * **rayon** -
+//!     When enabled, every cell of a resolution can be iterated in parallel
+//!     through `CellIndex::par_all_at`, and large batches of points or
+//!     polygons are indexed in parallel (`LatLng::to_cells_batch`,
+//!     `Tiler::into_coverage_par`). Implies `std`.
+//!
+//! * **rstar** -
+//!     When enabled, cells can be stored in an `rstar` R-tree through the
+//!     `RTreeCell` wrapper. Implies `geo`.
+//!
 //! * **serde** -
 //!     When enabled, H3 index types (cell, vertex and edge) derive serde traits.
+//!     The `serde_hex` module provides helpers to use the hexadecimal string
+//!     representation in human-readable formats.
+//!
+//! * **nohash** -
+//!     When enabled, `CellIndex` implements `nohash_hasher::IsEnabled`, allowing
+//!     the use of an identity hasher for high-throughput cell maps and sets.
+//!
+//! * **wkb** -
+//!     When enabled, the `Tiler` can be fed with (E)WKB polygons and
+//!     multipolygons, as exported by `PostGIS`. Implies `geozero`.
+//!
+//! * **wkt** -
+//!     When enabled, the `Tiler` can be fed with (E)WKT polygons and
+//!     multipolygons. Implies `geozero`.
 //!
 //! ## H3 to H3O mapping
 //!
@@ -56,6 +123,7 @@
 //! | `gridDiskDistancesUnsafe` | [`CellIndex::grid_disk_distances_fast`] |
 //! | `gridDiskDistancesSafe`   | [`CellIndex::grid_disk_distances_safe`] |
 //! | `gridDisksUnsafe`         | [`CellIndex::grid_disks_fast`]          |
+//! | `gridRing`                | [`CellIndex::grid_ring`]                |
 //! | `gridRingUnsafe`          | [`CellIndex::grid_ring_fast`]           |
 //! | `gridPathCells`           | [`CellIndex::grid_path_cells`]          |
 //! | `gridPathCellsSize`       | [`CellIndex::grid_path_cells_size`]     |
@@ -249,17 +317,45 @@
 
 extern crate alloc;
 
+#[cfg(feature = "std")]
+mod aggregate;
+#[cfg(feature = "arrow")]
+pub mod arrow;
 mod base_cell;
+mod bbox;
+pub mod bits;
 mod boundary;
+mod centroid_index;
+mod cluster;
 mod coord;
+mod delta;
 mod direction;
 pub mod error;
 mod face;
+mod geodesic;
+#[cfg(feature = "geojson")]
+pub mod geojson;
 #[cfg(feature = "geo")]
 pub mod geom;
+#[cfg(feature = "geoparquet")]
+pub mod geoparquet;
+#[cfg(feature = "petgraph")]
+mod graph;
 mod grid;
 mod index;
+mod meta;
+mod path;
+mod perimeter;
+mod range;
+#[cfg(feature = "std")]
+mod raster;
 mod resolution;
+#[cfg(feature = "serde")]
+pub mod serde_hex;
+mod shape;
+mod simplify;
+mod treemap;
+mod weights;
 
 #[cfg(not(feature = "std"))]
 #[path = "math-libm.rs"]
@@ -268,18 +364,63 @@ mod math;
 #[path = "math-std.rs"]
 mod math;
 
+#[cfg(feature = "std")]
+pub use aggregate::{
+    aggregate_by_cell, build_pyramid, merge_by_cell, resample, Aggregation,
+    Resampling,
+};
 pub use base_cell::BaseCell;
-pub use boundary::Boundary;
-pub use coord::{CoordIJ, LatLng, LocalIJ};
+pub use bbox::{
+    cells_in_bbox, cells_in_latitude_band, coverage_bbox,
+    coverage_bounding_cell,
+};
+pub use boundary::{Boundary, Winding};
+pub use centroid_index::CellCentroidIndex;
+pub use cluster::cluster_cells;
+pub use coord::{CoordIJ, DistanceMethod, LatLng, LocalIJ, Vec3d};
+pub use delta::{coverage_delta, CoverageDelta, DeltaSummary};
 pub use direction::Direction;
 pub use face::{Face, FaceSet};
+pub use geodesic::{
+    cells_along_geodesic, cells_on_side_of_great_circle, distance_field,
+    great_circle_intersection, weighted_centroid,
+};
+#[cfg(feature = "petgraph")]
+pub use graph::to_petgraph;
 pub use index::{
     CellIndex, DirectedEdgeIndex, Edge, IndexMode, Vertex, VertexIndex,
 };
+pub use meta::DatasetMeta;
+pub use path::{follow_directions, path_directions};
+pub use perimeter::shared_perimeter_m;
+pub use range::CellRange;
+#[cfg(feature = "std")]
+pub use raster::{
+    cells_from_raster, rasterize, RasterProjection, SampleAggregation,
+};
 pub use resolution::Resolution;
+pub use shape::{compactness_stats, CompactnessStats};
+pub use simplify::simplify_cell_path;
+pub use treemap::CellTreemap;
+pub use weights::{
+    adjacency_pairs, weights_matrix, EdgeWeights, Kernel, WeightScheme,
+    WeightsMatrix,
+};
 
 use resolution::ExtendedResolution;
 
+/// Set used internally: hash-based when `std` is available.
+#[cfg(feature = "std")]
+type Set<K> = ahash::HashSet<K>;
+#[cfg(not(feature = "std"))]
+type Set<K> = alloc::collections::BTreeSet<K>;
+
+/// Map used internally: hash-based when `std` is available.
+#[cfg(feature = "std")]
+type Map<K, V> = ahash::HashMap<K, V>;
+#[cfg(not(feature = "std"))]
+type Map<K, V> = alloc::collections::BTreeMap<K, V>;
+
 // -----------------------------------------------------------------------------
 
 /// An icosahedron has 20 faces.
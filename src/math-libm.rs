@@ -53,7 +53,24 @@ pub fn round(x: f64) -> f64 {
     libm::round(x)
 }
 
+#[inline]
+pub fn ceil(x: f64) -> f64 {
+    libm::ceil(x)
+}
+
 #[inline]
 pub fn mul_add(a: f64, b: f64, c: f64) -> f64 {
-    (a * b) + c
+    #[cfg(feature = "fma")]
+    {
+        libm::fma(a, b, c)
+    }
+    #[cfg(not(feature = "fma"))]
+    {
+        (a * b) + c
+    }
+}
+
+#[inline]
+pub fn exp(x: f64) -> f64 {
+    libm::exp(x)
 }
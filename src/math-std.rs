@@ -53,7 +53,17 @@ pub fn round(x: f64) -> f64 {
     x.round()
 }
 
+#[inline]
+pub fn ceil(x: f64) -> f64 {
+    x.ceil()
+}
+
 #[inline]
 pub fn mul_add(a: f64, b: f64, c: f64) -> f64 {
     a.mul_add(b, c)
 }
+
+#[inline]
+pub fn exp(x: f64) -> f64 {
+    x.exp()
+}
@@ -0,0 +1,90 @@
+use crate::{coverage_bbox, CellIndex, LatLng, Resolution};
+
+/// Self-describing metadata of a set of cells.
+///
+/// Meant to be stored alongside a dataset (e.g. as a file header, through
+/// `serde`) to describe its content without having to read it, and to detect
+/// corrupted or mismatched data when reading it back. Storing and validating
+/// it is up to the caller: it isn't tied to any file format.
+///
+/// The metadata only depends on the cells, not on their order.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DatasetMeta {
+    /// Coarsest resolution of the cells.
+    pub min_resolution: Resolution,
+    /// Finest resolution of the cells.
+    pub max_resolution: Resolution,
+    /// Number of cells.
+    pub count: u64,
+    /// Bounding box of the cells, as its south-west and north-east corners
+    /// (see [`coverage_bbox`]).
+    pub bbox: (LatLng, LatLng),
+    /// Order-independent checksum of the cells.
+    pub checksum: u64,
+}
+
+impl DatasetMeta {
+    /// Computes the metadata of the given cells, in a single pass.
+    ///
+    /// Returns `None` if there is no cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, DatasetMeta, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let meta = DatasetMeta::from_cells(index.children(Resolution::Eleven))
+    ///     .expect("non-empty dataset");
+    /// assert_eq!(meta.count, 7);
+    /// assert_eq!(meta.min_resolution, Resolution::Eleven);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn from_cells(
+        cells: impl IntoIterator<Item = CellIndex>,
+    ) -> Option<Self> {
+        let mut count = 0_u64;
+        let mut checksum = 0_u64;
+        let (mut min_resolution, mut max_resolution) =
+            (Resolution::Fifteen, Resolution::Zero);
+
+        let bbox = coverage_bbox(cells.into_iter().inspect(|cell| {
+            count += 1;
+            // Wrapping sum of the hashed indexes: order doesn't matter, but
+            // any change in the content does.
+            checksum = checksum.wrapping_add(cell.hash_key());
+            min_resolution = min_resolution.min(cell.resolution());
+            max_resolution = max_resolution.max(cell.resolution());
+        }))?;
+
+        Some(Self {
+            min_resolution,
+            max_resolution,
+            count,
+            bbox,
+            checksum,
+        })
+    }
+
+    /// Checks that the given cells match the metadata.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, DatasetMeta, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let cells = index.children(Resolution::Eleven).collect::<Vec<_>>();
+    /// let meta = DatasetMeta::from_cells(cells.iter().copied())
+    ///     .expect("non-empty dataset");
+    /// assert!(meta.matches(cells.iter().rev().copied()));
+    /// assert!(!meta.matches(cells[1..].iter().copied()));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn matches(&self, cells: impl IntoIterator<Item = CellIndex>) -> bool {
+        Self::from_cells(cells).as_ref() == Some(self)
+    }
+}
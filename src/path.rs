@@ -0,0 +1,86 @@
+use crate::{CellIndex, Direction};
+use alloc::vec::Vec;
+
+/// Translates a path of cells into the direction taken at each step.
+///
+/// Directions are expressed in the local frame of the cell the step starts
+/// from (cf. [`CellIndex::neighbors_with_direction`]). This frame rotates
+/// when crossing icosahedron faces or around pentagons, thus a given
+/// direction doesn't always mean the same heading: replaying the directions
+/// from the first cell (cf. [`follow_directions`]) is the only reliable way
+/// to get the path back.
+///
+/// Returns `None` if two consecutive cells are not neighbors.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{follow_directions, path_directions, CellIndex};
+///
+/// let start = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let end = CellIndex::try_from(0x8a1fb4644937fff)?;
+/// let path = start
+///     .grid_path_cells(end)?
+///     .collect::<Result<Vec<_>, _>>()?;
+///
+/// let directions = path_directions(&path).expect("contiguous path");
+/// assert_eq!(directions.len(), path.len() - 1);
+/// assert_eq!(follow_directions(start, directions), Some(path));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[must_use]
+pub fn path_directions(path: &[CellIndex]) -> Option<Vec<Direction>> {
+    path.windows(2)
+        .map(|pair| {
+            pair[0]
+                .edge(pair[1])
+                .map(|edge| Direction::from(edge.edge()))
+        })
+        .collect()
+}
+
+/// Builds the path of cells obtained by following the given directions from
+/// a starting cell.
+///
+/// This is the inverse of [`path_directions`]: each direction is taken in
+/// the local frame of the current cell. The returned path starts with
+/// `start`.
+///
+/// Returns `None` if a direction leads nowhere, i.e. [`Direction::Center`],
+/// or [`Direction::K`] from a pentagon.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{follow_directions, CellIndex, Direction};
+///
+/// let start = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let path = follow_directions(start, [Direction::I, Direction::IJ])
+///     .expect("valid directions");
+/// assert_eq!(path.len(), 3);
+/// assert_eq!(path[0], start);
+///
+/// assert_eq!(follow_directions(start, [Direction::Center]), None);
+/// # Ok::<(), h3o::error::InvalidCellIndex>(())
+/// ```
+#[must_use]
+pub fn follow_directions(
+    start: CellIndex,
+    directions: impl IntoIterator<Item = Direction>,
+) -> Option<Vec<CellIndex>> {
+    let directions = directions.into_iter();
+    let mut path = Vec::with_capacity(directions.size_hint().0 + 1);
+    path.push(start);
+
+    let mut cell = start;
+    for direction in directions {
+        cell = cell.neighbors_with_direction().find_map(
+            |(candidate, neighbor)| {
+                (candidate == direction).then_some(neighbor)
+            },
+        )?;
+        path.push(cell);
+    }
+
+    Some(path)
+}
@@ -0,0 +1,49 @@
+use crate::{error::ResolutionMismatch, CellIndex, DirectedEdgeIndex, Set};
+
+/// Computes the length, in meters, of the boundary shared by two coverages.
+///
+/// This is the sum of the lengths of the edges between a cell of `a` and a
+/// neighboring cell of `b`, using the same length as
+/// [`DirectedEdgeIndex::length_m`](crate::DirectedEdgeIndex::length_m).
+///
+/// Coverages are expected to be disjoint, and made of unique cells.
+///
+/// # Errors
+///
+/// [`ResolutionMismatch`] if the cells are not all at the same resolution.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{shared_perimeter_m, CellIndex};
+///
+/// let a = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let b = a.grid_disk::<Vec<_>>(1);
+/// // The center cell is fully surrounded.
+/// let length = shared_perimeter_m(&[a], &b[1..])?;
+/// assert_eq!(length, a.edges().map(|edge| edge.length_m()).sum::<f64>());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn shared_perimeter_m(
+    a: &[CellIndex],
+    b: &[CellIndex],
+) -> Result<f64, ResolutionMismatch> {
+    let Some(resolution) =
+        a.iter().chain(b).next().map(|cell| cell.resolution())
+    else {
+        return Ok(0.);
+    };
+    if a.iter()
+        .chain(b)
+        .any(|cell| cell.resolution() != resolution)
+    {
+        return Err(ResolutionMismatch);
+    }
+
+    let b = b.iter().copied().collect::<Set<_>>();
+    Ok(a.iter()
+        .flat_map(|cell| cell.edges())
+        .filter(|edge| b.contains(&edge.destination()))
+        .map(DirectedEdgeIndex::length_m)
+        .sum())
+}
@@ -0,0 +1,303 @@
+use crate::{error::ResolutionMismatch, CellIndex, Resolution};
+use alloc::vec::Vec;
+use core::iter;
+
+/// An inclusive range of cell indexes, at a given resolution.
+///
+/// Since cell indexes are totally ordered (cf. [`CellIndex::succ`]), a large
+/// set of cells (e.g. the tiling of a country) can be stored compactly as a
+/// list of ranges of contiguous indexes.
+///
+/// Functions working on lists of ranges return them sorted, without overlap
+/// and without adjacent ranges (i.e. the shortest list possible).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct CellRange {
+    start: CellIndex,
+    end: CellIndex,
+}
+
+impl CellRange {
+    /// Initializes a new range from its bounds (inclusive).
+    ///
+    /// The bounds are swapped if `end` is lower than `start`.
+    ///
+    /// # Errors
+    ///
+    /// [`ResolutionMismatch`] if the bounds are not at the same resolution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, CellRange, Resolution};
+    ///
+    /// let start = CellIndex::first(Resolution::Two);
+    /// let end = CellIndex::last(Resolution::Two);
+    /// let range = CellRange::new(start, end)?;
+    /// assert_eq!(range.len(), Resolution::Two.cell_count());
+    /// # Ok::<(), h3o::error::ResolutionMismatch>(())
+    /// ```
+    pub fn new(
+        start: CellIndex,
+        end: CellIndex,
+    ) -> Result<Self, ResolutionMismatch> {
+        if start.resolution() != end.resolution() {
+            return Err(ResolutionMismatch);
+        }
+        Ok(Self {
+            start: start.min(end),
+            end: start.max(end),
+        })
+    }
+
+    /// Initializes a range made of a single cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, CellRange};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let range = CellRange::single(index);
+    /// assert_eq!(range.len(), 1);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub const fn single(cell: CellIndex) -> Self {
+        Self {
+            start: cell,
+            end: cell,
+        }
+    }
+
+    /// Returns the first cell of the range.
+    #[must_use]
+    pub const fn start(self) -> CellIndex {
+        self.start
+    }
+
+    /// Returns the last cell of the range.
+    #[must_use]
+    pub const fn end(self) -> CellIndex {
+        self.end
+    }
+
+    /// Returns the resolution of the cells of the range.
+    #[must_use]
+    pub const fn resolution(self) -> Resolution {
+        self.start.resolution()
+    }
+
+    /// Returns the number of cells in the range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, CellRange, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let children = index.children(Resolution::Twelve).collect::<Vec<_>>();
+    /// let range = CellRange::new(children[0], children[48])?;
+    /// assert_eq!(range.len(), 49);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    #[expect(clippy::len_without_is_empty, reason = "never empty")]
+    pub fn len(self) -> u64 {
+        rank(self.end) - rank(self.start) + 1
+    }
+
+    /// Returns true if the cell is within the range.
+    ///
+    /// Cells at another resolution are never contained.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, CellRange, Resolution};
+    ///
+    /// let range = CellRange::new(
+    ///     CellIndex::first(Resolution::Two),
+    ///     CellIndex::last(Resolution::Two),
+    /// )?;
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    /// assert!(range.contains(index.parent(Resolution::Two).expect("parent")));
+    /// assert!(!range.contains(index));
+    /// # Ok::<(), h3o::error::ResolutionMismatch>(())
+    /// ```
+    #[must_use]
+    pub fn contains(self, cell: CellIndex) -> bool {
+        cell.resolution() == self.resolution()
+            && (self.start..=self.end).contains(&cell)
+    }
+
+    /// Returns the cells of the range, in order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, CellRange, Resolution};
+    ///
+    /// let range = CellRange::new(
+    ///     CellIndex::first(Resolution::Zero),
+    ///     CellIndex::last(Resolution::Zero),
+    /// )?;
+    /// assert!(range.iter().eq(CellIndex::base_cells()));
+    /// # Ok::<(), h3o::error::ResolutionMismatch>(())
+    /// ```
+    pub fn iter(self) -> impl Iterator<Item = CellIndex> {
+        iter::successors(Some(self.start), move |cell| {
+            (*cell != self.end).then(|| cell.succ()).flatten()
+        })
+    }
+
+    /// Converts a set of cells into the shortest list of ranges covering
+    /// them.
+    ///
+    /// # Errors
+    ///
+    /// [`ResolutionMismatch`] if the cells are not all at the same
+    /// resolution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, CellRange, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let children = index.children(Resolution::Fifteen);
+    /// let ranges = CellRange::from_cells(children)?;
+    /// assert_eq!(ranges.len(), 1);
+    /// assert_eq!(ranges[0].len(), 16_807);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_cells(
+        cells: impl IntoIterator<Item = CellIndex>,
+    ) -> Result<Vec<Self>, ResolutionMismatch> {
+        normalize(cells.into_iter().map(Self::single).collect())
+    }
+
+    /// Computes the union of two lists of ranges.
+    ///
+    /// The inputs don't need to be sorted nor disjoint.
+    ///
+    /// # Errors
+    ///
+    /// [`ResolutionMismatch`] if the ranges are not all at the same
+    /// resolution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, CellRange, Resolution};
+    ///
+    /// let cells = CellIndex::base_cells().collect::<Vec<_>>();
+    /// let a = CellRange::new(cells[0], cells[10])?;
+    /// let b = CellRange::new(cells[11], cells[20])?;
+    ///
+    /// let union = CellRange::union(&[a], &[b])?;
+    /// assert_eq!(union, vec![CellRange::new(cells[0], cells[20])?]);
+    /// # Ok::<(), h3o::error::ResolutionMismatch>(())
+    /// ```
+    pub fn union(
+        a: &[Self],
+        b: &[Self],
+    ) -> Result<Vec<Self>, ResolutionMismatch> {
+        normalize(a.iter().chain(b).copied().collect())
+    }
+
+    /// Computes the intersection of two lists of ranges.
+    ///
+    /// The inputs don't need to be sorted nor disjoint.
+    ///
+    /// # Errors
+    ///
+    /// [`ResolutionMismatch`] if the ranges are not all at the same
+    /// resolution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, CellRange, Resolution};
+    ///
+    /// let cells = CellIndex::base_cells().collect::<Vec<_>>();
+    /// let a = CellRange::new(cells[0], cells[10])?;
+    /// let b = CellRange::new(cells[5], cells[20])?;
+    ///
+    /// let intersection = CellRange::intersection(&[a], &[b])?;
+    /// assert_eq!(intersection, vec![CellRange::new(cells[5], cells[10])?]);
+    /// # Ok::<(), h3o::error::ResolutionMismatch>(())
+    /// ```
+    pub fn intersection(
+        a: &[Self],
+        b: &[Self],
+    ) -> Result<Vec<Self>, ResolutionMismatch> {
+        let a = normalize(a.to_vec())?;
+        let b = normalize(b.to_vec())?;
+        if let (Some(x), Some(y)) = (a.first(), b.first()) {
+            if x.resolution() != y.resolution() {
+                return Err(ResolutionMismatch);
+            }
+        }
+
+        let mut result = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(x), Some(y)) = (a.get(i), b.get(j)) {
+            let start = x.start.max(y.start);
+            let end = x.end.min(y.end);
+            if start <= end {
+                result.push(Self { start, end });
+            }
+            if x.end < y.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// Sorts and merges the overlapping or adjacent ranges.
+fn normalize(
+    mut ranges: Vec<CellRange>,
+) -> Result<Vec<CellRange>, ResolutionMismatch> {
+    let Some(resolution) = ranges.first().map(|range| range.resolution())
+    else {
+        return Ok(ranges);
+    };
+    if ranges.iter().any(|range| range.resolution() != resolution) {
+        return Err(ResolutionMismatch);
+    }
+
+    ranges.sort_unstable_by_key(|range| range.start);
+    let mut result = Vec::<CellRange>::with_capacity(ranges.len());
+    for range in ranges {
+        if let Some(last) = result.last_mut() {
+            if range.start <= last.end || last.end.succ() == Some(range.start) {
+                last.end = last.end.max(range.end);
+                continue;
+            }
+        }
+        result.push(range);
+    }
+
+    Ok(result)
+}
+
+/// Returns the position of the cell among every cell of its resolution.
+fn rank(cell: CellIndex) -> u64 {
+    let resolution = cell.resolution();
+    let base_cell = usize::from(u8::from(cell.base_cell()));
+    let offset = CellIndex::base_cells()
+        .take(base_cell)
+        .map(|base_cell| base_cell.children_count(resolution))
+        .sum::<u64>();
+
+    offset
+        + cell
+            .child_position(Resolution::Zero)
+            .expect("resolution 0 is the coarsest")
+}
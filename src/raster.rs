@@ -0,0 +1,254 @@
+use crate::{cells_in_bbox, math::mul_add, CellIndex, LatLng, Resolution};
+use std::{
+    collections::{BTreeSet, HashMap},
+    f64::consts::{FRAC_PI_2, FRAC_PI_4},
+    hash::BuildHasher,
+};
+
+/// Maximum latitude of the Web Mercator projection, in degrees.
+const WEB_MERCATOR_MAX_LAT: f64 = 85.051_128_779_806_59;
+
+/// Number of resolutions below the target one used to sample the raster
+/// (i.e. ~49 samples per cell).
+const SAMPLING_DEPTH: u8 = 2;
+
+/// Projection of a raster grid.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum RasterProjection {
+    /// Pixels are evenly spaced in latitude and longitude (i.e. EPSG:4326).
+    #[default]
+    Equirectangular,
+    /// Pixels are evenly spaced in Web Mercator (i.e. EPSG:3857), like the
+    /// tiles of most web maps.
+    ///
+    /// Latitudes are clamped to ±85.05°.
+    WebMercator,
+}
+
+impl RasterProjection {
+    /// Projects a latitude, in degrees, on the vertical axis.
+    fn project(self, lat: f64) -> f64 {
+        match self {
+            Self::Equirectangular => lat,
+            Self::WebMercator => {
+                let lat =
+                    lat.clamp(-WEB_MERCATOR_MAX_LAT, WEB_MERCATOR_MAX_LAT);
+                (FRAC_PI_4 + lat.to_radians() / 2.).tan().ln()
+            }
+        }
+    }
+
+    /// Unprojects a value of the vertical axis into a latitude, in degrees.
+    fn unproject(self, y: f64) -> f64 {
+        match self {
+            Self::Equirectangular => y,
+            Self::WebMercator => {
+                mul_add(2., y.exp().atan(), -FRAC_PI_2).to_degrees()
+            }
+        }
+    }
+}
+
+/// Aggregation function used to combine the raster samples of a cell.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SampleAggregation {
+    /// Mean of the samples, for continuous data (e.g. temperature).
+    Mean,
+    /// Sum of the samples.
+    ///
+    /// Note that the result depends on the number of samples per cell, which
+    /// is roughly the same for every cell but not exactly (e.g. pentagons).
+    Sum,
+    /// Most frequent sample, for categorical data (e.g. land cover).
+    ///
+    /// Ties are broken in favor of the smallest value.
+    Majority,
+}
+
+impl SampleAggregation {
+    /// Combines the given non-empty set of samples.
+    fn apply(self, samples: &[f64]) -> f64 {
+        match self {
+            Self::Mean => {
+                #[expect(
+                    clippy::cast_precision_loss,
+                    reason = "number of samples is small"
+                )]
+                let count = samples.len() as f64;
+                samples.iter().sum::<f64>() / count
+            }
+            Self::Sum => samples.iter().sum(),
+            Self::Majority => {
+                let mut counts = HashMap::<u64, usize>::new();
+                for sample in samples {
+                    *counts.entry(sample.to_bits()).or_default() += 1;
+                }
+                counts
+                    .into_iter()
+                    .map(|(bits, count)| (count, f64::from_bits(bits)))
+                    .max_by(|a, b| a.0.cmp(&b.0).then(b.1.total_cmp(&a.1)))
+                    .map(|(_, value)| value)
+                    .expect("non-empty samples")
+            }
+        }
+    }
+}
+
+/// Samples per-cell values onto a regular grid of pixels.
+///
+/// The grid covers the bounding box defined by its south-west (`min`) and
+/// north-east (`max`) corners, and boxes crossing the antimeridian are
+/// supported (when the longitude of `min` is greater than the one of `max`).
+///
+/// Each pixel takes the value of the cell containing its center, or `None`
+/// if there is no such cell. Cells may have different resolutions, in which
+/// case the finest one wins.
+///
+/// Pixels are returned in row-major order, from the north-west corner.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{rasterize, LatLng, RasterProjection, Resolution};
+/// use std::collections::HashMap;
+///
+/// let ll = LatLng::new(48.864716, 2.349014)?;
+/// let values = HashMap::from([(ll.to_cell(Resolution::Five), 42.)]);
+/// let min = LatLng::new(48.8, 2.3)?;
+/// let max = LatLng::new(48.9, 2.4)?;
+///
+/// let pixels =
+///     rasterize(&values, min, max, 4, 4, RasterProjection::WebMercator);
+/// // The north-west corner falls outside of the cell.
+/// assert_eq!(pixels[0], None);
+/// assert!(pixels[1..].iter().all(|&pixel| pixel == Some(42.)));
+/// # Ok::<(), h3o::error::InvalidLatLng>(())
+/// ```
+#[must_use]
+pub fn rasterize<S: BuildHasher>(
+    values: &HashMap<CellIndex, f64, S>,
+    min: LatLng,
+    max: LatLng,
+    width: usize,
+    height: usize,
+    projection: RasterProjection,
+) -> Vec<Option<f64>> {
+    // Finest resolutions first.
+    let resolutions = values
+        .keys()
+        .map(|cell| cell.resolution())
+        .collect::<BTreeSet<_>>();
+    let lookup = |ll: LatLng| {
+        resolutions
+            .iter()
+            .rev()
+            .find_map(|&resolution| values.get(&ll.to_cell(resolution)))
+            .copied()
+    };
+
+    pixel_centers(min, max, width, height, projection)
+        .map(lookup)
+        .collect()
+}
+
+/// Returns the center of the pixels of the grid, in row-major order from the
+/// north-west corner.
+fn pixel_centers(
+    min: LatLng,
+    max: LatLng,
+    width: usize,
+    height: usize,
+    projection: RasterProjection,
+) -> impl Iterator<Item = LatLng> {
+    let (north, south) =
+        (projection.project(max.lat()), projection.project(min.lat()));
+    let mut lng_span = max.lng() - min.lng();
+    if lng_span < 0. {
+        // Crossing the antimeridian.
+        lng_span += 360.;
+    }
+
+    #[expect(clippy::cast_precision_loss, reason = "grid size is small")]
+    let (width_f64, height_f64) = (width as f64, height as f64);
+    (0..height).flat_map(move |row| {
+        #[expect(clippy::cast_precision_loss, reason = "grid size is small")]
+        let v = (row as f64 + 0.5) / height_f64;
+        let lat = projection.unproject(mul_add(-v, north - south, north));
+        (0..width).map(move |col| {
+            #[expect(
+                clippy::cast_precision_loss,
+                reason = "grid size is small"
+            )]
+            let u = (col as f64 + 0.5) / width_f64;
+            let mut lng = mul_add(u, lng_span, min.lng());
+            if lng > 180. {
+                lng -= 360.;
+            }
+            LatLng::new(lat, lng).expect("valid pixel center")
+        })
+    })
+}
+
+/// Samples a raster into per-cell values.
+///
+/// Every cell whose center lies within the bounding box defined by its
+/// south-west (`min`) and north-east (`max`) corners (see
+/// [`cells_in_bbox`](crate::cells_in_bbox)) is sampled at the center of its
+/// descendants two resolutions below, and the samples are combined using the
+/// given aggregation function.
+///
+/// The sampler returns the value of the raster at a given point, or `None`
+/// if there is no data there. Such samples are ignored, and cells without
+/// any sample are omitted from the result.
+///
+/// This is the inverse of [`rasterize`].
+///
+/// # Example
+///
+/// ```
+/// use h3o::{cells_from_raster, LatLng, Resolution, SampleAggregation};
+/// use std::collections::HashMap;
+///
+/// let min = LatLng::new(48.81, 2.22)?;
+/// let max = LatLng::new(48.90, 2.47)?;
+/// let values: HashMap<_, _> = cells_from_raster(
+///     Resolution::Seven,
+///     min,
+///     max,
+///     |ll| Some(ll.lat()),
+///     SampleAggregation::Mean,
+/// );
+/// assert!(values.values().all(|lat| (48.8..=48.91).contains(lat)));
+/// # Ok::<(), h3o::error::InvalidLatLng>(())
+/// ```
+pub fn cells_from_raster<S>(
+    resolution: Resolution,
+    min: LatLng,
+    max: LatLng,
+    mut sampler: impl FnMut(LatLng) -> Option<f64>,
+    aggregation: SampleAggregation,
+) -> HashMap<CellIndex, f64, S>
+where
+    S: BuildHasher + Default,
+{
+    let sampling_resolution = Resolution::try_from(
+        (u8::from(resolution) + SAMPLING_DEPTH)
+            .min(u8::from(Resolution::Fifteen)),
+    )
+    .expect("valid resolution");
+    let mut buffer = Vec::new();
+
+    cells_in_bbox(min, max, resolution)
+        .into_iter()
+        .filter_map(|cell| {
+            buffer.clear();
+            buffer.extend(
+                cell.children(sampling_resolution)
+                    .filter_map(|child| sampler(LatLng::from(child))),
+            );
+            (!buffer.is_empty()).then(|| (cell, aggregation.apply(&buffer)))
+        })
+        .collect()
+}
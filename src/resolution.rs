@@ -1,4 +1,5 @@
 use crate::{error, index::bits, BaseCell, CellIndex, NUM_PENTAGONS};
+use alloc::vec::Vec;
 use core::{ffi::c_int, fmt, iter::DoubleEndedIterator, str::FromStr};
 
 /// Cell resolution, from 0 to 15.
@@ -380,6 +381,36 @@ impl Resolution {
             })
     }
 
+    /// Generates, for every pentagon at this resolution, the cells within `k`
+    /// distance of the pentagon.
+    ///
+    /// Each disk starts with its pentagon, and cells are sorted by distance
+    /// (see [`CellIndex::grid_disk_distances_safe`]).
+    ///
+    /// This is useful to precompute the regions where the pentagon
+    /// distortion needs a special treatment.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let disks = h3o::Resolution::Five
+    ///     .pentagon_disks(2)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(disks.len(), 12);
+    /// ```
+    pub fn pentagon_disks(
+        self,
+        k: u32,
+    ) -> impl Iterator<Item = (CellIndex, Vec<CellIndex>)> {
+        self.pentagons().map(move |pentagon| {
+            let disk = pentagon
+                .grid_disk_distances_safe(k)
+                .map(|(cell, _)| cell)
+                .collect();
+            (pentagon, disk)
+        })
+    }
+
     /// Initializes a new `Resolution` using a value that may be out of range.
     ///
     /// # Safety
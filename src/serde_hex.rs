@@ -0,0 +1,148 @@
+//! Serde helpers to represent H3 indexes (cell, vertex and edge) in their
+//! canonical form in human-readable formats.
+//!
+//! The derived serde traits always use the raw 64-bit integer. With these
+//! helpers, human-readable formats (JSON, YAML, ...) get the hexadecimal
+//! string (e.g. `"8a1fb46622dffff"`) instead, whereas binary formats
+//! (bincode, CBOR, ...) keep the compact integer.
+//!
+//! Deserialization from human-readable formats accepts both the string and
+//! the integer, so data written with the derived implementation can still be
+//! read.
+//!
+//! # Example
+//!
+//! ```
+//! use h3o::CellIndex;
+//!
+//! #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+//! struct Record {
+//!     #[serde(with = "h3o::serde_hex")]
+//!     cell: CellIndex,
+//! }
+//!
+//! let record = Record {
+//!     cell: CellIndex::try_from(0x8a1fb46622dffff)?,
+//! };
+//! let json = serde_json::to_string(&record)?;
+//! assert_eq!(json, r#"{"cell":"8a1fb46622dffff"}"#);
+//! assert_eq!(serde_json::from_str::<Record>(&json)?, record);
+//! // Integers are still accepted.
+//! let json = r#"{"cell":622054503267303423}"#;
+//! assert_eq!(serde_json::from_str::<Record>(json)?, record);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use core::{fmt, marker::PhantomData, str::FromStr};
+use serde::{de, Deserializer, Serializer};
+
+/// Serializes an H3 index as an hexadecimal string in human-readable
+/// formats, and as an integer otherwise.
+///
+/// # Errors
+///
+/// Returns the serializer error, if any.
+///
+/// # Example
+///
+/// ```
+/// use h3o::CellIndex;
+///
+/// #[derive(serde::Serialize)]
+/// struct Record {
+///     #[serde(serialize_with = "h3o::serde_hex::serialize")]
+///     cell: CellIndex,
+/// }
+///
+/// let record = Record {
+///     cell: CellIndex::try_from(0x8a1fb46622dffff)?,
+/// };
+/// assert_eq!(
+///     serde_json::to_string(&record)?,
+///     r#"{"cell":"8a1fb46622dffff"}"#
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn serialize<T, S>(index: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Copy + fmt::LowerHex,
+    u64: From<T>,
+    S: Serializer,
+{
+    if serializer.is_human_readable() {
+        serializer.collect_str(&format_args!("{index:x}"))
+    } else {
+        serializer.serialize_u64(u64::from(*index))
+    }
+}
+
+/// Deserializes an H3 index from either an hexadecimal string or an integer.
+///
+/// Binary formats only accept the integer.
+///
+/// # Errors
+///
+/// Returns an error if the value is neither a string nor an integer, or if
+/// it isn't a valid index.
+///
+/// # Example
+///
+/// ```
+/// use h3o::CellIndex;
+///
+/// #[derive(serde::Deserialize)]
+/// struct Record {
+///     #[serde(deserialize_with = "h3o::serde_hex::deserialize")]
+///     cell: CellIndex,
+/// }
+///
+/// let record: Record =
+///     serde_json::from_str(r#"{"cell":"8a1fb46622dffff"}"#)?;
+/// assert_eq!(u64::from(record.cell), 0x8a1fb46622dffff);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: TryFrom<u64> + FromStr,
+    <T as TryFrom<u64>>::Error: fmt::Display,
+    <T as FromStr>::Err: fmt::Display,
+    D: Deserializer<'de>,
+{
+    let visitor = IndexVisitor(PhantomData);
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(visitor)
+    } else {
+        deserializer.deserialize_u64(visitor)
+    }
+}
+
+/// Visitor accepting an H3 index as a string or an integer.
+struct IndexVisitor<T>(PhantomData<T>);
+
+impl<T> de::Visitor<'_> for IndexVisitor<T>
+where
+    T: TryFrom<u64> + FromStr,
+    <T as TryFrom<u64>>::Error: fmt::Display,
+    <T as FromStr>::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("an H3 index, as an hexadecimal string or integer")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<T, E> {
+        T::try_from(value).map_err(E::custom)
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<T, E> {
+        let value = u64::try_from(value).map_err(|_| {
+            E::invalid_value(de::Unexpected::Signed(value), &self)
+        })?;
+        self.visit_u64(value)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+        value.parse().map_err(E::custom)
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{
+    math::{mul_add, sqrt},
+    CellIndex,
+};
+
+/// Statistics about the compactness of a set of cells.
+///
+/// See [`CellIndex::compactness`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CompactnessStats {
+    /// Number of cells.
+    pub count: usize,
+    /// Lowest compactness (i.e. most distorted cell).
+    pub min: f64,
+    /// Highest compactness.
+    pub max: f64,
+    /// Average compactness.
+    pub mean: f64,
+    /// Standard deviation of the compactness.
+    pub std_dev: f64,
+}
+
+/// Computes statistics about the compactness of the given cells.
+///
+/// Returns `None` if there is no cell.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{compactness_stats, CellIndex};
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let stats = compactness_stats(index.grid_disk::<Vec<_>>(2));
+/// assert_eq!(stats.map(|stats| stats.count), Some(19));
+/// # Ok::<(), h3o::error::InvalidCellIndex>(())
+/// ```
+#[must_use]
+pub fn compactness_stats(
+    cells: impl IntoIterator<Item = CellIndex>,
+) -> Option<CompactnessStats> {
+    let mut stats = None::<CompactnessStats>;
+    // Welford's online algorithm, for a numerically stable variance.
+    let mut m2 = 0.;
+
+    for cell in cells {
+        let value = cell.compactness();
+        let stats = stats.get_or_insert(CompactnessStats {
+            count: 0,
+            min: value,
+            max: value,
+            mean: 0.,
+            std_dev: 0.,
+        });
+        stats.count += 1;
+        stats.min = stats.min.min(value);
+        stats.max = stats.max.max(value);
+        #[expect(clippy::cast_precision_loss, reason = "count is small enough")]
+        let count = stats.count as f64;
+        let delta = value - stats.mean;
+        stats.mean += delta / count;
+        m2 = mul_add(delta, value - stats.mean, m2);
+    }
+
+    stats.map(|mut stats| {
+        #[expect(clippy::cast_precision_loss, reason = "count is small enough")]
+        let count = stats.count as f64;
+        stats.std_dev = sqrt(m2 / count);
+        stats
+    })
+}
@@ -0,0 +1,122 @@
+use crate::{
+    coord::{CoordCube, CoordIJK},
+    error::LocalIjError,
+    CellIndex,
+};
+use alloc::{vec, vec::Vec};
+
+/// Simplifies a path of cells, using a grid-space Douglas-Peucker algorithm.
+///
+/// Intermediate cells are removed as long as every cell of the original path
+/// stays within grid distance `tolerance_k` of the grid line (cf.
+/// [`CellIndex::grid_path_cells`]) between the cells that are kept.
+///
+/// The first and last cells are always kept, so a path of less than three
+/// cells is returned unchanged.
+///
+/// Distances are computed in the local `IJ` coordinate space anchored on the
+/// first cell (cf. [`CellIndex::to_local_ij`]), thus the whole path must be
+/// reasonably close to it.
+///
+/// # Errors
+///
+/// [`LocalIjError`] if a cell of the path cannot be expressed in the local
+/// coordinate space of the first cell (e.g. cells at different resolutions,
+/// too far apart or on the opposite side of a pentagon).
+///
+/// # Example
+///
+/// ```
+/// use h3o::{simplify_cell_path, CellIndex};
+///
+/// let start = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let end = CellIndex::try_from(0x8a1fb4644937fff)?;
+/// let path = start
+///     .grid_path_cells(end)?
+///     .collect::<Result<Vec<_>, _>>()?;
+///
+/// let simplified = simplify_cell_path(&path, 0)?;
+/// assert_eq!(simplified, vec![start, end]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn simplify_cell_path(
+    path: &[CellIndex],
+    tolerance_k: u32,
+) -> Result<Vec<CellIndex>, LocalIjError> {
+    if path.len() < 3 {
+        return Ok(path.to_vec());
+    }
+
+    let anchor = path[0];
+    let coords = path
+        .iter()
+        .map(|cell| {
+            let local_ij = cell.to_local_ij(anchor)?;
+            Ok(CoordCube::from(CoordIJK::try_from(local_ij.coord)?))
+        })
+        .collect::<Result<Vec<_>, LocalIjError>>()?;
+
+    let mut keep = vec![false; path.len()];
+    keep[0] = true;
+    keep[path.len() - 1] = true;
+
+    let mut stack = vec![(0, path.len() - 1)];
+    while let Some((start, end)) = stack.pop() {
+        if end - start < 2 {
+            continue;
+        }
+        let line = grid_line(coords[start], coords[end]);
+        let (index, distance) = (start + 1..end)
+            .map(|i| {
+                let distance = line
+                    .iter()
+                    .map(|&coord| distance(coords[i], coord))
+                    .min()
+                    .expect("non-empty line");
+                (i, distance)
+            })
+            .max_by_key(|&(_, distance)| distance)
+            .expect("intermediate cells");
+
+        if distance > tolerance_k {
+            keep[index] = true;
+            stack.push((start, index));
+            stack.push((index, end));
+        }
+    }
+
+    Ok(path
+        .iter()
+        .zip(keep)
+        .filter_map(|(&cell, keep)| keep.then_some(cell))
+        .collect())
+}
+
+/// Returns the coordinates of the grid line between two cells.
+///
+/// Same interpolation as [`CellIndex::grid_path_cells`].
+fn grid_line(start: CoordCube, end: CoordCube) -> Vec<CoordCube> {
+    let length = distance(start, end);
+    if length == 0 {
+        return vec![start];
+    }
+
+    let i_step = f64::from(end.i - start.i) / f64::from(length);
+    let j_step = f64::from(end.j - start.j) / f64::from(length);
+    let k_step = f64::from(end.k - start.k) / f64::from(length);
+
+    (0..=length)
+        .map(|n| {
+            let n = f64::from(n);
+            start.translate((i_step * n, j_step * n, k_step * n))
+        })
+        .collect()
+}
+
+/// Returns the grid distance between two cube coordinates.
+fn distance(a: CoordCube, b: CoordCube) -> u32 {
+    (a.i - b.i)
+        .unsigned_abs()
+        .max((a.j - b.j).unsigned_abs())
+        .max((a.k - b.k).unsigned_abs())
+}
@@ -0,0 +1,641 @@
+use crate::{
+    error::InvalidTreemap, index::bits, BaseCell, CellIndex, Resolution,
+};
+use alloc::{vec, vec::Vec};
+
+/// Marker of the full nodes, in the serialized form.
+const FULL_NODE: u8 = 0x80;
+/// Size of the base cells bitmap, in the serialized form.
+const HEADER_SIZE: usize = 16;
+/// Direction skipped by the pentagons.
+const DELETED_DIGIT: usize = 1;
+/// Reserved slot of the arena, used as the null node.
+const NULL: u32 = 0;
+
+/// A hierarchical set of cells, at any resolution.
+///
+/// Cells are stored in a trie over their base cell and digits, where a cell
+/// contains all of its descendants: inserting a cell whose descendants are
+/// already in the set replaces them, and sets of siblings are merged into
+/// their parent. The set is thus always compacted, and the membership test
+/// is hierarchical.
+///
+/// This is the building block of polygon-membership services: tile the
+/// polygon once, then test points (or cells) at any resolution.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{CellIndex, CellTreemap, Resolution};
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let treemap = index.grid_disk::<CellTreemap>(1);
+///
+/// assert!(treemap.contains(index));
+/// let child = index.center_child(Resolution::Twelve).expect("child");
+/// assert!(treemap.contains(child));
+/// let parent = index.parent(Resolution::Nine).expect("parent");
+/// assert!(!treemap.contains(parent));
+/// # Ok::<(), h3o::error::InvalidCellIndex>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct CellTreemap {
+    /// Root node of each base cell.
+    roots: Vec<u32>,
+    /// Arena of nodes (the first slot is reserved).
+    nodes: Vec<Node>,
+}
+
+/// A node of the trie, i.e. a cell.
+#[derive(Debug, Clone, Copy, Default)]
+struct Node {
+    /// Nodes of the children (indexed by digit).
+    children: [u32; 7],
+    /// Whether the cell is in the set (then it has no children).
+    is_full: bool,
+}
+
+impl CellTreemap {
+    /// Initializes a new empty set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let treemap = h3o::CellTreemap::new();
+    /// assert!(treemap.is_empty());
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            roots: vec![NULL; usize::from(BaseCell::count())],
+            nodes: vec![Node::default()],
+        }
+    }
+
+    /// Returns true if the set contains no cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let treemap = h3o::CellTreemap::new();
+    /// assert!(treemap.is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.roots.iter().all(|&root| root == NULL)
+    }
+
+    /// Returns the number of cells of the set, once compacted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, CellTreemap, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let treemap = index.children(Resolution::Eleven).collect::<CellTreemap>();
+    /// // Children are merged into their parent.
+    /// assert_eq!(treemap.len(), 1);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Adds a cell to the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, CellTreemap};
+    ///
+    /// let mut treemap = CellTreemap::new();
+    /// treemap.insert(CellIndex::try_from(0x8a1fb46622dffff)?);
+    /// assert_eq!(treemap.len(), 1);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn insert(&mut self, cell: CellIndex) {
+        let resolution = cell.resolution();
+        let base_cell = usize::from(u8::from(cell.base_cell()));
+        // Nodes from the base cell to the cell.
+        let mut path = [NULL; 16];
+
+        if self.roots[base_cell] == NULL {
+            self.roots[base_cell] = self.allocate();
+        }
+        path[0] = self.roots[base_cell];
+        for res in Resolution::range(Resolution::One, resolution) {
+            let parent = path[usize::from(res) - 1];
+            if self.nodes[parent as usize].is_full {
+                return;
+            }
+            let digit = digit(cell, res);
+            let mut child = self.nodes[parent as usize].children[digit];
+            if child == NULL {
+                child = self.allocate();
+                self.nodes[parent as usize].children[digit] = child;
+            }
+            path[usize::from(res)] = child;
+        }
+
+        let node = path[usize::from(resolution)];
+        if self.nodes[node as usize].is_full {
+            return;
+        }
+        self.nodes[node as usize] = Node {
+            children: [NULL; 7],
+            is_full: true,
+        };
+
+        // Merge the siblings into their parent, as long as possible.
+        for res in Resolution::range(Resolution::Zero, resolution)
+            .rev()
+            .skip(1)
+        {
+            let parent = path[usize::from(res)];
+            let is_pentagon = cell.parent(res).expect("parent").is_pentagon();
+            let is_complete = valid_digits(is_pentagon).all(|digit| {
+                let child = self.nodes[parent as usize].children[digit];
+                child != NULL && self.nodes[child as usize].is_full
+            });
+            if !is_complete {
+                break;
+            }
+            self.nodes[parent as usize] = Node {
+                children: [NULL; 7],
+                is_full: true,
+            };
+        }
+    }
+
+    /// Returns true if the cell is in the set, either directly or through
+    /// one of its ancestors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, CellTreemap, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let treemap = CellTreemap::from_iter([index]);
+    /// let child = index.center_child(Resolution::Fifteen).expect("child");
+    /// assert!(treemap.contains(child));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn contains(&self, cell: CellIndex) -> bool {
+        let base_cell = usize::from(u8::from(cell.base_cell()));
+        let mut node = self.roots[base_cell];
+
+        for res in Resolution::range(Resolution::One, cell.resolution()) {
+            if node == NULL || self.nodes[node as usize].is_full {
+                break;
+            }
+            node = self.nodes[node as usize].children[digit(cell, res)];
+        }
+
+        node != NULL && self.nodes[node as usize].is_full
+    }
+
+    /// Returns the cell of the set covering the given cell, i.e. the cell
+    /// itself or its ancestor, if any.
+    ///
+    /// Like [`Self::contains`], the lookup walks at most one node per
+    /// resolution, whatever the size of the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, CellTreemap, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let treemap = CellTreemap::from_iter([index]);
+    /// let child = index.center_child(Resolution::Fifteen).expect("child");
+    /// assert_eq!(treemap.covering_ancestor(child), Some(index));
+    /// let parent = index.parent(Resolution::Nine).expect("parent");
+    /// assert_eq!(treemap.covering_ancestor(parent), None);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn covering_ancestor(&self, cell: CellIndex) -> Option<CellIndex> {
+        let base_cell = usize::from(u8::from(cell.base_cell()));
+        let mut node = self.roots[base_cell];
+
+        // Resolution of the current node.
+        let mut resolution = Resolution::Zero;
+
+        for res in Resolution::range(Resolution::One, cell.resolution()) {
+            if node == NULL || self.nodes[node as usize].is_full {
+                break;
+            }
+            node = self.nodes[node as usize].children[digit(cell, res)];
+            resolution = res;
+        }
+
+        (node != NULL && self.nodes[node as usize].is_full)
+            .then(|| cell.parent(resolution))
+            .flatten()
+    }
+
+    /// Returns the cells of the set, compacted and sorted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, CellTreemap};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let treemap = index.grid_disk::<CellTreemap>(1);
+    /// let cells = treemap.iter().collect::<Vec<_>>();
+    /// assert_eq!(cells.len(), 7);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = CellIndex> + '_ {
+        // Reversed, to pop them in order.
+        let mut stack = CellIndex::base_cells()
+            .zip(self.roots.iter().copied())
+            .filter(|&(_, node)| node != NULL)
+            .map(|(cell, node)| (node, cell))
+            .collect::<Vec<_>>();
+        stack.reverse();
+
+        core::iter::from_fn(move || {
+            while let Some((node, cell)) = stack.pop() {
+                let node = &self.nodes[node as usize];
+                if node.is_full {
+                    return Some(cell);
+                }
+                stack.extend(
+                    node.children
+                        .iter()
+                        .enumerate()
+                        .rev()
+                        .filter(|&(_, &child)| child != NULL)
+                        .map(|(digit, &child)| (child, child_at(cell, digit))),
+                );
+            }
+            None
+        })
+    }
+
+    /// Returns the cells covered by both sets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, CellTreemap, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let child = index.center_child(Resolution::Twelve).expect("child");
+    /// let lhs = CellTreemap::from_iter([index]);
+    /// let rhs = CellTreemap::from_iter([child]);
+    ///
+    /// let intersection = lhs.intersection(&rhs);
+    /// assert_eq!(intersection.iter().collect::<Vec<_>>(), vec![child]);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut cells = Vec::new();
+        let mut stack = CellIndex::base_cells()
+            .zip(self.roots.iter().copied().zip(other.roots.iter().copied()))
+            .filter(|&(_, (lhs, rhs))| lhs != NULL && rhs != NULL)
+            .map(|(cell, (lhs, rhs))| (lhs, rhs, cell))
+            .collect::<Vec<_>>();
+
+        while let Some((lhs, rhs, cell)) = stack.pop() {
+            let (lhs_node, rhs_node) =
+                (&self.nodes[lhs as usize], &other.nodes[rhs as usize]);
+            if lhs_node.is_full {
+                cells.extend(other.subtree(rhs, cell));
+            } else if rhs_node.is_full {
+                cells.extend(self.subtree(lhs, cell));
+            } else {
+                stack.extend(
+                    lhs_node
+                        .children
+                        .iter()
+                        .zip(rhs_node.children.iter())
+                        .enumerate()
+                        .filter(|&(_, (&lhs, &rhs))| lhs != NULL && rhs != NULL)
+                        .map(|(digit, (&lhs, &rhs))| {
+                            (lhs, rhs, child_at(cell, digit))
+                        }),
+                );
+            }
+        }
+
+        cells.into_iter().collect()
+    }
+
+    /// Returns the cells covered by either set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, CellTreemap, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let mut children = index.children(Resolution::Eleven);
+    /// let lhs = children.by_ref().take(3).collect::<CellTreemap>();
+    /// let rhs = children.collect::<CellTreemap>();
+    ///
+    /// let union = lhs.union(&rhs);
+    /// assert_eq!(union.iter().collect::<Vec<_>>(), vec![index]);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.iter());
+        union
+    }
+
+    /// Returns the cells covered by this set but not by the other one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, CellTreemap, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let child = index.center_child(Resolution::Eleven).expect("child");
+    /// let lhs = CellTreemap::from_iter([index]);
+    /// let rhs = CellTreemap::from_iter([child]);
+    ///
+    /// let difference = lhs.difference(&rhs);
+    /// assert_eq!(difference.len(), 6);
+    /// assert!(!difference.contains(child));
+    /// assert!(rhs.difference(&lhs).is_empty());
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut cells = Vec::new();
+        let mut stack = CellIndex::base_cells()
+            .zip(self.roots.iter().copied().zip(other.roots.iter().copied()))
+            .filter(|&(_, (lhs, _))| lhs != NULL)
+            .map(|(cell, (lhs, rhs))| (lhs, rhs, cell))
+            .collect::<Vec<_>>();
+
+        while let Some((lhs, rhs, cell)) = stack.pop() {
+            if rhs == NULL {
+                cells.extend(self.subtree(lhs, cell));
+                continue;
+            }
+            let (lhs_node, rhs_node) =
+                (&self.nodes[lhs as usize], &other.nodes[rhs as usize]);
+            if rhs_node.is_full {
+                continue;
+            }
+            // A full node covers every child: reuse it for each of them.
+            stack.extend(valid_digits(cell.is_pentagon()).filter_map(
+                |digit| {
+                    let lhs = if lhs_node.is_full {
+                        lhs
+                    } else {
+                        lhs_node.children[digit]
+                    };
+                    (lhs != NULL).then(|| {
+                        (lhs, rhs_node.children[digit], child_at(cell, digit))
+                    })
+                },
+            ));
+        }
+
+        cells.into_iter().collect()
+    }
+
+    /// Serializes the set into a compact binary form.
+    ///
+    /// Each node of the trie takes a single byte, which makes the output
+    /// way smaller than the list of cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, CellTreemap};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let treemap = index.grid_disk::<CellTreemap>(10);
+    /// let bytes = treemap.to_bytes();
+    /// assert!(bytes.len() < treemap.len() * 8);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let bitmap = self
+            .roots
+            .iter()
+            .enumerate()
+            .filter(|&(_, &root)| root != NULL)
+            .fold(0_u128, |bitmap, (base_cell, _)| bitmap | 1 << base_cell);
+        let mut bytes = bitmap.to_le_bytes().to_vec();
+
+        // Pre-order traversal.
+        let mut stack = self
+            .roots
+            .iter()
+            .rev()
+            .copied()
+            .filter(|&root| root != NULL)
+            .collect::<Vec<_>>();
+        while let Some(node) = stack.pop() {
+            let node = &self.nodes[node as usize];
+            if node.is_full {
+                bytes.push(FULL_NODE);
+                continue;
+            }
+            let mut mask = 0;
+            for (digit, &child) in node.children.iter().enumerate().rev() {
+                if child != NULL {
+                    mask |= 1 << digit;
+                    stack.push(child);
+                }
+            }
+            bytes.push(mask);
+        }
+
+        bytes
+    }
+
+    /// Deserializes a set from its binary form, as produced by
+    /// [`Self::to_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidTreemap`] if the input is malformed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, CellTreemap};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let treemap = index.grid_disk::<CellTreemap>(10);
+    /// let bytes = treemap.to_bytes();
+    /// assert_eq!(CellTreemap::from_bytes(&bytes)?, treemap);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, InvalidTreemap> {
+        let header = bytes
+            .get(..HEADER_SIZE)
+            .ok_or_else(|| InvalidTreemap::new("truncated header"))?;
+        let bitmap = u128::from_le_bytes(header.try_into().expect("16 bytes"));
+        if bitmap >> BaseCell::count() != 0 {
+            return Err(InvalidTreemap::new("invalid base cell"));
+        }
+
+        let mut treemap = Self::new();
+        let mut position = HEADER_SIZE;
+        for (base_cell, cell) in CellIndex::base_cells().enumerate() {
+            if bitmap & (1 << base_cell) != 0 {
+                treemap.roots[base_cell] = treemap.parse_node(
+                    bytes,
+                    &mut position,
+                    Resolution::Zero,
+                    cell.is_pentagon(),
+                )?;
+            }
+        }
+        if position != bytes.len() {
+            return Err(InvalidTreemap::new("trailing bytes"));
+        }
+
+        Ok(treemap)
+    }
+
+    /// Parses the subtree starting at the given position.
+    fn parse_node(
+        &mut self,
+        bytes: &[u8],
+        position: &mut usize,
+        resolution: Resolution,
+        is_pentagon: bool,
+    ) -> Result<u32, InvalidTreemap> {
+        let byte = *bytes
+            .get(*position)
+            .ok_or_else(|| InvalidTreemap::new("truncated node"))?;
+        *position += 1;
+
+        let node = self.allocate();
+        if byte == FULL_NODE {
+            self.nodes[node as usize].is_full = true;
+            return Ok(node);
+        }
+        if byte == 0 || byte & FULL_NODE != 0 {
+            return Err(InvalidTreemap::new("invalid node"));
+        }
+        if is_pentagon && byte & (1 << DELETED_DIGIT) != 0 {
+            return Err(InvalidTreemap::new("deleted pentagon child"));
+        }
+        let child_resolution = resolution
+            .succ()
+            .ok_or_else(|| InvalidTreemap::new("resolution overflow"))?;
+
+        let mut is_complete = true;
+        for digit in valid_digits(is_pentagon) {
+            if byte & (1 << digit) == 0 {
+                is_complete = false;
+                continue;
+            }
+            let child = self.parse_node(
+                bytes,
+                position,
+                child_resolution,
+                is_pentagon && digit == 0,
+            )?;
+            is_complete &= self.nodes[child as usize].is_full;
+            self.nodes[node as usize].children[digit] = child;
+        }
+        // Complete sets of siblings are always merged into their parent.
+        if is_complete {
+            return Err(InvalidTreemap::new("uncompacted node"));
+        }
+
+        Ok(node)
+    }
+
+    /// Returns the cells of the subtree rooted at the given node.
+    fn subtree(
+        &self,
+        node: u32,
+        cell: CellIndex,
+    ) -> impl Iterator<Item = CellIndex> + '_ {
+        let mut stack = vec![(node, cell)];
+
+        core::iter::from_fn(move || {
+            while let Some((node, cell)) = stack.pop() {
+                let node = &self.nodes[node as usize];
+                if node.is_full {
+                    return Some(cell);
+                }
+                stack.extend(
+                    node.children
+                        .iter()
+                        .enumerate()
+                        .filter(|&(_, &child)| child != NULL)
+                        .map(|(digit, &child)| (child, child_at(cell, digit))),
+                );
+            }
+            None
+        })
+    }
+
+    /// Allocates a new empty node.
+    fn allocate(&mut self) -> u32 {
+        let node = u32::try_from(self.nodes.len()).expect("too many nodes");
+        self.nodes.push(Node::default());
+        node
+    }
+}
+
+impl Default for CellTreemap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PartialEq for CellTreemap {
+    fn eq(&self, other: &Self) -> bool {
+        // Sets are always compacted, thus the representation is unique.
+        self.iter().eq(other.iter())
+    }
+}
+
+impl Eq for CellTreemap {}
+
+impl FromIterator<CellIndex> for CellTreemap {
+    fn from_iter<T: IntoIterator<Item = CellIndex>>(iter: T) -> Self {
+        let mut treemap = Self::new();
+        treemap.extend(iter);
+        treemap
+    }
+}
+
+impl Extend<CellIndex> for CellTreemap {
+    fn extend<T: IntoIterator<Item = CellIndex>>(&mut self, iter: T) {
+        for cell in iter {
+            self.insert(cell);
+        }
+    }
+}
+
+/// Returns the digit of the cell at the given resolution.
+fn digit(cell: CellIndex, resolution: Resolution) -> usize {
+    usize::from(bits::get_direction(u64::from(cell), resolution))
+}
+
+/// Returns the child of the cell with the given digit.
+fn child_at(cell: CellIndex, digit: usize) -> CellIndex {
+    let resolution = cell.resolution().succ().expect("child resolution");
+    let bits = bits::set_resolution(u64::from(cell), resolution);
+    let digit = u8::try_from(digit).expect("digit");
+    CellIndex::new_unchecked(bits::set_direction(bits, digit, resolution))
+}
+
+/// Returns the digits of the children of a cell.
+fn valid_digits(is_pentagon: bool) -> impl Iterator<Item = usize> {
+    (0..7).filter(move |&digit| !is_pentagon || digit != DELETED_DIGIT)
+}
@@ -0,0 +1,487 @@
+use crate::{
+    error::InvalidKernel, math::exp, CellIndex, DirectedEdgeIndex, Map, Set,
+};
+use alloc::vec::Vec;
+
+/// Weighting scheme used to build a [`WeightsMatrix`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WeightScheme {
+    /// Immediate neighbors (i.e. sharing an edge or a vertex), with a weight
+    /// of 1.
+    Queen,
+    /// Neighbors within `k` rings, with a weight of `1 / d` where `d` is the
+    /// grid distance.
+    InverseDistance {
+        /// Maximum grid distance of the neighbors.
+        k: u32,
+    },
+}
+
+/// Kernel used to weight the cells of a disk according to their distance.
+///
+/// Weights are computed from the grid distance `d` to the center of a disk of
+/// radius `k`, and are not normalized.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kernel {
+    /// Every cell has a weight of 1.
+    Uniform,
+    /// Weight decreases linearly with the distance: `1 - d / (k + 1)`.
+    Linear,
+    /// Weight follows a gaussian curve: `exp(-d² / (2σ²))`.
+    Gaussian {
+        /// Standard deviation, in rings (must be finite and positive).
+        sigma: f64,
+    },
+}
+
+impl Kernel {
+    /// Checks that the kernel parameters are valid.
+    pub(crate) fn validate(self) -> Result<Self, InvalidKernel> {
+        match self {
+            Self::Gaussian { sigma } if !(sigma.is_finite() && sigma > 0.) => {
+                Err(InvalidKernel::new(
+                    self,
+                    "standard deviation must be finite and positive",
+                ))
+            }
+            _ => Ok(self),
+        }
+    }
+
+    /// Returns the weight at distance `d` in a disk of radius `k`.
+    pub(crate) fn weight(self, d: u32, k: u32) -> f64 {
+        let d = f64::from(d);
+        match self {
+            Self::Uniform => 1.,
+            Self::Linear => 1. - d / (f64::from(k) + 1.),
+            Self::Gaussian { sigma } => exp(-(d * d) / (2. * sigma * sigma)),
+        }
+    }
+}
+
+/// Spatial weights between a list of cells, in Compressed Sparse Row form.
+///
+/// Rows and columns are the positions of the cells in the input list: the
+/// neighbors of the `i`-th cell are stored in
+/// `indices[offsets[i]..offsets[i + 1]]` (sorted by position), along with
+/// their weights in `weights[offsets[i]..offsets[i + 1]]`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WeightsMatrix {
+    offsets: Vec<usize>,
+    indices: Vec<usize>,
+    weights: Vec<f64>,
+}
+
+impl WeightsMatrix {
+    /// Returns the number of rows (i.e. input cells).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{weights_matrix, CellIndex, WeightScheme};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let cells = index.grid_disk::<Vec<_>>(1);
+    /// let matrix = weights_matrix(&cells, WeightScheme::Queen);
+    /// assert_eq!(matrix.len(), 7);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.offsets.len().saturating_sub(1)
+    }
+
+    /// Returns true if the matrix has no row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{weights_matrix, WeightScheme};
+    ///
+    /// let matrix = weights_matrix(&[], WeightScheme::Queen);
+    /// assert!(matrix.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the row offsets (one more than the number of rows).
+    #[must_use]
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    /// Returns the column indices of the non-zero weights.
+    #[must_use]
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Returns the non-zero weights.
+    #[must_use]
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    /// Returns the neighbors of the `i`-th cell, with their weights.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{weights_matrix, CellIndex, WeightScheme};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let cells = index.grid_disk::<Vec<_>>(2);
+    /// let scheme = WeightScheme::InverseDistance { k: 2 };
+    /// let matrix = weights_matrix(&cells, scheme);
+    ///
+    /// let neighbors = matrix.row(0).collect::<Vec<_>>();
+    /// assert_eq!(neighbors.len(), 18);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn row(&self, i: usize) -> impl Iterator<Item = (usize, f64)> + '_ {
+        let range = self
+            .offsets
+            .get(i)
+            .zip(self.offsets.get(i + 1))
+            .map_or(0..0, |(&start, &end)| start..end);
+
+        self.indices[range.clone()]
+            .iter()
+            .copied()
+            .zip(self.weights[range].iter().copied())
+    }
+}
+
+/// Computes the spatial weights between the given cells.
+///
+/// Only the neighbors that are part of `cells` are taken into account, and a
+/// cell is never its own neighbor. Cells are expected to be unique.
+///
+/// This is the building block of spatial statistics (e.g. Moran's I or
+/// LISA).
+///
+/// # Example
+///
+/// ```
+/// use h3o::{weights_matrix, CellIndex, WeightScheme};
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let cells = index.grid_disk::<Vec<_>>(1);
+/// let matrix = weights_matrix(&cells, WeightScheme::Queen);
+///
+/// // The center is connected to every other cell.
+/// assert_eq!(matrix.row(0).count(), 6);
+/// # Ok::<(), h3o::error::InvalidCellIndex>(())
+/// ```
+#[must_use]
+pub fn weights_matrix(
+    cells: &[CellIndex],
+    scheme: WeightScheme,
+) -> WeightsMatrix {
+    let k = match scheme {
+        WeightScheme::Queen => 1,
+        WeightScheme::InverseDistance { k } => k,
+    };
+
+    let mut positions = cells
+        .iter()
+        .enumerate()
+        .map(|(i, &cell)| (cell, i))
+        .collect::<Vec<_>>();
+    positions.sort_unstable();
+
+    let mut matrix = WeightsMatrix {
+        offsets: Vec::with_capacity(cells.len() + 1),
+        indices: Vec::new(),
+        weights: Vec::new(),
+    };
+    matrix.offsets.push(0);
+    let mut row = Vec::new();
+    for &cell in cells {
+        row.extend(
+            cell.grid_disk_distances::<Vec<_>>(k)
+                .into_iter()
+                .filter(|&(_, distance)| distance != 0)
+                .filter_map(|(neighbor, distance)| {
+                    let j = positions
+                        .binary_search_by_key(&neighbor, |&(cell, _)| cell)
+                        .ok()?;
+                    Some((positions[j].1, weight(scheme, distance)))
+                }),
+        );
+        row.sort_unstable_by_key(|&(j, _)| j);
+
+        matrix.indices.extend(row.iter().map(|&(j, _)| j));
+        matrix.weights.extend(row.iter().map(|&(_, w)| w));
+        matrix.offsets.push(matrix.indices.len());
+        row.clear();
+    }
+
+    matrix
+}
+
+/// Returns every pair of adjacent cells (i.e. sharing an edge) in the given
+/// list.
+///
+/// Each pair is returned once, with the smallest cell first, and the order of
+/// the pairs is unspecified. Duplicate cells are ignored, and cells at
+/// different resolutions are never adjacent.
+///
+/// The neighbors of each cell are probed in a set, which is way faster than
+/// calling [`CellIndex::is_neighbor_with`] on every pair of cells.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{adjacency_pairs, CellIndex};
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let cells = index.grid_disk::<Vec<_>>(1);
+/// let pairs = adjacency_pairs(&cells);
+///
+/// // 6 spokes, and 6 edges around the center.
+/// assert_eq!(pairs.len(), 12);
+/// # Ok::<(), h3o::error::InvalidCellIndex>(())
+/// ```
+#[must_use]
+pub fn adjacency_pairs(cells: &[CellIndex]) -> Vec<(CellIndex, CellIndex)> {
+    let set = cells.iter().copied().collect::<Set<_>>();
+
+    set.iter()
+        .flat_map(|&cell| {
+            cell.grid_disk::<Vec<_>>(1)
+                .into_iter()
+                .filter(move |&neighbor| cell < neighbor)
+                .map(move |neighbor| (cell, neighbor))
+        })
+        .filter(|(_, neighbor)| set.contains(neighbor))
+        .collect()
+}
+
+/// Traversal costs of directed edges.
+///
+/// Costs are attached to directed edges rather than cells, which allows
+/// asymmetric costs (e.g. one-way streets, or uphill vs downhill): the cost
+/// from `a` to `b` may differ from the one from `b` to `a`, or be missing
+/// altogether (i.e. the edge can't be traversed).
+///
+/// This is the input of shortest path algorithms over the grid.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EdgeWeights {
+    costs: Map<DirectedEdgeIndex, f64>,
+}
+
+impl EdgeWeights {
+    /// Initializes a new empty set of costs.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Computes the costs of the edges between adjacent cells of the given
+    /// list, from a function of the origin and destination cells.
+    ///
+    /// The function is called once per direction, and edges for which it
+    /// returns `None` are left out. Duplicate cells are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, EdgeWeights, LatLng};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let cells = index.grid_disk::<Vec<_>>(1);
+    /// // Going north is twice as expensive.
+    /// let weights = EdgeWeights::from_fn(&cells, |origin, destination| {
+    ///     let (from, to) = (LatLng::from(origin), LatLng::from(destination));
+    ///     Some(if to.lat() > from.lat() { 2. } else { 1. })
+    /// });
+    ///
+    /// // 6 spokes, and 6 edges around the center, in both directions.
+    /// assert_eq!(weights.len(), 24);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn from_fn(
+        cells: &[CellIndex],
+        mut cost: impl FnMut(CellIndex, CellIndex) -> Option<f64>,
+    ) -> Self {
+        let set = cells.iter().copied().collect::<Set<_>>();
+        let costs = set
+            .iter()
+            .flat_map(|cell| cell.edges())
+            .filter(|edge| set.contains(&edge.destination()))
+            .filter_map(|edge| {
+                cost(edge.origin(), edge.destination()).map(|cost| (edge, cost))
+            })
+            .collect();
+
+        Self { costs }
+    }
+
+    /// Returns the number of edges with a cost.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let weights = h3o::EdgeWeights::new();
+    /// assert_eq!(weights.len(), 0);
+    /// ```
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.costs.len()
+    }
+
+    /// Returns true if no edge has a cost.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let weights = h3o::EdgeWeights::new();
+    /// assert!(weights.is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.costs.is_empty()
+    }
+
+    /// Sets the cost of an edge, returning the previous one if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{DirectedEdgeIndex, EdgeWeights};
+    ///
+    /// let edge = DirectedEdgeIndex::try_from(0x13a194e699ab7fff)?;
+    /// let mut weights = EdgeWeights::new();
+    /// assert_eq!(weights.insert(edge, 1.), None);
+    /// assert_eq!(weights.insert(edge, 2.), Some(1.));
+    /// # Ok::<(), h3o::error::InvalidDirectedEdgeIndex>(())
+    /// ```
+    pub fn insert(
+        &mut self,
+        edge: DirectedEdgeIndex,
+        cost: f64,
+    ) -> Option<f64> {
+        self.costs.insert(edge, cost)
+    }
+
+    /// Removes the cost of an edge (i.e. makes it impassable), returning it
+    /// if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{DirectedEdgeIndex, EdgeWeights};
+    ///
+    /// let edge = DirectedEdgeIndex::try_from(0x13a194e699ab7fff)?;
+    /// let mut weights = EdgeWeights::new();
+    /// weights.insert(edge, 1.);
+    /// assert_eq!(weights.remove(edge), Some(1.));
+    /// assert!(weights.is_empty());
+    /// # Ok::<(), h3o::error::InvalidDirectedEdgeIndex>(())
+    /// ```
+    pub fn remove(&mut self, edge: DirectedEdgeIndex) -> Option<f64> {
+        self.costs.remove(&edge)
+    }
+
+    /// Returns the cost of an edge, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{DirectedEdgeIndex, EdgeWeights};
+    ///
+    /// let edge = DirectedEdgeIndex::try_from(0x13a194e699ab7fff)?;
+    /// let (origin, destination) = edge.cells();
+    /// let weights = EdgeWeights::from_fn(&[origin, destination], |_, _| {
+    ///     Some(1.)
+    /// });
+    /// assert_eq!(weights.get(edge), Some(1.));
+    /// # Ok::<(), h3o::error::InvalidDirectedEdgeIndex>(())
+    /// ```
+    #[must_use]
+    pub fn get(&self, edge: DirectedEdgeIndex) -> Option<f64> {
+        self.costs.get(&edge).copied()
+    }
+
+    /// Returns the cost of going from `origin` to `destination`, if they are
+    /// neighbors and the edge has a cost.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, EdgeWeights};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let cells = index.grid_disk::<Vec<_>>(1);
+    /// let weights = EdgeWeights::from_fn(&cells, |origin, _| {
+    ///     // One-way: edges leaving the center only.
+    ///     (origin == index).then_some(1.)
+    /// });
+    /// assert_eq!(weights.cost(index, cells[1]), Some(1.));
+    /// assert_eq!(weights.cost(cells[1], index), None);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn cost(
+        &self,
+        origin: CellIndex,
+        destination: CellIndex,
+    ) -> Option<f64> {
+        origin.edge(destination).and_then(|edge| self.get(edge))
+    }
+
+    /// Returns the neighbors reachable from a cell, with their costs.
+    ///
+    /// This is the successor function of shortest path algorithms (e.g.
+    /// Dijkstra or A*).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, EdgeWeights};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let cells = index.grid_disk::<Vec<_>>(1);
+    /// let weights = EdgeWeights::from_fn(&cells, |_, _| Some(1.));
+    /// assert_eq!(weights.successors(index).count(), 6);
+    /// assert_eq!(weights.successors(cells[1]).count(), 3);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn successors(
+        &self,
+        cell: CellIndex,
+    ) -> impl Iterator<Item = (CellIndex, f64)> + '_ {
+        cell.edges().filter_map(|edge| {
+            self.get(edge).map(|cost| (edge.destination(), cost))
+        })
+    }
+
+    /// Returns the edges with a cost, in an arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (DirectedEdgeIndex, f64)> + '_ {
+        self.costs.iter().map(|(&edge, &cost)| (edge, cost))
+    }
+}
+
+impl FromIterator<(DirectedEdgeIndex, f64)> for EdgeWeights {
+    fn from_iter<T: IntoIterator<Item = (DirectedEdgeIndex, f64)>>(
+        iter: T,
+    ) -> Self {
+        Self {
+            costs: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Returns the weight of a neighbor at the given grid distance.
+fn weight(scheme: WeightScheme, distance: u32) -> f64 {
+    match scheme {
+        WeightScheme::Queen => 1.,
+        WeightScheme::InverseDistance { .. } => 1. / f64::from(distance),
+    }
+}
@@ -0,0 +1,278 @@
+use h3o::{
+    aggregate_by_cell, build_pyramid, error::ResolutionMismatch, merge_by_cell,
+    resample, Aggregation, CellIndex, LatLng, Resampling, Resolution,
+};
+use std::collections::HashMap;
+
+fn points() -> Vec<(LatLng, f64)> {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    index
+        .children(Resolution::Thirteen)
+        .enumerate()
+        .map(|(i, cell)| (LatLng::from(cell), i as f64))
+        .collect()
+}
+
+#[test]
+fn aggregate() {
+    let points = points();
+    let result: HashMap<_, _> = aggregate_by_cell(
+        points.iter().copied(),
+        Resolution::Eleven,
+        || (0, 0.),
+        |(count, sum), value| {
+            *count += 1;
+            *sum += value;
+        },
+    );
+
+    assert_eq!(result.values().map(|(count, _)| count).sum::<usize>(), 343);
+    for (cell, (count, sum)) in result {
+        let values = points
+            .iter()
+            .filter(|(ll, _)| ll.to_cell(Resolution::Eleven) == cell)
+            .map(|(_, value)| value)
+            .collect::<Vec<_>>();
+        assert_eq!(count, values.len());
+        assert_eq!(sum, values.into_iter().sum::<f64>());
+    }
+}
+
+#[test]
+fn aggregate_empty() {
+    let result: HashMap<CellIndex, u32> =
+        aggregate_by_cell([], Resolution::Eleven, || 0, |_, ()| {});
+
+    assert!(result.is_empty());
+}
+
+#[test]
+fn merge() {
+    let points = points();
+    let (left, right) = points.split_at(100);
+    let count = |points: &[(LatLng, f64)]| -> HashMap<_, _> {
+        aggregate_by_cell(
+            points.iter().copied(),
+            Resolution::Eleven,
+            || 0,
+            |count, _| *count += 1,
+        )
+    };
+
+    let mut result = count(left);
+    merge_by_cell(&mut result, count(right), |count, other| *count += other);
+
+    assert_eq!(result, count(&points));
+}
+
+fn leaves() -> HashMap<CellIndex, f64> {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    index
+        .children(Resolution::Twelve)
+        .enumerate()
+        .map(|(i, cell)| (cell, i as f64))
+        .collect()
+}
+
+#[test]
+fn pyramid_sum() {
+    let values = leaves();
+    let pyramid = build_pyramid(&values, Resolution::Nine, Aggregation::Sum)
+        .expect("pyramid");
+
+    assert_eq!(
+        pyramid.keys().copied().collect::<Vec<_>>(),
+        vec![Resolution::Nine, Resolution::Ten, Resolution::Eleven]
+    );
+    assert_eq!(pyramid[&Resolution::Eleven].len(), 7);
+    assert_eq!(pyramid[&Resolution::Ten].len(), 1);
+    let total = values.values().sum::<f64>();
+    assert_eq!(pyramid[&Resolution::Nine].values().sum::<f64>(), total);
+    assert_eq!(pyramid[&Resolution::Eleven].values().sum::<f64>(), total);
+}
+
+#[test]
+fn pyramid_mean_max() {
+    let values = leaves();
+    let mean = build_pyramid(&values, Resolution::Eleven, Aggregation::Mean)
+        .expect("mean");
+    let max = build_pyramid(&values, Resolution::Eleven, Aggregation::Max)
+        .expect("max");
+
+    for (cell, value) in &mean[&Resolution::Eleven] {
+        let children = cell
+            .children(Resolution::Twelve)
+            .map(|child| values[&child])
+            .collect::<Vec<_>>();
+        let expected = children.iter().sum::<f64>() / children.len() as f64;
+        assert_eq!(*value, expected);
+        let expected = children.into_iter().fold(f64::MIN, f64::max);
+        assert_eq!(max[&Resolution::Eleven][cell], expected);
+    }
+}
+
+#[test]
+fn pyramid_pentagon() {
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+    let values = pentagon
+        .children(Resolution::Two)
+        .map(|cell| (cell, 1.))
+        .collect::<HashMap<_, _>>();
+    let sum = build_pyramid(&values, Resolution::Zero, Aggregation::Sum)
+        .expect("sum");
+    let mean = build_pyramid(&values, Resolution::Zero, Aggregation::Mean)
+        .expect("mean");
+
+    assert_eq!(sum[&Resolution::Zero][&pentagon], 41.);
+    assert_eq!(mean[&Resolution::Zero][&pentagon], 1.);
+}
+
+#[test]
+fn pyramid_empty() {
+    let values = HashMap::new();
+    let pyramid = build_pyramid(&values, Resolution::Zero, Aggregation::Sum)
+        .expect("pyramid");
+
+    assert!(pyramid.is_empty());
+}
+
+#[test]
+fn pyramid_mixed_resolutions() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let values = HashMap::from([
+        (index, 1.),
+        (index.parent(Resolution::Nine).expect("parent"), 2.),
+    ]);
+    let result = build_pyramid(&values, Resolution::Zero, Aggregation::Sum);
+
+    assert_eq!(result, Err(ResolutionMismatch));
+}
+
+#[test]
+fn pyramid_finer_coarsest() {
+    let values = leaves();
+    let result = build_pyramid(&values, Resolution::Thirteen, Aggregation::Sum);
+    assert_eq!(result, Err(ResolutionMismatch));
+
+    // Nothing coarser than the input, but still valid.
+    let pyramid = build_pyramid(&values, Resolution::Twelve, Aggregation::Sum)
+        .expect("pyramid");
+    assert!(pyramid.is_empty());
+}
+
+#[test]
+fn resample_coarsen() {
+    let values = leaves();
+    let sum: HashMap<_, _> =
+        resample(&values, Resolution::Eleven, Resampling::Sum).expect("sum");
+    let mean: HashMap<_, _> =
+        resample(&values, Resolution::Eleven, Resampling::Mean).expect("mean");
+    let pyramid = build_pyramid(&values, Resolution::Eleven, Aggregation::Sum)
+        .expect("pyramid");
+
+    assert_eq!(sum, pyramid[&Resolution::Eleven]);
+    for (cell, value) in &mean {
+        assert_eq!(*value, sum[cell] / 7.);
+    }
+}
+
+#[test]
+fn resample_majority() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let values = index
+        .children(Resolution::Eleven)
+        .enumerate()
+        .map(|(i, cell)| (cell, if i < 3 { 1. } else { f64::from(i as u8) }))
+        .collect::<HashMap<_, _>>();
+    let result: HashMap<_, _> =
+        resample(&values, Resolution::Ten, Resampling::Majority)
+            .expect("majority");
+    assert_eq!(result[&index], 1.);
+
+    // Ties are broken by picking the smallest value.
+    let values = index
+        .children(Resolution::Eleven)
+        .enumerate()
+        .map(|(i, cell)| (cell, f64::from(7 - i as u8)))
+        .collect::<HashMap<_, _>>();
+    let result: HashMap<_, _> =
+        resample(&values, Resolution::Ten, Resampling::Majority)
+            .expect("majority");
+    assert_eq!(result[&index], 1.);
+}
+
+#[test]
+fn resample_nearest() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let center = index.center_child(Resolution::Twelve).expect("center");
+    let mut values = index
+        .children(Resolution::Twelve)
+        .map(|cell| (cell, 0.))
+        .collect::<HashMap<_, _>>();
+    values.insert(center, 42.);
+    let result: HashMap<_, _> =
+        resample(&values, Resolution::Ten, Resampling::Nearest)
+            .expect("nearest");
+    assert_eq!(result[&index], 42.);
+
+    // Without the center child, the closest descendant is used.
+    values.remove(&center);
+    let result: HashMap<_, _> =
+        resample(&values, Resolution::Ten, Resampling::Nearest)
+            .expect("nearest");
+    assert_eq!(result[&index], 0.);
+}
+
+#[test]
+fn resample_refine() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let values = HashMap::from([(index, 49.)]);
+    let nearest: HashMap<_, _> =
+        resample(&values, Resolution::Twelve, Resampling::Nearest)
+            .expect("nearest");
+    let split: HashMap<_, _> =
+        resample(&values, Resolution::Twelve, Resampling::Split)
+            .expect("split");
+
+    assert_eq!(nearest.len(), 49);
+    assert!(nearest.values().all(|&value| value == 49.));
+    assert_eq!(split.len(), 49);
+    assert!(split.values().all(|&value| value == 1.));
+}
+
+#[test]
+fn resample_pentagon() {
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+    let values = HashMap::from([(pentagon, 41.)]);
+    let split: HashMap<_, _> =
+        resample(&values, Resolution::Two, Resampling::Split).expect("split");
+    assert_eq!(split.len(), 41);
+    assert!(split.values().all(|&value| value == 1.));
+
+    let back: HashMap<_, _> =
+        resample(&split, Resolution::Zero, Resampling::Sum).expect("sum");
+    assert_eq!(back, values);
+}
+
+#[test]
+fn resample_same_resolution() {
+    let values = leaves();
+    let result: HashMap<_, _> =
+        resample(&values, Resolution::Twelve, Resampling::Majority)
+            .expect("resample");
+
+    assert_eq!(result, values);
+}
+
+#[test]
+fn resample_mixed_resolutions() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let values = HashMap::from([
+        (index, 1.),
+        (index.parent(Resolution::Nine).expect("parent"), 2.),
+    ]);
+    let result: Result<HashMap<_, _>, _> =
+        resample(&values, Resolution::Zero, Resampling::Sum);
+
+    assert_eq!(result, Err(ResolutionMismatch));
+}
@@ -0,0 +1,98 @@
+use arrow_array::{BooleanArray, Int32Array, UInt64Array};
+use h3o::{
+    arrow::{
+        children_count_array, grid_distance_arrays, is_valid_array,
+        parent_array,
+    },
+    CellIndex, Resolution,
+};
+
+const CELL: u64 = 0x8a1fb46622dffff;
+const NEIGHBOR: u64 = 0x8a1fb46622d7fff;
+const PENTAGON: u64 = 0x8009fffffffffff;
+
+#[test]
+fn parent() {
+    let cells = UInt64Array::from(vec![
+        Some(CELL),
+        None,
+        Some(0),
+        Some(PENTAGON),
+        Some(0x851fb467fffffff),
+    ]);
+    let result = parent_array(&cells, Resolution::Five);
+    let expected = UInt64Array::from(vec![
+        Some(0x851fb467fffffff),
+        None,
+        None,
+        None,
+        Some(0x851fb467fffffff),
+    ]);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn children_count() {
+    let cells = UInt64Array::from(vec![Some(CELL), None, Some(PENTAGON)]);
+    let result = children_count_array(&cells, Resolution::Two);
+    let expected = UInt64Array::from(vec![Some(0), None, Some(41)]);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn grid_distance() {
+    let far = CellIndex::try_from(CELL)
+        .expect("cell")
+        .grid_ring_fast(3)
+        .next()
+        .flatten()
+        .map(u64::from);
+    let src = UInt64Array::from(vec![
+        Some(CELL),
+        Some(CELL),
+        None,
+        Some(CELL),
+        Some(1),
+    ]);
+    let dst = UInt64Array::from(vec![
+        Some(NEIGHBOR),
+        far,
+        Some(CELL),
+        Some(PENTAGON),
+        Some(CELL),
+    ]);
+    let result = grid_distance_arrays(&src, &dst).expect("distances");
+    let expected = Int32Array::from(vec![Some(1), Some(3), None, None, None]);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn grid_distance_length_mismatch() {
+    let src = UInt64Array::from(vec![CELL, NEIGHBOR]);
+    let dst = UInt64Array::from(vec![CELL]);
+
+    assert!(grid_distance_arrays(&src, &dst).is_err());
+}
+
+#[test]
+fn is_valid() {
+    let cells =
+        UInt64Array::from(vec![Some(CELL), None, Some(0), Some(u64::MAX)]);
+    let result = is_valid_array(&cells);
+    let expected =
+        BooleanArray::from(vec![Some(true), None, Some(false), Some(false)]);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn sliced() {
+    let cells = UInt64Array::from(vec![Some(0), Some(CELL), None]);
+    let result = is_valid_array(&cells.slice(1, 2));
+
+    assert_eq!(result.len(), 2);
+    assert_eq!(result, BooleanArray::from(vec![Some(true), None]));
+}
@@ -0,0 +1,211 @@
+use h3o::{
+    cells_in_bbox, cells_in_latitude_band, coverage_bbox,
+    coverage_bounding_cell, CellIndex, LatLng, Resolution,
+};
+use std::collections::HashSet;
+
+fn brute_force(
+    min: LatLng,
+    max: LatLng,
+    center: LatLng,
+    resolution: Resolution,
+    k: u32,
+) -> HashSet<CellIndex> {
+    center
+        .to_cell(resolution)
+        .grid_disk::<Vec<_>>(k)
+        .into_iter()
+        .filter(|&cell| {
+            let ll = LatLng::from(cell);
+            let lng_ok = if min.lng() > max.lng() {
+                ll.lng() >= min.lng() || ll.lng() <= max.lng()
+            } else {
+                ll.lng() >= min.lng() && ll.lng() <= max.lng()
+            };
+            ll.lat() >= min.lat() && ll.lat() <= max.lat() && lng_ok
+        })
+        .collect()
+}
+
+#[test]
+fn regular() {
+    let min = LatLng::new(48.81, 2.22).expect("min");
+    let max = LatLng::new(48.90, 2.47).expect("max");
+    let center = LatLng::new(48.855, 2.345).expect("center");
+    let cells = cells_in_bbox(min, max, Resolution::Eight);
+    let expected = brute_force(min, max, center, Resolution::Eight, 30);
+
+    assert_eq!(cells.len(), expected.len());
+    assert_eq!(cells.into_iter().collect::<HashSet<_>>(), expected);
+}
+
+#[test]
+fn antimeridian() {
+    let min = LatLng::new(-1., 179.5).expect("min");
+    let max = LatLng::new(1., -179.5).expect("max");
+    let center = LatLng::new(0., 180.).expect("center");
+    let cells = cells_in_bbox(min, max, Resolution::Five);
+    let expected = brute_force(min, max, center, Resolution::Five, 20);
+
+    assert!(!expected.is_empty());
+    assert_eq!(cells.len(), expected.len());
+    assert_eq!(cells.into_iter().collect::<HashSet<_>>(), expected);
+}
+
+#[test]
+fn high_latitude() {
+    let min = LatLng::new(80., -30.).expect("min");
+    let max = LatLng::new(85., 30.).expect("max");
+    let center = LatLng::new(82.5, 0.).expect("center");
+    let cells = cells_in_bbox(min, max, Resolution::Four);
+    let expected = brute_force(min, max, center, Resolution::Four, 30);
+
+    assert_eq!(cells.into_iter().collect::<HashSet<_>>(), expected);
+}
+
+#[test]
+fn invalid() {
+    let min = LatLng::new(10., 0.).expect("min");
+    let max = LatLng::new(0., 10.).expect("max");
+
+    assert!(cells_in_bbox(min, max, Resolution::Five).is_empty());
+}
+
+fn band_brute_force(
+    min_lat: f64,
+    max_lat: f64,
+    resolution: Resolution,
+) -> HashSet<CellIndex> {
+    CellIndex::base_cells()
+        .flat_map(|cell| cell.children(resolution))
+        .filter(|&cell| {
+            let lat = LatLng::from(cell).lat();
+            lat >= min_lat && lat <= max_lat
+        })
+        .collect()
+}
+
+#[test]
+fn latitude_band() {
+    for (min_lat, max_lat) in [(-10., 10.), (45., 46.), (-33.3, -12.7)] {
+        let cells = cells_in_latitude_band(min_lat, max_lat, Resolution::Three)
+            .collect::<Vec<_>>();
+        let expected = band_brute_force(min_lat, max_lat, Resolution::Three);
+
+        assert!(!expected.is_empty());
+        assert_eq!(cells.len(), expected.len(), "[{min_lat}, {max_lat}]");
+        assert_eq!(cells.into_iter().collect::<HashSet<_>>(), expected);
+    }
+}
+
+#[test]
+fn latitude_band_polar() {
+    for (min_lat, max_lat) in [(85., 90.), (-90., -80.), (88., 90.)] {
+        let cells = cells_in_latitude_band(min_lat, max_lat, Resolution::Three)
+            .collect::<HashSet<_>>();
+        let expected = band_brute_force(min_lat, max_lat, Resolution::Three);
+
+        assert!(!expected.is_empty());
+        assert_eq!(cells, expected, "[{min_lat}, {max_lat}]");
+    }
+}
+
+#[test]
+fn latitude_band_whole_globe() {
+    let count = cells_in_latitude_band(-90., 90., Resolution::Two).count();
+
+    assert_eq!(count as u64, Resolution::Two.cell_count());
+}
+
+#[test]
+fn latitude_band_invalid() {
+    assert_eq!(cells_in_latitude_band(10., 0., Resolution::Five).count(), 0);
+    assert_eq!(
+        cells_in_latitude_band(f64::NAN, 0., Resolution::Five).count(),
+        0
+    );
+}
+
+fn contains(min: LatLng, max: LatLng, ll: LatLng) -> bool {
+    let lng_ok = if min.lng() > max.lng() {
+        ll.lng() >= min.lng() || ll.lng() <= max.lng()
+    } else {
+        ll.lng() >= min.lng() && ll.lng() <= max.lng()
+    };
+    ll.lat() >= min.lat() && ll.lat() <= max.lat() && lng_ok
+}
+
+#[test]
+fn coverage_bbox_regular() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let cells = index.grid_disk::<Vec<_>>(3);
+    let vertices = cells
+        .iter()
+        .flat_map(|cell| cell.boundary().iter().copied().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    let (min, max) = coverage_bbox(cells).expect("bbox");
+    let lats = vertices.iter().map(|ll| ll.lat());
+    let lngs = vertices.iter().map(|ll| ll.lng());
+    assert_eq!(min.lat(), lats.clone().fold(f64::INFINITY, f64::min));
+    assert_eq!(max.lat(), lats.fold(f64::NEG_INFINITY, f64::max));
+    assert_eq!(min.lng(), lngs.clone().fold(f64::INFINITY, f64::min));
+    assert_eq!(max.lng(), lngs.fold(f64::NEG_INFINITY, f64::max));
+}
+
+#[test]
+fn coverage_bbox_transmeridian() {
+    let ll = LatLng::new(0., 180.).expect("ll");
+    let cells = ll.to_cell(Resolution::Five).grid_disk::<Vec<_>>(2);
+
+    let (min, max) = coverage_bbox(cells.iter().copied()).expect("bbox");
+    assert!(min.lng() > max.lng(), "crosses the antimeridian");
+    assert!(min.lng() > 179. && max.lng() < -179., "narrow box");
+    for cell in cells {
+        for vertex in cell.boundary().iter() {
+            assert!(contains(min, max, *vertex), "{vertex} in bbox");
+        }
+    }
+}
+
+#[test]
+fn coverage_bbox_polar() {
+    let ll = LatLng::new(90., 0.).expect("ll");
+    let cells = ll.to_cell(Resolution::Three).grid_disk::<Vec<_>>(1);
+
+    let (min, max) = coverage_bbox(cells).expect("bbox");
+    assert_eq!(max.lat(), 90.);
+    assert_eq!((min.lng(), max.lng()), (-180., 180.));
+}
+
+#[test]
+fn coverage_bbox_empty() {
+    assert_eq!(coverage_bbox([]), None);
+}
+
+#[test]
+fn coverage_bounding_cell_children() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let parent = index.parent(Resolution::Seven).expect("parent");
+
+    assert_eq!(coverage_bounding_cell([index]), Some(index));
+    assert_eq!(
+        coverage_bounding_cell(parent.children(Resolution::Nine)),
+        Some(parent)
+    );
+    // Mixed resolutions.
+    let cells = [
+        index,
+        parent.children(Resolution::Eight).last().expect("child"),
+    ];
+    assert_eq!(coverage_bounding_cell(cells), Some(parent));
+}
+
+#[test]
+fn coverage_bounding_cell_none() {
+    let a = CellIndex::try_from(0x8001fffffffffff).expect("a");
+    let b = CellIndex::try_from(0x8003fffffffffff).expect("b");
+
+    assert_eq!(coverage_bounding_cell([]), None);
+    assert_eq!(coverage_bounding_cell([a, b]), None);
+}
@@ -0,0 +1,122 @@
+use h3o::{
+    bits, CellIndex, DirectedEdgeIndex, Direction, Edge, IndexMode, Resolution,
+    Vertex,
+};
+
+#[test]
+fn get_mode() {
+    assert_eq!(bits::get_mode(0x8a1fb46622dffff), Ok(IndexMode::Cell));
+    assert_eq!(
+        bits::get_mode(0x13a194e699ab7fff),
+        Ok(IndexMode::DirectedEdge)
+    );
+    assert_eq!(bits::get_mode(0x2302bfffffffffff), Ok(IndexMode::Vertex));
+    assert!(bits::get_mode(0x7a1fb46622dffff).is_err());
+}
+
+#[test]
+fn set_mode() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let edge = cell.edges().next().expect("edge");
+    let vertex = cell.vertexes().next().expect("vertex");
+
+    // Only the mode differs between an edge and a cell with the edge bits.
+    let bits = u64::from(edge);
+    assert_eq!(bits::set_mode(bits, IndexMode::DirectedEdge), Ok(bits));
+    assert!(bits::set_mode(u64::from(cell), IndexMode::DirectedEdge).is_err());
+    assert!(bits::set_mode(u64::from(cell), IndexMode::UndirectedEdge).is_err());
+    assert!(bits::set_mode(u64::from(vertex), IndexMode::Cell).is_err());
+}
+
+#[test]
+fn set_edge() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    for edge in cell.edges() {
+        assert_eq!(bits::set_edge(cell.into(), edge.edge()), Ok(edge.into()));
+    }
+
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+    let deleted = Edge::try_from(1).expect("edge");
+    assert!(bits::set_edge(pentagon.into(), deleted).is_err());
+    let edge = u64::from(cell.edges().next().expect("edge"));
+    assert!(bits::set_edge(edge, deleted).is_err(), "not a cell");
+}
+
+#[test]
+fn set_vertex() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    for vertex in cell.vertexes() {
+        assert_eq!(
+            bits::set_vertex(vertex.owner().into(), vertex.vertex()),
+            Ok(vertex.into())
+        );
+    }
+
+    // Only the owner gives a valid vertex index.
+    let first = Vertex::try_from(0).expect("vertex");
+    assert!(bits::set_vertex(cell.into(), first).is_err());
+}
+
+#[test]
+fn resolution() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let bits = u64::from(cell);
+
+    assert_eq!(bits::get_resolution(bits), Resolution::Ten);
+    for resolution in Resolution::range(Resolution::Zero, Resolution::Ten) {
+        let expected = cell.parent(resolution).map(u64::from);
+        assert_eq!(bits::set_resolution(bits, resolution).ok(), expected);
+    }
+    for resolution in Resolution::range(Resolution::Ten, Resolution::Fifteen) {
+        let expected = cell.center_child(resolution).map(u64::from);
+        assert_eq!(bits::set_resolution(bits, resolution).ok(), expected);
+    }
+    assert!(bits::set_resolution(0, Resolution::Five).is_err());
+}
+
+#[test]
+fn resolution_edge() {
+    let edge = DirectedEdgeIndex::try_from(0x13a194e699ab7fff).expect("edge");
+    let bits = bits::set_resolution(edge.into(), Resolution::Eleven)
+        .expect("finer edge");
+
+    let finer = DirectedEdgeIndex::try_from(bits).expect("valid edge");
+    assert_eq!(finer.origin().parent(Resolution::Ten), Some(edge.origin()));
+    assert_eq!(finer.edge(), edge.edge());
+}
+
+#[test]
+fn direction() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let bits = u64::from(cell);
+
+    for resolution in Resolution::range(Resolution::One, Resolution::Ten) {
+        assert_eq!(
+            bits::get_direction(bits, resolution).ok(),
+            cell.direction_at(resolution)
+        );
+    }
+    assert!(bits::get_direction(bits, Resolution::Zero).is_err());
+    assert!(bits::get_direction(bits, Resolution::Eleven).is_err());
+
+    let bits = bits::set_direction(bits, Resolution::Ten, Direction::IJ)
+        .expect("sibling");
+    assert_eq!(CellIndex::try_from(bits).ok(), cell.sibling(Direction::IJ));
+    assert!(bits::set_direction(bits, Resolution::Zero, Direction::I).is_err());
+    assert!(
+        bits::set_direction(bits, Resolution::Twelve, Direction::I).is_err()
+    );
+}
+
+#[test]
+fn direction_pentagon() {
+    let pentagon = CellIndex::try_from(0x81083ffffffffff).expect("pentagon");
+
+    assert!(pentagon.is_pentagon());
+    assert!(bits::set_direction(
+        pentagon.into(),
+        Resolution::One,
+        Direction::K
+    )
+    .is_err());
+}
@@ -1,4 +1,13 @@
-use h3o::DirectedEdgeIndex;
+use h3o::{CellIndex, DirectedEdgeIndex, LatLng, Resolution, Winding};
+
+/// Signed area of the ring in the lng/lat plane (positive when CCW).
+fn signed_area(ring: &[LatLng]) -> f64 {
+    ring.iter()
+        .zip(ring.iter().cycle().skip(1))
+        .map(|(a, b)| a.lng().mul_add(b.lat(), -(b.lng() * a.lat())))
+        .sum::<f64>()
+        / 2.
+}
 
 #[test]
 fn display() {
@@ -10,3 +19,147 @@ fn display() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn libh3_reference() {
+    // Output of the example program of libh3, printed with 9 decimals.
+    let expected = [
+        (40.690058601, -74.044151762),
+        (40.689907695, -74.045061792),
+        (40.689270936, -74.045341418),
+        (40.688785091, -74.044711031),
+        (40.688935993, -74.043801021),
+        (40.689572744, -74.043521377),
+    ];
+    let index = LatLng::new(40.689167, -74.044444)
+        .expect("coordinate")
+        .to_cell(Resolution::Ten);
+    let boundary = index.boundary();
+
+    assert_eq!(index, CellIndex::try_from(0x8a2a1072b59ffff).expect("cell"));
+    assert_eq!(boundary.len(), expected.len());
+    for (vertex, (lat, lng)) in boundary.iter().zip(expected) {
+        assert!((vertex.lat() - lat).abs() <= 5e-10, "{vertex} vs {lat}");
+        assert!((vertex.lng() - lng).abs() <= 5e-10, "{vertex} vs {lng}");
+    }
+}
+
+#[test]
+fn oriented() {
+    let cells = CellIndex::base_cells()
+        .flat_map(|cell| cell.children(Resolution::Two))
+        .filter(|cell| {
+            // Skip cells crossing the antimeridian or containing a pole.
+            let boundary = cell.boundary();
+            let lngs = boundary.iter().map(|ll| ll.lng());
+            let span = lngs.clone().fold(f64::NEG_INFINITY, f64::max)
+                - lngs.fold(f64::INFINITY, f64::min);
+            span < 180.
+        });
+
+    for cell in cells {
+        let ccw = cell.boundary_oriented(Winding::CounterClockwise);
+        let cw = cell.boundary_oriented(Winding::Clockwise);
+
+        assert_eq!(ccw, cell.boundary(), "{cell}");
+        assert!(signed_area(&ccw) > 0., "{cell} is counter-clockwise");
+        assert!(signed_area(&cw) < 0., "{cell} is clockwise");
+    }
+}
+
+#[test]
+fn oriented_pentagon() {
+    for pentagon in Resolution::One.pentagons() {
+        let ccw = pentagon.boundary_oriented(Winding::CounterClockwise);
+        let cw = pentagon.boundary_oriented(Winding::Clockwise);
+
+        // Distortion vertices are kept.
+        assert_eq!(cw.len(), 10, "{pentagon}");
+        assert!(ccw.iter().eq(cw.iter().rev()), "{pentagon}");
+    }
+}
+
+/// Checks that the densified points follow the great circle arcs between the
+/// vertices, with segments no longer than `max_segment_rads`.
+fn check_densified(
+    vertices: &[LatLng],
+    points: &[LatLng],
+    max_segment_rads: f64,
+    closed: bool,
+) {
+    let positions = vertices
+        .iter()
+        .map(|vertex| {
+            points
+                .iter()
+                .position(|point| point == vertex)
+                .expect("vertex kept")
+        })
+        .collect::<Vec<_>>();
+    assert!(positions.is_sorted(), "vertices order is preserved");
+    assert_eq!(positions[0], 0);
+
+    let mut ends = positions.iter().skip(1).copied().collect::<Vec<_>>();
+    if closed {
+        ends.push(points.len());
+    } else {
+        assert_eq!(positions.last(), Some(&(points.len() - 1)));
+    }
+    for ((&start, end), (a, b)) in positions
+        .iter()
+        .zip(ends)
+        .zip(vertices.iter().zip(vertices.iter().cycle().skip(1)))
+    {
+        let segment = (start..=end).map(|i| points[i % points.len()]);
+        for (p, q) in segment.clone().zip(segment.clone().skip(1)) {
+            assert!(p.distance_rads(q) <= max_segment_rads + 1e-12);
+        }
+        for point in segment {
+            assert!(point.cross_track_distance_m(*a, *b).abs() < 1e-3);
+        }
+    }
+}
+
+#[test]
+fn densified() {
+    let max_segment_rads = 0.01;
+    for cell in [
+        CellIndex::try_from(0x8009fffffffffff).expect("pentagon"),
+        CellIndex::try_from(0x8001fffffffffff).expect("hexagon"),
+        CellIndex::try_from(0x81083ffffffffff).expect("distorted pentagon"),
+    ] {
+        let boundary = cell.boundary();
+        let points = cell.boundary_densified(max_segment_rads);
+
+        assert!(points.len() > boundary.len(), "{cell}");
+        check_densified(&boundary, &points, max_segment_rads, true);
+    }
+}
+
+#[test]
+fn densified_edge() {
+    let edge = DirectedEdgeIndex::try_from(0x13a194e699ab7fff).expect("edge");
+    let max_segment_rads = 1e-6;
+    let boundary = edge.boundary();
+    let points = edge.boundary_densified(max_segment_rads);
+
+    assert!(points.len() > boundary.len());
+    check_densified(&boundary, &points, max_segment_rads, false);
+}
+
+#[test]
+fn densified_disabled() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let edge = DirectedEdgeIndex::try_from(0x13a194e699ab7fff).expect("edge");
+
+    for max_segment_rads in [0., -1., f64::NAN, 1.] {
+        assert_eq!(
+            cell.boundary_densified(max_segment_rads).as_slice(),
+            &*cell.boundary()
+        );
+        assert_eq!(
+            edge.boundary_densified(max_segment_rads).as_slice(),
+            &*edge.boundary()
+        );
+    }
+}
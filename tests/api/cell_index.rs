@@ -1,4 +1,120 @@
-use h3o::{error, CellIndex, Resolution};
+use h3o::{
+    compactness_stats, error, BaseCell, CellIndex, Direction, EdgeWeights,
+    Kernel, LatLng, Resolution,
+};
+use std::{
+    collections::HashSet,
+    hash::{Hash, Hasher},
+    num::NonZeroU64,
+};
+
+#[test]
+fn grid_path_weighted() {
+    let src = CellIndex::try_from(0x8a1fb46622dffff).expect("src");
+    let area = src.grid_disk::<HashSet<_>>(6);
+    let step = |_, to| area.contains(&to).then_some(1.);
+
+    for dst in src.grid_disk::<Vec<_>>(4) {
+        let distance = src.grid_distance(dst).expect("distance");
+        let (path, cost) =
+            src.grid_path_weighted(dst, 1., step).expect("A* path");
+        let (_, dijkstra) = src
+            .grid_path_weighted(dst, 0., step)
+            .expect("Dijkstra path");
+
+        assert_eq!(cost, f64::from(distance), "{dst}");
+        assert_eq!(dijkstra, cost, "{dst}");
+        assert_eq!(path.len(), usize::try_from(distance).expect("len") + 1);
+        assert_eq!(path.first(), Some(&src));
+        assert_eq!(path.last(), Some(&dst));
+        assert!(path.windows(2).all(|pair| pair[0]
+            .is_neighbor_with(pair[1])
+            .expect("same resolution")));
+    }
+}
+
+#[test]
+fn grid_path_weighted_asymmetric() {
+    let src = CellIndex::try_from(0x8a1fb46622dffff).expect("src");
+    let cells = src.grid_disk::<Vec<_>>(5);
+    let dst = *cells.last().expect("dst");
+    // Cheap one-way detour: costly to go, cheap to come back.
+    let line = src
+        .grid_path_cells(dst)
+        .expect("line")
+        .collect::<Result<Vec<_>, _>>()
+        .expect("line cells");
+    let weights = EdgeWeights::from_fn(&cells, |from, to| {
+        let forward = line
+            .iter()
+            .position(|&cell| cell == from)
+            .is_some_and(|i| line.get(i + 1) == Some(&to));
+        Some(if forward { 10. } else { 1. })
+    });
+
+    let (forward, forward_cost) = src
+        .grid_path_weighted(dst, 1., |from, to| weights.cost(from, to))
+        .expect("forward");
+    let (backward, backward_cost) = dst
+        .grid_path_weighted(src, 1., |from, to| weights.cost(from, to))
+        .expect("backward");
+
+    assert_eq!(backward_cost, 5.);
+    assert!(forward_cost > backward_cost);
+    assert!(forward.len() > backward.len());
+    assert!(forward
+        .windows(2)
+        .any(|pair| line.contains(&pair[0]) != line.contains(&pair[1])));
+}
+
+#[test]
+fn grid_path_weighted_pentagon() {
+    let pentagon = CellIndex::try_from(0x81083ffffffffff).expect("pentagon");
+    let area = pentagon.grid_disk::<HashSet<_>>(3);
+    let ring = pentagon
+        .grid_disk_distances::<Vec<_>>(2)
+        .into_iter()
+        .filter_map(|(cell, distance)| (distance == 2).then_some(cell))
+        .collect::<Vec<_>>();
+    let (src, dst) = (ring[0], ring[ring.len() / 2]);
+
+    // Around the pentagon.
+    let (path, _) = src
+        .grid_path_weighted(dst, 1., |_, to| {
+            (area.contains(&to) && to != pentagon).then_some(1.)
+        })
+        .expect("path");
+    assert!(!path.contains(&pentagon));
+    assert!(path.windows(2).all(|pair| pair[0]
+        .is_neighbor_with(pair[1])
+        .expect("same resolution")));
+}
+
+#[test]
+fn grid_path_weighted_unreachable() {
+    let src = CellIndex::try_from(0x8a1fb46622dffff).expect("src");
+    let area = src.grid_disk::<HashSet<_>>(3);
+    let dst = src.grid_disk::<Vec<_>>(2).pop().expect("dst");
+    let child = src.center_child(Resolution::Eleven).expect("child");
+
+    // Walled destination.
+    let walls = dst.grid_disk::<HashSet<_>>(1);
+    let result = src.grid_path_weighted(dst, 1., |_, to| {
+        (area.contains(&to) && (to == dst || !walls.contains(&to)))
+            .then_some(1.)
+    });
+    assert_eq!(result, None);
+    // Negative and NaN costs are blocked.
+    assert_eq!(src.grid_path_weighted(dst, 1., |_, _| Some(-1.)), None);
+    assert_eq!(src.grid_path_weighted(dst, 1., |_, _| Some(f64::NAN)), None);
+    // Resolution mismatch.
+    assert_eq!(src.grid_path_weighted(child, 1., |_, _| Some(1.)), None);
+    // Trivial path.
+    assert_eq!(
+        src.grid_path_weighted(src, 1., |_, _| None),
+        Some((vec![src], 0.))
+    );
+}
 
 #[test]
 fn is_neighbor_with() {
@@ -31,6 +147,50 @@ fn to_local_ij() {
     assert!(result.is_err());
 }
 
+#[test]
+fn grid_move() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    assert_eq!(index.grid_move(0, 0), Ok(index));
+
+    // Unit moves reach every neighbor.
+    let moves = [(1, 0), (0, 1), (-1, 0), (0, -1), (1, 1), (-1, -1)];
+    let neighbors = moves
+        .into_iter()
+        .map(|(di, dj)| index.grid_move(di, dj).expect("neighbor"))
+        .collect::<HashSet<_>>();
+    let expected = index
+        .grid_disk::<HashSet<_>>(1)
+        .into_iter()
+        .filter(|&cell| cell != index)
+        .collect::<HashSet<_>>();
+    assert_eq!(neighbors, expected);
+
+    // Consistent with the local IJ coordinates.
+    let moved = index.grid_move(3, 2).expect("moved");
+    let origin = index.to_local_ij(index).expect("origin");
+    let target = moved.to_local_ij(index).expect("target");
+    assert_eq!(target.coord.i - origin.coord.i, 3);
+    assert_eq!(target.coord.j - origin.coord.j, 2);
+    assert_eq!(index.grid_distance(moved), Ok(3));
+}
+
+#[test]
+fn grid_move_errors() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    assert!(matches!(
+        index.grid_move(i32::MAX, 0),
+        Err(error::LocalIjError::HexGrid(_))
+    ));
+
+    // Moving across the deleted subsequence of a pentagon.
+    let pentagon = CellIndex::try_from(0x821c07fffffffff).expect("pentagon");
+    let failures = (-2..=2)
+        .flat_map(|di| (-2..=2).map(move |dj| (di, dj)))
+        .filter(|&(di, dj)| pentagon.grid_move(di, dj).is_err())
+        .count();
+    assert!(failures > 0);
+}
+
 #[test]
 fn try_from_str() {
     let result = "8a1fb46622dffff".parse::<CellIndex>();
@@ -88,6 +248,109 @@ fn child_at() {
     assert_eq!(index.child_at(24, Resolution::Five), None);
 }
 
+#[test]
+fn try_child_at() {
+    let index = CellIndex::try_from(0x881fb46623fffff).expect("index");
+
+    assert_eq!(
+        index.try_child_at(24, Resolution::Ten).ok(),
+        index.child_at(24, Resolution::Ten),
+    );
+    assert_eq!(
+        index.try_child_at(24, Resolution::Five),
+        Err(error::CellLookupError::CoarserResolution)
+    );
+    assert_eq!(
+        index.try_child_at(49, Resolution::Ten),
+        Err(error::CellLookupError::OutOfRange)
+    );
+}
+
+#[test]
+fn try_parent() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+
+    assert_eq!(index.try_parent(Resolution::Ten), Ok(index));
+    assert_eq!(
+        index.try_parent(Resolution::Five).ok(),
+        index.parent(Resolution::Five)
+    );
+    assert_eq!(
+        index.try_parent(Resolution::Eleven),
+        Err(error::CellLookupError::FinerResolution)
+    );
+}
+
+#[test]
+fn try_edge() {
+    let src = CellIndex::try_from(0x8a1fb46622dffff).expect("src");
+    let dst = CellIndex::try_from(0x8a1fb46622d7fff).expect("dst");
+
+    assert_eq!(src.try_edge(dst).ok(), src.edge(dst));
+    assert!(src.try_edge(dst).is_ok());
+    assert_eq!(src.try_edge(src), Err(error::CellLookupError::NotNeighbor));
+    assert_eq!(
+        src.try_edge(src.parent(Resolution::Nine).expect("parent")),
+        Err(error::CellLookupError::ResolutionMismatch)
+    );
+}
+
+#[test]
+fn try_vertex() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+    let vertex = h3o::Vertex::try_from(5).expect("vertex");
+
+    assert_eq!(index.try_vertex(vertex).ok(), index.vertex(vertex));
+    assert!(index.try_vertex(vertex).is_ok());
+    assert_eq!(
+        pentagon.try_vertex(vertex),
+        Err(error::CellLookupError::OutOfRange)
+    );
+}
+
+#[test]
+fn descendants_stride() {
+    for (index, resolution) in [
+        (
+            CellIndex::try_from(0x881fb46623fffff).expect("hexagon"),
+            Resolution::Eleven,
+        ),
+        (
+            CellIndex::try_from(0x8009fffffffffff).expect("pentagon"),
+            Resolution::Four,
+        ),
+    ] {
+        for stride in [1, 2, 7, 50, 1000] {
+            let stride = NonZeroU64::new(stride).expect("non-zero");
+            let result = index
+                .descendants_stride(resolution, stride)
+                .collect::<Vec<_>>();
+            let expected = index
+                .children(resolution)
+                .step_by(usize::try_from(stride.get()).expect("small stride"))
+                .collect::<Vec<_>>();
+
+            assert_eq!(result, expected, "{index} / {stride}");
+        }
+    }
+}
+
+#[test]
+fn descendants_stride_out_of_range() {
+    let index = CellIndex::try_from(0x881fb46623fffff).expect("index");
+    let stride = NonZeroU64::MAX;
+
+    let result = index.descendants_stride(Resolution::Five, stride).count();
+    assert_eq!(result, 0, "coarser resolution");
+
+    let result = index
+        .descendants_stride(Resolution::Ten, stride)
+        .collect::<Vec<_>>();
+    let expected = index.child_at(0, Resolution::Ten).expect("first child");
+    assert_eq!(result, vec![expected], "first child only");
+}
+
 #[test]
 fn child_position_roundtrip() {
     let res = Resolution::Zero;
@@ -128,6 +391,11 @@ fn succ() {
     let index = CellIndex::try_from(0x8009fffffffffff).expect("index");
     let expected = CellIndex::try_from(0x800bfffffffffff).ok();
     assert_eq!(index.succ(), expected, "base cell");
+
+    // Base cell and resolution bits look like IJ directions.
+    let index = CellIndex::try_from(0x836db6fffffffff).expect("index");
+    let expected = CellIndex::try_from(0x836e00fffffffff).ok();
+    assert_eq!(index.succ(), expected, "carry stops at base cell");
 }
 
 #[test]
@@ -158,6 +426,257 @@ fn pred() {
     let index = CellIndex::try_from(0x800bfffffffffff).expect("index");
     let expected = CellIndex::try_from(0x8009fffffffffff).ok();
     assert_eq!(index.pred(), expected, "base cell");
+
+    let index = CellIndex::try_from(0x836e00fffffffff).expect("index");
+    let expected = CellIndex::try_from(0x836db6fffffffff).ok();
+    assert_eq!(index.pred(), expected, "carry stops at base cell");
+}
+
+#[test]
+fn hash_key() {
+    let parent = CellIndex::try_from(0x8a1fb46622dffff).expect("parent");
+    let keys = parent
+        .children(Resolution::Thirteen)
+        .chain(parent.grid_disk::<Vec<_>>(5))
+        .map(CellIndex::hash_key)
+        .collect::<HashSet<_>>();
+
+    assert_eq!(keys.len(), 343 + 91, "bijective key");
+}
+
+#[test]
+fn hash_writes_hash_key() {
+    // Identity hashers expect exactly one `write_u64`.
+    #[derive(Default)]
+    struct IdentityHasher(Option<u64>);
+
+    impl Hasher for IdentityHasher {
+        fn finish(&self) -> u64 {
+            self.0.expect("hashed value")
+        }
+
+        fn write(&mut self, _bytes: &[u8]) {
+            panic!("unexpected write");
+        }
+
+        fn write_u64(&mut self, value: u64) {
+            assert!(self.0.is_none(), "single write");
+            self.0 = Some(value);
+        }
+    }
+
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let mut hasher = IdentityHasher::default();
+    index.hash(&mut hasher);
+
+    assert_eq!(hasher.finish(), index.hash_key());
+}
+
+#[test]
+fn all_at() {
+    for resolution in Resolution::range(Resolution::Zero, Resolution::Three) {
+        let cells = CellIndex::all_at(resolution);
+        let expected = CellIndex::base_cells()
+            .flat_map(|cell| cell.children(resolution))
+            .collect::<Vec<_>>();
+
+        assert_eq!(cells.len(), expected.len());
+        assert_eq!(cells.collect::<Vec<_>>(), expected);
+    }
+}
+
+#[test]
+fn all_at_len() {
+    let mut cells = CellIndex::all_at(Resolution::Fifteen);
+    let count = usize::try_from(Resolution::Fifteen.cell_count())
+        .expect("64-bit target");
+
+    assert_eq!(cells.len(), count);
+    assert_eq!(cells.next(), Some(CellIndex::first(Resolution::Fifteen)));
+    assert_eq!(cells.len(), count - 1);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_all_at() {
+    use rayon::iter::ParallelIterator;
+
+    for resolution in Resolution::range(Resolution::Zero, Resolution::Four) {
+        let cells = CellIndex::par_all_at(resolution).collect::<Vec<_>>();
+        let expected = CellIndex::all_at(resolution).collect::<Vec<_>>();
+
+        assert_eq!(cells, expected, "{resolution}");
+    }
+}
+
+#[test]
+fn pack_roundtrip() {
+    for resolution in Resolution::range(Resolution::Zero, Resolution::Fifteen) {
+        for cell in CellIndex::base_cells()
+            .flat_map(|cell| cell.center_child(resolution))
+            .chain(resolution.pentagons())
+            .chain([CellIndex::first(resolution), CellIndex::last(resolution)])
+        {
+            let key = cell.pack(resolution);
+            assert_eq!(
+                CellIndex::unpack(key, resolution),
+                Ok(cell),
+                "cell {cell}"
+            );
+        }
+    }
+}
+
+#[test]
+fn pack_ordering() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let parent = cell.parent(Resolution::Nine).expect("parent");
+
+    assert_eq!(
+        cell.pack(Resolution::Ten) >> 3,
+        parent.pack(Resolution::Nine)
+    );
+    let succ = cell.succ().expect("succ");
+    assert!(cell.pack(Resolution::Ten) < succ.pack(Resolution::Ten));
+}
+
+#[test]
+fn pack_other_resolution() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+
+    for resolution in Resolution::range(Resolution::Zero, Resolution::Fifteen) {
+        let expected = if resolution <= cell.resolution() {
+            cell.parent(resolution)
+        } else {
+            cell.center_child(resolution)
+        };
+        assert_eq!(
+            CellIndex::unpack(cell.pack(resolution), resolution).ok(),
+            expected,
+            "{resolution}"
+        );
+    }
+}
+
+#[test]
+fn unpack_invalid() {
+    // Base cell out of range.
+    assert!(CellIndex::unpack(122, Resolution::Zero).is_err());
+    assert!(CellIndex::unpack(u64::MAX, Resolution::Fifteen).is_err());
+    // Unused direction.
+    assert!(CellIndex::unpack(0o177, Resolution::One).is_err());
+    // Deleted subsequence of a pentagon.
+    assert!(CellIndex::unpack(0o41, Resolution::One).is_err());
+}
+
+#[test]
+fn token_roundtrip() {
+    for resolution in Resolution::range(Resolution::Zero, Resolution::Fifteen) {
+        for cell in CellIndex::base_cells()
+            .flat_map(|cell| cell.center_child(resolution))
+            .chain(resolution.pentagons())
+            .chain([CellIndex::first(resolution), CellIndex::last(resolution)])
+        {
+            let token = cell.to_token();
+            assert_eq!(token.len(), 18, "cell {cell}");
+            assert_eq!(CellIndex::from_token(&token), Ok(cell), "cell {cell}");
+        }
+    }
+}
+
+#[test]
+fn token_ordering() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let mut cells = cell.grid_disk::<Vec<_>>(2);
+    cells.extend(cell.children(Resolution::Eleven));
+    cells.extend(cell.ancestors());
+    cells.extend(CellIndex::base_cells());
+
+    let mut by_token = cells.clone();
+    by_token.sort_by_key(|cell| cell.to_token());
+    cells.sort_unstable();
+    assert_eq!(by_token, cells);
+}
+
+#[test]
+fn token_prefix() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let prefix = cell.token_prefix();
+
+    assert_eq!(prefix.len(), 13);
+    for resolution in Resolution::range(Resolution::Ten, Resolution::Twelve) {
+        assert!(cell
+            .children(resolution)
+            .all(|child| child.to_token().starts_with(&prefix)));
+    }
+    for neighbor in cell.grid_disk::<Vec<_>>(1).into_iter().skip(1) {
+        assert!(!neighbor.to_token().starts_with(&prefix));
+        let child = neighbor.center_child(Resolution::Twelve).expect("child");
+        assert!(!child.to_token().starts_with(&prefix));
+    }
+    let base_cell = CellIndex::base_cells().next().expect("base cell");
+    assert_eq!(base_cell.token_prefix(), "000");
+}
+
+#[test]
+fn from_token_invalid() {
+    // Wrong length.
+    assert!(CellIndex::from_token("").is_err());
+    assert!(CellIndex::from_token("0156643142133777777").is_err());
+    // Not digits.
+    assert!(CellIndex::from_token("+15664314213377777").is_err());
+    assert!(CellIndex::from_token("01566431421337777a").is_err());
+    // Base cell out of range.
+    assert!(CellIndex::from_token("122777777777777777").is_err());
+    // Not octal.
+    assert!(CellIndex::from_token("015864314213377777").is_err());
+    // Unused direction followed by a used one.
+    assert!(CellIndex::from_token("015764314213377777").is_err());
+    // Deleted subsequence of a pentagon.
+    assert!(CellIndex::from_token("004177777777777777").is_err());
+}
+
+#[test]
+fn path_roundtrip() {
+    for resolution in Resolution::range(Resolution::Zero, Resolution::Fifteen) {
+        for cell in CellIndex::base_cells()
+            .flat_map(|cell| cell.center_child(resolution))
+            .chain(resolution.pentagons())
+            .chain([CellIndex::first(resolution), CellIndex::last(resolution)])
+        {
+            let path = cell.path().collect::<Vec<_>>();
+            assert_eq!(path.len(), usize::from(resolution), "cell {cell}");
+            assert_eq!(
+                CellIndex::from_path(cell.base_cell(), &path),
+                Ok(cell),
+                "cell {cell}"
+            );
+        }
+    }
+}
+
+#[test]
+fn path_directions() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let expected = Resolution::range(Resolution::One, Resolution::Ten)
+        .map(|resolution| cell.direction_at(resolution).expect("direction"))
+        .collect::<Vec<_>>();
+
+    assert_eq!(cell.path().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn from_path_invalid() {
+    let base = BaseCell::try_from(15).expect("base cell");
+    let pentagon = BaseCell::try_from(4).expect("pentagon");
+
+    assert!(CellIndex::from_path(base, &[Direction::IJ; 16]).is_err());
+    assert!(CellIndex::from_path(pentagon, &[Direction::K]).is_err());
+    assert!(CellIndex::from_path(pentagon, &[Direction::Center; 15]).is_ok());
+    assert_eq!(
+        CellIndex::from_path(base, &[]),
+        Ok(CellIndex::base_cells().nth(15).expect("base cell 15"))
+    );
 }
 
 #[test]
@@ -302,3 +821,667 @@ fn bug_h3_915() {
     CellIndex::compact(&mut cells).expect("compact");
     assert_eq!(cells, expected);
 }
+
+fn compact_both_ways(mut cells: Vec<CellIndex>) {
+    cells.sort_unstable();
+    let result = CellIndex::compact_streaming(cells.iter().copied())
+        .collect::<Result<Vec<_>, _>>()
+        .expect("streaming compaction");
+
+    let mut expected = cells;
+    CellIndex::compact(&mut expected).expect("compaction");
+    expected.sort_unstable();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn compact_streaming() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    compact_both_ways(index.grid_disk(30));
+
+    // Base cells.
+    compact_both_ways(
+        CellIndex::base_cells()
+            .flat_map(|index| index.children(Resolution::One))
+            .collect(),
+    );
+
+    // Pentagons.
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+    let child = pentagon.center_child(Resolution::Five).expect("child");
+    compact_both_ways(child.grid_disk(20));
+    compact_both_ways(pentagon.children(Resolution::Three).collect());
+
+    // Holes.
+    let parent = index.parent(Resolution::Seven).expect("parent");
+    compact_both_ways(
+        parent
+            .children(Resolution::Ten)
+            .enumerate()
+            .filter_map(|(i, cell)| (i % 50 != 7).then_some(cell))
+            .collect(),
+    );
+}
+
+#[test]
+fn compact_streaming_errors() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let parent = index.parent(Resolution::Nine).expect("parent");
+    let next = index.succ().expect("next");
+
+    let result = CellIndex::compact_streaming([index, parent]).last();
+    assert_eq!(
+        result,
+        Some(Err(error::CompactionError::HeterogeneousResolution))
+    );
+    let result = CellIndex::compact_streaming([index, index]).last();
+    assert_eq!(result, Some(Err(error::CompactionError::DuplicateInput)));
+    let result = CellIndex::compact_streaming([next, index]).last();
+    assert_eq!(result, Some(Err(error::CompactionError::UnsortedInput)));
+    assert_eq!(CellIndex::compact_streaming([]).count(), 0);
+}
+
+#[test]
+fn centroid_of() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+
+    // Symmetric set.
+    let disk = index.grid_disk::<Vec<_>>(3);
+    let centroid = CellIndex::centroid_of(disk).expect("centroid");
+    assert!(centroid.distance_m(index.into()) < 1.);
+
+    // Area-weighted: the coarse cell pulls the centroid toward it.
+    let coarse = CellIndex::try_from(0x851fb467fffffff).expect("coarse");
+    let fine = coarse
+        .grid_ring_fast(3)
+        .find_map(|cell| cell.and_then(|c| c.center_child(Resolution::Ten)))
+        .expect("fine");
+    let centroid = CellIndex::centroid_of([coarse, fine]).expect("centroid");
+    let (to_coarse, to_fine) = (
+        centroid.distance_m(coarse.into()),
+        centroid.distance_m(fine.into()),
+    );
+    assert!(to_coarse < to_fine / 100., "{to_coarse} / {to_fine}");
+
+    assert_eq!(CellIndex::centroid_of([]), None);
+}
+
+#[test]
+fn centroid_of_antimeridian() {
+    let west = LatLng::new(0., 179.99).expect("west");
+    let east = LatLng::new(0., -179.99).expect("east");
+    let cells = [west, east].map(|ll| ll.to_cell(Resolution::Ten));
+    let centroid = CellIndex::centroid_of(cells).expect("centroid");
+
+    assert!(centroid.lng().abs() > 179.9, "{centroid}");
+}
+
+#[test]
+fn representative_point() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let ring = index
+        .grid_ring_fast(2)
+        .collect::<Option<Vec<_>>>()
+        .expect("ring");
+
+    // The centroid is in the hole, not the representative point.
+    let centroid = CellIndex::centroid_of(ring.iter().copied());
+    let centroid = centroid.expect("centroid").to_cell(Resolution::Ten);
+    assert!(!ring.contains(&centroid));
+    let point =
+        CellIndex::representative_point(ring.iter().copied()).expect("point");
+    assert!(ring.contains(&point.to_cell(Resolution::Ten)));
+
+    // Convex set: the center is picked.
+    let disk = index.grid_disk::<Vec<_>>(2);
+    let point = CellIndex::representative_point(disk).expect("point");
+    assert_eq!(point, LatLng::from(index));
+
+    assert_eq!(CellIndex::representative_point([]), None);
+}
+
+#[test]
+fn representative_point_degenerate() {
+    // Every base cell: the centroid is undefined.
+    let point = CellIndex::representative_point(CellIndex::base_cells());
+    assert!(point.is_some());
+}
+
+#[test]
+fn ancestors() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let expected = (0..10)
+        .rev()
+        .map(|res| {
+            let res = Resolution::try_from(res).expect("resolution");
+            cell.parent(res).expect("parent")
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(cell.ancestors().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn ancestors_base_cell() {
+    let cell = CellIndex::try_from(0x8009fffffffffff).expect("cell");
+
+    assert_eq!(cell.ancestors().count(), 0);
+}
+
+#[test]
+fn center_vec3() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let v = index.center_vec3();
+    let norm = (v.x * v.x + v.y * v.y + v.z * v.z).sqrt();
+
+    assert!((norm - 1.).abs() < 1e-12, "unit vector");
+    assert_eq!(v, LatLng::from(index).to_vec3());
+    // Neighbors are close to each other on the unit sphere.
+    for neighbor in index.grid_disk::<Vec<_>>(1) {
+        assert!(v.distance(&neighbor.center_vec3()) < 1e-5);
+    }
+}
+
+#[test]
+fn bearing_to() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let center = LatLng::from(index);
+
+    for neighbor in index.grid_disk::<Vec<_>>(1) {
+        let expected = center.azimuth_rads(neighbor.into());
+        assert_eq!(index.bearing_to(neighbor), expected);
+        assert!((0.0..std::f64::consts::TAU).contains(&expected));
+    }
+    // Neighbors are spread all around.
+    let mut bearings = index
+        .grid_ring_fast(1)
+        .map(|cell| index.bearing_to(cell.expect("neighbor")))
+        .collect::<Vec<_>>();
+    bearings.sort_by(f64::total_cmp);
+    for pair in bearings.windows(2) {
+        let delta = (pair[1] - pair[0]).to_degrees();
+        assert!((50.0..70.0).contains(&delta), "{delta}");
+    }
+}
+
+#[test]
+fn starts_with() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+
+    assert!(cell.starts_with(cell));
+    for ancestor in cell.ancestors() {
+        assert!(cell.starts_with(ancestor));
+        assert!(!ancestor.starts_with(cell));
+    }
+    for sibling in cell.siblings() {
+        assert!(!cell.starts_with(sibling));
+    }
+    let other = CellIndex::try_from(0x8009fffffffffff).expect("other");
+    assert!(!cell.starts_with(other));
+}
+
+#[test]
+fn descendant_key_range() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let parent = cell.parent(Resolution::Nine).expect("parent");
+    // A sorted store with the neighborhood at several resolutions.
+    let store = parent
+        .grid_disk::<Vec<_>>(1)
+        .into_iter()
+        .flat_map(|cell| {
+            Resolution::range(Resolution::Nine, Resolution::Twelve)
+                .flat_map(move |res| cell.children(res))
+        })
+        .map(u64::from)
+        .collect::<std::collections::BTreeSet<_>>();
+
+    for res in Resolution::range(Resolution::Ten, Resolution::Twelve) {
+        let range = cell.descendant_key_range(res).expect("range");
+        let scanned = store
+            .range(range)
+            .map(|&bits| CellIndex::try_from(bits).expect("cell"))
+            .collect::<Vec<_>>();
+        let mut expected = cell.children(res).collect::<Vec<_>>();
+        expected.sort_unstable();
+
+        assert_eq!(scanned, expected, "resolution {res}");
+        assert!(scanned.iter().all(|child| child.starts_with(cell)));
+    }
+
+    assert_eq!(
+        cell.descendant_key_range(Resolution::Ten),
+        Some(u64::from(cell)..=u64::from(cell))
+    );
+    assert_eq!(cell.descendant_key_range(Resolution::Nine), None);
+}
+
+#[test]
+fn descendant_key_range_pentagon() {
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+    let range = pentagon
+        .descendant_key_range(Resolution::Two)
+        .expect("range");
+
+    assert!(pentagon
+        .children(Resolution::Two)
+        .all(|child| range.contains(&u64::from(child))));
+    for bits in [*range.start(), *range.end()] {
+        let bound = CellIndex::try_from(bits).expect("valid bound");
+        assert!(bound.starts_with(pentagon));
+    }
+}
+
+#[test]
+fn recenter_at() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+
+    assert_eq!(index.recenter_at(Resolution::Ten), index);
+    for res in Resolution::range(Resolution::Zero, Resolution::Fifteen) {
+        let cell = index.recenter_at(res);
+        assert_eq!(cell.resolution(), res);
+        assert_eq!(cell.recenter_at(res), cell);
+    }
+    // Finer cells are centered on the center of the original cell.
+    let child = index.recenter_at(Resolution::Fifteen);
+    assert_eq!(child.recenter_at(Resolution::Ten), index);
+}
+
+#[test]
+fn recenter_at_differs_from_parent() {
+    // Cells don't exactly nest: some centers fall outside of the parent.
+    let index = CellIndex::try_from(0x851fb467fffffff).expect("index");
+    let (same, different): (Vec<_>, Vec<_>) = index
+        .children(Resolution::Seven)
+        .partition(|child| child.recenter_at(Resolution::Five) == index);
+
+    assert!(!same.is_empty());
+    assert!(!different.is_empty());
+    for child in different {
+        let cell = child.recenter_at(Resolution::Five);
+        assert_eq!(index.grid_distance(cell), Ok(1));
+    }
+}
+
+#[test]
+fn siblings() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let parent = index.parent(Resolution::Nine).expect("parent");
+    let expected = parent
+        .children(Resolution::Ten)
+        .filter(|&child| child != index)
+        .collect::<Vec<_>>();
+
+    assert_eq!(index.siblings().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn siblings_pentagon() {
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+    let child = pentagon.center_child(Resolution::One).expect("child");
+    let expected = pentagon
+        .children(Resolution::One)
+        .filter(|&cell| cell != child)
+        .collect::<Vec<_>>();
+
+    assert_eq!(child.siblings().collect::<Vec<_>>(), expected);
+    assert_eq!(child.sibling(h3o::Direction::K), None);
+    assert_eq!(pentagon.siblings().count(), 0);
+}
+
+#[test]
+fn sibling() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let direction = index.direction_at(Resolution::Ten).expect("direction");
+
+    assert_eq!(index.sibling(direction), Some(index));
+    for direction in h3o::Direction::iter() {
+        let sibling = index.sibling(direction).expect("sibling");
+        assert_eq!(
+            sibling.parent(Resolution::Nine),
+            index.parent(Resolution::Nine)
+        );
+        assert_eq!(sibling.direction_at(Resolution::Ten), Some(direction));
+    }
+}
+
+#[test]
+fn grid_disk_distances_safe_ordering() {
+    // Around a pentagon, to exercise the irregular case too.
+    for index in [0x8a1fb46622dffff, 0x8508000ffffffff] {
+        let index = CellIndex::try_from(index).expect("index");
+        let distances = index
+            .grid_disk_distances_safe(5)
+            .map(|(_, distance)| distance)
+            .collect::<Vec<_>>();
+
+        assert!(distances.is_sorted(), "{index}");
+    }
+}
+
+#[test]
+fn grid_ring() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let fast = index.grid_ring_fast(3).collect::<Option<Vec<_>>>();
+
+    assert_eq!(Some(index.grid_ring::<Vec<_>>(3)), fast);
+    assert_eq!(index.grid_ring::<Vec<_>>(0), vec![index]);
+}
+
+#[test]
+fn grid_ring_pentagon() {
+    // A pentagon, and a hexagon next to it.
+    for index in [0x8508000ffffffff, 0x8508001bfffffff] {
+        let index = CellIndex::try_from(index).expect("index");
+        let disk = index.grid_disk_distances_safe(4).collect::<Vec<_>>();
+
+        for k in 0..=4 {
+            let expected = disk
+                .iter()
+                .filter_map(|&(cell, distance)| (distance == k).then_some(cell))
+                .collect::<HashSet<_>>();
+            let ring = index.grid_ring::<Vec<_>>(k);
+
+            assert_eq!(ring.len(), expected.len(), "{index} at {k}");
+            assert_eq!(ring.into_iter().collect::<HashSet<_>>(), expected);
+        }
+    }
+}
+
+#[test]
+fn grid_rings() {
+    for index in [0x8a1fb46622dffff, 0x8508000ffffffff] {
+        let index = CellIndex::try_from(index).expect("index");
+        let rings = index.grid_rings(4).collect::<Vec<_>>();
+        let disk = index.grid_disk_distances_safe(4).collect::<HashSet<_>>();
+
+        assert_eq!(rings.len(), 5);
+        assert_eq!(rings.iter().map(Vec::len).sum::<usize>(), disk.len());
+        for (distance, ring) in rings.into_iter().enumerate() {
+            let distance = u32::try_from(distance).expect("distance");
+            for cell in ring {
+                assert!(disk.contains(&(cell, distance)), "{cell}");
+            }
+        }
+    }
+}
+
+#[test]
+fn neighbors_with_direction() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let neighbors = index.neighbors_with_direction().collect::<Vec<_>>();
+    let expected = index
+        .grid_disk::<HashSet<_>>(1)
+        .into_iter()
+        .filter(|&cell| cell != index)
+        .collect::<HashSet<_>>();
+
+    assert_eq!(neighbors.len(), 6);
+    assert_eq!(
+        neighbors
+            .iter()
+            .map(|&(_, cell)| cell)
+            .collect::<HashSet<_>>(),
+        expected
+    );
+    for (direction, neighbor) in neighbors {
+        let edge = index.edge(neighbor).expect("edge");
+        assert_eq!(Direction::from(edge.edge()), direction);
+    }
+}
+
+#[test]
+fn neighbors_with_direction_pentagon() {
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+    let neighbors = pentagon.neighbors_with_direction().collect::<Vec<_>>();
+
+    assert_eq!(neighbors.len(), 5);
+    assert!(neighbors
+        .iter()
+        .all(|&(direction, _)| direction != Direction::K));
+}
+
+#[test]
+fn buffer_m() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let edge_length = index.resolution().edge_length_m();
+
+    assert_eq!(index.buffer_m::<Vec<_>>(0.), vec![index]);
+    assert_eq!(index.buffer_m::<Vec<_>>(0.5 * edge_length), vec![index]);
+    assert_eq!(
+        index.buffer_m::<HashSet<_>>(edge_length),
+        index.grid_disk::<HashSet<_>>(1)
+    );
+}
+
+#[test]
+fn buffer_m_distances() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let center = LatLng::from(index);
+    // Generous bound on the cell radius.
+    let radius = 2. * index.resolution().edge_length_m();
+    let distance = 1000.;
+    let buffer = index.buffer_m::<HashSet<_>>(distance);
+
+    for cell in index.grid_disk::<Vec<_>>(30) {
+        let center_distance = LatLng::from(cell).distance_m(center);
+        if center_distance <= distance {
+            assert!(buffer.contains(&cell), "{cell} is close enough");
+        }
+        if center_distance > distance + radius {
+            assert!(!buffer.contains(&cell), "{cell} is too far");
+        }
+    }
+    assert!(buffer.is_subset(&index.buffer_m(2. * distance)));
+}
+
+#[test]
+fn grid_distance_to_set() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let set = index.grid_ring_fast(3).collect::<Option<HashSet<_>>>();
+    let set = set.expect("ring");
+    let contains = |cell| set.contains(&cell);
+
+    assert_eq!(index.grid_distance_to_set(contains, 5), Some(3));
+    assert_eq!(index.grid_distance_to_set(contains, 3), Some(3));
+    assert_eq!(index.grid_distance_to_set(contains, 2), None);
+    assert_eq!(index.grid_distance_to_set(|cell| cell == index, 0), Some(0));
+    assert_eq!(index.grid_distance_to_set(|_| false, 4), None);
+}
+
+#[test]
+fn grid_distance_to_set_matches_grid_distance() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let set = [
+        CellIndex::try_from(0x8a1fb4644937fff).expect("first"),
+        CellIndex::try_from(0x8a1fb46622d7fff).expect("second"),
+    ];
+    let expected = set
+        .iter()
+        .map(|&cell| index.grid_distance(cell).expect("distance"))
+        .min()
+        .and_then(|distance| u32::try_from(distance).ok());
+
+    let result = index.grid_distance_to_set(|cell| set.contains(&cell), 100);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn grid_distance_to_set_pentagon() {
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+    let neighbor = pentagon.grid_disk::<Vec<_>>(1)[1];
+
+    let result = neighbor.grid_distance_to_set(|cell| cell == pentagon, 2);
+    assert_eq!(result, Some(1));
+}
+
+#[test]
+fn grid_disks() {
+    // Overlapping disks, one of them around a pentagon.
+    let origins = [0x8a1fb46622dffff, 0x8a1fb46622d7fff, 0x8508000ffffffff]
+        .map(|index| CellIndex::try_from(index).expect("index"));
+    let expected = origins
+        .iter()
+        .flat_map(|origin| origin.grid_disk_safe(2))
+        .collect::<HashSet<_>>();
+
+    let result = CellIndex::grid_disks::<Vec<_>>(origins, 2);
+    assert_eq!(result.len(), expected.len(), "no duplicate");
+    assert_eq!(result.into_iter().collect::<HashSet<_>>(), expected);
+}
+
+#[test]
+fn grid_disks_grouped() {
+    let origins = [0x8a1fb46622dffff, 0x8a1fb46622d7fff, 0x8508000ffffffff]
+        .map(|index| CellIndex::try_from(index).expect("index"));
+    let groups = CellIndex::grid_disks_grouped(origins, 2).collect::<Vec<_>>();
+
+    assert_eq!(groups.len(), origins.len());
+    for ((origin, cells), expected) in groups.into_iter().zip(origins) {
+        assert_eq!(origin, expected);
+        assert_eq!(
+            cells.into_iter().collect::<HashSet<_>>(),
+            origin.grid_disk_safe(2).collect::<HashSet<_>>()
+        );
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_grid_disks_grouped() {
+    use rayon::iter::ParallelIterator;
+
+    let origins = [0x8a1fb46622dffff, 0x8a1fb46622d7fff, 0x8508000ffffffff]
+        .map(|index| CellIndex::try_from(index).expect("index"));
+    let groups =
+        CellIndex::par_grid_disks_grouped(origins, 2).collect::<Vec<_>>();
+    let expected =
+        CellIndex::grid_disks_grouped(origins, 2).collect::<Vec<_>>();
+
+    assert_eq!(groups, expected);
+}
+
+#[test]
+fn grid_disk_weighted() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let distances = index.grid_disk_distances::<Vec<_>>(3);
+
+    let weighted = |kernel| {
+        index
+            .grid_disk_weighted::<Vec<_>>(3, kernel)
+            .expect("valid kernel")
+    };
+
+    let uniform = weighted(Kernel::Uniform);
+    assert_eq!(uniform.len(), distances.len());
+    assert!(uniform.iter().all(|&(_, weight)| weight == 1.));
+
+    let linear = weighted(Kernel::Linear);
+    for (&(cell, weight), &(expected, distance)) in
+        linear.iter().zip(&distances)
+    {
+        assert_eq!(cell, expected);
+        assert_eq!(weight, 1. - f64::from(distance) / 4.);
+    }
+
+    let gaussian = weighted(Kernel::Gaussian { sigma: 1. });
+    for (&(_, weight), &(_, distance)) in gaussian.iter().zip(&distances) {
+        let expected = (-f64::from(distance * distance) / 2.).exp();
+        assert!((weight - expected).abs() < f64::EPSILON);
+    }
+}
+
+#[test]
+fn grid_disk_weighted_invalid_kernel() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+
+    for sigma in [0., -1., f64::NAN, f64::INFINITY] {
+        let kernel = Kernel::Gaussian { sigma };
+        let result = index.grid_disk_weighted::<Vec<_>>(3, kernel);
+        assert!(result.is_err(), "{sigma}");
+    }
+}
+
+#[test]
+fn antipode() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let antipode = index.antipode();
+    let ll = LatLng::from(index);
+    let expected = LatLng::new(-ll.lat(), ll.lng() - 180.).expect("antipode");
+
+    assert_eq!(antipode.resolution(), index.resolution());
+    assert_eq!(antipode, expected.to_cell(Resolution::Ten));
+}
+
+#[test]
+fn antipode_involution() {
+    // Centers are symmetric at coarse resolutions.
+    for index in CellIndex::base_cells() {
+        assert_eq!(index.antipode().antipode(), index, "{index}");
+    }
+}
+
+#[test]
+fn compactness() {
+    // Close to the one of a regular hexagon (π√3 / 6).
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let compactness = index.compactness();
+    assert!((0.88..0.91).contains(&compactness), "{compactness}");
+
+    // Pentagons are less compact than hexagons.
+    let pentagon = CellIndex::try_from(0x8a0800000007fff).expect("pentagon");
+    assert!(pentagon.compactness() < compactness);
+}
+
+#[test]
+fn compactness_stats_batch() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let cells = index.grid_disk::<Vec<_>>(2);
+    let stats = compactness_stats(cells.iter().copied()).expect("stats");
+    let values = cells
+        .iter()
+        .map(|cell| cell.compactness())
+        .collect::<Vec<_>>();
+
+    assert_eq!(stats.count, cells.len());
+    assert_eq!(stats.min, values.iter().copied().fold(f64::MAX, f64::min));
+    assert_eq!(stats.max, values.iter().copied().fold(f64::MIN, f64::max));
+    assert!(stats.min <= stats.mean && stats.mean <= stats.max);
+    assert!(stats.std_dev >= 0. && stats.std_dev <= stats.max - stats.min);
+
+    let single = compactness_stats([index]).expect("single");
+    assert_eq!(single.mean, index.compactness());
+    assert_eq!(single.std_dev, 0.);
+
+    assert_eq!(compactness_stats([]), None);
+}
+
+#[test]
+fn center_roundtrip() {
+    for resolution in Resolution::range(Resolution::Zero, Resolution::Three) {
+        for index in
+            CellIndex::base_cells().flat_map(|cell| cell.children(resolution))
+        {
+            let center = index.center_snapped();
+            assert_eq!(center, LatLng::from(index), "{index}");
+            assert_eq!(center.to_cell(resolution), index, "{index}");
+        }
+    }
+    // Pentagon centers lie on icosahedron vertices, and the boundaries of the
+    // cells spanning several faces have vertices on the icosahedron edges: the
+    // worst cases.
+    let face_edges = CellIndex::base_cells()
+        .flat_map(|cell| cell.children(Resolution::Two))
+        .filter(|cell| cell.icosahedron_faces().len() > 1)
+        .flat_map(|cell| cell.boundary().iter().copied().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    for resolution in Resolution::range(Resolution::Four, Resolution::Fifteen) {
+        let cells = resolution
+            .pentagons()
+            .chain(face_edges.iter().map(|ll| ll.to_cell(resolution)))
+            .flat_map(|cell| cell.grid_disk::<Vec<_>>(1));
+        for index in cells {
+            let center = index.center_snapped();
+            assert_eq!(center.to_cell(resolution), index, "{index}");
+        }
+    }
+}
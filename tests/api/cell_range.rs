@@ -0,0 +1,135 @@
+use h3o::{error::ResolutionMismatch, CellIndex, CellRange, Resolution};
+
+fn cells(resolution: Resolution) -> Vec<CellIndex> {
+    CellIndex::all_at(resolution).collect()
+}
+
+#[test]
+fn new() {
+    let cells = cells(Resolution::One);
+    let range = CellRange::new(cells[10], cells[2]).expect("range");
+
+    assert_eq!(range.start(), cells[2], "bounds are reordered");
+    assert_eq!(range.end(), cells[10]);
+    assert_eq!(range.resolution(), Resolution::One);
+
+    let result = CellRange::new(cells[0], CellIndex::first(Resolution::Two));
+    assert_eq!(result, Err(ResolutionMismatch));
+}
+
+#[test]
+fn len() {
+    for resolution in Resolution::range(Resolution::Zero, Resolution::Three) {
+        let cells = cells(resolution);
+        let bounds = [(0, cells.len() - 1), (5, 5), (3, 100), (90, 700)];
+        for (start, end) in
+            bounds.into_iter().filter(|&(_, end)| end < cells.len())
+        {
+            let range =
+                CellRange::new(cells[start], cells[end]).expect("range");
+            let expected = u64::try_from(end - start + 1).expect("len");
+
+            assert_eq!(range.len(), expected, "{resolution}: {start}..={end}");
+            assert_eq!(range.iter().count(), end - start + 1);
+        }
+    }
+}
+
+#[test]
+fn iter() {
+    let cells = cells(Resolution::Two);
+    let range = CellRange::new(cells[42], cells[1337]).expect("range");
+
+    assert!(range.iter().eq(cells[42..=1337].iter().copied()));
+    assert!(CellRange::single(cells[42]).iter().eq([cells[42]]));
+}
+
+#[test]
+fn contains() {
+    let cells = cells(Resolution::Two);
+    let range = CellRange::new(cells[42], cells[1337]).expect("range");
+
+    assert!(range.contains(cells[42]));
+    assert!(range.contains(cells[1000]));
+    assert!(range.contains(cells[1337]));
+    assert!(!range.contains(cells[41]));
+    assert!(!range.contains(cells[1338]));
+
+    // Children share the prefix of their parent, but not its resolution.
+    let child = cells[1000].center_child(Resolution::Five).expect("child");
+    assert!(!range.contains(child));
+}
+
+#[test]
+fn from_cells() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let mut disk = index.grid_disk::<Vec<_>>(5);
+    disk.extend(disk.clone()); // Duplicates are ignored.
+
+    let ranges = CellRange::from_cells(disk.iter().copied()).expect("ranges");
+    let mut expected = disk;
+    expected.sort_unstable();
+    expected.dedup();
+
+    assert!(ranges.len() < expected.len(), "contiguous cells are merged");
+    assert!(ranges.windows(2).all(|pair| {
+        pair[0].end() < pair[1].start()
+            && pair[0].end().succ() != Some(pair[1].start())
+    }));
+    let result = ranges.iter().flat_map(|range| range.iter());
+    assert!(result.eq(expected));
+}
+
+#[test]
+fn from_cells_mismatch() {
+    let result = CellRange::from_cells([
+        CellIndex::first(Resolution::One),
+        CellIndex::first(Resolution::Two),
+    ]);
+    assert_eq!(result, Err(ResolutionMismatch));
+
+    let result = CellRange::from_cells([]);
+    assert_eq!(result, Ok(Vec::new()));
+}
+
+#[test]
+fn union() {
+    let cells = cells(Resolution::One);
+    let range = |start: usize, end: usize| {
+        CellRange::new(cells[start], cells[end]).expect("range")
+    };
+
+    let result =
+        CellRange::union(&[range(20, 30), range(0, 10)], &[range(5, 19)]);
+    assert_eq!(result, Ok(vec![range(0, 30)]));
+
+    let result = CellRange::union(&[range(0, 10)], &[range(12, 20)]);
+    assert_eq!(result, Ok(vec![range(0, 10), range(12, 20)]));
+
+    let result = CellRange::union(&[range(0, 10)], &[]);
+    assert_eq!(result, Ok(vec![range(0, 10)]));
+}
+
+#[test]
+fn intersection() {
+    let cells = cells(Resolution::One);
+    let range = |start: usize, end: usize| {
+        CellRange::new(cells[start], cells[end]).expect("range")
+    };
+
+    let result = CellRange::intersection(
+        &[range(0, 10), range(20, 30), range(40, 50)],
+        &[range(5, 25), range(50, 60)],
+    );
+    assert_eq!(result, Ok(vec![range(5, 10), range(20, 25), range(50, 50)]));
+
+    let result = CellRange::intersection(&[range(0, 10)], &[range(11, 20)]);
+    assert_eq!(result, Ok(Vec::new()));
+
+    let result = CellRange::intersection(&[range(0, 10)], &[]);
+    assert_eq!(result, Ok(Vec::new()));
+
+    let other = CellRange::single(CellIndex::first(Resolution::Two));
+    let result = CellRange::intersection(&[range(0, 10)], &[other]);
+    assert_eq!(result, Err(ResolutionMismatch));
+}
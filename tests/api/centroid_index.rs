@@ -0,0 +1,80 @@
+use h3o::{CellCentroidIndex, CellIndex, LatLng, Resolution};
+
+fn cells() -> Vec<CellIndex> {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    // Sparse coverage: keep one cell out of three.
+    index
+        .grid_disk::<Vec<_>>(20)
+        .into_iter()
+        .step_by(3)
+        .collect()
+}
+
+fn brute_force(cells: &[CellIndex], ll: LatLng) -> Vec<(f64, CellIndex)> {
+    let mut distances = cells
+        .iter()
+        .map(|&cell| (ll.distance_km(LatLng::from(cell)), cell))
+        .collect::<Vec<_>>();
+    distances.sort_by(|a, b| a.0.total_cmp(&b.0));
+    distances
+}
+
+#[test]
+fn empty() {
+    let centroids = CellCentroidIndex::default();
+    let ll = LatLng::new(48.864716, 2.349014).expect("ll");
+
+    assert!(centroids.is_empty());
+    assert!(centroids.nearest(ll, 3).is_empty());
+    assert!(centroids.within_radius(ll, 100.).is_empty());
+}
+
+#[test]
+fn nearest() {
+    let cells = cells();
+    let centroids = cells.iter().copied().collect::<CellCentroidIndex>();
+    assert_eq!(centroids.len(), cells.len());
+
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    for target in index.children(Resolution::Twelve).step_by(5) {
+        let ll = LatLng::from(target);
+        let expected = brute_force(&cells, ll)
+            .into_iter()
+            .take(10)
+            .map(|(_, cell)| cell)
+            .collect::<Vec<_>>();
+
+        assert_eq!(centroids.nearest(ll, 10), expected);
+    }
+
+    assert!(centroids.nearest(LatLng::from(index), 0).is_empty());
+    assert_eq!(
+        centroids
+            .nearest(LatLng::from(index), cells.len() + 10)
+            .len(),
+        cells.len()
+    );
+}
+
+#[test]
+fn within_radius() {
+    let cells = cells();
+    let centroids = cells.iter().copied().collect::<CellCentroidIndex>();
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let ll = LatLng::from(index);
+
+    for radius in [0., 0.1, 0.5, 1., 2.5] {
+        let mut result = centroids.within_radius(ll, radius);
+        let mut expected = brute_force(&cells, ll)
+            .into_iter()
+            .filter_map(|(distance, cell)| (distance <= radius).then_some(cell))
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        expected.sort_unstable();
+
+        assert_eq!(result, expected, "radius {radius}");
+    }
+
+    assert_eq!(centroids.within_radius(ll, 40_000.).len(), cells.len());
+    assert!(centroids.within_radius(ll, -1.).is_empty());
+}
@@ -0,0 +1,90 @@
+use h3o::{cluster_cells, CellIndex, LatLng, Resolution};
+
+fn blob(lat: f64, lng: f64, k: u32) -> Vec<CellIndex> {
+    LatLng::new(lat, lng)
+        .expect("center")
+        .to_cell(Resolution::Nine)
+        .grid_disk(k)
+}
+
+#[test]
+fn two_clusters_and_noise() {
+    let paris = blob(48.864716, 2.349014, 2);
+    let shanghai = blob(31.224361, 121.469170, 1);
+    let isolated = LatLng::new(0., 0.)
+        .expect("isolated")
+        .to_cell(Resolution::Nine);
+    let cells = paris
+        .iter()
+        .chain(&shanghai)
+        .copied()
+        .chain([isolated])
+        .collect::<Vec<_>>();
+
+    let labels = cluster_cells(&cells, 3, 1);
+
+    assert_eq!(labels.len(), cells.len());
+    assert!(labels[..paris.len()].iter().all(|&label| label == Some(0)));
+    assert!(labels[paris.len()..cells.len() - 1]
+        .iter()
+        .all(|&label| label == Some(1)));
+    assert_eq!(labels.last(), Some(&None));
+}
+
+#[test]
+fn border_cells() {
+    // A ring of 6 cells around a missing center: each one only has 2
+    // neighbors.
+    let center = blob(48.864716, 2.349014, 0)[0];
+    let ring = center.grid_ring_fast(1).flatten().collect::<Vec<_>>();
+
+    assert!(cluster_cells(&ring, 3, 1).iter().all(Option::is_none));
+    assert!(cluster_cells(&ring, 2, 1)
+        .iter()
+        .all(|&label| label == Some(0)));
+
+    // With the center, it's the only core cell but reaches every other one.
+    let cells = [vec![center], ring].concat();
+    let labels = cluster_cells(&cells, 6, 1);
+    assert!(labels.iter().all(|&label| label == Some(0)));
+}
+
+#[test]
+fn larger_neighborhood() {
+    let center = blob(48.864716, 2.349014, 0)[0];
+    // Two blobs separated by a gap of one ring.
+    let cells =
+        [vec![center], center.grid_ring_fast(2).flatten().collect()].concat();
+
+    let labels = cluster_cells(&cells, 1, 1);
+    assert_eq!(labels[0], None);
+    let labels = cluster_cells(&cells, 1, 2);
+    assert!(labels.iter().all(|&label| label == Some(0)));
+}
+
+#[test]
+fn no_core_cell() {
+    let cells = blob(48.864716, 2.349014, 1);
+    let labels = cluster_cells(&cells, 7, 1);
+
+    assert!(labels.iter().all(Option::is_none));
+}
+
+#[test]
+fn every_cell_is_core() {
+    let cells = blob(48.864716, 2.349014, 0)[0]
+        .grid_ring_fast(3)
+        .flatten()
+        .step_by(3)
+        .collect::<Vec<_>>();
+    let labels = cluster_cells(&cells, 0, 1);
+
+    // Isolated cells are clusters on their own.
+    let expected = (0..cells.len()).map(Some).collect::<Vec<_>>();
+    assert_eq!(labels, expected);
+}
+
+#[test]
+fn empty() {
+    assert!(cluster_cells(&[], 1, 1).is_empty());
+}
@@ -0,0 +1,104 @@
+use h3o::{coverage_delta, CellIndex, Resolution};
+use std::collections::BTreeSet;
+
+fn disk(center: u64, k: u32) -> Vec<CellIndex> {
+    let center = CellIndex::try_from(center).expect("center");
+    let mut cells = center.grid_disk::<Vec<_>>(k);
+    CellIndex::compact(&mut cells).expect("compact");
+    cells
+}
+
+fn expand(cells: &[CellIndex], resolution: Resolution) -> BTreeSet<CellIndex> {
+    CellIndex::uncompact(cells.iter().copied(), resolution).collect()
+}
+
+#[test]
+fn compacted() {
+    let resolution = Resolution::Eleven;
+    let before = disk(0x8a1fb46622dffff, 20);
+    let after = disk(0x8a1fb46622d7fff, 25);
+    let delta = coverage_delta(before.iter().copied(), after.iter().copied());
+
+    let before = expand(&before, resolution);
+    let after = expand(&after, resolution);
+    assert_eq!(
+        expand(&delta.added, resolution),
+        after.difference(&before).copied().collect()
+    );
+    assert_eq!(
+        expand(&delta.removed, resolution),
+        before.difference(&after).copied().collect()
+    );
+    assert_eq!(
+        expand(&delta.retained, resolution),
+        before.intersection(&after).copied().collect()
+    );
+}
+
+#[test]
+fn mixed_resolutions() {
+    let index = CellIndex::try_from(0x851fb467fffffff).expect("index");
+    let children = index.children(Resolution::Six).collect::<Vec<_>>();
+    let grandchild = children[3]
+        .center_child(Resolution::Eight)
+        .expect("grandchild");
+
+    let delta = coverage_delta([index], [children[0], grandchild]);
+
+    assert_eq!(delta.retained, vec![children[0], grandchild]);
+    assert!(delta.added.is_empty());
+    // 5 children, 6 grandchildren and 6 great-grandchildren.
+    assert_eq!(delta.removed.len(), 5 + 6 + 6);
+    assert!(!delta.removed.contains(&children[3]));
+}
+
+#[test]
+fn identical() {
+    let cells = disk(0x8a1fb46622dffff, 5);
+    let delta = coverage_delta(cells.iter().copied(), cells.iter().copied());
+
+    assert!(delta.added.is_empty());
+    assert!(delta.removed.is_empty());
+    assert_eq!(delta.retained.len(), cells.len());
+}
+
+#[test]
+fn covered_inputs() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let child = index.center_child(Resolution::Eleven).expect("child");
+    let delta = coverage_delta([index, child, index], [index]);
+
+    assert_eq!(delta.retained, vec![index]);
+    assert!(delta.added.is_empty());
+    assert!(delta.removed.is_empty());
+}
+
+#[test]
+fn pentagon() {
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+    let child = pentagon.center_child(Resolution::Two).expect("child");
+    let delta = coverage_delta([child], [pentagon]);
+
+    assert_eq!(delta.retained, vec![child]);
+    assert!(delta.removed.is_empty());
+    // 5 other children, and 5 other grandchildren under the center child.
+    assert_eq!(delta.added.len(), 5 + 5);
+    assert_eq!(
+        expand(&delta.added, Resolution::Two).len() as u64 + 1,
+        pentagon.children_count(Resolution::Two)
+    );
+}
+
+#[test]
+fn summary() {
+    let before = disk(0x8a1fb46622dffff, 2);
+    let delta = coverage_delta(before.iter().copied(), []);
+    let summary = delta.summary();
+
+    assert_eq!(summary.added_km2, 0.);
+    assert_eq!(summary.retained_km2, 0.);
+    assert_eq!(
+        summary.removed_km2,
+        before.iter().map(|cell| cell.area_km2()).sum::<f64>()
+    );
+}
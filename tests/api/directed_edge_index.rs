@@ -1,4 +1,5 @@
-use h3o::DirectedEdgeIndex;
+use float_eq::assert_float_eq;
+use h3o::{CellIndex, DirectedEdgeIndex};
 
 #[test]
 fn try_from_str() {
@@ -37,3 +38,25 @@ fn display() {
             .to_owned();
     assert_eq!(result, expected, "binary");
 }
+
+#[test]
+fn length_geodesic_m() {
+    let index = DirectedEdgeIndex::try_from(0x13a194e699ab7fff).expect("index");
+    let result = index.length_geodesic_m();
+
+    // Close to the spherical length, but not equal.
+    assert_float_eq!(result, index.length_m(), r2nd <= 5e-3);
+    assert_ne!(result, index.length_m());
+
+    // Both directions have the same length.
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    for edge in cell.edges() {
+        let (origin, destination) = edge.cells();
+        let reverse = destination.edge(origin).expect("reverse edge");
+        assert_float_eq!(
+            edge.length_geodesic_m(),
+            reverse.length_geodesic_m(),
+            r2nd <= 1e-9
+        );
+    }
+}
@@ -1,4 +1,5 @@
-use h3o::Face;
+use h3o::{CellIndex, Face, Resolution};
+use std::collections::HashMap;
 
 #[test]
 fn try_from_u8() {
@@ -17,3 +18,23 @@ fn display() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn cells() {
+    let resolution = Resolution::Two;
+    let mut counts = HashMap::<CellIndex, usize>::new();
+    for face in (0..20).map(|i| Face::try_from(i).expect("face")) {
+        let cells = face.cells(resolution);
+        assert!(!cells.is_empty(), "{face}");
+        for cell in cells {
+            assert!(cell.icosahedron_faces().contains(face), "{cell}");
+            *counts.entry(cell).or_default() += 1;
+        }
+    }
+
+    // Every cell is listed once per intersected face.
+    assert_eq!(counts.len() as u64, resolution.cell_count());
+    for (cell, count) in counts {
+        assert_eq!(count, cell.icosahedron_faces().len(), "{cell}");
+    }
+}
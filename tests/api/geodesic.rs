@@ -0,0 +1,282 @@
+use float_eq::assert_float_eq;
+use h3o::{
+    cells_along_geodesic, cells_on_side_of_great_circle, distance_field,
+    great_circle_intersection, weighted_centroid, CellIndex, LatLng,
+    Resolution, Vec3d,
+};
+use std::collections::HashSet;
+
+#[test]
+fn chain() {
+    let a = LatLng::new(48.864716, 2.349014).expect("a");
+    let b = LatLng::new(51.507351, -0.127758).expect("b");
+    let cells = cells_along_geodesic(a, b, Resolution::Seven, 0.);
+
+    assert_eq!(cells.first().copied(), Some(a.to_cell(Resolution::Seven)));
+    assert_eq!(cells.last().copied(), Some(b.to_cell(Resolution::Seven)));
+    assert_eq!(cells.iter().collect::<HashSet<_>>().len(), cells.len());
+    for pair in cells.windows(2) {
+        assert_eq!(pair[0].is_neighbor_with(pair[1]), Ok(true));
+    }
+}
+
+#[test]
+fn corridor() {
+    let a = LatLng::new(48.864716, 2.349014).expect("a");
+    let b = LatLng::new(51.507351, -0.127758).expect("b");
+    let chain = cells_along_geodesic(a, b, Resolution::Seven, 0.);
+    let corridor = cells_along_geodesic(a, b, Resolution::Seven, 5_000.);
+
+    let corridor = corridor.into_iter().collect::<HashSet<_>>();
+    assert!(chain.iter().all(|cell| corridor.contains(cell)));
+    // ~2.4km edges, a 10km wide corridor is a few cells wide.
+    assert!(corridor.len() > 3 * chain.len());
+}
+
+#[test]
+fn antimeridian() {
+    let a = LatLng::new(0., 179.9).expect("a");
+    let b = LatLng::new(0., -179.9).expect("b");
+    let cells = cells_along_geodesic(a, b, Resolution::Six, 0.);
+
+    // Short path across the antimeridian, not around the world.
+    assert!(cells.len() < 10);
+    for pair in cells.windows(2) {
+        assert_eq!(pair[0].is_neighbor_with(pair[1]), Ok(true));
+    }
+}
+
+#[test]
+fn same_point() {
+    let a = LatLng::new(48.864716, 2.349014).expect("a");
+    let cells = cells_along_geodesic(a, a, Resolution::Ten, 0.);
+
+    assert_eq!(cells, vec![a.to_cell(Resolution::Ten)]);
+}
+
+#[test]
+fn antipodal() {
+    let a = LatLng::new(10., 20.).expect("a");
+    let b = LatLng::new(-10., -160.).expect("b");
+    let cells = cells_along_geodesic(a, b, Resolution::Two, 0.);
+
+    assert_eq!(cells.first().copied(), Some(a.to_cell(Resolution::Two)));
+    assert_eq!(cells.last().copied(), Some(b.to_cell(Resolution::Two)));
+    for pair in cells.windows(2) {
+        assert_eq!(pair[0].is_neighbor_with(pair[1]), Ok(true));
+    }
+}
+
+#[test]
+fn cross_track_distance() {
+    let a = LatLng::new(0., 0.).expect("a");
+    let b = LatLng::new(0., 10.).expect("b");
+    let north = LatLng::new(1., 5.).expect("north");
+    let south = LatLng::new(-1., 5.).expect("south");
+    let one_degree = LatLng::new(0., 0.)
+        .expect("origin")
+        .distance_m(LatLng::new(1., 0.).expect("1°"));
+
+    assert_float_eq!(
+        north.cross_track_distance_m(a, b),
+        -one_degree,
+        r2nd <= 1e-9
+    );
+    assert_float_eq!(
+        south.cross_track_distance_m(a, b),
+        one_degree,
+        r2nd <= 1e-9
+    );
+    assert_float_eq!(
+        LatLng::new(0., 42.)
+            .expect("ll")
+            .cross_track_distance_m(a, b),
+        0.,
+        abs <= 1e-6
+    );
+    // Undefined great circle.
+    assert_eq!(north.cross_track_distance_m(a, a), north.distance_m(a));
+}
+
+#[test]
+fn intersection() {
+    let a1 = LatLng::new(0., -10.).expect("a1");
+    let a2 = LatLng::new(0., 10.).expect("a2");
+    let b1 = LatLng::new(-10., 90.).expect("b1");
+    let b2 = LatLng::new(10., 90.).expect("b2");
+    let result = great_circle_intersection(a1, a2, b1, b2).expect("result");
+    assert_float_eq!(result.lat(), 0., abs <= 1e-9);
+    assert_float_eq!(result.lng(), 90., abs <= 1e-9);
+
+    // The result lies on both great circles.
+    let a1 = LatLng::new(48.864716, 2.349014).expect("a1");
+    let a2 = LatLng::new(31.224361, 121.469170).expect("a2");
+    let b1 = LatLng::new(51.507351, -0.127758).expect("b1");
+    let b2 = LatLng::new(-33.868820, 151.209290).expect("b2");
+    let result = great_circle_intersection(a1, a2, b1, b2).expect("result");
+    assert_float_eq!(result.cross_track_distance_m(a1, a2), 0., abs <= 1e-6);
+    assert_float_eq!(result.cross_track_distance_m(b1, b2), 0., abs <= 1e-6);
+    assert!(result.distance_m(a1) < 10_000_000.);
+}
+
+#[test]
+fn intersection_undefined() {
+    let a1 = LatLng::new(0., -10.).expect("a1");
+    let a2 = LatLng::new(0., 10.).expect("a2");
+    let b = LatLng::new(10., 90.).expect("b");
+
+    assert_eq!(great_circle_intersection(a1, a1, a2, b), None);
+    assert_eq!(great_circle_intersection(a2, b, b, b), None);
+    // Same great circle.
+    let a3 = LatLng::new(0., 20.).expect("a3");
+    assert_eq!(great_circle_intersection(a1, a2, a2, a3), None);
+}
+
+// Cells whose center is at most 90° away from the pole.
+fn hemisphere_brute_force(
+    pole: LatLng,
+    resolution: Resolution,
+) -> HashSet<CellIndex> {
+    CellIndex::base_cells()
+        .flat_map(|cell| cell.children(resolution))
+        .filter(|&cell| {
+            let (lat, lng) = (pole.lat_radians(), pole.lng_radians());
+            let center = LatLng::from(cell);
+            let (clat, clng) = (center.lat_radians(), center.lng_radians());
+            // Cosine of the angular distance.
+            lat.sin() * clat.sin() + lat.cos() * clat.cos() * (lng - clng).cos()
+                >= 0.
+        })
+        .collect()
+}
+
+#[test]
+fn great_circle_side() {
+    for (lat, lng) in [(90., 0.), (0., 0.), (23.44, -151.2), (-47.3, 12.8)] {
+        let pole = LatLng::new(lat, lng).expect("pole");
+        let cells =
+            cells_on_side_of_great_circle(pole.to_vec3(), Resolution::Three)
+                .collect::<Vec<_>>();
+        let expected = hemisphere_brute_force(pole, Resolution::Three);
+
+        assert_eq!(cells.len(), expected.len(), "({lat}, {lng})");
+        assert_eq!(cells.into_iter().collect::<HashSet<_>>(), expected);
+    }
+}
+
+#[test]
+fn great_circle_side_complement() {
+    let normal = LatLng::new(12.3, 45.6).expect("pole").to_vec3();
+    let opposite = Vec3d::new(-normal.x, -normal.y, -normal.z);
+    let day = cells_on_side_of_great_circle(normal, Resolution::Two)
+        .collect::<HashSet<_>>();
+    let night = cells_on_side_of_great_circle(opposite, Resolution::Two)
+        .collect::<HashSet<_>>();
+
+    let all = CellIndex::base_cells()
+        .flat_map(|cell| cell.children(Resolution::Two))
+        .collect::<HashSet<_>>();
+    assert_eq!(day.union(&night).copied().collect::<HashSet<_>>(), all);
+    assert!(day.intersection(&night).count() < 10, "almost disjoint");
+}
+
+#[test]
+fn great_circle_side_coarse() {
+    let pole = LatLng::new(-90., 0.).expect("pole");
+    let cells = cells_on_side_of_great_circle(pole.to_vec3(), Resolution::Zero)
+        .collect::<HashSet<_>>();
+
+    assert_eq!(cells, hemisphere_brute_force(pole, Resolution::Zero));
+}
+
+#[test]
+fn great_circle_side_unnormalized() {
+    let pole = LatLng::new(23.44, -151.2).expect("pole");
+    let normal = pole.to_vec3();
+    let scaled = Vec3d::new(normal.x * 42., normal.y * 42., normal.z * 42.);
+    let cells = cells_on_side_of_great_circle(scaled, Resolution::Two)
+        .collect::<HashSet<_>>();
+
+    assert_eq!(cells, hemisphere_brute_force(pole, Resolution::Two));
+    let null = Vec3d::new(0., 0., 0.);
+    assert_eq!(
+        cells_on_side_of_great_circle(null, Resolution::Two).count(),
+        0
+    );
+}
+
+#[test]
+fn distance_field_matches_distance_m() {
+    let origin = LatLng::new(48.864716, 2.349014).expect("origin");
+    let cells = CellIndex::try_from(0x851fb467fffffff)
+        .expect("cell")
+        .grid_disk::<Vec<_>>(10);
+    let distances = distance_field(origin, &cells);
+
+    assert_eq!(distances.len(), cells.len());
+    for (cell, distance) in cells.into_iter().zip(distances) {
+        let expected = origin.distance_m(LatLng::from(cell));
+        assert_float_eq!(distance, expected, r2nd <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn distance_field_empty() {
+    let origin = LatLng::new(48.864716, 2.349014).expect("origin");
+
+    assert!(distance_field(origin, &[]).is_empty());
+}
+
+#[test]
+fn weighted_centroid_single() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let center = LatLng::from(cell);
+    let centroid = weighted_centroid([(cell, 42.)]).expect("centroid");
+
+    assert_float_eq!(centroid.lat(), center.lat(), abs <= 1e-9);
+    assert_float_eq!(centroid.lng(), center.lng(), abs <= 1e-9);
+}
+
+#[test]
+fn weighted_centroid_weights() {
+    let a = LatLng::new(0., 10.).expect("a");
+    let b = LatLng::new(0., 20.).expect("b");
+    let (cell_a, cell_b) = (
+        a.to_cell(Resolution::Fifteen),
+        b.to_cell(Resolution::Fifteen),
+    );
+
+    let centroid =
+        weighted_centroid([(cell_a, 1.), (cell_b, 1.)]).expect("centroid");
+    assert_float_eq!(centroid.lng(), 15., abs <= 1e-5);
+
+    // Heavier weight pulls the centroid toward `b`.
+    let centroid =
+        weighted_centroid([(cell_a, 1.), (cell_b, 3.)]).expect("centroid");
+    assert!(centroid.lng() > 15.);
+
+    // Null weight is ignored.
+    let centroid =
+        weighted_centroid([(cell_a, 1.), (cell_b, 0.)]).expect("centroid");
+    assert_float_eq!(centroid.lng(), LatLng::from(cell_a).lng(), abs <= 1e-9);
+}
+
+#[test]
+fn weighted_centroid_antimeridian() {
+    let a = LatLng::new(10., 179.).expect("a").to_cell(Resolution::Ten);
+    let b = LatLng::new(10., -179.).expect("b").to_cell(Resolution::Ten);
+    let centroid = weighted_centroid([(a, 1.), (b, 1.)]).expect("centroid");
+
+    // Naive average would be around the meridian 0.
+    assert_float_eq!(centroid.lng().abs(), 180., abs <= 1e-3);
+    assert_float_eq!(centroid.lat(), 10., abs <= 0.1);
+}
+
+#[test]
+fn weighted_centroid_undefined() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+
+    assert_eq!(weighted_centroid([]), None, "empty");
+    assert_eq!(weighted_centroid([(cell, 0.)]), None, "zero weight");
+    assert_eq!(weighted_centroid([(cell, f64::NAN)]), None, "NaN weight");
+}
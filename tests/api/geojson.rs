@@ -0,0 +1,177 @@
+use h3o::{error::GeoJsonError, geojson, CellIndex};
+
+fn collection(properties: &[&str]) -> String {
+    let features = properties
+        .iter()
+        .map(|properties| {
+            format!(
+                r#"{{"type":"Feature","geometry":null,"properties":{properties}}}"#
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    format!(r#"{{"type":"FeatureCollection","features":[{features}]}}"#)
+}
+
+#[test]
+fn read_cells() {
+    let input = collection(&[
+        r#"{"h3":"8a1fb46622dffff","name":"string"}"#,
+        r#"{"h3":622054503267303423,"name":"integer"}"#,
+    ]);
+    let expected = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+
+    let cells =
+        geojson::read_cells(input.as_bytes(), geojson::DEFAULT_INDEX_KEY)
+            .expect("cells");
+
+    assert_eq!(cells.len(), 2);
+    assert_eq!(cells[0].0, expected);
+    assert_eq!(cells[0].1["name"], "string");
+    assert_eq!(cells[1].0, expected);
+    assert_eq!(cells[1].1["name"], "integer");
+}
+
+#[test]
+fn custom_key() {
+    let input = collection(&[r#"{"cell":"8a1fb46622dffff"}"#]);
+
+    let result = geojson::read_cells(input.as_bytes(), "cell");
+    assert!(result.is_ok());
+
+    let result = geojson::read_cells(input.as_bytes(), "h3");
+    assert!(matches!(result, Err(GeoJsonError::MissingIndex(0))));
+}
+
+#[test]
+fn invalid_index() {
+    let input =
+        collection(&[r#"{"h3":"8a1fb46622dffff"}"#, r#"{"h3":"not a cell"}"#]);
+    let result = geojson::read_cells(input.as_bytes(), "h3");
+    assert!(matches!(result, Err(GeoJsonError::InvalidIndex(1, _))));
+
+    // Valid number but not a cell index.
+    let input = collection(&[r#"{"h3":42}"#]);
+    let result = geojson::read_cells(input.as_bytes(), "h3");
+    assert!(matches!(result, Err(GeoJsonError::InvalidIndex(0, _))));
+}
+
+#[test]
+fn invalid_structure() {
+    let result = geojson::read_cells(&b"{"[..], "h3");
+    assert!(matches!(result, Err(GeoJsonError::Json(_))));
+
+    let input = r#"{"type":"Feature","properties":{"h3":"8a1fb46622dffff"}}"#;
+    let result = geojson::read_cells(input.as_bytes(), "h3");
+    assert!(matches!(result, Err(GeoJsonError::InvalidStructure(_))));
+
+    let input = r#"{"type":"FeatureCollection","features":[42]}"#;
+    let result = geojson::read_cells(input.as_bytes(), "h3");
+    assert!(matches!(result, Err(GeoJsonError::InvalidStructure(_))));
+}
+
+#[cfg(feature = "geo")]
+mod geo {
+    use super::*;
+    use h3o::{
+        geom::{ContainmentMode, SolventBuilder, TilerBuilder},
+        Resolution,
+    };
+
+    #[test]
+    fn write_cells_roundtrip() {
+        let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+        let mut cells = index.grid_disk::<Vec<_>>(1);
+        cells.sort_unstable();
+
+        let mut output = Vec::new();
+        geojson::write_cells(&mut output, cells.iter().copied(), Some("h3"))
+            .expect("write");
+
+        // Indexes are stored in the properties.
+        let result = geojson::read_cells(output.as_slice(), "h3")
+            .expect("cells")
+            .into_iter()
+            .map(|(cell, _)| cell)
+            .collect::<Vec<_>>();
+        assert_eq!(result, cells);
+
+        // Geometries cover the cells.
+        let polygons = geojson::read_polygons(output.as_slice()).expect("read");
+        assert_eq!(polygons.len(), cells.len());
+        let mut tiler = TilerBuilder::new(Resolution::Ten)
+            .containment_mode(ContainmentMode::ContainsCentroid)
+            .build();
+        tiler.add_batch(polygons).expect("tiler");
+        let mut result = tiler.into_coverage().collect::<Vec<_>>();
+        result.sort_unstable();
+        assert_eq!(result, cells);
+    }
+
+    #[test]
+    fn write_cells_without_key() {
+        let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+
+        let mut output = Vec::new();
+        geojson::write_cells(&mut output, [index], None).expect("write");
+
+        let result = geojson::read_cells(output.as_slice(), "h3");
+        assert!(matches!(result, Err(GeoJsonError::MissingIndex(0))));
+    }
+
+    #[test]
+    fn write_polygons() {
+        let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+        let cells = index.grid_ring_fast(2).collect::<Option<Vec<_>>>();
+        let shape = SolventBuilder::new()
+            .build()
+            .dissolve(cells.expect("ring"))
+            .expect("shape");
+
+        let mut output = Vec::new();
+        geojson::write_polygons(&mut output, &shape).expect("write");
+        let polygons = geojson::read_polygons(output.as_slice()).expect("read");
+
+        assert_eq!(polygons.len(), 1);
+        assert_eq!(polygons[0].interiors().len(), 1, "ring has a hole");
+    }
+
+    #[test]
+    fn read_polygons_geometries() {
+        let polygon = r#"[[[0,0],[1,0],[1,1],[0,0]]]"#;
+        let input = format!(
+            r#"{{"type":"FeatureCollection","features":[
+                {{"type":"Feature","properties":null,"geometry":null}},
+                {{"type":"Feature","properties":null,"geometry":
+                  {{"type":"Polygon","coordinates":{polygon}}}}},
+                {{"type":"Feature","properties":null,"geometry":
+                  {{"type":"MultiPolygon","coordinates":[{polygon},{polygon}]}}}}
+            ]}}"#
+        );
+
+        let polygons = geojson::read_polygons(input.as_bytes()).expect("read");
+        assert_eq!(polygons.len(), 3);
+    }
+
+    #[test]
+    fn read_polygons_invalid() {
+        for geometry in [
+            r#"{"type":"Point","coordinates":[0,0]}"#,
+            r#"{"type":"Polygon"}"#,
+            r#"{"type":"Polygon","coordinates":[]}"#,
+            r#"{"type":"Polygon","coordinates":[[[0,"a"]]]}"#,
+            "42",
+        ] {
+            let input = format!(
+                r#"{{"type":"FeatureCollection","features":[
+                    {{"type":"Feature","properties":null,"geometry":{geometry}}}
+                ]}}"#
+            );
+            let result = geojson::read_polygons(input.as_bytes());
+            assert!(
+                matches!(result, Err(GeoJsonError::InvalidStructure(_))),
+                "{geometry}"
+            );
+        }
+    }
+}
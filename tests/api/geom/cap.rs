@@ -0,0 +1,123 @@
+use h3o::{
+    geom::{cap_to_cells, ContainmentMode},
+    CellIndex, LatLng, Resolution,
+};
+use std::{collections::HashSet, f64::consts::PI};
+
+fn all_cells(resolution: Resolution) -> impl Iterator<Item = CellIndex> {
+    CellIndex::base_cells().flat_map(move |cell| cell.children(resolution))
+}
+
+fn cap(
+    center: LatLng,
+    radius: f64,
+    resolution: Resolution,
+    mode: ContainmentMode,
+) -> HashSet<CellIndex> {
+    cap_to_cells(center, radius, resolution, mode).collect()
+}
+
+#[test]
+fn contains_centroid_brute_force() {
+    for (lat, lng, radius) in [
+        (48.86, 2.35, 0.2),
+        (90., 0., 0.5),
+        (-12.3, 179.9, 1.),
+        (0., 0., 2.),
+    ] {
+        let center = LatLng::new(lat, lng).expect("center");
+        let cells = cap(
+            center,
+            radius,
+            Resolution::Three,
+            ContainmentMode::ContainsCentroid,
+        );
+        let expected = all_cells(Resolution::Three)
+            .filter(|&cell| center.distance_rads(LatLng::from(cell)) <= radius)
+            .collect::<HashSet<_>>();
+
+        assert_eq!(cells, expected, "({lat}, {lng}, {radius})");
+    }
+}
+
+#[test]
+fn no_duplicates() {
+    let center = LatLng::new(-47.3, 12.8).expect("center");
+    let cells =
+        cap_to_cells(center, 0.3, Resolution::Four, ContainmentMode::Covers)
+            .collect::<Vec<_>>();
+
+    assert_eq!(cells.iter().collect::<HashSet<_>>().len(), cells.len());
+}
+
+#[test]
+fn modes_ordering() {
+    let center = LatLng::new(23.44, -151.2).expect("center");
+    let [boundary, centroid, intersects, covers] = [
+        ContainmentMode::ContainsBoundary,
+        ContainmentMode::ContainsCentroid,
+        ContainmentMode::IntersectsBoundary,
+        ContainmentMode::Covers,
+    ]
+    .map(|mode| cap(center, 0.1, Resolution::Four, mode));
+
+    assert!(!boundary.is_empty());
+    assert!(boundary.is_subset(&centroid));
+    assert!(centroid.is_subset(&intersects));
+    assert!(intersects.is_subset(&covers));
+    assert!(intersects.len() > centroid.len());
+}
+
+#[test]
+fn contains_boundary() {
+    let center = LatLng::new(48.86, 2.35).expect("center");
+    let radius = 0.05;
+    let cells = cap(
+        center,
+        radius,
+        Resolution::Five,
+        ContainmentMode::ContainsBoundary,
+    );
+
+    assert!(!cells.is_empty());
+    for cell in cells {
+        assert!(cell
+            .boundary()
+            .iter()
+            .all(|vertex| center.distance_rads(*vertex) <= radius));
+    }
+}
+
+#[test]
+fn whole_sphere() {
+    let center = LatLng::new(10., 20.).expect("center");
+    for mode in [
+        ContainmentMode::ContainsCentroid,
+        ContainmentMode::ContainsBoundary,
+        ContainmentMode::IntersectsBoundary,
+        ContainmentMode::Covers,
+    ] {
+        let cells = cap(center, PI, Resolution::Two, mode);
+        assert_eq!(
+            cells.len() as u64,
+            Resolution::Two.cell_count(),
+            "{mode:?}"
+        );
+    }
+}
+
+#[test]
+fn negative_radius() {
+    let center = LatLng::new(10., 20.).expect("center");
+    let cells = cap(center, -1., Resolution::Two, ContainmentMode::Covers);
+
+    assert!(cells.is_empty());
+}
+
+#[test]
+fn zero_radius() {
+    let center = LatLng::new(10., 20.).expect("center");
+    let cells = cap(center, 0., Resolution::Six, ContainmentMode::Covers);
+
+    assert_eq!(cells, HashSet::from([center.to_cell(Resolution::Six)]));
+}
@@ -0,0 +1,69 @@
+#![expect(deprecated, reason = "testing the compatibility layer")]
+
+use geo::{coord, polygon, MultiPolygon, Rect, Triangle};
+use h3o::{
+    geom::{ContainmentMode, PolyfillConfig, TilerBuilder, ToCells},
+    Resolution,
+};
+
+#[test]
+fn same_as_tiler() {
+    let polygon = polygon![
+        (x: 2.33, y: 48.84),
+        (x: 2.36, y: 48.84),
+        (x: 2.35, y: 48.87),
+        (x: 2.33, y: 48.84),
+    ];
+    for mode in [
+        ContainmentMode::ContainsCentroid,
+        ContainmentMode::ContainsBoundary,
+        ContainmentMode::IntersectsBoundary,
+        ContainmentMode::Covers,
+    ] {
+        let config =
+            PolyfillConfig::new(Resolution::Nine).containment_mode(mode);
+        let mut tiler = TilerBuilder::new(Resolution::Nine)
+            .containment_mode(mode)
+            .build();
+        tiler.add(polygon.clone()).expect("valid polygon");
+
+        assert_eq!(polygon.max_cells_count(config), tiler.coverage_size_hint());
+        assert!(
+            polygon.to_cells(config).eq(tiler.into_coverage()),
+            "{mode:?}"
+        );
+    }
+}
+
+#[test]
+fn shapes() {
+    let config = PolyfillConfig::new(Resolution::Eight);
+    let rect =
+        Rect::new(coord! { x: 2.33, y: 48.84 }, coord! { x: 2.36, y: 48.87 });
+    let triangle = Triangle::new(
+        coord! { x: 2.33, y: 48.84 },
+        coord! { x: 2.36, y: 48.84 },
+        coord! { x: 2.35, y: 48.87 },
+    );
+    let multi = MultiPolygon::new(vec![rect.to_polygon()]);
+
+    let count = rect.to_cells(config).count();
+    assert!(count > 0);
+    assert!(count <= rect.max_cells_count(config));
+    assert_eq!(multi.to_cells(config).count(), count);
+    assert!(triangle.to_cells(config).count() < count);
+}
+
+#[test]
+fn invalid() {
+    let config = PolyfillConfig::new(Resolution::Eight);
+    let polygon = polygon![
+        (x: f64::NAN, y: 48.84),
+        (x: 2.36, y: 48.84),
+        (x: 2.35, y: 48.87),
+        (x: f64::NAN, y: 48.84),
+    ];
+
+    assert_eq!(polygon.max_cells_count(config), 0);
+    assert_eq!(polygon.to_cells(config).count(), 0);
+}
@@ -0,0 +1,45 @@
+use geo::Coord;
+use h3o::{geom::edges_to_linestrings, CellIndex, LatLng};
+
+#[test]
+fn preserve_direction() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let neighbors = index
+        .grid_ring_fast(1)
+        .map(|cell| cell.expect("cell"))
+        .collect::<Vec<_>>();
+    // Edges going out of the center cell.
+    let edges = neighbors
+        .iter()
+        .take(3)
+        .map(|&cell| index.edge(cell).expect("edge"))
+        .collect::<Vec<_>>();
+
+    for edge in &edges {
+        let lines = edges_to_linestrings([*edge]);
+        assert_eq!(lines.len(), 1);
+
+        let boundary = edge.boundary();
+        let start = Coord::from(boundary[0]);
+        let end = Coord::from(boundary[boundary.len() - 1]);
+        assert_eq!(lines[0].0.first(), Some(&start));
+        assert_eq!(lines[0].0.last(), Some(&end));
+    }
+}
+
+#[test]
+fn disjoint_lines() {
+    let a = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let b = LatLng::new(10., 10.)
+        .expect("coordinate")
+        .to_cell(a.resolution());
+
+    let lines = edges_to_linestrings(a.edges().chain(b.edges()));
+    assert_eq!(lines.len(), 2);
+    assert!(lines.iter().all(geo::LineString::is_closed));
+}
+
+#[test]
+fn empty() {
+    assert!(edges_to_linestrings([]).is_empty());
+}
@@ -0,0 +1,120 @@
+use arrow_data::ArrayData;
+use geo::{polygon, LineString, MultiPolygon, Polygon};
+use h3o::{
+    geom::{MultiPolygonArrayBuilder, PolygonArrayBuilder, SolventBuilder},
+    CellIndex, Resolution,
+};
+
+#[test]
+fn polygons_from_cells() {
+    let hexagon = CellIndex::try_from(0x89283470803ffff).expect("hexagon");
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+    let mut builder = PolygonArrayBuilder::new();
+    builder.extend([hexagon, pentagon]);
+    assert_eq!(builder.len(), 2);
+
+    let array = builder.finish();
+    assert_eq!(array.len(), 2);
+    assert_eq!(offsets(&array), &[0, 1, 2]);
+
+    let rings = &array.child_data()[0];
+    assert_eq!(offsets(rings), &[0, 7, 13]);
+
+    // Rings are closed and match the `geo` conversion.
+    let expected = Polygon::from(hexagon);
+    assert_eq!(ring_coords(rings, 0), coords(expected.exterior()));
+}
+
+#[test]
+fn polygons_with_holes() {
+    let polygon = polygon!(
+        exterior: [
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 0., y: 10.),
+        ],
+        interiors: [
+            [
+                (x: 2., y: 2.),
+                (x: 4., y: 2.),
+                (x: 4., y: 4.),
+            ],
+        ],
+    );
+    let mut builder = PolygonArrayBuilder::new();
+    builder.push_polygon(&polygon);
+    builder
+        .push_polygon(&Polygon::new(LineString::new(Vec::new()), Vec::new()));
+
+    let array = builder.finish();
+    assert_eq!(offsets(&array), &[0, 2, 2]);
+
+    let rings = &array.child_data()[0];
+    assert_eq!(offsets(rings), &[0, 5, 9]);
+    assert_eq!(ring_coords(rings, 1), coords(&polygon.interiors()[0]));
+}
+
+#[test]
+fn multipolygons_from_dissolve() {
+    let index = CellIndex::try_from(0x89283470803ffff).expect("index");
+    let solvent = SolventBuilder::new().build();
+    let shape = solvent
+        .dissolve(index.children(Resolution::Ten))
+        .expect("shape");
+    let mut builder = MultiPolygonArrayBuilder::new();
+    builder.push_multipolygon(&shape);
+    builder.push_multipolygon(&MultiPolygon::new(Vec::new()));
+    assert_eq!(builder.len(), 2);
+
+    let array = builder.finish();
+    assert_eq!(offsets(&array), &[0, 1, 1]);
+
+    let polygons = &array.child_data()[0];
+    assert_eq!(offsets(polygons), &[0, 1]);
+
+    let rings = &polygons.child_data()[0];
+    assert_eq!(ring_coords(rings, 0), coords(shape.0[0].exterior()));
+}
+
+#[test]
+fn field_metadata() {
+    let field = PolygonArrayBuilder::field("geometry");
+    assert_eq!(field.name(), "geometry");
+    assert_eq!(
+        field.metadata().get("ARROW:extension:name"),
+        Some(&"geoarrow.polygon".to_owned())
+    );
+    assert_eq!(
+        field.data_type(),
+        PolygonArrayBuilder::new().finish().data_type()
+    );
+
+    let field = MultiPolygonArrayBuilder::field("geometry");
+    assert_eq!(
+        field.metadata().get("ARROW:extension:name"),
+        Some(&"geoarrow.multipolygon".to_owned())
+    );
+    assert_eq!(
+        field.data_type(),
+        MultiPolygonArrayBuilder::new().finish().data_type()
+    );
+}
+
+// -----------------------------------------------------------------------------
+
+fn offsets(data: &ArrayData) -> &[i32] {
+    data.buffers()[0].typed_data::<i32>()
+}
+
+fn ring_coords(rings: &ArrayData, idx: usize) -> Vec<f64> {
+    let offsets = offsets(rings);
+    let (start, end) = (offsets[idx] as usize, offsets[idx + 1] as usize);
+    let values = &rings.child_data()[0].child_data()[0];
+
+    values.buffers()[0].typed_data::<f64>()[start * 2..end * 2].to_vec()
+}
+
+fn coords(ring: &LineString) -> Vec<f64> {
+    ring.coords().flat_map(|coord| [coord.x, coord.y]).collect()
+}
@@ -0,0 +1,84 @@
+use geozero::{geojson::GeoJson, GeozeroGeometry, ToJson};
+use h3o::{
+    geom::{SolventBuilder, TilerBuilder, TilerSink},
+    CellIndex, DirectedEdgeIndex, LatLng, Resolution, VertexIndex,
+};
+
+#[test]
+fn cell_to_geojson() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let result = index.to_json().expect("GeoJSON");
+    let expected = geo::Geometry::from(geo::Polygon::from(index))
+        .to_json()
+        .expect("GeoJSON");
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn edge_to_geojson() {
+    let index =
+        DirectedEdgeIndex::try_from(0x13a1_94e6_99ab_7fff).expect("index");
+    let result = index.to_json().expect("GeoJSON");
+    let expected =
+        geo::Geometry::from(geo::LineString::from(geo::Line::from(index)))
+            .to_json()
+            .expect("GeoJSON");
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn vertex_to_geojson() {
+    let index = VertexIndex::try_from(0x2302_bfff_ffff_ffff).expect("index");
+    let result = index.to_json().expect("GeoJSON");
+    let expected = geo::Geometry::from(geo::Point::from(index))
+        .to_json()
+        .expect("GeoJSON");
+
+    assert_eq!(result, expected);
+    assert_eq!(LatLng::from(index).to_json().expect("GeoJSON"), expected);
+}
+
+#[test]
+fn tiler_sink() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let mut tiler = TilerBuilder::new(Resolution::Ten).build();
+    index
+        .process_geom(&mut TilerSink::new(&mut tiler))
+        .expect("tiled");
+    let result = tiler.into_coverage().collect::<Vec<_>>();
+
+    assert_eq!(result, vec![index]);
+}
+
+#[test]
+fn tiler_sink_multipolygon() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let cells = index.grid_disk::<Vec<_>>(2);
+    let shape = SolventBuilder::new()
+        .build()
+        .dissolve(cells.iter().copied())
+        .expect("shape");
+    let geojson = geo::Geometry::from(shape).to_json().expect("GeoJSON");
+
+    let mut tiler = TilerBuilder::new(Resolution::Ten).build();
+    GeoJson(&geojson)
+        .process_geom(&mut TilerSink::new(&mut tiler))
+        .expect("tiled");
+    let mut result = tiler.into_coverage().collect::<Vec<_>>();
+    let mut expected = cells;
+    result.sort_unstable();
+    expected.sort_unstable();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn tiler_sink_unsupported() {
+    let index = VertexIndex::try_from(0x2302_bfff_ffff_ffff).expect("index");
+    let mut tiler = TilerBuilder::new(Resolution::Ten).build();
+    let result = index.process_geom(&mut TilerSink::new(&mut tiler));
+
+    assert!(result.is_err());
+}
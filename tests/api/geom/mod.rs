@@ -1,5 +1,20 @@
+mod cap;
+mod compat;
+mod edges;
+#[cfg(feature = "geoarrow")]
+mod geoarrow;
+#[cfg(feature = "geozero")]
+mod geozero;
 mod plotter;
+mod ring_hierarchy;
+#[cfg(feature = "rstar")]
+mod rtree;
 mod solvent;
 mod tiler;
 mod to_geo;
 mod utils;
+mod vertex_graph;
+#[cfg(feature = "wkb")]
+mod wkb;
+#[cfg(feature = "wkt")]
+mod wkt;
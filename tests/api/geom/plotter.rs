@@ -1,5 +1,8 @@
-use geo::{coord, line_string, Line};
-use h3o::{geom::PlotterBuilder, Resolution};
+use geo::{coord, line_string, Line, LineString, MultiLineString};
+use h3o::{
+    geom::{PlotMode, Plotter, PlotterBuilder},
+    CellIndex, LatLng, Resolution,
+};
 
 fn line_rads() -> Line {
     Line::new(
@@ -72,3 +75,134 @@ fn plot() {
 
     assert_eq!(result, 2423);
 }
+
+fn line_string() -> LineString {
+    line_string![
+        (x: 2.363503198417334,  y: 48.8203086545891),
+        (x: 2.3730684893043588, y: 48.85398407690437),
+        (x: 2.334964762310932,  y: 48.870861968772914),
+    ]
+}
+
+fn is_connected(cells: &[CellIndex]) -> bool {
+    cells
+        .windows(2)
+        .all(|pair| pair[0].is_neighbor_with(pair[1]).expect("same resolution"))
+}
+
+#[test]
+fn add_line_string() {
+    for mode in [PlotMode::GridPath, PlotMode::Geodesic] {
+        let mut plotter =
+            PlotterBuilder::new(Resolution::Ten).plot_mode(mode).build();
+        plotter
+            .add_line_string(&line_string())
+            .expect("failed to add line string");
+
+        let cells = plotter
+            .plot()
+            .collect::<Result<Vec<_>, _>>()
+            .expect("failed to plot");
+
+        let coords = line_string().into_inner();
+        let start = LatLng::try_from(coords[0]).expect("start");
+        let end = LatLng::try_from(coords[2]).expect("end");
+        assert_eq!(cells.first(), Some(&start.to_cell(Resolution::Ten)));
+        assert_eq!(cells.last(), Some(&end.to_cell(Resolution::Ten)));
+        assert!(is_connected(&cells), "{mode:?}");
+    }
+}
+
+#[test]
+fn junction_cells() {
+    let plot = |add: &dyn Fn(&mut Plotter)| {
+        let mut plotter = PlotterBuilder::new(Resolution::Ten).build();
+        add(&mut plotter);
+        plotter
+            .plot()
+            .collect::<Result<Vec<_>, _>>()
+            .expect("failed to plot")
+    };
+    let segments = plot(&|plotter| {
+        plotter
+            .add_batch(line_string().lines())
+            .expect("failed to add lines");
+    });
+    let joined = plot(&|plotter| {
+        plotter
+            .add_line_string(&line_string())
+            .expect("failed to add line string");
+    });
+
+    // Independent lines are returned as-is, junction included.
+    let junction = LatLng::try_from(line_string().into_inner()[1])
+        .expect("junction")
+        .to_cell(Resolution::Ten);
+    let count = |cells: &[CellIndex]| {
+        cells.iter().filter(|&&cell| cell == junction).count()
+    };
+    assert_eq!(count(&segments), 2);
+    assert_eq!(count(&joined), 1);
+    assert_eq!(joined.len(), segments.len() - 1);
+}
+
+#[test]
+fn add_multi_line_string() {
+    let lines = MultiLineString::new(vec![line_string(), line_string()]);
+    let mut plotter = PlotterBuilder::new(Resolution::Ten)
+        .plot_mode(PlotMode::Geodesic)
+        .build();
+    plotter
+        .add_multi_line_string(&lines)
+        .expect("failed to add lines");
+    let count = plotter.plot().count();
+
+    let mut plotter = PlotterBuilder::new(Resolution::Ten)
+        .plot_mode(PlotMode::Geodesic)
+        .build();
+    plotter
+        .add_line_string(&line_string())
+        .expect("failed to add line string");
+    let expected = plotter.plot().count();
+
+    assert_eq!(count, expected * 2);
+}
+
+#[test]
+fn plot_geodesic() {
+    let mut plotter = PlotterBuilder::new(Resolution::Ten)
+        .plot_mode(PlotMode::Geodesic)
+        .build();
+    plotter.add(line_degs()).expect("failed to add line");
+
+    let cells = plotter
+        .plot()
+        .collect::<Result<Vec<_>, _>>()
+        .expect("failed to plot");
+
+    assert!(is_connected(&cells));
+}
+
+#[test]
+fn plot_geodesic_pentagon() {
+    // Grid path cannot be computed across the pentagon distortion.
+    let line =
+        Line::new(coord! { x: 10.5, y: 67. }, coord! { x: 10.5, y: 62. });
+    let mut plotter = PlotterBuilder::new(Resolution::Two).build();
+    let result = plotter
+        .add(line)
+        .and_then(|()| plotter.plot().collect::<Result<Vec<_>, _>>());
+    assert!(result.is_err());
+
+    let mut plotter = PlotterBuilder::new(Resolution::Two)
+        .plot_mode(PlotMode::Geodesic)
+        .build();
+    plotter.add(line).expect("failed to add line");
+
+    let cells = plotter
+        .plot()
+        .collect::<Result<Vec<_>, _>>()
+        .expect("failed to plot");
+
+    assert!(is_connected(&cells));
+}
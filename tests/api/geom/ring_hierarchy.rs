@@ -0,0 +1,73 @@
+use geo::{LineString, MultiPolygon};
+use h3o::{
+    geom::{RingHierarchy, VertexGraph},
+    CellIndex, LatLng, Resolution,
+};
+
+#[test]
+fn nested_rings() {
+    let hierarchy = RingHierarchy::new(vec![
+        LineString::from(vec![(3., 4.), (4., 4.), (3., 3.), (3., 4.)]),
+        LineString::from(vec![(1., 1.), (1., 6.), (6., 6.), (1., 1.)]),
+        LineString::from(vec![(2., 5.), (5., 5.), (2., 2.), (2., 5.)]),
+        LineString::from(vec![(7., 7.), (7., 8.), (8., 8.), (7., 7.)]),
+    ]);
+
+    assert_eq!(hierarchy.len(), 4);
+    assert_eq!(
+        (0..4).map(|id| hierarchy.depth(id)).collect::<Vec<_>>(),
+        vec![2, 0, 1, 0]
+    );
+    assert_eq!(
+        (0..4).map(|id| hierarchy.parent(id)).collect::<Vec<_>>(),
+        vec![Some(2), None, Some(1), None]
+    );
+    assert_eq!(
+        (0..4).map(|id| hierarchy.is_hole(id)).collect::<Vec<_>>(),
+        vec![false, false, true, false]
+    );
+    assert!(hierarchy.contains(1, 0));
+    assert!(!hierarchy.contains(3, 0));
+    assert_eq!(hierarchy.holes(1).collect::<Vec<_>>(), vec![2]);
+    // Holes don't have holes.
+    assert_eq!(hierarchy.holes(2).count(), 0);
+
+    let geom = MultiPolygon::from(hierarchy);
+    assert_eq!(geom.0.len(), 3);
+}
+
+#[test]
+fn transmeridian() {
+    let index = LatLng::new(0., 179.9)
+        .expect("coordinate")
+        .to_cell(Resolution::Five);
+    let mut graph = VertexGraph::new(Resolution::Five);
+    for cell in index.grid_ring_fast(1) {
+        graph.insert_cell(cell.expect("cell")).expect("insert");
+    }
+    let rings = graph.into_rings();
+    let hierarchy = RingHierarchy::new(rings);
+
+    assert_eq!(hierarchy.len(), 2);
+    let exterior = usize::from(hierarchy.is_hole(0));
+    let hole = 1 - exterior;
+    assert_eq!(hierarchy.parent(hole), Some(exterior));
+    assert_eq!(hierarchy.holes(exterior).collect::<Vec<_>>(), vec![hole]);
+}
+
+#[test]
+fn empty() {
+    let hierarchy = RingHierarchy::new(Vec::new());
+
+    assert!(hierarchy.is_empty());
+    assert!(MultiPolygon::from(hierarchy).0.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "invalid ring ID")]
+fn out_of_bounds() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let hierarchy = RingHierarchy::new(vec![index.boundary().into()]);
+
+    let _ = hierarchy.depth(1);
+}
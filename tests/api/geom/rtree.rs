@@ -0,0 +1,52 @@
+use h3o::{geom::RTreeCell, CellIndex, LatLng, Resolution};
+use rstar::{Envelope, PointDistance, RTree, RTreeObject};
+
+#[test]
+fn envelope() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let cell = RTreeCell::from(index);
+    let envelope = cell.envelope();
+
+    assert_eq!(cell.cell(), index);
+    for ll in index.boundary().iter() {
+        assert!(envelope.contains_point(&[ll.lng(), ll.lat()]));
+    }
+}
+
+#[test]
+fn distance() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let cell = RTreeCell::from(index);
+    let center = LatLng::from(index);
+    let center = [center.lng(), center.lat()];
+
+    assert!(cell.contains_point(&center));
+    assert_eq!(cell.distance_2(&center), 0.);
+    assert!(!cell.contains_point(&[0., 0.]));
+    assert!(cell.distance_2(&[0., 0.]) > 0.);
+}
+
+#[test]
+fn nearest_neighbor() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let tree = RTree::bulk_load(
+        index
+            .children(Resolution::Twelve)
+            .map(RTreeCell::from)
+            .collect(),
+    );
+
+    for child in index.children(Resolution::Twelve) {
+        let center = LatLng::from(child);
+        let result = tree
+            .nearest_neighbor(&[center.lng(), center.lat()])
+            .map(|cell| CellIndex::from(*cell));
+        assert_eq!(result, Some(child));
+
+        let result = tree
+            .locate_all_at_point(&[center.lng(), center.lat()])
+            .map(RTreeCell::cell)
+            .collect::<Vec<_>>();
+        assert_eq!(result, vec![child]);
+    }
+}
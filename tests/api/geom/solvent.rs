@@ -1,5 +1,5 @@
 use super::utils::load_polygon;
-use ahash::HashSet;
+use ahash::{HashMap, HashSet};
 use approx::{assert_relative_eq, relative_eq};
 use geo::{polygon, Area, BooleanOps, LineString, MultiPolygon, Polygon};
 use h3o::{
@@ -91,6 +91,24 @@ fn duplicate_heterogeneous() {
     assert!(result.is_err())
 }
 
+#[test]
+fn large_heterogeneous() {
+    // Expanding this cell would yield ~5.7M cells.
+    let index = CellIndex::try_from(0x822837fffffffff).expect("index");
+    let solvent = SolventBuilder::new()
+        .enable_heterogeneous_support(Resolution::Ten)
+        .build();
+    let result = solvent.dissolve([index]).expect("geometry");
+
+    assert_eq!(result.0.len(), 1);
+    assert!(result.0[0].interiors().is_empty());
+    assert_relative_eq!(
+        result.unsigned_area(),
+        Polygon::from(index).unsigned_area(),
+        max_relative = 0.05
+    );
+}
+
 #[test]
 fn heterogeneous_resolution() {
     let set = [0x89283082813ffff, 0x8828308299fffff]
@@ -673,3 +691,330 @@ fn assert_hetero_equal_homo(
     let intersection_area = result.union(expected).unsigned_area();
     assert_eq!(intersection_area / union_area, 1., "geom mismatch")
 }
+
+#[test]
+fn deterministic_output() {
+    let index = CellIndex::try_from(0x89283470803ffff).expect("index");
+    // Two concentric rings of cells (i.e. two polygons with a hole).
+    let mut cells =
+        index
+            .grid_ring_fast(2)
+            .map(|cell| cell.expect("cell"))
+            .chain(index.grid_disk::<Vec<_>>(6).into_iter().filter(|cell| {
+                index.grid_distance(*cell).expect("distance") == 6
+            }))
+            .collect::<Vec<_>>();
+    let solvent = SolventBuilder::new().enable_deterministic_output().build();
+
+    let result = solvent.dissolve(cells.iter().copied()).expect("geometry");
+    assert_eq!(result.0.len(), 2);
+    cells.reverse();
+    let reversed = solvent.dissolve(cells).expect("geometry");
+
+    assert_eq!(result, reversed);
+}
+
+#[test]
+fn dissolve_edges_loop() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let solvent = SolventBuilder::new().build();
+
+    let result = solvent.dissolve_edges(index.edges()).expect("geometry");
+    assert_eq!(result.0.len(), 1);
+    assert!(result.0[0].is_closed());
+    assert_eq!(result.0[0].0.len(), 7);
+
+    // Outline of a disk.
+    let cells = index.grid_disk::<HashSet<_>>(1);
+    let edges = cells
+        .iter()
+        .flat_map(|cell| cell.edges())
+        .filter(|edge| !cells.contains(&edge.destination()));
+    let result = solvent.dissolve_edges(edges).expect("geometry");
+    assert_eq!(result.0.len(), 1);
+    assert!(result.0[0].is_closed());
+    assert_eq!(result.0[0].0.len(), 19);
+}
+
+#[test]
+fn dissolve_edges_open() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let solvent = SolventBuilder::new().build();
+    let edges = index.edges().skip(1);
+
+    let result = solvent.dissolve_edges(edges).expect("geometry");
+    assert_eq!(result.0.len(), 1);
+    assert!(!result.0[0].is_closed());
+    assert_eq!(result.0[0].0.len(), 6);
+}
+
+#[test]
+fn dissolve_edges_junctions() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let solvent = SolventBuilder::new().enable_deterministic_output().build();
+    // Two neighbors: their shared edge is traversed in both directions.
+    let neighbor = index.grid_ring_fast(1).next().flatten().expect("cell");
+    let edges = index.edges().chain(neighbor.edges());
+
+    let result = solvent.dissolve_edges(edges).expect("geometry");
+    assert!(result.0.len() > 1);
+    let edge_count =
+        result.0.iter().map(|line| line.0.len() - 1).sum::<usize>();
+    assert_eq!(edge_count, 12);
+}
+
+#[test]
+fn dissolve_edges_deterministic_output() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let solvent = SolventBuilder::new().enable_deterministic_output().build();
+    // Junctions (two neighbors sharing an edge) and several closed loops.
+    let neighbor = index.grid_ring_fast(1).next().flatten().expect("cell");
+    let ring = index.grid_ring_fast(4).flatten().collect::<Vec<_>>();
+    let lone = index.grid_ring_fast(8).next().flatten().expect("cell");
+    let mut edges = index
+        .edges()
+        .chain(neighbor.edges())
+        .chain(ring.iter().flat_map(|cell| cell.edges()))
+        .chain(lone.edges())
+        .collect::<Vec<_>>();
+
+    let expected = solvent
+        .dissolve_edges(edges.iter().copied())
+        .expect("geometry");
+    for step in [1, 5, 13, 29] {
+        edges.rotate_left(step);
+        let result = solvent
+            .dissolve_edges(edges.iter().copied())
+            .expect("geometry");
+        assert_eq!(result, expected, "rotated by {step}");
+        edges.reverse();
+        let result = solvent
+            .dissolve_edges(edges.iter().copied())
+            .expect("geometry");
+        assert_eq!(result, expected, "reversed");
+    }
+}
+
+#[test]
+fn dissolve_edges_duplicate() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let solvent = SolventBuilder::new().build();
+    let edges = index.edges().chain(index.edges().take(1));
+
+    assert!(solvent.dissolve_edges(edges).is_err());
+}
+
+#[test]
+fn outline_segments() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let cells = index.grid_disk::<Vec<_>>(1);
+    let solvent = SolventBuilder::new().build();
+
+    let segments = solvent.outline_segments(cells.clone()).expect("segments");
+    assert_eq!(segments.len(), 18);
+    for segment in &segments {
+        assert_ne!(segment.cell, index, "center isn't on the outline");
+        assert_eq!(segment.edge.origin(), segment.cell);
+        assert!(!cells.contains(&segment.edge.destination()));
+        assert_eq!(segment.line.0.len(), 2);
+    }
+
+    // Same outline as the dissolved shape.
+    let shape = solvent.dissolve(cells).expect("geometry");
+    assert_eq!(shape.0[0].exterior().0.len(), segments.len() + 1);
+}
+
+#[test]
+fn outline_segments_invalid_input() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let solvent = SolventBuilder::new().build();
+
+    let duplicate = solvent.outline_segments([index, index]);
+    assert!(duplicate.is_err(), "duplicate");
+
+    let parent = index.parent(Resolution::Nine).expect("parent");
+    let mixed = solvent.outline_segments([index, parent]);
+    assert!(mixed.is_err(), "resolution mismatch");
+}
+
+/// Values decreasing with the grid distance from the center (3 at the center,
+/// 0 on the outer ring).
+fn cone() -> HashMap<CellIndex, f64> {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    index
+        .grid_disk_distances::<Vec<_>>(3)
+        .into_iter()
+        .map(|(cell, distance)| (cell, 3. - f64::from(distance)))
+        .collect()
+}
+
+#[test]
+fn contours() {
+    let values = cone();
+    let solvent = SolventBuilder::new().build();
+    let contours = solvent
+        .contours(&values, &[0., 1., 2., 3., 4.])
+        .expect("contours");
+
+    assert_eq!(contours.len(), 5);
+    for (i, radius) in (0..4).rev().enumerate() {
+        let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+        let expected = solvent
+            .dissolve(index.grid_disk::<Vec<_>>(radius))
+            .expect("disk");
+        assert_relative_eq!(
+            contours[i].unsigned_area(),
+            expected.unsigned_area(),
+            epsilon = 1e-12
+        );
+    }
+    assert!(contours[4].0.is_empty(), "above the maximum");
+}
+
+#[test]
+fn contour_bands() {
+    let values = cone();
+    let solvent = SolventBuilder::new().build();
+    let bands = solvent
+        .contour_bands(&values, &[0., 1., 2.])
+        .expect("bands");
+
+    assert_eq!(bands.len(), 3);
+    // Rings around the center, then the center and its neighbors.
+    assert_eq!(bands[0].0.len(), 1);
+    assert_eq!(bands[0].0[0].interiors().len(), 1);
+    assert_eq!(bands[1].0[0].interiors().len(), 1);
+    assert!(bands[2].0[0].interiors().is_empty());
+
+    // Bands add up to the whole coverage.
+    let contours = solvent.contours(&values, &[0.]).expect("contours");
+    let total = bands.iter().map(Area::unsigned_area).sum::<f64>();
+    assert_relative_eq!(total, contours[0].unsigned_area(), epsilon = 1e-12);
+}
+
+#[test]
+fn isolines() {
+    let values = cone();
+    let solvent = SolventBuilder::new().build();
+    let isolines = solvent.isolines(&values, &[1., 2.]).expect("isolines");
+
+    assert_eq!(isolines.len(), 2);
+    // Outlines of the disks of radius 2 and 1.
+    for (lines, segments) in isolines.iter().zip([30, 18]) {
+        assert_eq!(lines.0.len(), 1);
+        assert!(lines.0[0].is_closed());
+        assert_eq!(lines.0[0].0.len(), segments + 1);
+    }
+}
+
+#[test]
+fn contours_invalid_input() {
+    let mut values = cone();
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    values.insert(index.parent(Resolution::Nine).expect("parent"), 42.);
+    let solvent = SolventBuilder::new().build();
+
+    assert!(solvent.contours(&values, &[10.]).is_ok(), "only the parent");
+    assert!(
+        solvent.contours(&values, &[0.]).is_err(),
+        "mixed resolutions"
+    );
+}
+
+#[test]
+fn summarize_clusters() {
+    let paris = h3o::LatLng::new(48.864716, 2.349014)
+        .expect("paris")
+        .to_cell(Resolution::Nine);
+    let shanghai = h3o::LatLng::new(31.224361, 121.469170)
+        .expect("shanghai")
+        .to_cell(Resolution::Nine);
+    let mut cells = paris.grid_disk::<Vec<_>>(2);
+    cells.extend(shanghai.grid_ring_fast(1).flatten());
+    cells.push(paris.grid_ring_fast(5).next().flatten().expect("noise"));
+    let labels = h3o::cluster_cells(&cells, 2, 1);
+    let solvent = SolventBuilder::new().enable_deterministic_output().build();
+
+    let clusters = solvent
+        .summarize_clusters(&cells, &labels)
+        .expect("clusters");
+
+    assert_eq!(clusters.len(), 2);
+    for (label, cluster) in clusters.into_iter().enumerate() {
+        let members = cells
+            .iter()
+            .zip(&labels)
+            .filter_map(|(&cell, &l)| (l == Some(label)).then_some(cell))
+            .collect::<Vec<_>>();
+        let centroid = h3o::weighted_centroid(
+            members.iter().map(|&cell| (cell, cell.area_km2())),
+        )
+        .expect("centroid");
+
+        assert_eq!(cluster.label, label);
+        assert_eq!(cluster.cell_count, members.len());
+        assert_eq!(
+            cluster.shape,
+            solvent.dissolve(members.iter().copied()).expect("shape")
+        );
+        assert_relative_eq!(
+            cluster.area_km2,
+            members.iter().map(|cell| cell.area_km2()).sum::<f64>(),
+            epsilon = 1e-9
+        );
+        assert_eq!(cluster.centroid, centroid);
+    }
+}
+
+#[test]
+fn summarize_clusters_adjacent() {
+    // Two clusters sharing a border are not merged.
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let cells = index.grid_disk::<Vec<_>>(1);
+    let labels = std::iter::once(Some(7))
+        .chain(std::iter::repeat(Some(3)))
+        .take(cells.len())
+        .collect::<Vec<_>>();
+    let solvent = SolventBuilder::new().build();
+
+    let clusters = solvent
+        .summarize_clusters(&cells, &labels)
+        .expect("clusters");
+
+    assert_eq!(clusters.len(), 2);
+    assert_eq!(clusters[0].label, 3);
+    assert_eq!(clusters[0].cell_count, 6);
+    // The ring has a hole where the center cell is.
+    assert_eq!(clusters[0].shape.0[0].interiors().len(), 1);
+    assert_eq!(clusters[1].label, 7);
+    assert_eq!(clusters[1].cell_count, 1);
+    assert_eq!(clusters[1].centroid, h3o::LatLng::from(index));
+}
+
+#[test]
+fn summarize_clusters_invalid() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let parent = index.parent(Resolution::Nine).expect("parent");
+    let solvent = SolventBuilder::new().build();
+
+    assert_eq!(
+        solvent.summarize_clusters(&[index, index], &[Some(0), Some(0)]),
+        Err(h3o::error::DissolutionError::DuplicateInput)
+    );
+    assert_eq!(
+        solvent.summarize_clusters(&[index, parent], &[Some(0), Some(0)]),
+        Err(h3o::error::DissolutionError::UnsupportedResolution)
+    );
+    assert_eq!(
+        solvent.summarize_clusters(&[index, parent], &[Some(0)]),
+        Err(h3o::error::DissolutionError::LengthMismatch)
+    );
+    // Noise is ignored, and different clusters can have different
+    // resolutions.
+    assert!(solvent
+        .summarize_clusters(&[index, index], &[Some(0), None])
+        .is_ok());
+    assert!(solvent
+        .summarize_clusters(&[index, parent], &[Some(0), Some(1)])
+        .is_ok());
+}
@@ -1,10 +1,17 @@
 use super::utils::load_polygon;
-use geo::{coord, polygon, LineString, MultiPolygon, Polygon, Rect};
+use float_eq::assert_float_eq;
+use geo::{
+    coord, polygon, Area, BooleanOps, BoundingRect, Centroid, LineString,
+    MultiPolygon, Polygon, Rect,
+};
 use h3o::{
-    geom::{ContainmentMode, TilerBuilder},
+    geom::{ContainmentMode, HolePolicy, TilerBuilder},
     CellIndex, LatLng, Resolution,
 };
-use std::{collections::BTreeSet, f64::consts::PI};
+use std::{
+    collections::{BTreeSet, HashSet},
+    f64::consts::PI,
+};
 
 #[test]
 fn add_rads() {
@@ -616,3 +623,811 @@ fn bbox_transmeridian() {
     result.sort_unstable();
     assert_eq!(result, expected);
 }
+
+// Four rectangles meeting at a cell center: every cell must be assigned to
+// exactly one of them.
+#[test]
+fn robust_predicates_shared_edges() {
+    let resolution = Resolution::Seven;
+    let center =
+        LatLng::from(CellIndex::try_from(0x871fb4662ffffff).expect("cell"));
+    let (x, y) = (center.lng_radians(), center.lat_radians());
+    let delta = 0.002;
+    let quadrants = [
+        Rect::new(coord! { x: x - delta, y: y - delta }, coord! { x: x, y: y }),
+        Rect::new(coord! { x: x, y: y - delta }, coord! { x: x + delta, y: y }),
+        Rect::new(coord! { x: x - delta, y: y }, coord! { x: x, y: y + delta }),
+        Rect::new(coord! { x: x, y: y }, coord! { x: x + delta, y: y + delta }),
+    ];
+    let whole = Rect::new(
+        coord! { x: x - delta, y: y - delta },
+        coord! { x: x + delta, y: y + delta },
+    );
+    let coverage = |rect: Rect| {
+        let mut tiler = TilerBuilder::new(resolution)
+            .disable_radians_conversion()
+            .enable_robust_predicates()
+            .build();
+        tiler.add(rect.to_polygon()).expect("failed to add polygon");
+        tiler.into_coverage().collect::<Vec<_>>()
+    };
+
+    let mut result =
+        quadrants.into_iter().flat_map(coverage).collect::<Vec<_>>();
+    let count = result.len();
+    result.sort_unstable();
+    result.dedup();
+    assert_eq!(result.len(), count, "no cell shared by two quadrants");
+    assert!(result.contains(&center.to_cell(resolution)));
+
+    let mut expected = coverage(whole);
+    expected.sort_unstable();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn spherical_containment() {
+    let polygon = polygon![
+        (x: -60., y: 60.),
+        (x: 60., y: 60.),
+        (x: 60., y: 50.),
+        (x: -60., y: 50.),
+    ];
+    let inside = LatLng::new(70., 0.)
+        .expect("inside")
+        .to_cell(Resolution::Three);
+    let outside = LatLng::new(55., 0.)
+        .expect("outside")
+        .to_cell(Resolution::Three);
+
+    let mut tiler = TilerBuilder::new(Resolution::Three)
+        .enable_spherical_containment()
+        .build();
+    tiler.add(polygon.clone()).expect("failed to add polygon");
+    let result = tiler.into_coverage().collect::<BTreeSet<_>>();
+    assert!(result.contains(&inside));
+    assert!(!result.contains(&outside));
+    assert_eq!(result, convex_coverage(&polygon, Resolution::Three));
+
+    // Edges are straight lines in the planar mode.
+    let mut tiler = TilerBuilder::new(Resolution::Three).build();
+    tiler.add(polygon).expect("failed to add polygon");
+    let result = tiler.into_coverage().collect::<BTreeSet<_>>();
+    assert!(!result.contains(&inside));
+    assert!(result.contains(&outside));
+}
+
+#[test]
+fn spherical_containment_transmeridian() {
+    let polygon = polygon![
+        (x: 170., y: 10.),
+        (x: -170., y: 10.),
+        (x: -170., y: -10.),
+        (x: 170., y: -10.),
+    ];
+    let mut tiler = TilerBuilder::new(Resolution::Three)
+        .enable_spherical_containment()
+        .build();
+    tiler.add(polygon.clone()).expect("failed to add polygon");
+    let result = tiler.into_coverage().collect::<BTreeSet<_>>();
+
+    assert_eq!(result, convex_coverage(&polygon, Resolution::Three));
+}
+
+// Brute-force the cells whose center is inside a convex spherical polygon
+// (given in degrees, clockwise).
+fn convex_coverage(
+    polygon: &Polygon,
+    resolution: Resolution,
+) -> BTreeSet<CellIndex> {
+    let to_vec = |ll: LatLng| {
+        let (lat, lng) = (ll.lat_radians(), ll.lng_radians());
+        [lat.cos() * lng.cos(), lat.cos() * lng.sin(), lat.sin()]
+    };
+    let normals = polygon
+        .exterior()
+        .lines()
+        .map(|line| {
+            let a = to_vec(LatLng::new(line.start.y, line.start.x).expect("a"));
+            let b = to_vec(LatLng::new(line.end.y, line.end.x).expect("b"));
+            [
+                a[1] * b[2] - a[2] * b[1],
+                a[2] * b[0] - a[0] * b[2],
+                a[0] * b[1] - a[1] * b[0],
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    CellIndex::base_cells()
+        .flat_map(|cell| cell.children(resolution))
+        .filter(|&cell| {
+            let p = to_vec(LatLng::from(cell));
+            normals
+                .iter()
+                .all(|n| n[0] * p[0] + n[1] * p[1] + n[2] * p[2] < 0.)
+        })
+        .collect()
+}
+
+#[test]
+fn large_polygons() {
+    let resolution = Resolution::Two;
+    let counter_clockwise = polygon![
+        (x: 0., y: 0.),
+        (x: 10., y: 0.),
+        (x: 10., y: 10.),
+        (x: 0., y: 10.),
+    ];
+    let mut clockwise = counter_clockwise.clone();
+    clockwise.exterior_mut(|ring| ring.0.reverse());
+    let coverage = |polygon: Polygon| {
+        let mut tiler = TilerBuilder::new(resolution)
+            .enable_large_polygons()
+            .build();
+        tiler.add(polygon).expect("failed to add polygon");
+        tiler.into_coverage().collect::<BTreeSet<_>>()
+    };
+
+    // Small polygons are unaffected.
+    let zone = coverage(counter_clockwise.clone());
+    let mut tiler = TilerBuilder::new(resolution)
+        .enable_spherical_containment()
+        .build();
+    tiler.add(counter_clockwise).expect("failed to add polygon");
+    assert_eq!(zone, tiler.into_coverage().collect::<BTreeSet<_>>());
+
+    // Clockwise ring covers everything else.
+    let world = coverage(clockwise);
+    let all = CellIndex::base_cells()
+        .flat_map(|cell| cell.children(resolution))
+        .collect::<BTreeSet<_>>();
+    assert!(!zone.is_empty());
+    assert!(zone.is_disjoint(&world));
+    assert_eq!(zone.union(&world).copied().collect::<BTreeSet<_>>(), all);
+}
+
+#[test]
+fn large_polygons_with_hole() {
+    let resolution = Resolution::Two;
+    // Everything but a 20° square, with a (clockwise) hole around 90°E.
+    let polygon = Polygon::new(
+        LineString::from(vec![
+            (-10., -10.),
+            (-10., 10.),
+            (10., 10.),
+            (10., -10.),
+            (-10., -10.),
+        ]),
+        vec![LineString::from(vec![
+            (85., -5.),
+            (85., 5.),
+            (95., 5.),
+            (95., -5.),
+            (85., -5.),
+        ])],
+    );
+    let mut tiler = TilerBuilder::new(resolution)
+        .enable_large_polygons()
+        .build();
+    tiler.add(polygon).expect("failed to add polygon");
+    let result = tiler.into_coverage().collect::<BTreeSet<_>>();
+
+    let cell =
+        |lat, lng| LatLng::new(lat, lng).expect("ll").to_cell(resolution);
+    assert!(result.contains(&cell(0., 180.)));
+    assert!(result.contains(&cell(45., 0.)));
+    assert!(result.contains(&cell(0., 80.)));
+    assert!(!result.contains(&cell(0., 0.)));
+    assert!(!result.contains(&cell(0., 90.)));
+}
+
+#[test]
+fn even_odd_rule() {
+    let resolution = Resolution::Five;
+    // Pentagram: the center is enclosed twice.
+    let pentagram = Polygon::new(
+        LineString::from(
+            (0..=5)
+                .map(|i| {
+                    let angle = f64::from(i * 4 % 10) * PI / 5. + PI / 2.;
+                    (10. * angle.cos(), 10. * angle.sin())
+                })
+                .collect::<Vec<_>>(),
+        ),
+        vec![],
+    );
+    let center = LatLng::new(0., 0.).expect("center").to_cell(resolution);
+    let tip = LatLng::new(8., 0.).expect("tip").to_cell(resolution);
+
+    let bowtie = Polygon::new(
+        LineString::from(vec![
+            (0., 0.),
+            (1., 1.),
+            (1., 0.),
+            (0., 1.),
+            (0., 0.),
+        ]),
+        vec![],
+    );
+    let coverage = |builder: TilerBuilder, polygon: &Polygon| {
+        let mut tiler = builder.build();
+        tiler.add(polygon.clone()).expect("failed to add polygon");
+        tiler.into_coverage().collect::<BTreeSet<_>>()
+    };
+
+    for mode in [
+        ContainmentMode::ContainsCentroid,
+        ContainmentMode::ContainsBoundary,
+        ContainmentMode::IntersectsBoundary,
+        ContainmentMode::Covers,
+    ] {
+        let builder = || TilerBuilder::new(resolution).containment_mode(mode);
+
+        let nonzero = coverage(builder(), &pentagram);
+        let even_odd = coverage(builder().enable_even_odd_rule(), &pentagram);
+        assert!(nonzero.contains(&center), "{mode:?}");
+        assert!(!even_odd.contains(&center), "{mode:?}");
+        assert!(even_odd.contains(&tip), "{mode:?}");
+        assert!(even_odd.is_subset(&nonzero), "{mode:?}");
+
+        // Each lobe is enclosed once: both rules agree.
+        assert_eq!(
+            coverage(builder().enable_even_odd_rule(), &bowtie),
+            coverage(builder(), &bowtie),
+            "{mode:?}"
+        );
+    }
+}
+
+#[test]
+fn union() {
+    let resolution = Resolution::Six;
+    let polygons = [
+        Rect::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. }),
+        Rect::new(coord! { x: 0.5, y: 0.5 }, coord! { x: 1.5, y: 1.5 }),
+        Rect::new(coord! { x: 1.5, y: 0. }, coord! { x: 2., y: 1.5 }),
+    ]
+    .map(|rect| rect.to_polygon());
+    let coverage = |builder: TilerBuilder, polygons: &[Polygon]| {
+        let mut tiler = builder.build();
+        tiler
+            .add_batch(polygons.iter().cloned())
+            .expect("failed to add polygons");
+        tiler.into_coverage().collect::<Vec<_>>()
+    };
+
+    for mode in [
+        ContainmentMode::ContainsCentroid,
+        ContainmentMode::IntersectsBoundary,
+        ContainmentMode::Covers,
+    ] {
+        let builder = || TilerBuilder::new(resolution).containment_mode(mode);
+        // Tile each polygon independently.
+        let separate = polygons
+            .iter()
+            .flat_map(|polygon| {
+                coverage(builder(), std::slice::from_ref(polygon))
+            })
+            .collect::<Vec<_>>();
+        let expected = separate.iter().copied().collect::<BTreeSet<_>>();
+        assert!(separate.len() > expected.len(), "{mode:?}: duplicates");
+
+        let result = coverage(builder().enable_union(), &polygons);
+        let unique = result.iter().copied().collect::<BTreeSet<_>>();
+        assert_eq!(result.len(), unique.len(), "{mode:?}: no duplicates");
+        assert_eq!(unique, expected, "{mode:?}");
+    }
+}
+
+#[test]
+fn small_hole_policy() {
+    let resolution = Resolution::Six;
+    let cell = LatLng::new(0.5, 0.5)
+        .expect("valid coordinate")
+        .to_cell(resolution);
+    let center = LatLng::from(cell);
+    let vertex = cell.boundary()[0];
+    // A tiny square hole, centered on the given point.
+    let hole = |lat: f64, lng: f64| {
+        Rect::new(
+            coord! { x: lng - 0.001, y: lat - 0.001 },
+            coord! { x: lng + 0.001, y: lat + 0.001 },
+        )
+        .to_polygon()
+        .exterior()
+        .clone()
+    };
+    let centered = hole(center.lat(), center.lng());
+    let off_center = hole(
+        f64::midpoint(center.lat(), vertex.lat()),
+        f64::midpoint(center.lng(), vertex.lng()),
+    );
+    let exterior = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. })
+        .to_polygon()
+        .exterior()
+        .clone();
+    let coverage = |builder: TilerBuilder, hole: &LineString| {
+        let mut tiler = builder.build();
+        let polygon = Polygon::new(exterior.clone(), vec![hole.clone()]);
+        tiler.add(polygon).expect("failed to add polygon");
+        tiler.into_coverage().collect::<BTreeSet<_>>()
+    };
+
+    for mode in [
+        ContainmentMode::ContainsCentroid,
+        ContainmentMode::ContainsBoundary,
+        ContainmentMode::IntersectsBoundary,
+        ContainmentMode::Covers,
+    ] {
+        let builder = || TilerBuilder::new(resolution).containment_mode(mode);
+        for (hole, name) in [(&centered, "centered"), (&off_center, "off")] {
+            let policy =
+                |policy| coverage(builder().small_hole_policy(policy), hole);
+            let ignored = policy(HolePolicy::Ignore);
+            assert!(ignored.contains(&cell), "{mode:?}/{name}: ignore");
+
+            let expected = ignored
+                .iter()
+                .copied()
+                .filter(|&index| index != cell)
+                .collect::<BTreeSet<_>>();
+            assert_eq!(
+                policy(HolePolicy::ExcludeIntersecting),
+                expected,
+                "{mode:?}/{name}: exclude intersecting"
+            );
+
+            let result = policy(HolePolicy::ExcludeCentroid);
+            if hole == &centered {
+                assert_eq!(result, expected, "{mode:?}/{name}: centroid");
+            } else {
+                assert_eq!(result, ignored, "{mode:?}/{name}: centroid");
+            }
+        }
+    }
+}
+
+#[test]
+fn small_hole_spherical_area() {
+    let resolution = Resolution::Four;
+    // Flat in the plane, but the northern edge follows a great circle arc
+    // that bulges up to ~71°N: way larger than a cell on the sphere.
+    let hole = LineString::from(vec![
+        (-20., 70.),
+        (0., 69.99),
+        (20., 70.),
+        (-20., 70.),
+    ]);
+    let exterior = LineString::from(
+        (-6..=6)
+            .map(|i| (f64::from(i) * 5., 65.))
+            .chain((-6..=6).rev().map(|i| (f64::from(i) * 5., 75.)))
+            .chain([(-30., 65.)])
+            .collect::<Vec<_>>(),
+    );
+    let mut tiler = TilerBuilder::new(resolution)
+        .enable_spherical_containment()
+        .small_hole_policy(HolePolicy::Ignore)
+        .build();
+    tiler
+        .add(Polygon::new(exterior, vec![hole]))
+        .expect("failed to add polygon");
+    let result = tiler.into_coverage().collect::<BTreeSet<_>>();
+
+    let cell = |lat, lng| {
+        LatLng::new(lat, lng)
+            .expect("valid coordinate")
+            .to_cell(resolution)
+    };
+    assert!(!result.contains(&cell(70.6, 0.)), "inside the hole");
+    assert!(result.contains(&cell(73., 0.)), "outside the hole");
+}
+
+#[test]
+fn coverage_report() {
+    let resolution = Resolution::Seven;
+    let polygon = polygon![
+        (x: 0., y: 0.),
+        (x: 0.2, y: 0.2),
+        (x: 0.2, y: 0.),
+        (x: 0., y: 0.),
+    ];
+    let report = |mode| {
+        let mut tiler =
+            TilerBuilder::new(resolution).containment_mode(mode).build();
+        tiler.add(polygon.clone()).expect("failed to add polygon");
+        tiler.into_coverage_with_report()
+    };
+
+    let (cells, covers) = report(ContainmentMode::Covers);
+    assert!(!cells.is_empty());
+    assert!(covers.residual_area_km2 < 1e-6, "complete coverage");
+
+    let (_, centroid) = report(ContainmentMode::ContainsCentroid);
+    assert!(centroid.residual_area_km2 > 1., "incomplete coverage");
+    assert!(!centroid.residual.0.is_empty());
+}
+
+#[test]
+fn coverage_report_complete() {
+    let polygon = polygon![
+        (x: 0., y: 0.),
+        (x: 1., y: 1.),
+        (x: 1., y: 0.),
+        (x: 0., y: 0.),
+    ];
+    let mut tiler = TilerBuilder::new(Resolution::Seven)
+        .containment_mode(ContainmentMode::Covers)
+        .build();
+    tiler.add(polygon).expect("failed to add polygon");
+
+    let (cells, report) = tiler.into_coverage_with_report();
+    assert!(cells.len() > 1000);
+    assert!(report.residual.0.is_empty(), "no slivers");
+    assert_eq!(report.residual_area_km2, 0.);
+}
+
+#[test]
+fn coverage_report_boundary_only() {
+    let cell = LatLng::new(0.5, 0.5)
+        .expect("valid coordinate")
+        .to_cell(Resolution::Six);
+    let mut tiler = TilerBuilder::new(Resolution::Six)
+        .containment_mode(ContainmentMode::Covers)
+        .build();
+    tiler
+        .add(Polygon::new(LineString::from(cell.boundary()), Vec::new()))
+        .expect("failed to add polygon");
+
+    let (cells, report) = tiler.into_coverage_with_report();
+    let neighbors = cell
+        .grid_disk::<BTreeSet<_>>(1)
+        .into_iter()
+        .filter(|&index| index != cell)
+        .collect::<BTreeSet<_>>();
+    assert!(cells.contains(&cell));
+    let boundary_only =
+        report.boundary_only.into_iter().collect::<BTreeSet<_>>();
+    if cfg!(feature = "fma") {
+        // Shared vertices may differ in the last bits from one cell to
+        // another, so a neighbor can overlap the polygon by a sliver.
+        assert!(boundary_only.is_subset(&neighbors));
+        assert!(neighbors.iter().all(|neighbor| cells.contains(neighbor)));
+    } else {
+        assert_eq!(boundary_only, neighbors);
+    }
+}
+
+#[test]
+fn clipped_coverage() {
+    let resolution = Resolution::Seven;
+    let polygon = polygon![
+        (x: 0., y: 0.),
+        (x: 0.2, y: 0.2),
+        (x: 0.2, y: 0.),
+        (x: 0., y: 0.),
+    ];
+    let tiler = || {
+        let mut tiler = TilerBuilder::new(resolution)
+            .containment_mode(ContainmentMode::Covers)
+            .build();
+        tiler.add(polygon.clone()).expect("failed to add polygon");
+        tiler
+    };
+
+    let clipped = tiler().into_clipped_coverage().collect::<Vec<_>>();
+    let cells = tiler().into_coverage().collect::<BTreeSet<_>>();
+    assert_eq!(
+        clipped
+            .iter()
+            .map(|(cell, _)| *cell)
+            .collect::<BTreeSet<_>>(),
+        cells
+    );
+
+    // Slivers add up to the whole polygon.
+    let area = clipped
+        .iter()
+        .map(|(_, geom)| geom.unsigned_area())
+        .sum::<f64>();
+    assert_float_eq!(area, polygon.unsigned_area(), r2nd <= 1e-9);
+
+    // Inner cells are returned whole, outline cells are clipped.
+    let cell_area = |cell: CellIndex| {
+        Polygon::new(LineString::from(cell.boundary()), Vec::new())
+            .unsigned_area()
+    };
+    let inner = LatLng::new(0.05, 0.15)
+        .expect("valid coordinate")
+        .to_cell(resolution);
+    let outline = LatLng::new(0., 0.1)
+        .expect("valid coordinate")
+        .to_cell(resolution);
+    for (cell, geom) in clipped {
+        if cell == inner {
+            assert_float_eq!(
+                geom.unsigned_area(),
+                cell_area(cell),
+                r2nd <= 1e-9
+            );
+        } else if cell == outline {
+            assert!(geom.unsigned_area() < cell_area(cell));
+        }
+    }
+}
+
+fn assert_incremental_update(mode: ContainmentMode, edited: Polygon) {
+    let polygon = load_polygon("Paris");
+    let builder =
+        || TilerBuilder::new(Resolution::Eight).containment_mode(mode);
+    let mut tiler = builder().build();
+    tiler.add(polygon).expect("failed to add polygon");
+    let mut coverage = tiler.clone().into_coverage().collect::<HashSet<_>>();
+
+    tiler
+        .update_coverage([edited.clone()], &mut coverage)
+        .expect("failed to update coverage");
+
+    let mut expected = builder().build();
+    expected.add(edited).expect("failed to add polygon");
+    let expected = expected.into_coverage().collect::<HashSet<_>>();
+    assert_eq!(coverage, expected, "{mode:?}");
+    // The tiler now tiles the edited polygon.
+    assert_eq!(tiler.into_coverage().collect::<HashSet<_>>(), expected);
+}
+
+#[test]
+fn clipped_coverage_overlaps_and_small_holes() {
+    let resolution = Resolution::Seven;
+    let squares = [
+        Rect::new(coord! { x: 0., y: 0. }, coord! { x: 0.2, y: 0.2 }),
+        Rect::new(coord! { x: 0.1, y: 0.1 }, coord! { x: 0.3, y: 0.3 }),
+    ]
+    .map(|rect| rect.to_polygon());
+    // A hole smaller than a cell.
+    let hole =
+        Rect::new(coord! { x: 0.04, y: 0.04 }, coord! { x: 0.05, y: 0.05 })
+            .to_polygon();
+    let polygon = Polygon::new(
+        squares[0].exterior().clone(),
+        vec![hole.exterior().clone()],
+    );
+    let mut tiler = TilerBuilder::new(resolution)
+        .containment_mode(ContainmentMode::Covers)
+        .small_hole_policy(HolePolicy::Ignore)
+        .enable_union()
+        .build();
+    tiler
+        .add_batch([polygon, squares[1].clone()])
+        .expect("failed to add polygons");
+
+    // Slivers add up to the union, without the hole.
+    let expected = 0.2 * 0.2 * 2. - 0.1 * 0.1 - 0.01 * 0.01;
+    let area = tiler
+        .into_clipped_coverage()
+        .map(|(_, geom)| geom.unsigned_area())
+        .sum::<f64>();
+    assert_float_eq!(area, expected, r2nd <= 1e-8);
+}
+#[test]
+fn update_coverage_move_vertex() {
+    let mut edited = load_polygon("Paris");
+    edited.exterior_mut(|ring| {
+        ring.0[10].x += 0.01;
+        ring.0[10].y += 0.005;
+    });
+
+    for mode in [
+        ContainmentMode::ContainsCentroid,
+        ContainmentMode::ContainsBoundary,
+        ContainmentMode::IntersectsBoundary,
+        ContainmentMode::Covers,
+    ] {
+        assert_incremental_update(mode, edited.clone());
+    }
+}
+
+#[test]
+fn update_coverage_add_remove_area() {
+    let polygon = load_polygon("Paris");
+    let center = polygon.centroid().expect("centroid");
+    let square = |x: f64, y: f64, size: f64| {
+        Polygon::new(
+            LineString::from(vec![
+                (x, y),
+                (x + size, y),
+                (x + size, y + size),
+                (x, y + size),
+                (x, y),
+            ]),
+            Vec::new(),
+        )
+    };
+
+    // Punch a hole in the middle.
+    let hole = square(center.x(), center.y(), 0.01);
+    let edited =
+        Polygon::new(polygon.exterior().clone(), vec![hole.exterior().clone()]);
+    assert_incremental_update(ContainmentMode::ContainsCentroid, edited);
+
+    // Grow the polygon.
+    let rect = polygon.bounding_rect().expect("bbox");
+    let extension = square(rect.max().x - 0.01, center.y(), 0.05);
+    let edited = polygon.union(&extension).0.remove(0);
+    assert_incremental_update(ContainmentMode::IntersectsBoundary, edited);
+}
+
+#[test]
+fn update_coverage_invalid() {
+    let polygon = load_polygon("Paris");
+    let mut tiler = TilerBuilder::new(Resolution::Eight).build();
+    tiler.add(polygon).expect("failed to add polygon");
+    let mut coverage = tiler.clone().into_coverage().collect::<HashSet<_>>();
+    let expected = coverage.clone();
+
+    let invalid = Polygon::new(
+        LineString::from(vec![(0., 0.), (f64::NAN, 0.), (1., 1.), (0., 0.)]),
+        Vec::new(),
+    );
+    assert!(tiler.update_coverage([invalid], &mut coverage).is_err());
+    assert_eq!(coverage, expected);
+    assert_eq!(tiler.into_coverage().collect::<HashSet<_>>(), expected);
+}
+
+#[test]
+fn deterministic_output() {
+    let polygon = load_polygon("Paris");
+    let mut tiler = TilerBuilder::new(Resolution::Nine)
+        .enable_deterministic_output()
+        .build();
+    tiler.add(polygon).expect("failed to add polygon");
+
+    let result = tiler.into_coverage().collect::<Vec<_>>();
+    assert!(result.is_sorted());
+}
+
+#[test]
+fn into_compact_coverage() {
+    for mode in [
+        ContainmentMode::ContainsCentroid,
+        ContainmentMode::ContainsBoundary,
+        ContainmentMode::IntersectsBoundary,
+        ContainmentMode::Covers,
+    ] {
+        let mut tiler = TilerBuilder::new(Resolution::Seven)
+            .containment_mode(mode)
+            .build();
+        tiler
+            .add(load_polygon("Paris"))
+            .expect("failed to add polygon");
+        let mut expected = tiler.clone().into_coverage().collect::<Vec<_>>();
+        CellIndex::compact(&mut expected).expect("compacted");
+        expected.sort_unstable();
+
+        let mut result = tiler.into_compact_coverage().collect::<Vec<_>>();
+        result.sort_unstable();
+
+        assert_eq!(result, expected, "{mode:?}");
+    }
+}
+
+#[test]
+fn into_compact_coverage_duplicates() {
+    // Overlapping polygons, without union.
+    let mut tiler = TilerBuilder::new(Resolution::Seven)
+        .enable_deterministic_output()
+        .build();
+    tiler
+        .add(load_polygon("Paris"))
+        .expect("failed to add polygon");
+    tiler
+        .add(load_polygon("Paris"))
+        .expect("failed to add polygon");
+    let mut expected = tiler.clone().into_coverage().collect::<Vec<_>>();
+    expected.dedup();
+    CellIndex::compact(&mut expected).expect("compacted");
+    expected.sort_unstable();
+
+    let result = tiler.into_compact_coverage().collect::<Vec<_>>();
+
+    assert!(result.is_sorted(), "deterministic output");
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn into_compact_coverage_full_base_cell() {
+    let base_cell = CellIndex::base_cells().nth(20).expect("base cell");
+    let mut tiler = TilerBuilder::new(Resolution::Three)
+        .containment_mode(ContainmentMode::ContainsCentroid)
+        .build();
+    tiler
+        .add(Polygon::from(base_cell))
+        .expect("failed to add polygon");
+
+    let result = tiler.into_compact_coverage().collect::<Vec<_>>();
+    assert!(result.len() < 7 * 7 * 7, "compacted");
+    assert!(
+        result
+            .iter()
+            .any(|cell| cell.resolution() < Resolution::Three),
+        "coarser cells"
+    );
+}
+
+#[cfg(feature = "rayon")]
+fn assert_same_coverage_par(builder: TilerBuilder, polygons: &[Polygon]) {
+    let mut tiler = builder.build();
+    tiler
+        .add_batch(polygons.iter().cloned())
+        .expect("failed to add polygon");
+    let mut expected = tiler.clone().into_coverage().collect::<Vec<_>>();
+    expected.sort_unstable();
+
+    let mut result = tiler.into_coverage_par();
+    result.sort_unstable();
+
+    assert_eq!(result, expected);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn into_coverage_par() {
+    for (name, resolution) in [
+        ("Paris", Resolution::Seven),
+        ("Holes", Resolution::Five),
+        ("Rabi", Resolution::Seven),
+        ("Transmeridian", Resolution::Five),
+        ("SanFranciscoHole", Resolution::Seven),
+    ] {
+        for mode in [
+            ContainmentMode::ContainsCentroid,
+            ContainmentMode::ContainsBoundary,
+            ContainmentMode::IntersectsBoundary,
+            ContainmentMode::Covers,
+        ] {
+            let builder = TilerBuilder::new(resolution).containment_mode(mode);
+            assert_same_coverage_par(builder, &[load_polygon(name)]);
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn into_coverage_par_options() {
+    let polygons = [load_polygon("Paris"), load_polygon("Paris")];
+
+    // Duplicates from overlapping polygons are kept...
+    let builder = TilerBuilder::new(Resolution::Eight);
+    assert_same_coverage_par(builder, &polygons);
+    // ... unless they are merged.
+    let builder = TilerBuilder::new(Resolution::Eight).enable_union();
+    assert_same_coverage_par(builder, &polygons);
+
+    let builder =
+        TilerBuilder::new(Resolution::Eight).enable_spherical_containment();
+    assert_same_coverage_par(builder, &polygons[..1]);
+
+    let mut tiler = TilerBuilder::new(Resolution::Eight)
+        .enable_deterministic_output()
+        .build();
+    tiler
+        .add(polygons[0].clone())
+        .expect("failed to add polygon");
+    assert!(tiler.into_coverage_par().is_sorted());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn into_coverage_par_tiny_polygon() {
+    let polygon = polygon![
+        (x: 2.3490, y: 48.8647),
+        (x: 2.3491, y: 48.8647),
+        (x: 2.3491, y: 48.8648),
+        (x: 2.3490, y: 48.8647),
+    ];
+    for mode in [ContainmentMode::ContainsCentroid, ContainmentMode::Covers] {
+        let builder =
+            TilerBuilder::new(Resolution::Five).containment_mode(mode);
+        assert_same_coverage_par(builder, std::slice::from_ref(&polygon));
+    }
+}
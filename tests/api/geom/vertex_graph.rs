@@ -0,0 +1,94 @@
+use geo::MultiPolygon;
+use h3o::{
+    error::DissolutionError,
+    geom::{Node, SolventBuilder, VertexGraph},
+    CellIndex, Resolution,
+};
+
+#[test]
+fn insert_cell() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let cells = index.grid_disk::<Vec<_>>(2);
+
+    let mut graph = VertexGraph::new(Resolution::Ten);
+    for &cell in &cells {
+        graph.insert_cell(cell).expect("insert");
+    }
+    let result = MultiPolygon::from(graph);
+
+    let solvent = SolventBuilder::new().build();
+    let expected = solvent.dissolve(cells).expect("dissolve");
+    assert_eq!(result.0.len(), expected.0.len());
+    assert_eq!(
+        result.0[0].exterior().0.len(),
+        expected.0[0].exterior().0.len()
+    );
+}
+
+#[test]
+fn insert_cell_resolution_mismatch() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let mut graph = VertexGraph::new(Resolution::Nine);
+
+    assert_eq!(
+        graph.insert_cell(index),
+        Err(DissolutionError::UnsupportedResolution)
+    );
+    assert!(graph.is_empty());
+}
+
+#[test]
+fn insert_shared_edge() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let vertexes = index.vertexes().collect::<Vec<_>>();
+    let mut graph = VertexGraph::new(Resolution::Ten);
+
+    graph.insert(&Node::new(vertexes[0], vertexes[1]));
+    graph.insert(&Node::new(vertexes[2], vertexes[3]));
+    // Reversed edge cancel each other.
+    graph.insert(&Node::new(vertexes[1], vertexes[0]));
+
+    assert_eq!(graph.get_from_vertex(vertexes[0]), None);
+    assert_eq!(
+        graph.get_from_vertex(vertexes[2]),
+        Some(Node::new(vertexes[2], vertexes[3]))
+    );
+}
+
+#[test]
+fn into_rings() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let mut graph = VertexGraph::new(Resolution::Ten);
+    for cell in index.grid_ring_fast(1) {
+        graph.insert_cell(cell.expect("cell")).expect("insert");
+    }
+
+    let rings = graph.into_rings();
+    assert_eq!(rings.len(), 2);
+    // 6 cells * 3 edges outside, 6 cells * 1 edge inside.
+    let mut sizes = rings.iter().map(|ring| ring.0.len()).collect::<Vec<_>>();
+    sizes.sort_unstable();
+    assert_eq!(sizes, vec![6, 18]);
+}
+
+#[test]
+fn into_rings_open_path() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let vertexes = index.vertexes().collect::<Vec<_>>();
+    let mut graph = VertexGraph::new(Resolution::Ten);
+    graph.insert(&Node::new(vertexes[0], vertexes[1]));
+    graph.insert(&Node::new(vertexes[1], vertexes[2]));
+
+    assert!(graph.clone().into_rings().is_empty());
+    assert!(MultiPolygon::from(graph).0.is_empty());
+}
+
+#[test]
+fn node_accessors() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let vertexes = index.vertexes().collect::<Vec<_>>();
+    let node = Node::new(vertexes[0], vertexes[1]);
+
+    assert_eq!(node.origin(), vertexes[0]);
+    assert_eq!(node.destination(), vertexes[1]);
+}
@@ -0,0 +1,188 @@
+use geo::{LineString, Polygon};
+use h3o::{
+    geom::{Tiler, TilerBuilder},
+    CellIndex, Resolution,
+};
+use std::slice;
+
+const RING: [(f64, f64); 5] = [
+    (2.33, 48.84),
+    (2.36, 48.84),
+    (2.36, 48.87),
+    (2.33, 48.87),
+    (2.33, 48.84),
+];
+
+fn tiler() -> Tiler {
+    TilerBuilder::new(Resolution::Nine).build()
+}
+
+fn expected(polygons: &[Polygon]) -> Vec<CellIndex> {
+    let mut tiler = tiler();
+    tiler
+        .add_batch(polygons.iter().cloned())
+        .expect("valid polygons");
+    tiler.into_coverage().collect()
+}
+
+fn shifted(lng: f64) -> Vec<(f64, f64)> {
+    RING.iter().map(|&(x, y)| (x + lng, y)).collect()
+}
+
+// Encodes a polygon, big endian, with the given type header and ordinates.
+fn encode_polygon(
+    wkb: &mut Vec<u8>,
+    kind: u32,
+    srid: Option<u32>,
+    rings: &[Vec<(f64, f64)>],
+    extra: usize,
+) {
+    wkb.push(0);
+    wkb.extend(kind.to_be_bytes());
+    if let Some(srid) = srid {
+        wkb.extend(srid.to_be_bytes());
+    }
+    wkb.extend(
+        u32::try_from(rings.len())
+            .expect("ring count")
+            .to_be_bytes(),
+    );
+    for ring in rings {
+        wkb.extend(
+            u32::try_from(ring.len())
+                .expect("point count")
+                .to_be_bytes(),
+        );
+        for &(x, y) in ring {
+            wkb.extend(x.to_be_bytes());
+            wkb.extend(y.to_be_bytes());
+            for _ in 0..extra {
+                wkb.extend(42_f64.to_be_bytes());
+            }
+        }
+    }
+}
+
+#[test]
+fn polygon_little_endian() {
+    let mut wkb = vec![1];
+    wkb.extend(3_u32.to_le_bytes());
+    wkb.extend(1_u32.to_le_bytes());
+    wkb.extend(5_u32.to_le_bytes());
+    for (x, y) in RING {
+        wkb.extend(x.to_le_bytes());
+        wkb.extend(y.to_le_bytes());
+    }
+    let mut tiler = tiler();
+    tiler.add_wkb(&wkb).expect("valid WKB");
+
+    let polygon = Polygon::new(LineString::from(RING.to_vec()), vec![]);
+    assert_eq!(
+        tiler.into_coverage().collect::<Vec<_>>(),
+        expected(&[polygon])
+    );
+}
+
+#[test]
+fn polygon_big_endian() {
+    let mut wkb = Vec::new();
+    encode_polygon(&mut wkb, 3, None, &[RING.to_vec()], 0);
+    let mut tiler = tiler();
+    tiler.add_wkb(&wkb).expect("valid WKB");
+
+    let polygon = Polygon::new(LineString::from(RING.to_vec()), vec![]);
+    assert_eq!(
+        tiler.into_coverage().collect::<Vec<_>>(),
+        expected(&[polygon])
+    );
+}
+
+#[test]
+fn ewkb_with_srid_and_z() {
+    let mut wkb = Vec::new();
+    encode_polygon(&mut wkb, 0xa000_0003, Some(4326), &[RING.to_vec()], 1);
+    let mut tiler = tiler();
+    tiler.add_wkb(&wkb).expect("valid EWKB");
+
+    let polygon = Polygon::new(LineString::from(RING.to_vec()), vec![]);
+    assert_eq!(
+        tiler.into_coverage().collect::<Vec<_>>(),
+        expected(&[polygon])
+    );
+}
+
+#[test]
+fn ewkb_srid() {
+    let mut wkb = Vec::new();
+    encode_polygon(&mut wkb, 0x2000_0003, Some(0), &[RING.to_vec()], 0);
+    assert!(tiler().add_wkb(&wkb).is_ok(), "undefined SRID");
+
+    let mut wkb = Vec::new();
+    encode_polygon(&mut wkb, 0x2000_0003, Some(3857), &[RING.to_vec()], 0);
+    assert!(tiler().add_wkb(&wkb).is_err(), "web mercator");
+}
+
+#[test]
+fn iso_zm() {
+    let mut wkb = Vec::new();
+    encode_polygon(&mut wkb, 3003, None, &[RING.to_vec()], 2);
+    let mut tiler = tiler();
+    tiler.add_wkb(&wkb).expect("valid WKB");
+
+    let polygon = Polygon::new(LineString::from(RING.to_vec()), vec![]);
+    assert_eq!(
+        tiler.into_coverage().collect::<Vec<_>>(),
+        expected(&[polygon])
+    );
+}
+
+#[test]
+fn multipolygon() {
+    let rings = [RING.to_vec(), shifted(0.1)];
+    let mut wkb = vec![0];
+    wkb.extend(6_u32.to_be_bytes());
+    wkb.extend(3_u32.to_be_bytes());
+    for ring in &rings {
+        encode_polygon(&mut wkb, 3, None, slice::from_ref(ring), 0);
+    }
+    // Empty polygons are skipped.
+    encode_polygon(&mut wkb, 3, None, &[], 0);
+    let mut tiler = tiler();
+    tiler.add_wkb(&wkb).expect("valid WKB");
+
+    let polygons = rings
+        .map(|ring| Polygon::new(LineString::from(ring), vec![]))
+        .to_vec();
+    assert_eq!(
+        tiler.into_coverage().collect::<Vec<_>>(),
+        expected(&polygons)
+    );
+}
+
+#[test]
+fn invalid() {
+    let mut wkb = Vec::new();
+    encode_polygon(&mut wkb, 3, None, &[RING.to_vec()], 0);
+
+    // Truncated.
+    assert!(tiler().add_wkb(&wkb[..wkb.len() - 1]).is_err());
+    // Trailing bytes.
+    let mut trailing = wkb.clone();
+    trailing.push(0);
+    assert!(tiler().add_wkb(&trailing).is_err());
+    // Invalid byte order.
+    let mut byte_order = wkb.clone();
+    byte_order[0] = 2;
+    assert!(tiler().add_wkb(&byte_order).is_err());
+    // Unsupported geometry (point).
+    let mut point = vec![0];
+    point.extend(1_u32.to_be_bytes());
+    point.extend(0_f64.to_be_bytes());
+    point.extend(0_f64.to_be_bytes());
+    assert!(tiler().add_wkb(&point).is_err());
+    // Invalid coordinates.
+    let mut nan = Vec::new();
+    encode_polygon(&mut nan, 3, None, &[vec![(f64::NAN, 0.); 4]], 0);
+    assert!(tiler().add_wkb(&nan).is_err());
+    assert!(tiler().add_wkb(&[]).is_err());
+}
@@ -0,0 +1,118 @@
+use geo::{LineString, Polygon};
+use h3o::{
+    geom::{Tiler, TilerBuilder},
+    CellIndex, Resolution,
+};
+
+fn tiler() -> Tiler {
+    TilerBuilder::new(Resolution::Nine).build()
+}
+
+fn coverage(wkt: &str) -> Vec<CellIndex> {
+    let mut tiler = tiler();
+    tiler.add_wkt(wkt).expect("valid WKT");
+    tiler.into_coverage().collect()
+}
+
+fn expected(polygons: Vec<Polygon>) -> Vec<CellIndex> {
+    let mut tiler = tiler();
+    tiler.add_batch(polygons).expect("valid polygons");
+    tiler.into_coverage().collect()
+}
+
+fn exterior() -> LineString {
+    LineString::from(vec![
+        (2.30, 48.82),
+        (2.40, 48.82),
+        (2.40, 48.90),
+        (2.30, 48.90),
+        (2.30, 48.82),
+    ])
+}
+
+fn hole() -> LineString {
+    LineString::from(vec![
+        (2.33, 48.85),
+        (2.36, 48.85),
+        (2.36, 48.87),
+        (2.33, 48.87),
+        (2.33, 48.85),
+    ])
+}
+
+#[test]
+fn polygon() {
+    let result = coverage(
+        "POLYGON ((2.30 48.82, 2.40 48.82, 2.40 48.90, 2.30 48.90, 2.30 48.82),
+                  (2.33 48.85, 2.36 48.85, 2.36 48.87, 2.33 48.87, 2.33 48.85))",
+    );
+
+    assert_eq!(
+        result,
+        expected(vec![Polygon::new(exterior(), vec![hole()])])
+    );
+}
+
+#[test]
+fn ewkt_with_z() {
+    let result = coverage(
+        "SRID=4326;polygon z((2.33 48.85 1, 2.36 48.85 2, 2.36 48.87 3, \
+         2.33 48.87 4, 2.33 48.85 1))",
+    );
+
+    assert_eq!(result, expected(vec![Polygon::new(hole(), vec![])]));
+}
+
+#[test]
+fn ewkt_srid() {
+    let wkt = "POLYGON ((2.33 48.85, 2.36 48.85, 2.36 48.87, 2.33 48.85))";
+    assert!(
+        tiler().add_wkt(&format!("SRID=0;{wkt}")).is_ok(),
+        "undefined"
+    );
+    assert!(
+        tiler().add_wkt(&format!("SRID=3857;{wkt}")).is_err(),
+        "web mercator"
+    );
+}
+
+#[test]
+fn multipolygon() {
+    let result = coverage(
+        "MULTIPOLYGON(((2.30 48.82,2.40 48.82,2.40 48.90,2.30 48.90,2.30 48.82)),
+                      EMPTY,
+                      ((3.33 48.85,3.36 48.85,3.36 48.87,3.33 48.87,3.33 48.85)))",
+    );
+    let mut other = hole();
+    other.0.iter_mut().for_each(|coord| coord.x += 1.);
+
+    assert_eq!(
+        result,
+        expected(vec![
+            Polygon::new(exterior(), vec![]),
+            Polygon::new(other, vec![]),
+        ])
+    );
+}
+
+#[test]
+fn empty() {
+    assert!(coverage("POLYGON EMPTY").is_empty());
+    assert!(coverage("MULTIPOLYGON ZM EMPTY").is_empty());
+}
+
+#[test]
+fn invalid() {
+    for wkt in [
+        "",
+        "POINT (1 2)",
+        "POLYGON ((0 0, 1 1, 1 0, 0 0)",
+        "POLYGON ((0 0, 1 1, 1 0, 0 0)) garbage",
+        "POLYGON ((0 0, 1, 1 0, 0 0))",
+        "POLYGON ((0 zero, 1 1, 1 0, 0 0))",
+        "SRID=4326 POLYGON ((0 0, 1 1, 1 0, 0 0))",
+        "POLYGON ((0 0, 400 1, 1 0, 0 0))",
+    ] {
+        assert!(tiler().add_wkt(wkt).is_err(), "{wkt}");
+    }
+}
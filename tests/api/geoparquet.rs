@@ -0,0 +1,148 @@
+use arrow_array::{
+    Array, ArrayRef, BinaryArray, RecordBatch, StringArray, UInt32Array,
+};
+use arrow_schema::{DataType, Field, Schema};
+use h3o::{geoparquet::GeoParquetWriter, CellIndex, LatLng, Resolution};
+use parquet::{
+    arrow::arrow_reader::ParquetRecordBatchReaderBuilder,
+    file::reader::{FileReader, SerializedFileReader},
+};
+use std::{fs::File, path::PathBuf, sync::Arc};
+
+fn tmp_file(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("h3o-{}-{name}", std::process::id()))
+}
+
+fn write(name: &str, cells: &[CellIndex]) -> PathBuf {
+    let path = tmp_file(name);
+    let schema = Schema::new(vec![Field::new("id", DataType::UInt32, false)]);
+    let ids: ArrayRef = Arc::new(UInt32Array::from_iter_values(
+        (0..cells.len()).map(|i| u32::try_from(i).expect("small")),
+    ));
+
+    let file = File::create(&path).expect("file");
+    let mut writer = GeoParquetWriter::try_new(file, &schema).expect("writer");
+    writer.write(cells, &[ids]).expect("write");
+    writer.close().expect("close");
+
+    path
+}
+
+#[test]
+fn roundtrip() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let cells = index.children(Resolution::Eleven).collect::<Vec<_>>();
+    let path = write("roundtrip.parquet", &cells);
+
+    let file = File::open(&path).expect("file");
+    let reader = ParquetRecordBatchReaderBuilder::try_new(file)
+        .expect("builder")
+        .build()
+        .expect("reader");
+    let batches = reader
+        .collect::<Result<Vec<RecordBatch>, _>>()
+        .expect("rows");
+    std::fs::remove_file(path).expect("cleanup");
+
+    assert_eq!(batches.len(), 1);
+    let batch = &batches[0];
+    assert_eq!(batch.num_rows(), cells.len());
+
+    let ids = batch
+        .column_by_name("cell")
+        .and_then(|col| col.as_any().downcast_ref::<StringArray>())
+        .expect("cell column");
+    for (id, cell) in ids.iter().zip(&cells) {
+        assert_eq!(id, Some(cell.to_string().as_str()));
+    }
+
+    let geometries = batch
+        .column_by_name("geometry")
+        .and_then(|col| col.as_any().downcast_ref::<BinaryArray>())
+        .expect("geometry column");
+    let wkb = geometries.value(0);
+    // Little endian polygon, with a single ring of 7 points.
+    assert_eq!(&wkb[..13], &[1, 3, 0, 0, 0, 1, 0, 0, 0, 7, 0, 0, 0]);
+    assert_eq!(wkb.len(), 13 + 7 * 16);
+
+    assert!(batch.column_by_name("id").is_some());
+}
+
+fn geo_metadata(path: PathBuf) -> String {
+    let file = File::open(&path).expect("file");
+    let reader = SerializedFileReader::new(file).expect("reader");
+    let metadata = reader
+        .metadata()
+        .file_metadata()
+        .key_value_metadata()
+        .and_then(|kv| kv.iter().find(|kv| kv.key == "geo"))
+        .and_then(|kv| kv.value.clone())
+        .expect("geo metadata");
+    std::fs::remove_file(path).expect("cleanup");
+
+    metadata
+}
+
+#[test]
+fn metadata() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let metadata = geo_metadata(write("metadata.parquet", &[index]));
+
+    let boundary = index.boundary();
+    let lng = boundary.iter().map(|ll| ll.lng());
+    let xmin = lng.clone().fold(f64::INFINITY, f64::min);
+    let xmax = lng.fold(f64::NEG_INFINITY, f64::max);
+
+    assert!(metadata.contains(r#""primary_column":"geometry""#));
+    assert!(metadata.contains(r#""encoding":"WKB""#));
+    assert!(metadata.contains(r#""geometry_types":["Polygon"]"#));
+    assert!(metadata.contains(&format!(r#""bbox":[{xmin},"#)));
+    assert!(metadata.contains(&format!(",{xmax},")));
+}
+
+#[test]
+fn metadata_bbox_across_batches() {
+    let cell = |lng| {
+        LatLng::new(0., lng)
+            .expect("valid coordinate")
+            .to_cell(Resolution::Five)
+    };
+    let ids = |count: u32| -> ArrayRef {
+        Arc::new(UInt32Array::from_iter_values(0..count))
+    };
+    let path = tmp_file("bbox.parquet");
+    let schema = Schema::new(vec![Field::new("id", DataType::UInt32, false)]);
+    let file = File::create(&path).expect("file");
+    let mut writer = GeoParquetWriter::try_new(file, &schema).expect("writer");
+    writer.write(&[cell(179.9)], &[ids(1)]).expect("write");
+    // A failed write doesn't extend the bounding box.
+    assert!(writer.write(&[cell(0.)], &[ids(2)]).is_err());
+    writer.write(&[cell(-179.9)], &[ids(1)]).expect("write");
+    writer.close().expect("close");
+
+    let metadata: serde_json::Value =
+        serde_json::from_str(&geo_metadata(path)).expect("JSON metadata");
+    let bbox = metadata["columns"]["geometry"]["bbox"]
+        .as_array()
+        .expect("bbox")
+        .iter()
+        .map(|value| value.as_f64().expect("number"))
+        .collect::<Vec<_>>();
+
+    // Crosses the antimeridian, instead of going around the world.
+    assert!(bbox[0] > 179. && bbox[2] < -179., "{bbox:?}");
+}
+
+#[test]
+fn invalid_properties() {
+    let schema = Schema::new(vec![Field::new("cell", DataType::UInt32, false)]);
+    assert!(GeoParquetWriter::try_new(Vec::new(), &schema).is_err());
+
+    let schema = Schema::new(vec![Field::new("id", DataType::UInt32, false)]);
+    let mut writer =
+        GeoParquetWriter::try_new(Vec::new(), &schema).expect("writer");
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let ids: ArrayRef = Arc::new(UInt32Array::from(vec![1, 2]));
+    assert!(writer.write(&[index], &[ids]).is_err());
+    assert!(writer.write(&[index], &[]).is_err());
+}
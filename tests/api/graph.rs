@@ -0,0 +1,65 @@
+use h3o::{to_petgraph, CellIndex, Resolution};
+use petgraph::{
+    algo::{connected_components, dijkstra},
+    graph::{DiGraph, UnGraph},
+};
+
+#[test]
+fn undirected() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let mut cells = index.grid_disk::<Vec<_>>(2);
+    cells.push(index); // Duplicates are ignored.
+    let graph: UnGraph<_, _> = to_petgraph(&cells, |_| ());
+
+    assert_eq!(graph.node_count(), 19);
+    // 3 edges per pair of neighbors in a hexagonal lattice, minus the border.
+    assert_eq!(graph.edge_count(), 42);
+    assert_eq!(connected_components(&graph), 1);
+    for edge in graph.raw_edges() {
+        let (a, b) = (graph[edge.source()], graph[edge.target()]);
+        assert!(a.is_neighbor_with(b).expect("same resolution"));
+    }
+}
+
+#[test]
+fn directed() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let cells = index.grid_disk::<Vec<_>>(1);
+    let graph: DiGraph<_, _> = to_petgraph(&cells, |edge| edge);
+
+    assert_eq!(graph.node_count(), 7);
+    assert_eq!(graph.edge_count(), 24);
+    for edge in graph.raw_edges() {
+        let (origin, destination) = edge.weight.cells();
+        assert_eq!(origin, graph[edge.source()]);
+        assert_eq!(destination, graph[edge.target()]);
+    }
+}
+
+#[test]
+fn weighted() {
+    let start = CellIndex::try_from(0x8a1fb46622dffff).expect("start");
+    let cells = start.grid_disk::<Vec<_>>(3);
+    let graph: UnGraph<_, _> = to_petgraph(&cells, |edge| edge.length_m());
+
+    let origin = graph
+        .node_indices()
+        .find(|&node| graph[node] == start)
+        .expect("start node");
+    let distances = dijkstra(&graph, origin, None, |edge| *edge.weight());
+
+    assert_eq!(distances.len(), cells.len());
+    assert!(distances.values().all(|&distance| distance >= 0.));
+}
+
+#[test]
+fn disconnected() {
+    let a = CellIndex::try_from(0x8a1fb46622dffff).expect("a");
+    let b = a.center_child(Resolution::Eleven).expect("child");
+    let far = CellIndex::try_from(0x8a194e699ab7fff).expect("far");
+    let graph: UnGraph<_, _> = to_petgraph(&[a, b, far], |_| ());
+
+    assert_eq!(graph.node_count(), 3);
+    assert_eq!(graph.edge_count(), 0);
+    assert_eq!(connected_components(&graph), 3);
+}
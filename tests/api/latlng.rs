@@ -1,5 +1,8 @@
 use float_eq::assert_float_eq;
-use h3o::{CellIndex, LatLng, Resolution};
+use h3o::{
+    error::ParseLatLngError, CellIndex, DistanceMethod, LatLng, Resolution,
+    EARTH_RADIUS_KM,
+};
 use std::f64::consts::{FRAC_PI_2, PI};
 
 const EPSILON: f64 = 1e-9 * PI / 180.0;
@@ -95,6 +98,81 @@ fn distance_wrapped_longitude() {
     );
 }
 
+#[test]
+fn azimuth_rads() {
+    let origin = LatLng::new(0., 0.).expect("origin");
+    let north = LatLng::new(10., 0.).expect("north");
+    let east = LatLng::new(0., 10.).expect("east");
+    let south = LatLng::new(-10., 0.).expect("south");
+    let west = LatLng::new(0., -10.).expect("west");
+
+    assert_float_eq!(origin.azimuth_rads(north), 0., abs <= EPSILON);
+    assert_float_eq!(origin.azimuth_rads(east), FRAC_PI_2, abs <= EPSILON);
+    assert_float_eq!(origin.azimuth_rads(south), PI, abs <= EPSILON);
+    assert_float_eq!(origin.azimuth_rads(west), 3. * FRAC_PI_2, abs <= EPSILON);
+    assert_eq!(origin.azimuth_rads(origin), 0.);
+    let paris = LatLng::new(48.864716, 2.349014).expect("paris");
+    assert_eq!(paris.azimuth_rads(paris), 0.);
+
+    // Across the antimeridian, heading east.
+    let src = LatLng::new(0., 179.).expect("src");
+    let dst = LatLng::new(0., -179.).expect("dst");
+    assert_float_eq!(src.azimuth_rads(dst), FRAC_PI_2, abs <= EPSILON);
+
+    // Every direction is south from the North Pole, north from the South one.
+    let north_pole = LatLng::new(90., 0.).expect("north pole");
+    let south_pole = LatLng::new(-90., 0.).expect("south pole");
+    for lng in [-135., -10., 0., 45., 180.] {
+        let dst = LatLng::new(10., lng).expect("dst");
+        assert_eq!(north_pole.azimuth_rads(dst), PI, "{lng}");
+        assert_eq!(south_pole.azimuth_rads(dst), 0., "{lng}");
+    }
+    assert_eq!(north_pole.azimuth_rads(north_pole), 0.);
+}
+
+#[test]
+fn offset() {
+    let src = LatLng::new(48.864716, 2.349014).expect("src");
+    for azimuth in [0., 0.5, 2., PI, 4., 6.] {
+        let dst = src.offset(azimuth, 50_000.).expect("dst");
+        assert_float_eq!(src.distance_m(dst), 50_000., abs <= 1e-6);
+        assert_float_eq!(src.azimuth_rads(dst), azimuth, abs <= 1e-9);
+
+        // Negative distances go backward.
+        let back = dst.offset(dst.azimuth_rads(src), -50_000.).expect("back");
+        assert_float_eq!(dst.distance_m(back), 50_000., abs <= 1e-6);
+        assert_float_eq!(src.distance_m(back), 100_000., abs <= 1e-6);
+    }
+    assert_eq!(src.offset(1., 0.), Ok(src));
+}
+
+#[test]
+fn offset_over_pole() {
+    let src = LatLng::new(80., 10.).expect("src");
+    // 20° northward: over the pole.
+    let distance = 20_f64.to_radians() * EARTH_RADIUS_KM * 1000.;
+    let dst = src.offset(0., distance).expect("dst");
+
+    assert_float_eq!(dst.lat(), 80., abs <= 1e-6);
+    assert_float_eq!(dst.lng(), -170., abs <= 1e-6);
+}
+
+#[test]
+fn offset_wrapped_longitude() {
+    let src = LatLng::new(0., 179.9).expect("src");
+    let dst = src.offset(FRAC_PI_2, 100_000.).expect("dst");
+
+    assert!(dst.lng() < -179., "{dst}");
+}
+
+#[test]
+fn offset_invalid() {
+    let src = LatLng::new(0., 0.).expect("src");
+
+    assert!(src.offset(f64::NAN, 1.).is_err());
+    assert!(src.offset(0., f64::INFINITY).is_err());
+}
+
 #[test]
 fn to_cell_icosahedron_center() {
     let ll = LatLng::new(28.173218757257807, 23.03222744086644).expect("ll");
@@ -127,3 +205,244 @@ fn latlng_from_typed_floats() {
 
     let _ = LatLng::from((lat, lng));
 }
+
+#[test]
+fn from_degrees_slice() {
+    let lats = [48.864716, 31.224361, -33.8688];
+    let lngs = [2.349014, 121.469170, 151.2093];
+    let coords = LatLng::from_degrees_slice(&lats, &lngs).expect("coords");
+    let expected = lats
+        .iter()
+        .zip(&lngs)
+        .map(|(&lat, &lng)| LatLng::new(lat, lng).expect("coord"))
+        .collect::<Vec<_>>();
+
+    assert_eq!(coords, expected);
+    assert_eq!(LatLng::from_degrees_slice(&[], &[]), Ok(Vec::new()));
+}
+
+#[test]
+fn from_degrees_slice_invalid() {
+    let lats = [0., 1., f64::INFINITY, f64::NAN];
+    let lngs = [0., f64::NAN, 0., 0.];
+    let result = LatLng::from_degrees_slice(&lats, &lngs);
+
+    assert_eq!(result.map_err(|(i, _)| i), Err(1));
+}
+
+#[test]
+fn from_degrees_slice_length_mismatch() {
+    let result = LatLng::from_degrees_slice(&[0., 1., 2.], &[0., 1.]);
+    assert_eq!(result.map_err(|(i, _)| i), Err(2));
+
+    // Invalid values are reported before the missing ones.
+    let result = LatLng::from_degrees_slice(&[f64::NAN, 0.], &[0.]);
+    assert_eq!(result.map_err(|(i, _)| i), Err(0));
+}
+
+#[test]
+fn to_cells() {
+    let resolutions = Resolution::range(Resolution::Zero, Resolution::Fifteen)
+        .collect::<Vec<_>>();
+    for lat in (-90..=90).step_by(5) {
+        for lng in (-180..=180).step_by(5) {
+            let ll =
+                LatLng::new(f64::from(lat) + 0.123, f64::from(lng) + 0.456)
+                    .expect("ll");
+            let expected = resolutions
+                .iter()
+                .map(|&res| ll.to_cell(res))
+                .collect::<Vec<_>>();
+
+            let cells = ll.to_cells(resolutions.iter().copied());
+            assert_eq!(cells.collect::<Vec<_>>(), expected, "{ll}");
+        }
+    }
+}
+
+#[test]
+fn to_vec3() {
+    let north = LatLng::new(90., 0.).expect("north").to_vec3();
+    assert_float_eq!(north.z, 1., abs <= f64::EPSILON);
+
+    let east = LatLng::new(0., 90.).expect("east").to_vec3();
+    assert_float_eq!(east.x, 0., abs <= f64::EPSILON);
+    assert_float_eq!(east.y, 1., abs <= f64::EPSILON);
+
+    let ll = LatLng::new(48.864716, 2.349014).expect("ll");
+    let v = ll.to_vec3();
+    assert_float_eq!(v.z.asin(), ll.lat_radians(), abs <= EPSILON);
+    assert_float_eq!(v.y.atan2(v.x), ll.lng_radians(), abs <= EPSILON);
+}
+
+#[test]
+fn to_cells_batch() {
+    // Large enough to be split across threads with `rayon`.
+    let points = (-90..=90)
+        .flat_map(|lat| {
+            (-180..180).step_by(6).map(move |lng| {
+                (f64::from(lat) + 0.123, f64::from(lng) + 0.456)
+            })
+        })
+        .collect::<Vec<_>>();
+    let sentinel = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let mut cells = vec![sentinel];
+    LatLng::to_cells_batch(&points, Resolution::Nine, &mut cells)
+        .expect("valid points");
+    let expected = points.iter().map(|&(lat, lng)| {
+        LatLng::new(lat, lng).expect("ll").to_cell(Resolution::Nine)
+    });
+
+    assert_eq!(cells.len(), points.len() + 1);
+    assert_eq!(cells[0], sentinel, "appended");
+    assert!(cells[1..].iter().copied().eq(expected));
+}
+
+#[test]
+fn to_cells_batch_invalid() {
+    let points = [(0., 0.), (1., 1.), (f64::NAN, 0.), (0., f64::INFINITY)];
+    let mut cells = Vec::new();
+    let result = LatLng::to_cells_batch(&points, Resolution::Five, &mut cells);
+
+    assert_eq!(result.map_err(|(i, _)| i), Err(2));
+    assert!(cells.is_empty(), "left untouched");
+}
+
+#[test]
+fn to_cells_face_center() {
+    // Icosahedron face center, where the projection degenerates.
+    let ll = LatLng::new(28.173218757257807, 23.03222744086644).expect("ll");
+    let cells = ll.to_cells([Resolution::Zero, Resolution::Nine]);
+
+    assert_eq!(
+        cells.collect::<Vec<_>>(),
+        vec![ll.to_cell(Resolution::Zero), ll.to_cell(Resolution::Nine)]
+    );
+}
+
+#[test]
+fn display_decimal() {
+    let ll = LatLng::new(48.864716, 2.349014).expect("ll");
+    let text = ll.display_decimal().to_string();
+
+    assert_eq!(text, "48.864716,2.349014");
+    assert_eq!(text.parse::<LatLng>(), Ok(ll));
+}
+
+#[test]
+fn display_dms() {
+    let ll = LatLng::new(-33.8688, -151.2093).expect("ll");
+    let text = ll.display_dms().to_string();
+
+    assert_eq!(text, r#"33°52'7.680"S, 151°12'33.480"W"#);
+    assert_eq!(text.parse::<LatLng>(), Ok(ll));
+}
+
+#[test]
+fn display_dms_rounding() {
+    // Seconds rounding up must carry over minutes and degrees.
+    let ll = LatLng::new(10.9999999, 0.).expect("ll");
+
+    assert_eq!(ll.display_dms().to_string(), r#"11°0'0.000"N, 0°0'0.000"E"#);
+}
+
+#[test]
+fn parse() {
+    let expected = LatLng::new(48.864716, -2.349014).expect("ll");
+    for text in [
+        "48.864716,-2.349014",
+        " 48.864716 , -2.349014 ",
+        "+48.864716,-2.349014",
+        "N48.864716,W2.349014",
+        "48.864716 n, 2.349014 w",
+        "48.864716°N, 2.349014°W",
+        r#"48°51'52.9776"N, 2°20'56.4504"W"#,
+        "48° 51′ 52.9776″ N, 2° 20′ 56.4504″ W",
+        "48°51.88296'N, -2°20.94084'",
+    ] {
+        assert_eq!(text.parse::<LatLng>(), Ok(expected), "{text}");
+    }
+}
+
+#[test]
+fn parse_invalid() {
+    for (text, error) in [
+        ("48.86", ParseLatLngError::Separator),
+        ("48.86,2.34,0", ParseLatLngError::Separator),
+        ("48.86;2.34", ParseLatLngError::Separator),
+        ("abc,2.34", ParseLatLngError::Number),
+        ("NaN,2.34", ParseLatLngError::Number),
+        ("inf,2.34", ParseLatLngError::Number),
+        ("48.5°30',2.34", ParseLatLngError::Number),
+        ("48°30.5'10\",2.34", ParseLatLngError::Number),
+        ("48°30'10,2.34", ParseLatLngError::Number),
+        ("48.86E,2.34", ParseLatLngError::Hemisphere),
+        ("48.86,2.34N", ParseLatLngError::Hemisphere),
+        ("-48.86S,2.34", ParseLatLngError::Hemisphere),
+        ("91,2.34", ParseLatLngError::OutOfRange),
+        ("48.86,180.5", ParseLatLngError::OutOfRange),
+        ("48°60',2.34", ParseLatLngError::OutOfRange),
+        ("48°30'60\",2.34", ParseLatLngError::OutOfRange),
+    ] {
+        assert_eq!(text.parse::<LatLng>(), Err(error), "{text}");
+    }
+}
+
+#[test]
+fn distance_vincenty() {
+    // Reference geodesic from Vincenty's paper (Flinders Peak to Buninyong).
+    let src: LatLng =
+        r#"37°57'3.72030"S, 144°25'29.52440"E"#.parse().expect("src");
+    let dst: LatLng =
+        r#"37°39'10.15610"S, 143°55'35.38390"E"#.parse().expect("dst");
+    let distance = src.distance_m_with(dst, DistanceMethod::Vincenty);
+
+    assert_float_eq!(distance, 54_972.271, abs <= 1e-3);
+    assert_float_eq!(
+        src.distance_km_with(dst, DistanceMethod::Vincenty),
+        distance / 1000.,
+        r2nd <= f64::EPSILON
+    );
+}
+
+#[test]
+fn distance_vincenty_edge_cases() {
+    let ll = LatLng::new(48.864716, 2.349014).expect("ll");
+    assert_eq!(ll.distance_m_with(ll, DistanceMethod::Vincenty), 0.);
+
+    // Along the equator.
+    let src = LatLng::new(0., 0.).expect("src");
+    let dst = LatLng::new(0., 1.).expect("dst");
+    assert_float_eq!(
+        src.distance_m_with(dst, DistanceMethod::Vincenty),
+        111_319.491,
+        abs <= 1e-3
+    );
+
+    // Nearly antipodal: doesn't converge, fallback on the haversine.
+    let dst = LatLng::new(0.5, 179.7).expect("dst");
+    assert_eq!(
+        src.distance_m_with(dst, DistanceMethod::Vincenty),
+        src.distance_m(dst)
+    );
+}
+
+#[test]
+fn distance_law_of_cosines() {
+    let src = LatLng::new(48.864716, 2.349014).expect("src");
+    let dst = LatLng::new(31.224361, 121.469170).expect("dst");
+
+    assert_eq!(
+        src.distance_m_with(dst, DistanceMethod::Haversine),
+        src.distance_m(dst)
+    );
+    assert_float_eq!(
+        src.distance_m_with(dst, DistanceMethod::SphericalLawOfCosines),
+        src.distance_m(dst),
+        r2nd <= 1e-9
+    );
+    assert_eq!(
+        src.distance_m_with(src, DistanceMethod::SphericalLawOfCosines),
+        0.
+    );
+}
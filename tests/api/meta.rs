@@ -0,0 +1,54 @@
+use h3o::{coverage_bbox, CellIndex, DatasetMeta, Resolution};
+
+fn cells() -> Vec<CellIndex> {
+    let center = CellIndex::try_from(0x8a1fb46622dffff).expect("center");
+    let mut cells = center.grid_disk::<Vec<_>>(5);
+    CellIndex::compact(&mut cells).expect("compact");
+    cells
+}
+
+#[test]
+fn from_cells() {
+    let cells = cells();
+    let meta = DatasetMeta::from_cells(cells.iter().copied()).expect("meta");
+
+    assert_eq!(meta.count, cells.len() as u64);
+    assert_eq!(meta.min_resolution, Resolution::Nine);
+    assert_eq!(meta.max_resolution, Resolution::Ten);
+    assert_eq!(
+        Some(meta.bbox),
+        coverage_bbox(cells.iter().copied()),
+        "same bbox"
+    );
+}
+
+#[test]
+fn from_cells_empty() {
+    assert_eq!(DatasetMeta::from_cells(std::iter::empty()), None);
+}
+
+#[test]
+fn order_independent() {
+    let mut cells = cells();
+    let meta = DatasetMeta::from_cells(cells.iter().copied()).expect("meta");
+
+    cells.reverse();
+    assert!(meta.matches(cells.iter().copied()));
+    cells.sort_unstable();
+    assert!(meta.matches(cells.iter().copied()));
+}
+
+#[test]
+fn mismatch() {
+    let cells = cells();
+    let meta = DatasetMeta::from_cells(cells.iter().copied()).expect("meta");
+
+    // Missing cell.
+    assert!(!meta.matches(cells[1..].iter().copied()));
+    // Altered cell.
+    let mut altered = cells.clone();
+    altered[0] = cells[0].center_child(Resolution::Eleven).expect("child");
+    assert!(!meta.matches(altered));
+    // Empty dataset.
+    assert!(!meta.matches(std::iter::empty()));
+}
@@ -1,20 +1,45 @@
+mod aggregate;
+#[cfg(feature = "arrow")]
+mod arrow;
 mod avg_edge_len;
 mod base_cell;
+mod bbox;
+mod bits;
 mod boundary;
 mod cell_index;
+mod cell_range;
+mod centroid_index;
+mod cluster;
+mod delta;
 mod directed_edge_index;
 mod direction;
 mod edge;
 mod face;
 mod face_set;
+mod geodesic;
+#[cfg(feature = "geojson")]
+mod geojson;
 #[cfg(feature = "geo")]
 mod geom;
+#[cfg(feature = "geoparquet")]
+mod geoparquet;
+#[cfg(feature = "petgraph")]
+mod graph;
 mod index_mode;
 mod latlng;
 mod localij;
+mod meta;
+mod path;
+mod perimeter;
+mod raster;
 mod resolution;
+#[cfg(feature = "serde")]
+mod serde_hex;
+mod simplify;
+mod treemap;
 mod vertex;
 mod vertex_index;
+mod weights;
 
 #[test]
 fn max_grid_disk_size_overflow() {
@@ -0,0 +1,77 @@
+use h3o::{
+    follow_directions, path_directions, CellIndex, Direction, Resolution,
+};
+
+#[test]
+fn round_trip() {
+    let start = CellIndex::try_from(0x8a1fb46622dffff).expect("start");
+    let end = start.grid_disk::<Vec<_>>(20).pop().expect("end");
+    let path = start
+        .grid_path_cells(end)
+        .expect("path")
+        .collect::<Result<Vec<_>, _>>()
+        .expect("path cells");
+
+    let directions = path_directions(&path).expect("directions");
+    assert_eq!(directions.len(), path.len() - 1);
+    assert!(directions
+        .iter()
+        .all(|&direction| direction != Direction::Center));
+    assert_eq!(follow_directions(start, directions), Some(path));
+}
+
+#[test]
+fn round_trip_pentagon() {
+    let pentagon = CellIndex::try_from(0x81083ffffffffff).expect("pentagon");
+    let mut ring = pentagon.grid_disk::<Vec<_>>(1);
+    ring.retain(|&cell| cell != pentagon);
+
+    // Walk around the pentagon, and then through it.
+    let mut path = vec![ring.remove(0)];
+    while !ring.is_empty() {
+        let last = *path.last().expect("last");
+        let next = ring
+            .iter()
+            .position(|&cell| last.is_neighbor_with(cell).expect("same res"))
+            .expect("adjacent cell");
+        path.push(ring.remove(next));
+    }
+    path.push(pentagon);
+    path.push(path[2]);
+
+    let directions = path_directions(&path).expect("directions");
+    assert_eq!(follow_directions(path[0], directions), Some(path));
+}
+
+#[test]
+fn invalid_path() {
+    let start = CellIndex::try_from(0x8a1fb46622dffff).expect("start");
+    let far = start.grid_disk::<Vec<_>>(2).pop().expect("far");
+
+    assert_eq!(path_directions(&[start, far]), None);
+    assert_eq!(path_directions(&[start, start]), None);
+    let child = start.center_child(Resolution::Eleven).expect("child");
+    assert_eq!(path_directions(&[start, child]), None);
+}
+
+#[test]
+fn trivial_path() {
+    let start = CellIndex::try_from(0x8a1fb46622dffff).expect("start");
+
+    assert_eq!(path_directions(&[]), Some(Vec::new()));
+    assert_eq!(path_directions(&[start]), Some(Vec::new()));
+    assert_eq!(follow_directions(start, []), Some(vec![start]));
+}
+
+#[test]
+fn invalid_directions() {
+    let start = CellIndex::try_from(0x8a1fb46622dffff).expect("start");
+    let pentagon = CellIndex::try_from(0x81083ffffffffff).expect("pentagon");
+
+    assert_eq!(
+        follow_directions(start, [Direction::I, Direction::Center]),
+        None
+    );
+    assert_eq!(follow_directions(pentagon, [Direction::K]), None);
+    assert!(follow_directions(pentagon, [Direction::J]).is_some());
+}
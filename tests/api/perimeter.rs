@@ -0,0 +1,56 @@
+use float_eq::assert_float_eq;
+use h3o::{error::ResolutionMismatch, shared_perimeter_m, CellIndex};
+
+#[test]
+fn surrounded() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let ring = cell
+        .grid_ring_fast(1)
+        .collect::<Option<Vec<_>>>()
+        .expect("ring");
+    let perimeter = cell.edges().map(|edge| edge.length_m()).sum::<f64>();
+
+    let result = shared_perimeter_m(&[cell], &ring).expect("perimeter");
+    assert_float_eq!(result, perimeter, r2nd <= 1e-12);
+}
+
+#[test]
+fn symmetric() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let disk = cell.grid_disk::<Vec<_>>(2);
+    let (a, b) = disk.split_at(7);
+
+    let ab = shared_perimeter_m(a, b).expect("a/b");
+    let ba = shared_perimeter_m(b, a).expect("b/a");
+    assert_float_eq!(ab, ba, r2nd <= 1e-9);
+    // Only the first ring touches the second one.
+    assert_float_eq!(
+        shared_perimeter_m(&a[1..], b).expect("ring/b"),
+        ab,
+        r2nd <= 1e-12
+    );
+}
+
+#[test]
+fn disjoint() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let far = cell
+        .grid_ring_fast(3)
+        .collect::<Option<Vec<_>>>()
+        .expect("ring");
+
+    assert_eq!(shared_perimeter_m(&[cell], &far), Ok(0.));
+    assert_eq!(shared_perimeter_m(&[], &far), Ok(0.));
+    assert_eq!(shared_perimeter_m(&[], &[]), Ok(0.));
+}
+
+#[test]
+fn resolution_mismatch() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let parent = cell.parent(h3o::Resolution::Five).expect("parent");
+
+    assert_eq!(
+        shared_perimeter_m(&[cell], &[parent]),
+        Err(ResolutionMismatch)
+    );
+}
@@ -0,0 +1,239 @@
+use float_eq::assert_float_eq;
+use h3o::{
+    cells_from_raster, cells_in_bbox, rasterize, LatLng, RasterProjection,
+    Resolution, SampleAggregation,
+};
+use std::collections::HashMap;
+
+#[test]
+fn uniform() {
+    let ll = LatLng::new(48.864716, 2.349014).expect("ll");
+    let values = HashMap::from([(ll.to_cell(Resolution::Four), 1.)]);
+    let min = LatLng::new(48.85, 2.33).expect("min");
+    let max = LatLng::new(48.88, 2.37).expect("max");
+    let pixels =
+        rasterize(&values, min, max, 8, 4, RasterProjection::Equirectangular);
+
+    assert_eq!(pixels, vec![Some(1.); 32]);
+}
+
+#[test]
+fn partial_coverage_edges() {
+    let ll = LatLng::new(48.864716, 2.349014).expect("ll");
+    let values = HashMap::from([(ll.to_cell(Resolution::Five), 42.)]);
+    let min = LatLng::new(48.8, 2.3).expect("min");
+    let max = LatLng::new(48.9, 2.4).expect("max");
+    let pixels =
+        rasterize(&values, min, max, 16, 16, RasterProjection::WebMercator);
+    let pixel = |row: usize, col: usize| pixels[row * 16 + col];
+
+    assert_eq!(pixel(0, 0), None, "north-west corner");
+    assert_eq!(pixel(0, 15), Some(42.), "north-east corner");
+    assert_eq!(pixel(15, 0), Some(42.), "south-west corner");
+    assert_eq!(pixel(15, 15), Some(42.), "south-east corner");
+    assert_eq!(pixels.iter().filter(|pixel| pixel.is_none()).count(), 20);
+}
+
+#[test]
+fn empty_pixels() {
+    let min = LatLng::new(10., 10.).expect("min");
+    let max = LatLng::new(11., 11.).expect("max");
+    let pixels = rasterize(
+        &HashMap::new(),
+        min,
+        max,
+        3,
+        2,
+        RasterProjection::Equirectangular,
+    );
+
+    assert_eq!(pixels, vec![None; 6]);
+}
+
+#[test]
+fn finest_resolution_wins() {
+    let ll = LatLng::new(48.864716, 2.349014).expect("ll");
+    let coarse = ll.to_cell(Resolution::Three);
+    let fine = ll.to_cell(Resolution::Nine);
+    let values = HashMap::from([(coarse, 1.), (fine, 2.)]);
+    let center = LatLng::from(fine);
+    let pixels = rasterize(
+        &values,
+        center,
+        center,
+        1,
+        1,
+        RasterProjection::Equirectangular,
+    );
+
+    assert_eq!(pixels, vec![Some(2.)]);
+}
+
+#[test]
+fn row_major_from_north_west() {
+    let cell =
+        |lat, lng| LatLng::new(lat, lng).expect("ll").to_cell(Resolution::Nine);
+    let values = HashMap::from([
+        (cell(0.75, -0.5), 1.),
+        (cell(0.75, 0.5), 2.),
+        (cell(0.25, -0.5), 3.),
+        (cell(0.25, 0.5), 4.),
+    ]);
+    let min = LatLng::new(0., -1.).expect("min");
+    let max = LatLng::new(1., 1.).expect("max");
+    let pixels =
+        rasterize(&values, min, max, 2, 2, RasterProjection::Equirectangular);
+
+    assert_eq!(pixels, vec![Some(1.), Some(2.), Some(3.), Some(4.)]);
+}
+
+#[test]
+fn antimeridian() {
+    let west = LatLng::new(0., 179.)
+        .expect("west")
+        .to_cell(Resolution::Two);
+    let east = LatLng::new(0., -179.)
+        .expect("east")
+        .to_cell(Resolution::Two);
+    let values = HashMap::from([(west, 1.), (east, 2.)]);
+    let min = LatLng::new(-0.5, 178.5).expect("min");
+    let max = LatLng::new(0.5, -178.5).expect("max");
+    let pixels =
+        rasterize(&values, min, max, 2, 1, RasterProjection::Equirectangular);
+
+    assert_eq!(pixels, vec![Some(1.), Some(2.)]);
+}
+
+#[test]
+fn web_mercator() {
+    let cell =
+        |lat| LatLng::new(lat, 0.).expect("ll").to_cell(Resolution::Nine);
+    // Centers of the rows, in Web Mercator.
+    let values =
+        HashMap::from([(cell(71.722_718), 1.), (cell(32.919_907), 2.)]);
+    let min = LatLng::new(0., -0.001).expect("min");
+    let max = LatLng::new(80., 0.001).expect("max");
+
+    let pixels =
+        rasterize(&values, min, max, 1, 2, RasterProjection::WebMercator);
+    assert_eq!(pixels, vec![Some(1.), Some(2.)]);
+
+    // Rows are evenly spaced in latitude otherwise.
+    let pixels =
+        rasterize(&values, min, max, 1, 2, RasterProjection::Equirectangular);
+    assert_eq!(pixels, vec![None, None]);
+}
+
+#[test]
+fn from_raster_mean() {
+    let min = LatLng::new(48.81, 2.22).expect("min");
+    let max = LatLng::new(48.90, 2.47).expect("max");
+    let values: HashMap<_, _> = cells_from_raster(
+        Resolution::Seven,
+        min,
+        max,
+        |_| Some(3.),
+        SampleAggregation::Mean,
+    );
+
+    let mut cells = values.keys().copied().collect::<Vec<_>>();
+    let mut expected = cells_in_bbox(min, max, Resolution::Seven);
+    cells.sort_unstable();
+    expected.sort_unstable();
+    assert_eq!(cells, expected);
+    for value in values.values() {
+        assert_float_eq!(*value, 3., abs <= 1e-12);
+    }
+}
+
+#[test]
+fn from_raster_sum() {
+    let ll = LatLng::new(48.864716, 2.349014).expect("ll");
+    let cell = ll.to_cell(Resolution::Seven);
+    let center = LatLng::from(cell);
+    let values: HashMap<_, _> = cells_from_raster(
+        Resolution::Seven,
+        center,
+        center,
+        |_| Some(1.),
+        SampleAggregation::Sum,
+    );
+
+    // One sample per grandchild.
+    assert_eq!(values, HashMap::from([(cell, 49.)]));
+}
+
+#[test]
+fn from_raster_majority() {
+    let ll = LatLng::new(48.864716, 2.349014).expect("ll");
+    let cell = ll.to_cell(Resolution::Seven);
+    let center = LatLng::from(cell);
+    // Only the central child is class 2, the other six are class 1.
+    let central = center.to_cell(Resolution::Eight);
+    let values: HashMap<_, _> = cells_from_raster(
+        Resolution::Seven,
+        center,
+        center,
+        |ll| {
+            Some(if ll.to_cell(Resolution::Eight) == central {
+                2.
+            } else {
+                1.
+            })
+        },
+        SampleAggregation::Majority,
+    );
+
+    assert_eq!(values, HashMap::from([(cell, 1.)]));
+}
+
+#[test]
+fn from_raster_nodata() {
+    let min = LatLng::new(-1., -1.).expect("min");
+    let max = LatLng::new(1., 1.).expect("max");
+    // Data only in the northern hemisphere.
+    let values: HashMap<_, _> = cells_from_raster(
+        Resolution::Five,
+        min,
+        max,
+        |ll| (ll.lat() > 0.).then_some(1.),
+        SampleAggregation::Mean,
+    );
+
+    assert!(!values.is_empty());
+    assert!(values
+        .keys()
+        .all(|&cell| { cell.boundary().iter().any(|ll| ll.lat() > 0.) }));
+}
+
+#[test]
+fn from_raster_roundtrip() {
+    let ll = LatLng::new(48.864716, 2.349014).expect("ll");
+    let cell = ll.to_cell(Resolution::Five);
+    let center = LatLng::from(cell);
+    let values = HashMap::from([(cell, 42.)]);
+    let min = LatLng::new(center.lat() - 1., center.lng() - 1.).expect("min");
+    let max = LatLng::new(center.lat() + 1., center.lng() + 1.).expect("max");
+    let pixels = rasterize(
+        &values,
+        min,
+        max,
+        512,
+        512,
+        RasterProjection::Equirectangular,
+    );
+
+    let sampler = |ll: LatLng| {
+        let u = (ll.lng() - min.lng()) / 2. * 512.;
+        let v = (max.lat() - ll.lat()) / 2. * 512.;
+        pixels[v as usize * 512 + u as usize]
+    };
+    let result: HashMap<_, _> = cells_from_raster(
+        Resolution::Five,
+        center,
+        center,
+        sampler,
+        SampleAggregation::Majority,
+    );
+    assert_eq!(result, values);
+}
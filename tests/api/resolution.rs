@@ -34,3 +34,19 @@ fn display() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn pentagon_disks() {
+    let disks = Resolution::Five.pentagon_disks(2).collect::<Vec<_>>();
+
+    assert_eq!(disks.len(), usize::from(Resolution::pentagon_count()));
+    for (pentagon, disk) in disks {
+        assert!(pentagon.is_pentagon(), "{pentagon}");
+        assert_eq!(disk.first().copied(), Some(pentagon));
+        // 1 + 5 + 10 cells.
+        assert_eq!(disk.len(), 16, "{pentagon}");
+        assert!(disk.iter().all(|cell| {
+            pentagon.grid_distance(*cell).expect("distance") <= 2
+        }));
+    }
+}
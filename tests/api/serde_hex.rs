@@ -0,0 +1,80 @@
+use h3o::{CellIndex, DirectedEdgeIndex, VertexIndex};
+use serde::{Deserialize, Serialize};
+use serde_test::{assert_de_tokens_error, assert_tokens, Configure, Token};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+struct Cell(#[serde(with = "h3o::serde_hex")] CellIndex);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+struct Edge(#[serde(with = "h3o::serde_hex")] DirectedEdgeIndex);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+struct Vertex(#[serde(with = "h3o::serde_hex")] VertexIndex);
+
+#[test]
+fn human_readable() {
+    let cell = Cell(CellIndex::try_from(0x8a1fb46622dffff).expect("cell"));
+    assert_tokens(&cell.readable(), &[Token::Str("8a1fb46622dffff")]);
+
+    let edge =
+        Edge(DirectedEdgeIndex::try_from(0x13a194e699ab7fff).expect("edge"));
+    assert_tokens(&edge.readable(), &[Token::Str("13a194e699ab7fff")]);
+
+    let vertex =
+        Vertex(VertexIndex::try_from(0x2222597fffffffff).expect("vertex"));
+    assert_tokens(&vertex.readable(), &[Token::Str("2222597fffffffff")]);
+}
+
+#[test]
+fn compact() {
+    let cell = Cell(CellIndex::try_from(0x8a1fb46622dffff).expect("cell"));
+    assert_tokens(&cell.compact(), &[Token::U64(0x8a1fb46622dffff)]);
+}
+
+#[test]
+fn human_readable_accepts_integers() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let json = format!("{}", u64::from(cell));
+
+    let result = serde_json::from_str::<Cell>(&json).expect("integer");
+    assert_eq!(result, Cell(cell));
+}
+
+#[test]
+fn json_roundtrip() {
+    let cells = CellIndex::try_from(0x8a1fb46622dffff)
+        .expect("cell")
+        .children(h3o::Resolution::Eleven)
+        .map(Cell)
+        .collect::<Vec<_>>();
+
+    let json = serde_json::to_string(&cells).expect("serialize");
+    assert!(json.starts_with(r#"["8b1fb46622d8fff","#), "{json}");
+    let result = serde_json::from_str::<Vec<Cell>>(&json).expect("deserialize");
+    assert_eq!(result, cells);
+}
+
+#[test]
+fn invalid() {
+    assert_de_tokens_error::<serde_test::Readable<Cell>>(
+        &[Token::Str("8a1fb46622dfffz")],
+        "invalid cell index (got None): invalid 64-bit hex number",
+    );
+    assert_de_tokens_error::<serde_test::Compact<Cell>>(
+        &[Token::U64(0)],
+        "invalid cell index (got Some(0)): invalid index mode",
+    );
+    assert_de_tokens_error::<serde_test::Readable<Cell>>(
+        &[Token::I64(-1)],
+        "invalid value: integer `-1`, expected an H3 index, as an \
+         hexadecimal string or integer",
+    );
+    assert_de_tokens_error::<serde_test::Readable<Cell>>(
+        &[Token::Bool(true)],
+        "invalid type: boolean `true`, expected an H3 index, as an \
+         hexadecimal string or integer",
+    );
+}
@@ -0,0 +1,88 @@
+use h3o::{error::LocalIjError, simplify_cell_path, CellIndex, Resolution};
+
+fn grid_path(start: CellIndex, end: CellIndex) -> Vec<CellIndex> {
+    start
+        .grid_path_cells(end)
+        .expect("path")
+        .collect::<Result<Vec<_>, _>>()
+        .expect("path cells")
+}
+
+#[test]
+fn short_path() {
+    let start = CellIndex::try_from(0x8a1fb46622dffff).expect("start");
+    let path = grid_path(start, start.grid_disk::<Vec<_>>(1)[1]);
+
+    assert_eq!(simplify_cell_path(&[], 0), Ok(Vec::new()));
+    assert_eq!(simplify_cell_path(&path, 0), Ok(path));
+}
+
+#[test]
+fn straight_line() {
+    let start = CellIndex::try_from(0x8a1fb46622dffff).expect("start");
+    let end = CellIndex::try_from(0x8a1fb4644937fff).expect("end");
+    let path = grid_path(start, end);
+
+    let result = simplify_cell_path(&path, 0).expect("simplified");
+    assert_eq!(result, vec![start, end]);
+}
+
+#[test]
+fn detour() {
+    let start = CellIndex::try_from(0x8a1fb46622dffff).expect("start");
+    let end = CellIndex::try_from(0x8a1fb4644937fff).expect("end");
+    let detour = start.grid_move(-4, 4).expect("detour");
+    let mut path = grid_path(start, detour);
+    path.extend(grid_path(detour, end).into_iter().skip(1));
+    let distance = path
+        .iter()
+        .filter_map(|&cell| {
+            grid_path(start, end)
+                .into_iter()
+                .map(|other| cell.grid_distance(other).expect("distance"))
+                .min()
+        })
+        .max()
+        .and_then(|distance| u32::try_from(distance).ok())
+        .expect("distance");
+
+    // Within tolerance, the detour is flattened.
+    let result = simplify_cell_path(&path, distance).expect("simplified");
+    assert_eq!(result, vec![start, end]);
+
+    // Otherwise it's preserved.
+    let result = simplify_cell_path(&path, distance - 1).expect("simplified");
+    assert_eq!(result, vec![start, detour, end]);
+}
+
+#[test]
+fn keeps_order_and_endpoints() {
+    let start = CellIndex::try_from(0x8a1fb46622dffff).expect("start");
+    let path = start
+        .grid_rings(6)
+        .last()
+        .expect("ring")
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    let result = simplify_cell_path(&path, 1).expect("simplified");
+    assert_eq!(result.first(), path.first());
+    assert_eq!(result.last(), path.last());
+    let positions = result
+        .iter()
+        .map(|cell| path.iter().position(|other| other == cell))
+        .collect::<Option<Vec<_>>>()
+        .expect("subset of the path");
+    assert!(positions.is_sorted());
+}
+
+#[test]
+fn resolution_mismatch() {
+    let start = CellIndex::try_from(0x8a1fb46622dffff).expect("start");
+    let parent = start.parent(Resolution::Nine).expect("parent");
+
+    assert_eq!(
+        simplify_cell_path(&[start, start, parent], 0),
+        Err(LocalIjError::ResolutionMismatch)
+    );
+}
@@ -0,0 +1,238 @@
+use h3o::{CellIndex, CellTreemap, Resolution};
+use std::collections::BTreeSet;
+
+fn disk(k: u32) -> Vec<CellIndex> {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    index.grid_disk::<Vec<_>>(k)
+}
+
+#[test]
+fn contains() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let treemap = CellTreemap::from_iter([index]);
+
+    assert!(treemap.contains(index));
+    for resolution in Resolution::range(Resolution::Eleven, Resolution::Fifteen)
+    {
+        let child = index.center_child(resolution).expect("child");
+        assert!(treemap.contains(child), "{child}");
+    }
+    for resolution in Resolution::range(Resolution::Zero, Resolution::Nine) {
+        let parent = index.parent(resolution).expect("parent");
+        assert!(!treemap.contains(parent), "{parent}");
+    }
+    let neighbor = CellIndex::try_from(0x8a1fb46622d7fff).expect("neighbor");
+    assert!(!treemap.contains(neighbor));
+    assert!(!CellTreemap::new().contains(index));
+}
+
+#[test]
+fn compacted() {
+    let mut cells = disk(20);
+    let mut expected = cells.clone();
+    CellIndex::compact(&mut expected).expect("compact");
+    expected.sort_unstable();
+    // Insertion order doesn't matter.
+    cells.reverse();
+
+    let treemap = cells.into_iter().collect::<CellTreemap>();
+    assert_eq!(treemap.iter().collect::<Vec<_>>(), expected);
+    assert_eq!(treemap.len(), expected.len());
+}
+
+#[test]
+fn compacted_pentagon() {
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+    let treemap = pentagon.children(Resolution::Two).collect::<CellTreemap>();
+
+    assert_eq!(treemap.iter().collect::<Vec<_>>(), vec![pentagon]);
+}
+
+#[test]
+fn insert_ancestor() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let parent = index.parent(Resolution::Seven).expect("parent");
+    let mut treemap = parent
+        .children(Resolution::Ten)
+        .step_by(2)
+        .collect::<CellTreemap>();
+    assert!(treemap.len() > 1);
+
+    treemap.insert(parent);
+    assert_eq!(treemap.iter().collect::<Vec<_>>(), vec![parent]);
+    // Descendants of a cell are already in the set.
+    treemap.insert(index);
+    assert_eq!(treemap.len(), 1);
+}
+
+#[test]
+fn intersection() {
+    let lhs = disk(10).into_iter().collect::<CellTreemap>();
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let far = index.grid_disk::<Vec<_>>(15).pop().expect("far cell");
+    let rhs = far
+        .grid_disk::<Vec<_>>(10)
+        .into_iter()
+        .map(|cell| cell.parent(Resolution::Nine).expect("parent"))
+        .collect::<CellTreemap>();
+
+    let expected = CellIndex::uncompact(lhs.iter(), Resolution::Ten)
+        .filter(|&cell| rhs.contains(cell))
+        .collect::<BTreeSet<_>>();
+    let result = lhs.intersection(&rhs);
+    assert!(!expected.is_empty());
+    assert_eq!(
+        CellIndex::uncompact(result.iter(), Resolution::Ten)
+            .collect::<BTreeSet<_>>(),
+        expected
+    );
+    assert_eq!(rhs.intersection(&lhs), result);
+    assert!(lhs.intersection(&CellTreemap::new()).is_empty());
+}
+
+#[test]
+fn covering_ancestor() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let treemap = disk(1).into_iter().collect::<CellTreemap>();
+
+    assert_eq!(treemap.covering_ancestor(index), Some(index));
+    for resolution in Resolution::range(Resolution::Eleven, Resolution::Fifteen)
+    {
+        let child = index.center_child(resolution).expect("child");
+        assert_eq!(treemap.covering_ancestor(child), Some(index));
+    }
+    let parent = index.parent(Resolution::Nine).expect("parent");
+    assert_eq!(treemap.covering_ancestor(parent), None);
+    let far = index.grid_disk::<Vec<_>>(5).pop().expect("far cell");
+    assert_eq!(treemap.covering_ancestor(far), None);
+
+    // Base cells are covering ancestors too.
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+    let treemap = CellTreemap::from_iter([pentagon]);
+    let child = pentagon.center_child(Resolution::Fifteen).expect("child");
+    assert_eq!(treemap.covering_ancestor(child), Some(pentagon));
+    assert_eq!(treemap.covering_ancestor(pentagon), Some(pentagon));
+}
+
+#[test]
+fn union() {
+    let lhs = disk(10).into_iter().collect::<CellTreemap>();
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let far = index.grid_disk::<Vec<_>>(15).pop().expect("far cell");
+    let rhs = far
+        .grid_disk::<Vec<_>>(10)
+        .into_iter()
+        .map(|cell| cell.parent(Resolution::Nine).expect("parent"))
+        .collect::<CellTreemap>();
+
+    let expected = CellIndex::uncompact(lhs.iter(), Resolution::Ten)
+        .chain(CellIndex::uncompact(rhs.iter(), Resolution::Ten))
+        .collect::<BTreeSet<_>>();
+    let result = lhs.union(&rhs);
+    assert_eq!(
+        CellIndex::uncompact(result.iter(), Resolution::Ten)
+            .collect::<BTreeSet<_>>(),
+        expected
+    );
+    assert_eq!(rhs.union(&lhs), result);
+    assert_eq!(lhs.union(&CellTreemap::new()), lhs);
+}
+
+#[test]
+fn difference() {
+    let lhs = disk(10).into_iter().collect::<CellTreemap>();
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let far = index.grid_disk::<Vec<_>>(15).pop().expect("far cell");
+    let rhs = far
+        .grid_disk::<Vec<_>>(10)
+        .into_iter()
+        .flat_map(|cell| cell.children(Resolution::Eleven).skip(1))
+        .collect::<CellTreemap>();
+
+    for (lhs, rhs) in [(&lhs, &rhs), (&rhs, &lhs)] {
+        let expected = CellIndex::uncompact(lhs.iter(), Resolution::Eleven)
+            .filter(|&cell| !rhs.contains(cell))
+            .collect::<BTreeSet<_>>();
+        let result = lhs.difference(rhs);
+        assert!(!expected.is_empty());
+        assert_eq!(
+            CellIndex::uncompact(result.iter(), Resolution::Eleven)
+                .collect::<BTreeSet<_>>(),
+            expected
+        );
+        assert!(result.intersection(rhs).is_empty());
+        assert_eq!(result.union(&lhs.intersection(rhs)), *lhs);
+    }
+    assert_eq!(lhs.difference(&CellTreemap::new()), lhs);
+    assert!(lhs.difference(&lhs).is_empty());
+}
+
+#[test]
+fn difference_pentagon() {
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+    let child = pentagon.center_child(Resolution::Two).expect("child");
+    let lhs = CellTreemap::from_iter([pentagon]);
+    let rhs = CellTreemap::from_iter([child]);
+
+    let result = lhs.difference(&rhs);
+    assert!(!result.contains(child));
+    assert_eq!(
+        CellIndex::uncompact(result.iter(), Resolution::Two).count(),
+        40
+    );
+}
+
+#[test]
+fn serialization_roundtrip() {
+    let mut treemap = disk(30).into_iter().collect::<CellTreemap>();
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+    treemap.extend(pentagon.children(Resolution::Three).skip(3));
+
+    let bytes = treemap.to_bytes();
+    let result = CellTreemap::from_bytes(&bytes).expect("valid treemap");
+    assert_eq!(result, treemap);
+    assert_eq!(result.to_bytes(), bytes);
+
+    let empty = CellTreemap::new();
+    let result = CellTreemap::from_bytes(&empty.to_bytes()).expect("empty");
+    assert!(result.is_empty());
+}
+
+#[test]
+fn serialization_invalid() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let bytes = CellTreemap::from_iter([index]).to_bytes();
+
+    // Truncated.
+    assert!(CellTreemap::from_bytes(&bytes[..8]).is_err());
+    assert!(CellTreemap::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    // Trailing bytes.
+    let mut trailing = bytes.clone();
+    trailing.push(0x80);
+    assert!(CellTreemap::from_bytes(&trailing).is_err());
+    // Unknown base cell.
+    let mut header = bytes.clone();
+    header[15] |= 0x80;
+    assert!(CellTreemap::from_bytes(&header).is_err());
+    // Empty node.
+    let mut empty = bytes.clone();
+    empty[16] = 0;
+    assert!(CellTreemap::from_bytes(&empty).is_err());
+    // Uncompacted node (every child is in the set).
+    let mut uncompacted = vec![0; 16];
+    uncompacted[0] = 1;
+    uncompacted.push(0x7f);
+    uncompacted.extend([0x80; 7]);
+    assert!(CellTreemap::from_bytes(&uncompacted).is_err());
+    // Deleted child of a pentagon (base cell 4).
+    let mut pentagon = vec![0; 16];
+    pentagon[0] = 1 << 4;
+    pentagon.extend([0x03, 0x80, 0x80]);
+    assert!(CellTreemap::from_bytes(&pentagon).is_err());
+    // Too deep.
+    let mut deep = vec![0; 16];
+    deep[0] = 1;
+    deep.extend([0x01; 16]);
+    deep.push(0x80);
+    assert!(CellTreemap::from_bytes(&deep).is_err());
+}
@@ -0,0 +1,224 @@
+use h3o::{
+    adjacency_pairs, weights_matrix, CellIndex, DirectedEdgeIndex, EdgeWeights,
+    LatLng, Resolution, WeightScheme,
+};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
+
+fn cells(k: u32) -> Vec<CellIndex> {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    index.grid_disk::<Vec<_>>(k)
+}
+
+#[test]
+fn queen() {
+    let cells = cells(2);
+    let matrix = weights_matrix(&cells, WeightScheme::Queen);
+
+    assert_eq!(matrix.len(), cells.len());
+    assert_eq!(matrix.offsets().len(), cells.len() + 1);
+    for (i, cell) in cells.iter().enumerate() {
+        let row = matrix.row(i).collect::<Vec<_>>();
+        let expected = cells
+            .iter()
+            .enumerate()
+            .filter(|&(j, other)| {
+                j != i && cell.grid_distance(*other).expect("distance") == 1
+            })
+            .map(|(j, _)| (j, 1.))
+            .collect::<Vec<_>>();
+        assert_eq!(row, expected, "row {i}");
+    }
+}
+
+#[test]
+fn inverse_distance() {
+    let cells = cells(3);
+    let matrix = weights_matrix(&cells, WeightScheme::InverseDistance { k: 2 });
+
+    for (i, cell) in cells.iter().enumerate() {
+        for (j, weight) in matrix.row(i) {
+            let distance = cell.grid_distance(cells[j]).expect("distance");
+            assert!((1..=2).contains(&distance));
+            assert_eq!(weight, 1. / f64::from(distance));
+        }
+    }
+    // Symmetric.
+    for i in 0..cells.len() {
+        for (j, weight) in matrix.row(i) {
+            assert!(matrix.row(j).any(|(k, w)| k == i && w == weight));
+        }
+    }
+    assert_eq!(matrix.row(0).count(), 18);
+}
+
+#[test]
+fn missing_neighbors() {
+    let cells = cells(1);
+    let sparse = [cells[0], cells[3]];
+    let matrix = weights_matrix(&sparse, WeightScheme::Queen);
+
+    assert_eq!(matrix.indices(), &[1, 0]);
+    assert_eq!(matrix.weights(), &[1., 1.]);
+    assert_eq!(matrix.offsets(), &[0, 1, 2]);
+}
+
+#[test]
+fn isolated() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let far = CellIndex::try_from(0x8a2a1072b59ffff).expect("far");
+    let matrix = weights_matrix(&[index, far], WeightScheme::Queen);
+
+    assert_eq!(matrix.row(0).count(), 0);
+    assert_eq!(matrix.row(1).count(), 0);
+    assert_eq!(matrix.row(2).count(), 0);
+}
+
+#[test]
+fn empty() {
+    let matrix = weights_matrix(&[], WeightScheme::Queen);
+
+    assert!(matrix.is_empty());
+    assert_eq!(matrix.offsets(), &[0]);
+}
+
+#[test]
+fn adjacency() {
+    let cells = cells(2);
+    let mut pairs = adjacency_pairs(&cells);
+    pairs.sort_unstable();
+
+    let mut expected = Vec::new();
+    for (i, &a) in cells.iter().enumerate() {
+        for &b in &cells[i + 1..] {
+            if a.is_neighbor_with(b).expect("same resolution") {
+                expected.push((a.min(b), a.max(b)));
+            }
+        }
+    }
+    expected.sort_unstable();
+    assert_eq!(pairs, expected);
+}
+
+#[test]
+fn adjacency_pentagon_and_duplicates() {
+    let pentagon = CellIndex::base_cells()
+        .find(|cell| cell.is_pentagon())
+        .and_then(|cell| cell.center_child(Resolution::Five))
+        .expect("pentagon");
+    let mut cells = pentagon.grid_disk::<Vec<_>>(1);
+    cells.extend(cells.clone());
+    // Different resolution, never adjacent.
+    cells.push(pentagon.center_child(Resolution::Six).expect("child"));
+
+    let pairs = adjacency_pairs(&cells);
+    // 5 spokes, and 5 edges around the pentagon.
+    assert_eq!(pairs.len(), 10);
+    assert!(pairs.iter().all(|(a, b)| a < b));
+}
+
+#[test]
+fn edge_weights_from_fn() {
+    let cells = cells(2);
+    let weights = EdgeWeights::from_fn(&cells, |origin, destination| {
+        let (from, to) = (LatLng::from(origin), LatLng::from(destination));
+        Some(if to.lat() > from.lat() { 2. } else { 1. })
+    });
+
+    // Every ordered pair of adjacent cells.
+    assert_eq!(weights.len(), adjacency_pairs(&cells).len() * 2);
+    for (edge, cost) in weights.iter() {
+        let (origin, destination) = edge.cells();
+        let reverse = destination.edge(origin).expect("reverse edge");
+        assert_eq!(cost + weights.get(reverse).expect("reverse cost"), 3.);
+        assert_eq!(weights.cost(origin, destination), Some(cost));
+    }
+    let far = cells[0].grid_disk::<Vec<_>>(5).pop().expect("far cell");
+    assert_eq!(weights.cost(cells[0], far), None);
+}
+
+#[test]
+fn edge_weights_one_way() {
+    let cells = cells(1);
+    let center = cells[0];
+    let weights = EdgeWeights::from_fn(&cells, |origin, _| {
+        (origin == center).then_some(1.)
+    });
+
+    assert_eq!(weights.len(), 6);
+    assert_eq!(weights.successors(center).count(), 6);
+    assert!(cells[1..]
+        .iter()
+        .all(|&cell| weights.successors(cell).count() == 0));
+}
+
+#[test]
+fn edge_weights_update() {
+    let edge = DirectedEdgeIndex::try_from(0x13a194e699ab7fff).expect("edge");
+    let mut weights = [(edge, 1.)].into_iter().collect::<EdgeWeights>();
+
+    assert_eq!(weights.insert(edge, 3.), Some(1.));
+    assert_eq!(weights.get(edge), Some(3.));
+    assert_eq!(weights.remove(edge), Some(3.));
+    assert_eq!(weights.remove(edge), None);
+    assert!(weights.is_empty());
+    assert_eq!(weights, EdgeWeights::new());
+}
+
+// Cost of the shortest path between two cells.
+fn shortest_path(weights: &EdgeWeights, from: CellIndex, to: CellIndex) -> f64 {
+    let mut costs = HashMap::from([(from, 0.)]);
+    // Costs are non-negative, so their bits are ordered like them.
+    let mut queue = BinaryHeap::from([(Reverse(0_f64.to_bits()), from)]);
+
+    while let Some((Reverse(cost), cell)) = queue.pop() {
+        let cost = f64::from_bits(cost);
+        if cell == to {
+            return cost;
+        }
+        for (next, step) in weights.successors(cell) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_none_or(|&known| next_cost < known) {
+                costs.insert(next, next_cost);
+                queue.push((Reverse(next_cost.to_bits()), next));
+            }
+        }
+    }
+
+    f64::INFINITY
+}
+
+#[test]
+fn edge_weights_asymmetric_path() {
+    let cells = cells(5);
+    // Going up costs more than going down.
+    let weights = EdgeWeights::from_fn(&cells, |origin, destination| {
+        let (from, to) = (LatLng::from(origin), LatLng::from(destination));
+        Some(if to.lat() > from.lat() { 3. } else { 1. })
+    });
+    let (south, north) =
+        cells
+            .iter()
+            .fold((cells[0], cells[0]), |(south, north), &cell| {
+                let lat = LatLng::from(cell).lat();
+                (
+                    if lat < LatLng::from(south).lat() {
+                        cell
+                    } else {
+                        south
+                    },
+                    if lat > LatLng::from(north).lat() {
+                        cell
+                    } else {
+                        north
+                    },
+                )
+            });
+
+    let up = shortest_path(&weights, south, north);
+    let down = shortest_path(&weights, north, south);
+    assert!(up.is_finite() && down.is_finite());
+    assert!(up > down, "{up} / {down}");
+}